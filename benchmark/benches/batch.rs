@@ -0,0 +1,58 @@
+use criterion::{black_box, BenchmarkId, Criterion};
+
+use fastnum::{
+    decimal::{batch, Context, Sign},
+    D128, U128,
+};
+
+fn values(len: u64) -> Vec<D128> {
+    (0..len)
+        .map(|i| {
+            let sign = if i % 3 == 0 { Sign::Minus } else { Sign::Plus };
+            D128::from_parts(U128::from_digit(i * 7919 % 1_000_003), -2, sign)
+        })
+        .collect()
+}
+
+pub fn sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum");
+
+    for len in [16, 1024] {
+        let values = values(len);
+
+        group.bench_with_input(BenchmarkId::new("D128 fold", len), &values, |b, values| {
+            b.iter(|| black_box(values.iter().copied().sum::<D128>()))
+        });
+
+        group.bench_with_input(BenchmarkId::new("D128 sum_slice", len), &values, |b, values| {
+            b.iter(|| black_box(batch::sum_slice(values, Context::default())))
+        });
+    }
+
+    group.finish();
+}
+
+pub fn dot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dot");
+
+    for len in [16, 1024] {
+        let values = (values(len), values(len));
+
+        group.bench_with_input(BenchmarkId::new("D128 fold", len), &values, |b, (lhs, rhs)| {
+            b.iter(|| {
+                black_box(
+                    lhs.iter()
+                        .zip(rhs)
+                        .map(|(lhs, rhs)| *lhs * *rhs)
+                        .sum::<D128>(),
+                )
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("D128 dot", len), &values, |b, (lhs, rhs)| {
+            b.iter(|| black_box(batch::dot(lhs, rhs, Context::default())))
+        });
+    }
+
+    group.finish();
+}
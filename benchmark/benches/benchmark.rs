@@ -1,4 +1,6 @@
 mod allocation;
+mod batch;
+mod format;
 mod math;
 mod parse;
 
@@ -6,6 +8,8 @@ use criterion::{criterion_group, criterion_main};
 
 criterion_group!(allocation, allocation::vector);
 criterion_group!(parse, parse::from_str);
-criterion_group!(math, math::div, math::add);
+criterion_group!(format, format::to_string);
+criterion_group!(math, math::div, math::div_inexact, math::add, math::mul_wide, math::div_wide);
+criterion_group!(batch, batch::sum, batch::dot);
 
-criterion_main!(allocation, parse, math);
+criterion_main!(allocation, parse, format, math, batch);
@@ -0,0 +1,187 @@
+//! Timing leakage test of the constant-time functions in the style of dudect
+//! (Reparaz, Balasch and Verbauwhede, "Dude, is my code constant time?").
+//!
+//! Every target is timed on two classes of inputs: a fixed value chosen to
+//! trigger the shortcuts of the variable-time code (such as zero digits) and
+//! uniformly random values. The classes are interleaved randomly, and Welch's
+//! t-test compares the timing distributions, also after cropping the slowest
+//! measurements, which are mostly noise. `|t|` above 10 means the timing
+//! certainly depends on the inputs, while values below 4.5 show no leak at
+//! the number of measurements taken.
+//!
+//! The variable-time methods of `UInt` are measured as controls, they are
+//! expected to leak.
+//!
+//! ```text
+//! cargo bench --bench dudect [filter]
+//! DUDECT_SAMPLES=10000000 cargo bench --bench dudect ct::mod_mul
+//! ```
+
+use std::hint::black_box;
+
+use fastnum::{int::ct, test_util::Xorshift, U256};
+
+/// `|t|` above which the timing certainly depends on the inputs.
+const LEAK_THRESHOLD: f64 = 10.0;
+
+/// Default number of measurements per target.
+const DEFAULT_SAMPLES: usize = 1_000_000;
+
+/// Percentiles at which the measurements are cropped.
+const PERCENTILES: [f64; 6] = [1.0, 0.99, 0.95, 0.9, 0.75, 0.5];
+
+type Target = (&'static str, bool, fn(&U256, &U256) -> U256);
+
+const TARGETS: [Target; 8] = [
+    ("ct::eq", true, |a, b| U256::from(ct::eq(a, b).to_word())),
+    ("ct::lt", true, |a, b| U256::from(ct::lt(a, b).to_word())),
+    ("ct::overflowing_add", true, |a, b| {
+        ct::overflowing_add(a, b).0
+    }),
+    ("ct::wrapping_mul", true, |a, b| ct::wrapping_mul(a, b)),
+    ("ct::rem", true, |a, b| ct::rem(a, b)),
+    ("ct::mod_mul", true, |a, b| ct::mod_mul(a, b, &MODULUS)),
+    ("UInt::wrapping_mul (control)", false, |a, b| {
+        a.wrapping_mul(*b)
+    }),
+    ("UInt::rem (control)", false, |a, b| *a % *b),
+];
+
+/// Odd modulus of `ct::mod_mul`.
+const MODULUS: U256 = U256::MAX.wrapping_sub(U256::from_digit(188));
+
+fn main() {
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with("--"));
+    let samples = std::env::var("DUDECT_SAMPLES")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_SAMPLES);
+
+    let mut leaks = false;
+
+    for (name, constant_time, f) in TARGETS {
+        if filter
+            .as_ref()
+            .is_some_and(|filter| !name.contains(filter.as_str()))
+        {
+            continue;
+        }
+
+        let t = measure(f, samples);
+        let verdict = if t.abs() > LEAK_THRESHOLD {
+            "leaks"
+        } else if t.abs() > 4.5 {
+            "may leak"
+        } else {
+            "no leak found"
+        };
+
+        println!("{name:<28} max |t| = {:>10.2}  {verdict}", t.abs());
+
+        leaks |= constant_time && t.abs() > LEAK_THRESHOLD;
+    }
+
+    if leaks {
+        std::process::exit(1);
+    }
+}
+
+/// Takes the measurements and returns the largest t statistic over the
+/// cropped sets.
+fn measure(f: fn(&U256, &U256) -> U256, samples: usize) -> f64 {
+    let mut rng = Xorshift::new(0x2545_f491_4f6c_dd1d);
+
+    // The fixed class: small operands, which are all zero digits but one.
+    let fixed = U256::from_digit(0x1234_5678);
+
+    // Inputs are generated up front, so that only the call is timed.
+    let inputs: Vec<(bool, U256, U256)> = (0..samples)
+        .map(|_| {
+            let class = rng.next_u64() & 1 == 1;
+            let random = U256::from_digits([rng.next_u64(), rng.next_u64(), rng.next_u64(), rng.next_u64()]);
+            let lhs = if class { random } else { fixed };
+            let rhs = U256::from_digits([rng.next_u64(), rng.next_u64(), rng.next_u64(), rng.next_u64() | 1]);
+            (class, lhs, rhs)
+        })
+        .collect();
+
+    let mut times = Vec::with_capacity(samples);
+
+    for (_, lhs, rhs) in &inputs {
+        let start = ticks();
+        black_box(f(black_box(lhs), black_box(rhs)));
+        times.push(ticks() - start);
+    }
+
+    let mut sorted = times.clone();
+    sorted.sort_unstable();
+
+    PERCENTILES
+        .iter()
+        .map(|p| {
+            let threshold = sorted[((sorted.len() - 1) as f64 * p) as usize];
+            let mut classes = [Welford::default(), Welford::default()];
+
+            for ((class, _, _), &time) in inputs.iter().zip(&times) {
+                if time <= threshold {
+                    classes[*class as usize].push(time as f64);
+                }
+            }
+
+            welch_t(&classes[0], &classes[1])
+        })
+        .fold(0.0, |max: f64, t| max.max(t.abs()))
+}
+
+/// Online mean and variance.
+#[derive(Default)]
+struct Welford {
+    n: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Welford {
+    fn push(&mut self, x: f64) {
+        self.n += 1.0;
+        let delta = x - self.mean;
+        self.mean += delta / self.n;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        self.m2 / (self.n - 1.0)
+    }
+}
+
+fn welch_t(a: &Welford, b: &Welford) -> f64 {
+    if a.n < 2.0 || b.n < 2.0 {
+        return 0.0;
+    }
+
+    let se = (a.variance() / a.n + b.variance() / b.n).sqrt();
+
+    if se == 0.0 {
+        0.0
+    } else {
+        (a.mean - b.mean) / se
+    }
+}
+
+/// Time stamp counter, or nanoseconds where it is not available.
+#[inline(always)]
+fn ticks() -> u64 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        // SAFETY: the time stamp counter is available on every x86_64 CPU.
+        unsafe { core::arch::x86_64::_rdtsc() }
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        use std::{sync::OnceLock, time::Instant};
+
+        static START: OnceLock<Instant> = OnceLock::new();
+        START.get_or_init(Instant::now).elapsed().as_nanos() as u64
+    }
+}
@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use criterion::{black_box, BenchmarkId, Criterion};
+
+use bigdecimal::BigDecimal;
+use fastnum::{decimal::Sign, D1024, D128, D8192, U1024, U128, U8192};
+
+macro_rules! macro_impl {
+    ($group: ident, $D: ident, $digits: expr) => {{
+        let d = $D::from_parts($digits, 10, Sign::Plus);
+        let s = d.to_string();
+        let size = s.len();
+
+        $group.bench_with_input(BenchmarkId::new("fastnum", size), &d, |b, d| {
+            b.iter(|| black_box(d.to_string()))
+        });
+
+        let big = BigDecimal::from_str(&s).unwrap();
+        $group.bench_with_input(BenchmarkId::new("bigdecimal", size), &big, |b, big| {
+            b.iter(|| black_box(big.to_string()))
+        });
+    }};
+}
+
+pub fn to_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_string");
+
+    macro_impl!(group, D128, U128::from_digit(123456789));
+    macro_impl!(group, D128, U128::MAX);
+    macro_impl!(group, D1024, U1024::MAX);
+    macro_impl!(group, D8192, U8192::MAX / U8192::from_digit(3));
+
+    group.finish();
+}
@@ -1,9 +1,9 @@
 use std::str::FromStr;
 
-use criterion::{black_box, Criterion};
+use criterion::{black_box, BenchmarkId, Criterion};
 
 use bigdecimal::BigDecimal;
-use fastnum::{udec128};
+use fastnum::{dec1024, dec256, decimal::Sign, udec128, D2048, D4096, D8192, U2048, U4096, U8192};
 
 pub fn add(c: &mut Criterion) {
     let mut group = c.benchmark_group("a+b");
@@ -50,3 +50,97 @@ pub fn div(c: &mut Criterion) {
 
     group.finish();
 }
+
+pub fn div_inexact(c: &mut Criterion) {
+    let mut group = c.benchmark_group("a/b inexact");
+
+    group.bench_function("f64", |b| b.iter(|| black_box(2_f64 / 7_f64)));
+
+    let a = dec256!(2);
+    let b = dec256!(7);
+    group.bench_with_input("D256", &(a, b), |bench, (a, b)| {
+        bench.iter(|| black_box(*a / *b))
+    });
+
+    let a = dec1024!(2);
+    let b = dec1024!(7);
+    group.bench_with_input("D1024", &(a, b), |bench, (a, b)| {
+        bench.iter(|| black_box(*a / *b))
+    });
+
+    let a = dec1024!(125348);
+    let b = dec1024!(352.2283);
+    group.bench_with_input("D1024 long divisor", &(a, b), |bench, (a, b)| {
+        bench.iter(|| black_box(*a / *b))
+    });
+
+    let a = BigDecimal::from(2);
+    let b = BigDecimal::from(7);
+
+    group.bench_with_input("BigDecimal", &(a, b), |bench, (a, b)| {
+        bench.iter(|| black_box(a / b))
+    });
+
+    group.finish();
+}
+
+macro_rules! mul_impl {
+    ($group: ident, $D: ident, $U: ident) => {{
+        // Operands of half the width, so that the product is exact.
+        let bits = $U::BITS / 2 - 1;
+        let a = $D::from_parts($U::MAX >> ($U::BITS - bits), 0, Sign::Plus);
+        let b = $D::from_parts(($U::MAX >> ($U::BITS - bits)) / $U::from_digit(7), 0, Sign::Plus);
+
+        $group.bench_with_input(BenchmarkId::new("fastnum", stringify!($D)), &(a, b), |bench, (a, b)| {
+            bench.iter(|| black_box(*a * *b))
+        });
+
+        let a = BigDecimal::from_str(&a.to_string()).unwrap();
+        let b = BigDecimal::from_str(&b.to_string()).unwrap();
+
+        $group.bench_with_input(BenchmarkId::new("bigdecimal", stringify!($D)), &(a, b), |bench, (a, b)| {
+            bench.iter(|| black_box(a * b))
+        });
+    }};
+}
+
+pub fn mul_wide(c: &mut Criterion) {
+    let mut group = c.benchmark_group("a*b wide");
+
+    mul_impl!(group, D2048, U2048);
+    mul_impl!(group, D4096, U4096);
+    mul_impl!(group, D8192, U8192);
+
+    group.finish();
+}
+
+macro_rules! div_impl {
+    ($group: ident, $D: ident, $U: ident) => {{
+        // The dividend is an exact product, so that the quotient is exact.
+        let bits = $U::BITS / 2 - 1;
+        let b = ($U::MAX >> ($U::BITS - bits)) / $U::from_digit(7);
+        let a = $D::from_parts(($U::MAX >> ($U::BITS - bits)) * b, 0, Sign::Plus);
+        let b = $D::from_parts(b, 0, Sign::Plus);
+
+        $group.bench_with_input(BenchmarkId::new("fastnum", stringify!($D)), &(a, b), |bench, (a, b)| {
+            bench.iter(|| black_box(*a / *b))
+        });
+
+        let a = BigDecimal::from_str(&a.to_string()).unwrap();
+        let b = BigDecimal::from_str(&b.to_string()).unwrap();
+
+        $group.bench_with_input(BenchmarkId::new("bigdecimal", stringify!($D)), &(a, b), |bench, (a, b)| {
+            bench.iter(|| black_box(a / b))
+        });
+    }};
+}
+
+pub fn div_wide(c: &mut Criterion) {
+    let mut group = c.benchmark_group("a/b wide");
+
+    div_impl!(group, D2048, U2048);
+    div_impl!(group, D4096, U4096);
+    div_impl!(group, D8192, U8192);
+
+    group.finish();
+}
@@ -7,14 +7,23 @@ pub mod extras;
 #[cfg(not(feature = "test-util"))]
 pub(crate) mod extras;
 
+pub mod batch;
+pub mod codec;
+
+pub(crate) mod compact;
 pub(crate) mod dec;
 pub(crate) mod doc;
+pub(crate) mod fixed;
 pub(crate) mod round;
+pub(crate) mod ucompact;
 pub(crate) mod udec;
+pub(crate) mod ufixed;
 
+mod bytes;
 mod category;
 mod context;
 mod flags;
+mod interchange;
 mod sign;
 mod signals;
 
@@ -33,15 +42,21 @@ pub use flags::Flags;
 #[cfg(not(feature = "test-util"))]
 pub(crate) use flags::Flags;
 
+pub use bytes::{DecimalBytes, OrderedBytes};
 pub use category::Category;
+pub use compact::CompactDecimal;
 pub use context::{Context, RoundingMode, SignalsTraps};
 pub use dec::Decimal;
 pub use errors::ParseError;
+pub use fixed::FixedDecimal;
+pub use interchange::Interchange;
 pub use sign::Sign;
 pub use signals::Signal;
+pub use ucompact::UnsignedCompactDecimal;
 pub use udec::UnsignedDecimal;
+pub use ufixed::UnsignedFixedDecimal;
 
-use crate::decimal::doc::decimal_type_doc;
+use crate::decimal::doc::{decimal_type_doc, fixed_decimal_type_doc};
 
 macro_rules! decimal_types {
     ( $($bits: literal $u: ident $s: ident; ) *)  => {
@@ -55,7 +70,14 @@ macro_rules! decimal_types {
     };
 }
 
+#[doc = decimal_type_doc!(32, "unsigned")]
+pub type UD32 = UnsignedCompactDecimal;
+
+#[doc = decimal_type_doc!(32, "signed")]
+pub type D32 = CompactDecimal;
+
 decimal_types!(
+    64 UD64 D64;
     128 UD128 D128;
     256 UD256 D256;
     512 UD512 D512;
@@ -64,3 +86,25 @@ decimal_types!(
     4096 UD4096 D4096;
     8192 UD8192 D8192;
 );
+
+macro_rules! fixed_decimal_types {
+    ( $($bits: literal $u: ident $s: ident; ) *)  => {
+        $(
+            #[doc = fixed_decimal_type_doc!($bits, "unsigned")]
+            pub type $u<const SCALE: i16> = UnsignedFixedDecimal::<{$bits / 64}, SCALE>;
+
+            #[doc = fixed_decimal_type_doc!($bits, "signed")]
+            pub type $s<const SCALE: i16> = FixedDecimal::<{$bits / 64}, SCALE>;
+        )*
+    };
+}
+
+fixed_decimal_types!(
+    128 UFD128 FD128;
+    256 UFD256 FD256;
+    512 UFD512 FD512;
+    1024 UFD1024 FD1024;
+    2048 UFD2048 FD2048;
+    4096 UFD4096 FD4096;
+    8192 UFD8192 FD8192;
+);
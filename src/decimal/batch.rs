@@ -0,0 +1,16 @@
+//! # Batch operations
+//!
+//! Kernels operating on slices of [`Decimal`](crate::decimal::Decimal)
+//! values.
+//!
+//! When all the values of a slice are finite and share the same scale (which
+//! is typical for amounts of money or other fixed-point data), reductions
+//! accumulate the coefficients exactly in a double-width integer and round the
+//! result only once at the end. The accumulator keeps the limbs in independent
+//! lanes, so the inner loops are free of carry chains and can be vectorised by
+//! the compiler where the target allows.
+//!
+//! Values with mixed scales or special values are processed one by one in
+//! order with the regular arithmetic operations.
+
+pub use crate::decimal::dec::batch::{add_slices, dot, scale_slice, sum_slice};
@@ -0,0 +1,288 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+};
+
+use crate::{decimal::Flags, int::UInt};
+
+/// Fixed-size byte representation of a [Decimal](crate::decimal::Decimal)
+/// or an [UnsignedDecimal](crate::decimal::UnsignedDecimal) with `N` 64-bit
+/// limbs, as returned by their `to_le_bytes` and `to_be_bytes` methods.
+///
+/// The representation is [`LEN`](Self::LEN) = `8 * N + 4` bytes long and has
+/// no padding, so it can be copied as is into shared memory or a memory
+/// mapped file. In the little-endian encoding the bytes are:
+///
+/// | Offset      | Size    | Field                                     |
+/// |-------------|---------|-------------------------------------------|
+/// | `0`         | `8 * N` | coefficient, least significant limb first |
+/// | `8 * N`     | `2`     | scale                                     |
+/// | `8 * N + 2` | `1`     | sign and special value flags              |
+/// | `8 * N + 3` | `1`     | raised signals                            |
+///
+/// The big-endian encoding has the same fields in the same order, but the
+/// coefficient and the scale are stored most significant byte first.
+///
+/// The bytes are accessed through [Self::as_bytes] or the [AsRef] and
+/// [AsMut] implementations, and the type compares and hashes like a byte
+/// slice.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{dec256, decimal::DecimalBytes, D256};
+///
+/// let bytes = dec256!(-1.5).to_le_bytes();
+///
+/// assert_eq!(bytes.as_ref().len(), DecimalBytes::<4>::LEN);
+/// assert_eq!(bytes.as_ref()[..8], 15u64.to_le_bytes());
+/// assert_eq!(D256::from_le_bytes(&bytes), dec256!(-1.5));
+///
+/// let mut buf = [0; 64];
+/// buf[..DecimalBytes::<4>::LEN].copy_from_slice(bytes.as_ref());
+///
+/// let bytes = DecimalBytes::<4>::from_slice(&buf).unwrap();
+/// assert_eq!(D256::from_le_bytes(&bytes), dec256!(-1.5));
+/// assert!(DecimalBytes::<4>::from_slice(&buf[..8]).is_none());
+/// ```
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct DecimalBytes<const N: usize> {
+    limbs: [[u8; 8]; N],
+    tail: [u8; 4],
+}
+
+impl<const N: usize> DecimalBytes<N> {
+    /// The size of the representation in bytes.
+    pub const LEN: usize = core::mem::size_of::<Self>();
+
+    /// All zero bytes, which decode to positive zero with scale zero.
+    pub const ZERO: Self = Self {
+        limbs: [[0; 8]; N],
+        tail: [0; 4],
+    };
+
+    #[inline]
+    pub(crate) const fn encode(digits: &UInt<N>, scale: i16, flags: Flags, be: bool) -> Self {
+        let mut result = Self::ZERO;
+        let digits = digits.digits();
+        let [flags, signals] = flags.to_bits();
+
+        let mut i = 0;
+        while i < N {
+            result.limbs[i] = if be {
+                digits[N - 1 - i].to_be_bytes()
+            } else {
+                digits[i].to_le_bytes()
+            };
+            i += 1;
+        }
+
+        let [s0, s1] = if be {
+            scale.to_be_bytes()
+        } else {
+            scale.to_le_bytes()
+        };
+
+        result.tail = [s0, s1, flags, signals];
+        result
+    }
+
+    /// Decodes the raw parts. The coefficient and scale of special values
+    /// are reset to zero, so that any bytes decode to a valid decimal.
+    #[inline]
+    pub(crate) const fn decode(&self, be: bool) -> (UInt<N>, i16, Flags) {
+        let [s0, s1, flags, signals] = self.tail;
+        let flags = Flags::from_bits([flags, signals]);
+
+        if flags.is_special() {
+            return (UInt::ZERO, 0, flags);
+        }
+
+        let mut digits = [0; N];
+
+        let mut i = 0;
+        while i < N {
+            if be {
+                digits[N - 1 - i] = u64::from_be_bytes(self.limbs[i]);
+            } else {
+                digits[i] = u64::from_le_bytes(self.limbs[i]);
+            }
+            i += 1;
+        }
+
+        let scale = if be {
+            i16::from_be_bytes([s0, s1])
+        } else {
+            i16::from_le_bytes([s0, s1])
+        };
+
+        (UInt::from_digits(digits), scale, flags)
+    }
+}
+
+/// Fixed-size order-preserving key of a [Decimal](crate::decimal::Decimal)
+/// with `N` 64-bit limbs, as returned by its `to_ordered_bytes` method.
+///
+/// Byte-wise lexicographic comparison of the keys agrees with the numeric
+/// comparison of the decimals:
+/// `-Infinity` < negative numbers < `-0` < `0` < positive numbers <
+/// `+Infinity` < `NaN`. Decimals that compare equal, such as `1.0` and `1`,
+/// have equal keys, so they can be used as is in ordered key-value stores.
+///
+/// The key is [`LEN`](Self::LEN) = `10 * N + 5` bytes long:
+///
+/// | Offset | Size     | Field                                                   |
+/// |--------|----------|---------------------------------------------------------|
+/// | `0`    | `1`      | class: sign, zero and special values                    |
+/// | `1`    | `4`      | adjusted exponent, big-endian with the sign bit flipped |
+/// | `5`    | `10 * N` | coefficient as packed BCD, most significant digit first |
+///
+/// The adjusted exponent is the exponent of the most significant digit, and
+/// the digits are left aligned and padded with zeros, so trailing zeros of
+/// the coefficient don't change the key. The exponent and digits of negative
+/// numbers are complemented, and are zero for zeros and special values.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct OrderedBytes<const N: usize> {
+    pub(crate) head: [u8; 5],
+    pub(crate) digits: [[u8; LIMB_BCD_BYTES]; N],
+}
+
+/// Packed BCD bytes per limb, enough for the 20 decimal digits a 64-bit limb
+/// may add to the coefficient.
+pub(crate) const LIMB_BCD_BYTES: usize = 10;
+
+impl<const N: usize> OrderedBytes<N> {
+    /// The size of the key in bytes.
+    pub const LEN: usize = core::mem::size_of::<Self>();
+
+    /// All zero bytes, the key of `-Infinity`.
+    pub const ZERO: Self = Self {
+        head: [0; 5],
+        digits: [[0; LIMB_BCD_BYTES]; N],
+    };
+}
+
+macro_rules! bytes_impl {
+    ($Ty: ident) => {
+        impl<const N: usize> $Ty<N> {
+            /// Returns the bytes as a slice.
+            #[inline]
+            pub const fn as_bytes(&self) -> &[u8] {
+                // SAFETY: `Self` is `repr(C)` and consists of byte arrays only,
+                // so it has no padding and its `LEN` bytes are initialized.
+                #[allow(unsafe_code)]
+                unsafe {
+                    core::slice::from_raw_parts(self as *const Self as *const u8, Self::LEN)
+                }
+            }
+
+            /// Returns the bytes as a mutable slice.
+            #[inline]
+            pub const fn as_bytes_mut(&mut self) -> &mut [u8] {
+                // SAFETY: see `as_bytes`, any bytes are a valid value of `Self`.
+                #[allow(unsafe_code)]
+                unsafe {
+                    core::slice::from_raw_parts_mut(self as *mut Self as *mut u8, Self::LEN)
+                }
+            }
+
+            /// Copies the bytes from the first [`LEN`](Self::LEN) bytes of
+            /// `bytes`, or returns `None` if it is too short.
+            #[inline]
+            pub const fn from_slice(bytes: &[u8]) -> Option<Self> {
+                if bytes.len() < Self::LEN {
+                    return None;
+                }
+
+                let mut result = Self::ZERO;
+                let dst = result.as_bytes_mut();
+
+                let mut i = 0;
+                while i < Self::LEN {
+                    dst[i] = bytes[i];
+                    i += 1;
+                }
+
+                Some(result)
+            }
+        }
+
+        impl<const N: usize> Default for $Ty<N> {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<const N: usize> AsRef<[u8]> for $Ty<N> {
+            #[inline]
+            fn as_ref(&self) -> &[u8] {
+                self.as_bytes()
+            }
+        }
+
+        impl<const N: usize> AsMut<[u8]> for $Ty<N> {
+            #[inline]
+            fn as_mut(&mut self) -> &mut [u8] {
+                self.as_bytes_mut()
+            }
+        }
+
+        impl<const N: usize> Borrow<[u8]> for $Ty<N> {
+            #[inline]
+            fn borrow(&self) -> &[u8] {
+                self.as_bytes()
+            }
+        }
+
+        impl<const N: usize> BorrowMut<[u8]> for $Ty<N> {
+            #[inline]
+            fn borrow_mut(&mut self) -> &mut [u8] {
+                self.as_bytes_mut()
+            }
+        }
+
+        impl<const N: usize> PartialEq for $Ty<N> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.as_bytes() == other.as_bytes()
+            }
+        }
+
+        impl<const N: usize> Eq for $Ty<N> {}
+
+        impl<const N: usize> PartialOrd for $Ty<N> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<const N: usize> Ord for $Ty<N> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.as_bytes().cmp(other.as_bytes())
+            }
+        }
+
+        impl<const N: usize> Hash for $Ty<N> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.as_bytes().hash(state)
+            }
+        }
+
+        impl<const N: usize> Debug for $Ty<N> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Debug::fmt(self.as_bytes(), f)
+            }
+        }
+    };
+}
+
+bytes_impl!(DecimalBytes);
+bytes_impl!(OrderedBytes);
@@ -0,0 +1,199 @@
+//! # Codecs
+//!
+//! Encoding and decoding of decimals in the fixed-width numeric formats of
+//! other systems:
+//!
+//! - [packed] – COBOL packed decimal (`COMP-3`), two BCD digits per byte and a
+//!   sign nibble;
+//! - [zoned] – COBOL zoned decimal (`DISPLAY`), one digit per byte and the
+//!   sign overpunched into the last byte;
+//! - [mysql] – MySQL binary `DECIMAL`, groups of 9 digits in 4 bytes.
+//!
+//! A field is described by its number of digits and its scale, e.g. COBOL
+//! `PIC S9(7)V99` and MySQL `DECIMAL(9, 2)` have 9 digits and scale 2. In
+//! COBOL a negative scale stands for the `P` scaling positions of
+//! `PIC 9(3)PP`. The codecs work on caller provided byte slices and don't
+//! allocate.
+//!
+//! Signed fields are encoded from and decoded to
+//! [Decimal](crate::decimal::Decimal), unsigned ones from and to
+//! [UnsignedDecimal](crate::decimal::UnsignedDecimal). Values are encoded
+//! only if they are representable exactly in the field, and malformed fields
+//! are rejected when decoding. Errors are reported as
+//! [ParseError](crate::decimal::ParseError):
+//!
+//! - [Empty](crate::decimal::ParseError::Empty) if the field has no digits;
+//! - [InvalidLiteral](crate::decimal::ParseError::InvalidLiteral) for a wrong
+//!   field length, an invalid digit, sign or pad, or `NaN`;
+//! - [PosOverflow](crate::decimal::ParseError::PosOverflow) or
+//!   [NegOverflow](crate::decimal::ParseError::NegOverflow) if the value
+//!   doesn't fit into the field or the decimal type;
+//! - [Inexact](crate::decimal::ParseError::Inexact) if the value has more
+//!   fractional digits than the field;
+//! - [Signed](crate::decimal::ParseError::Signed) for a negative value and an
+//!   unsigned field or decimal type.
+
+pub mod mysql;
+pub mod packed;
+pub mod zoned;
+
+use crate::{
+    decimal::{Decimal, ParseError},
+    int::{
+        div::Reciprocal,
+        math::{div_rem, mul_add_digit},
+        pow10::Pow10,
+        UInt,
+    },
+};
+
+/// Decimal digits per chunk converted at once, the most fitting into `u64`.
+const CHUNK: u32 = 19;
+
+/// Rescales the coefficient of the finite `d` to `scale` and checks that it
+/// has at most `digits` digits.
+const fn coefficient<const N: usize>(
+    d: &Decimal<N>,
+    digits: u32,
+    scale: i16,
+) -> Result<UInt<N>, ParseError> {
+    let flags = d.flags();
+    let d_scale = d.fractional_digits_count();
+
+    let overflow = if flags.is_negative() {
+        ParseError::NegOverflow
+    } else {
+        ParseError::PosOverflow
+    };
+
+    if flags.is_nan() {
+        return Err(ParseError::InvalidLiteral);
+    }
+
+    if flags.is_infinity() {
+        return Err(overflow);
+    }
+
+    let mut value = d.digits();
+
+    if value.is_zero() {
+        return Ok(value);
+    }
+
+    if d_scale > scale {
+        let k = (d_scale as i32 - scale as i32) as u32;
+
+        if k > Pow10::<N>::MAX_POWER {
+            return Err(ParseError::Inexact);
+        }
+
+        let (quotient, remainder) = div_rem(value, Pow10::<N>::power(k));
+
+        if !remainder.is_zero() {
+            return Err(ParseError::Inexact);
+        }
+
+        value = quotient;
+    } else if d_scale < scale {
+        let k = (scale as i32 - d_scale as i32) as u32;
+
+        if k > Pow10::<N>::MAX_POWER {
+            return Err(overflow);
+        }
+
+        match value.overflowing_mul(Pow10::<N>::power(k)) {
+            (value_, false) => value = value_,
+            (_, true) => return Err(overflow),
+        }
+    }
+
+    if Pow10::<N>::digits_count(value) > digits {
+        return Err(overflow);
+    }
+
+    Ok(value)
+}
+
+/// Digits of a coefficient, from the least significant one.
+struct Digits<const N: usize> {
+    rest: [u64; N],
+    chunk: u64,
+    left: u32,
+}
+
+impl<const N: usize> Digits<N> {
+    #[inline]
+    const fn new(value: UInt<N>) -> Self {
+        Self {
+            rest: *value.digits(),
+            chunk: 0,
+            left: 0,
+        }
+    }
+
+    /// Returns the next digit, zero past the most significant one.
+    #[inline]
+    const fn next(&mut self) -> u8 {
+        if self.left == 0 {
+            (self.rest, self.chunk) = Reciprocal::TEN_POW_19.div_rem_digits(&self.rest, 0);
+            self.left = CHUNK;
+        }
+
+        let digit = (self.chunk % 10) as u8;
+        self.chunk /= 10;
+        self.left -= 1;
+
+        digit
+    }
+}
+
+/// Accumulates a coefficient from its digits, the most significant first.
+struct Accumulator<const N: usize> {
+    value: UInt<N>,
+    chunk: u64,
+    power: u64,
+    overflow: bool,
+}
+
+impl<const N: usize> Accumulator<N> {
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            value: UInt::ZERO,
+            chunk: 0,
+            power: 1,
+            overflow: false,
+        }
+    }
+
+    #[inline]
+    const fn push(&mut self, digit: u8) {
+        self.chunk = self.chunk * 10 + digit as u64;
+        self.power *= 10;
+
+        if self.power == 10u64.pow(CHUNK) {
+            self.flush();
+        }
+    }
+
+    #[inline]
+    const fn flush(&mut self) {
+        let carry;
+        (self.value, carry) = mul_add_digit(self.value, self.power, self.chunk);
+        self.overflow |= carry != 0;
+        self.chunk = 0;
+        self.power = 1;
+    }
+
+    /// Returns the coefficient, or `None` if it doesn't fit into `UInt<N>`.
+    #[inline]
+    const fn finish(mut self) -> Option<UInt<N>> {
+        self.flush();
+
+        if self.overflow {
+            None
+        } else {
+            Some(self.value)
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! MySQL binary `DECIMAL` codec.
+//!
+//! The binary format of `DECIMAL(M, D)` columns in MySQL table files and
+//! row-based binary log events, with `M` = `precision` digits of which `D` =
+//! `scale` are fractional. The client/server protocol transfers decimals as
+//! text, so the format is mostly of interest to binary log (CDC) readers and
+//! storage tools.
+//!
+//! The integer and the fractional digits are split into groups of 9 digits,
+//! counting from the decimal point, and every full group is stored as a
+//! 4-byte big-endian integer. The leftover digits of the most significant
+//! integer group and of the least significant fractional group take 1 to 4
+//! bytes depending on their count. All bytes of a negative value are
+//! inverted, and the high bit of the first byte is flipped, so that the
+//! encoded values compare byte-wise as numbers.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastnum::{dec128, decimal::codec::mysql};
+//!
+//! // DECIMAL(14, 4)
+//! let mut buf = [0; mysql::len(14, 4)];
+//!
+//! mysql::encode(&dec128!(1234567890.1234), 14, 4, &mut buf).unwrap();
+//! assert_eq!(buf, [0x81, 0x0d, 0xfb, 0x38, 0xd2, 0x04, 0xd2]);
+//!
+//! mysql::encode(&dec128!(-1234567890.1234), 14, 4, &mut buf).unwrap();
+//! assert_eq!(buf, [0x7e, 0xf2, 0x04, 0xc7, 0x2d, 0xfb, 0x2d]);
+//! assert_eq!(mysql::decode::<2>(&buf, 14, 4), Ok(dec128!(-1234567890.1234)));
+//! ```
+
+use crate::decimal::{
+    codec::{coefficient, Accumulator, Digits},
+    Decimal, Flags, ParseError, UnsignedDecimal,
+};
+
+/// Digits per full group.
+const GROUP_DIGITS: u32 = 9;
+
+/// Bytes per full group.
+const GROUP_BYTES: usize = 4;
+
+/// Bytes taken by a group of `0..=9` digits.
+const GROUP_LEN: [usize; 10] = [0, 1, 1, 2, 2, 3, 3, 4, 4, 4];
+
+/// Returns the length in bytes of a `DECIMAL(precision, scale)` value.
+#[inline]
+pub const fn len(precision: u32, scale: u32) -> usize {
+    let int = precision.saturating_sub(scale);
+    part_len(int) + part_len(scale)
+}
+
+/// Decodes a `DECIMAL(precision, scale)` value.
+///
+/// `bytes` must be exactly [`len(precision, scale)`](len) bytes long.
+#[inline]
+pub const fn decode<const N: usize>(
+    bytes: &[u8],
+    precision: u32,
+    scale: u32,
+) -> Result<Decimal<N>, ParseError> {
+    if let Err(e) = check(bytes.len(), precision, scale) {
+        return Err(e);
+    }
+
+    let negative = bytes[0] & 0x80 == 0;
+    let mask = if negative { 0xff } else { 0 };
+
+    let mut acc = Accumulator::new();
+    let mut pos = 0;
+
+    let groups = Groups::new(precision, scale);
+
+    let mut g = 0;
+    while g < groups.count {
+        let size = groups.size(g);
+        let len = GROUP_LEN[size as usize];
+
+        let mut value = 0u32;
+
+        let mut i = 0;
+        while i < len {
+            let mut byte = bytes[pos] ^ mask;
+
+            if pos == 0 {
+                byte ^= 0x80;
+            }
+
+            value = value << 8 | byte as u32;
+            pos += 1;
+            i += 1;
+        }
+
+        if value >= 10u32.pow(size) {
+            return Err(ParseError::InvalidLiteral);
+        }
+
+        let mut k = size;
+        while k > 0 {
+            k -= 1;
+            acc.push((value / 10u32.pow(k) % 10) as u8);
+        }
+
+        g += 1;
+    }
+
+    let flags = if negative { Flags::NEG } else { Flags::EMPTY };
+
+    match acc.finish() {
+        Some(value) => Ok(Decimal::new(value, scale as i16, flags)),
+        None if negative => Err(ParseError::NegOverflow),
+        None => Err(ParseError::PosOverflow),
+    }
+}
+
+/// Decodes a `DECIMAL(precision, scale) UNSIGNED` value, failing with
+/// [ParseError::Signed] if it is negative.
+///
+/// `bytes` must be exactly [`len(precision, scale)`](len) bytes long.
+#[inline]
+pub const fn decode_unsigned<const N: usize>(
+    bytes: &[u8],
+    precision: u32,
+    scale: u32,
+) -> Result<UnsignedDecimal<N>, ParseError> {
+    match decode(bytes, precision, scale) {
+        Ok(d) if d.is_negative() => Err(ParseError::Signed),
+        Ok(d) => Ok(UnsignedDecimal::new(d)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Encodes `d` as a `DECIMAL(precision, scale)` value.
+///
+/// `buf` must be exactly [`len(precision, scale)`](len) bytes long, and is
+/// left unchanged if `d` can't be encoded.
+#[inline]
+pub const fn encode<const N: usize>(
+    d: &Decimal<N>,
+    precision: u32,
+    scale: u32,
+    buf: &mut [u8],
+) -> Result<(), ParseError> {
+    if let Err(e) = check(buf.len(), precision, scale) {
+        return Err(e);
+    }
+
+    let value = match coefficient(d, precision, scale as i16) {
+        Ok(value) => value,
+        Err(e) => return Err(e),
+    };
+
+    let negative = d.is_negative() && !value.is_zero();
+    let mask = if negative { 0xff } else { 0 };
+
+    let mut src = Digits::new(value);
+    let mut pos = buf.len();
+
+    let groups = Groups::new(precision, scale);
+
+    // Fill in the groups from the least significant one.
+    let mut g = groups.count;
+    while g > 0 {
+        g -= 1;
+
+        let size = groups.size(g);
+        let mut value = 0u32;
+
+        let mut k = 0;
+        while k < size {
+            value += src.next() as u32 * 10u32.pow(k);
+            k += 1;
+        }
+
+        let mut i = GROUP_LEN[size as usize];
+        while i > 0 {
+            i -= 1;
+            pos -= 1;
+            buf[pos] = value as u8 ^ mask;
+            value >>= 8;
+        }
+    }
+
+    buf[0] ^= 0x80;
+
+    Ok(())
+}
+
+/// Encodes `d` as a `DECIMAL(precision, scale) UNSIGNED` value.
+///
+/// `buf` must be exactly [`len(precision, scale)`](len) bytes long, and is
+/// left unchanged if `d` can't be encoded.
+#[inline]
+pub const fn encode_unsigned<const N: usize>(
+    d: &UnsignedDecimal<N>,
+    precision: u32,
+    scale: u32,
+    buf: &mut [u8],
+) -> Result<(), ParseError> {
+    encode(&d.to_signed(), precision, scale, buf)
+}
+
+/// Bytes taken by the integer or fractional part of `digits` digits.
+#[inline]
+const fn part_len(digits: u32) -> usize {
+    (digits / GROUP_DIGITS) as usize * GROUP_BYTES + GROUP_LEN[(digits % GROUP_DIGITS) as usize]
+}
+
+#[inline]
+const fn check(bytes: usize, precision: u32, scale: u32) -> Result<(), ParseError> {
+    if precision == 0 {
+        Err(ParseError::Empty)
+    } else if scale > precision || scale > i16::MAX as u32 || bytes != len(precision, scale) {
+        Err(ParseError::InvalidLiteral)
+    } else {
+        Ok(())
+    }
+}
+
+/// Digit groups of a value, the most significant first: the leftover
+/// integer digits, the full integer groups, the full fractional groups and
+/// the leftover fractional digits.
+struct Groups {
+    int_rest: u32,
+    frac_rest: u32,
+    count: u32,
+}
+
+impl Groups {
+    #[inline]
+    const fn new(precision: u32, scale: u32) -> Self {
+        let int = precision - scale;
+
+        Self {
+            int_rest: int % GROUP_DIGITS,
+            frac_rest: scale % GROUP_DIGITS,
+            count: int / GROUP_DIGITS + scale / GROUP_DIGITS + 2,
+        }
+    }
+
+    /// Returns the number of digits of the `g`-th group, zero for an empty
+    /// leftover group.
+    #[inline]
+    const fn size(&self, g: u32) -> u32 {
+        if g == 0 {
+            self.int_rest
+        } else if g == self.count - 1 {
+            self.frac_rest
+        } else {
+            GROUP_DIGITS
+        }
+    }
+}
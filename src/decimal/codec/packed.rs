@@ -0,0 +1,195 @@
+//! Packed decimal (`COMP-3`) codec.
+//!
+//! Each byte holds two BCD digits, the most significant first, and the low
+//! nibble of the last byte holds the sign: `C` for positive, `D` for negative
+//! and `F` for unsigned fields. A field of `digits` digits takes
+//! [`len(digits)`](len) = `digits / 2 + 1` bytes; if the number of digits is
+//! even, the high nibble of the first byte is a zero pad.
+//!
+//! Decoding also accepts the alternative signs `A` and `E` as positive and
+//! `B` as negative. A negative zero is decoded as such, but zero is always
+//! encoded as positive.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastnum::{dec128, udec128, decimal::{codec::packed, ParseError}, D128, UD128};
+//!
+//! // PIC S9(5)V99 COMP-3
+//! let mut buf = [0; packed::len(7)];
+//!
+//! packed::encode(&dec128!(-12345.67), 7, 2, &mut buf).unwrap();
+//! assert_eq!(buf, [0x12, 0x34, 0x56, 0x7d]);
+//! assert_eq!(packed::decode::<2>(&buf, 7, 2), Ok(dec128!(-12345.67)));
+//!
+//! // PIC 9(4) COMP-3
+//! let mut buf = [0; packed::len(4)];
+//!
+//! packed::encode_unsigned(&udec128!(42), 4, 0, &mut buf).unwrap();
+//! assert_eq!(buf, [0x00, 0x04, 0x2f]);
+//! assert_eq!(packed::decode_unsigned::<2>(&buf, 4, 0), Ok(udec128!(42)));
+//!
+//! assert_eq!(packed::encode(&dec128!(0.001), 4, 2, &mut buf), Err(ParseError::Inexact));
+//! assert_eq!(packed::decode::<2>(&[0x00, 0x0a, 0x2c], 4, 0), Err(ParseError::InvalidLiteral));
+//! ```
+
+use crate::decimal::{
+    codec::{coefficient, Accumulator, Digits},
+    Decimal, Flags, ParseError, UnsignedDecimal,
+};
+
+const POSITIVE: u8 = 0xc;
+const NEGATIVE: u8 = 0xd;
+const UNSIGNED: u8 = 0xf;
+
+/// Returns the length in bytes of a field with `digits` digits.
+#[inline]
+pub const fn len(digits: u32) -> usize {
+    digits as usize / 2 + 1
+}
+
+/// Decodes a signed or unsigned field of `digits` digits and `scale`.
+///
+/// `bytes` must be exactly [`len(digits)`](len) bytes long.
+#[inline]
+pub const fn decode<const N: usize>(
+    bytes: &[u8],
+    digits: u32,
+    scale: i16,
+) -> Result<Decimal<N>, ParseError> {
+    if digits == 0 {
+        return Err(ParseError::Empty);
+    }
+
+    let n = bytes.len();
+
+    if n != len(digits) {
+        return Err(ParseError::InvalidLiteral);
+    }
+
+    let flags = match bytes[n - 1] & 0x0f {
+        0xa | 0xc | 0xe | 0xf => Flags::EMPTY,
+        0xb | 0xd => Flags::NEG,
+        _ => return Err(ParseError::InvalidLiteral),
+    };
+
+    // The pad nibble of an even number of digits.
+    let first = 2 * n - 1 - digits as usize;
+
+    if first == 1 && bytes[0] >> 4 != 0 {
+        return Err(ParseError::InvalidLiteral);
+    }
+
+    let mut acc = Accumulator::new();
+
+    let mut i = first;
+    while i < 2 * n - 1 {
+        let digit = if i % 2 == 0 {
+            bytes[i / 2] >> 4
+        } else {
+            bytes[i / 2] & 0x0f
+        };
+
+        if digit > 9 {
+            return Err(ParseError::InvalidLiteral);
+        }
+
+        acc.push(digit);
+        i += 1;
+    }
+
+    match acc.finish() {
+        Some(value) => Ok(Decimal::new(value, scale, flags)),
+        None if flags.is_negative() => Err(ParseError::NegOverflow),
+        None => Err(ParseError::PosOverflow),
+    }
+}
+
+/// Decodes an unsigned field of `digits` digits and `scale`, failing with
+/// [ParseError::Signed] if the field holds a negative value.
+///
+/// `bytes` must be exactly [`len(digits)`](len) bytes long.
+#[inline]
+pub const fn decode_unsigned<const N: usize>(
+    bytes: &[u8],
+    digits: u32,
+    scale: i16,
+) -> Result<UnsignedDecimal<N>, ParseError> {
+    match decode(bytes, digits, scale) {
+        Ok(d) if d.is_negative() => Err(ParseError::Signed),
+        Ok(d) => Ok(UnsignedDecimal::new(d)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Encodes `d` into a signed field of `digits` digits and `scale`.
+///
+/// `buf` must be exactly [`len(digits)`](len) bytes long, and is left
+/// unchanged if `d` can't be encoded.
+#[inline]
+pub const fn encode<const N: usize>(
+    d: &Decimal<N>,
+    digits: u32,
+    scale: i16,
+    buf: &mut [u8],
+) -> Result<(), ParseError> {
+    let sign = if d.is_negative() && !d.is_zero() {
+        NEGATIVE
+    } else {
+        POSITIVE
+    };
+
+    encode_with_sign(d, sign, digits, scale, buf)
+}
+
+/// Encodes `d` into an unsigned field of `digits` digits and `scale`.
+///
+/// `buf` must be exactly [`len(digits)`](len) bytes long, and is left
+/// unchanged if `d` can't be encoded.
+#[inline]
+pub const fn encode_unsigned<const N: usize>(
+    d: &UnsignedDecimal<N>,
+    digits: u32,
+    scale: i16,
+    buf: &mut [u8],
+) -> Result<(), ParseError> {
+    encode_with_sign(&d.to_signed(), UNSIGNED, digits, scale, buf)
+}
+
+#[inline]
+const fn encode_with_sign<const N: usize>(
+    d: &Decimal<N>,
+    sign: u8,
+    digits: u32,
+    scale: i16,
+    buf: &mut [u8],
+) -> Result<(), ParseError> {
+    if digits == 0 {
+        return Err(ParseError::Empty);
+    }
+
+    let n = buf.len();
+
+    if n != len(digits) {
+        return Err(ParseError::InvalidLiteral);
+    }
+
+    let value = match coefficient(d, digits, scale) {
+        Ok(value) => value,
+        Err(e) => return Err(e),
+    };
+
+    // The coefficient has at most `digits` digits, so the pad nibble of an
+    // even number of digits is zero.
+    let mut src = Digits::new(value);
+    buf[n - 1] = src.next() << 4 | sign;
+
+    let mut i = n - 1;
+    while i > 0 {
+        i -= 1;
+        let low = src.next();
+        buf[i] = src.next() << 4 | low;
+    }
+
+    Ok(())
+}
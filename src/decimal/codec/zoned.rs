@@ -0,0 +1,224 @@
+//! Zoned decimal (`DISPLAY`) codec.
+//!
+//! Each byte holds one digit, the most significant first, so a field is as
+//! many bytes long as it has digits. In [EBCDIC](Charset::Ebcdic) a digit is
+//! stored with the zone nibble `F`, and the sign is overpunched into the zone
+//! of the last byte: `C` for positive and `D` for negative values, unsigned
+//! fields keep the zone `F`. In [ASCII](Charset::Ascii) the digits are `'0'`
+//! to `'9'` and the last byte of a signed field is the ASCII counterpart of
+//! the overpunched EBCDIC character: `'{'` and `'A'` to `'I'` for positive
+//! `0` to `9`, `'}'` and `'J'` to `'R'` for negative ones.
+//!
+//! Decoding also accepts the alternative zones `A` and `E` as positive and
+//! `B` as negative in EBCDIC, and unsigned digits in the last byte of any
+//! field. A negative zero is decoded as such, but zero is always encoded as
+//! positive.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastnum::{dec128, udec128, decimal::{codec::zoned::{self, Charset}, ParseError}};
+//!
+//! // PIC S9(3)V99
+//! let mut buf = [0; 5];
+//!
+//! zoned::encode(&dec128!(-123.45), 2, Charset::Ebcdic, &mut buf).unwrap();
+//! assert_eq!(buf, [0xf1, 0xf2, 0xf3, 0xf4, 0xd5]);
+//! assert_eq!(zoned::decode::<2>(&buf, 2, Charset::Ebcdic), Ok(dec128!(-123.45)));
+//!
+//! zoned::encode(&dec128!(-123.45), 2, Charset::Ascii, &mut buf).unwrap();
+//! assert_eq!(&buf, b"1234N");
+//! assert_eq!(zoned::decode::<2>(b"0012{", 2, Charset::Ascii), Ok(dec128!(1.20)));
+//!
+//! assert_eq!(zoned::decode_unsigned::<2>(b"1234N", 2, Charset::Ascii), Err(ParseError::Signed));
+//! assert_eq!(zoned::decode_unsigned::<2>(b"12345", 2, Charset::Ascii), Ok(udec128!(123.45)));
+//! ```
+
+use crate::decimal::{
+    codec::{coefficient, Accumulator, Digits},
+    Decimal, Flags, ParseError, UnsignedDecimal,
+};
+
+/// Character set of a zoned decimal field.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Charset {
+    /// EBCDIC, the native character set of mainframes.
+    Ebcdic,
+
+    /// ASCII, as found in files converted from EBCDIC or written on other
+    /// platforms.
+    Ascii,
+}
+
+/// Sign of an encoded digit.
+#[derive(Copy, Clone)]
+enum Zone {
+    Positive,
+    Negative,
+    Unsigned,
+}
+
+/// Decodes a signed or unsigned field of `bytes.len()` digits and `scale`.
+#[inline]
+pub const fn decode<const N: usize>(
+    bytes: &[u8],
+    scale: i16,
+    charset: Charset,
+) -> Result<Decimal<N>, ParseError> {
+    let n = bytes.len();
+
+    if n == 0 {
+        return Err(ParseError::Empty);
+    }
+
+    let mut acc = Accumulator::new();
+    let mut zone = Zone::Unsigned;
+
+    let mut i = 0;
+    while i < n {
+        let (digit, z) = match decode_digit(bytes[i], charset) {
+            Some(digit) => digit,
+            None => return Err(ParseError::InvalidLiteral),
+        };
+
+        // Only the last byte may carry the sign.
+        if i + 1 < n && !matches!(z, Zone::Unsigned) {
+            return Err(ParseError::InvalidLiteral);
+        }
+
+        acc.push(digit);
+        zone = z;
+        i += 1;
+    }
+
+    let flags = match zone {
+        Zone::Negative => Flags::NEG,
+        Zone::Positive | Zone::Unsigned => Flags::EMPTY,
+    };
+
+    match acc.finish() {
+        Some(value) => Ok(Decimal::new(value, scale, flags)),
+        None if flags.is_negative() => Err(ParseError::NegOverflow),
+        None => Err(ParseError::PosOverflow),
+    }
+}
+
+/// Decodes an unsigned field of `bytes.len()` digits and `scale`, failing
+/// with [ParseError::Signed] if the field holds a negative value.
+#[inline]
+pub const fn decode_unsigned<const N: usize>(
+    bytes: &[u8],
+    scale: i16,
+    charset: Charset,
+) -> Result<UnsignedDecimal<N>, ParseError> {
+    match decode(bytes, scale, charset) {
+        Ok(d) if d.is_negative() => Err(ParseError::Signed),
+        Ok(d) => Ok(UnsignedDecimal::new(d)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Encodes `d` into a signed field of `buf.len()` digits and `scale`.
+///
+/// `buf` is left unchanged if `d` can't be encoded.
+#[inline]
+pub const fn encode<const N: usize>(
+    d: &Decimal<N>,
+    scale: i16,
+    charset: Charset,
+    buf: &mut [u8],
+) -> Result<(), ParseError> {
+    let zone = if d.is_negative() && !d.is_zero() {
+        Zone::Negative
+    } else {
+        Zone::Positive
+    };
+
+    encode_with_zone(d, zone, scale, charset, buf)
+}
+
+/// Encodes `d` into an unsigned field of `buf.len()` digits and `scale`.
+///
+/// `buf` is left unchanged if `d` can't be encoded.
+#[inline]
+pub const fn encode_unsigned<const N: usize>(
+    d: &UnsignedDecimal<N>,
+    scale: i16,
+    charset: Charset,
+    buf: &mut [u8],
+) -> Result<(), ParseError> {
+    encode_with_zone(&d.to_signed(), Zone::Unsigned, scale, charset, buf)
+}
+
+#[inline]
+const fn encode_with_zone<const N: usize>(
+    d: &Decimal<N>,
+    zone: Zone,
+    scale: i16,
+    charset: Charset,
+    buf: &mut [u8],
+) -> Result<(), ParseError> {
+    let n = buf.len();
+
+    if n == 0 {
+        return Err(ParseError::Empty);
+    }
+
+    let value = match coefficient(d, n as u32, scale) {
+        Ok(value) => value,
+        Err(e) => return Err(e),
+    };
+
+    let mut src = Digits::new(value);
+    buf[n - 1] = encode_digit(src.next(), zone, charset);
+
+    let mut i = n - 1;
+    while i > 0 {
+        i -= 1;
+        buf[i] = encode_digit(src.next(), Zone::Unsigned, charset);
+    }
+
+    Ok(())
+}
+
+#[inline]
+const fn encode_digit(digit: u8, zone: Zone, charset: Charset) -> u8 {
+    match (charset, zone) {
+        (Charset::Ebcdic, Zone::Positive) => 0xc0 | digit,
+        (Charset::Ebcdic, Zone::Negative) => 0xd0 | digit,
+        (Charset::Ebcdic, Zone::Unsigned) => 0xf0 | digit,
+        (Charset::Ascii, Zone::Positive) if digit == 0 => b'{',
+        (Charset::Ascii, Zone::Positive) => b'A' + digit - 1,
+        (Charset::Ascii, Zone::Negative) if digit == 0 => b'}',
+        (Charset::Ascii, Zone::Negative) => b'J' + digit - 1,
+        (Charset::Ascii, Zone::Unsigned) => b'0' + digit,
+    }
+}
+
+#[inline]
+const fn decode_digit(byte: u8, charset: Charset) -> Option<(u8, Zone)> {
+    match charset {
+        Charset::Ebcdic => {
+            let digit = byte & 0x0f;
+
+            if digit > 9 {
+                return None;
+            }
+
+            match byte >> 4 {
+                0xf => Some((digit, Zone::Unsigned)),
+                0xa | 0xc | 0xe => Some((digit, Zone::Positive)),
+                0xb | 0xd => Some((digit, Zone::Negative)),
+                _ => None,
+            }
+        }
+        Charset::Ascii => match byte {
+            b'0'..=b'9' => Some((byte - b'0', Zone::Unsigned)),
+            b'{' => Some((0, Zone::Positive)),
+            b'A'..=b'I' => Some((byte - b'A' + 1, Zone::Positive)),
+            b'}' => Some((0, Zone::Negative)),
+            b'J'..=b'R' => Some((byte - b'J' + 1, Zone::Negative)),
+            _ => None,
+        },
+    }
+}
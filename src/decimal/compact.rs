@@ -0,0 +1,626 @@
+//! # Compact Decimal
+
+mod impls;
+mod math;
+
+use impls::consts::consts_impl;
+
+use core::cmp::Ordering;
+
+use crate::{
+    decimal::{
+        dec::resize::reduce, doc, Context, Decimal, Flags, ParseError, RoundingMode, Sign, Signal,
+        UnsignedCompactDecimal,
+    },
+    int::UInt,
+};
+
+/// # Compact Decimal
+///
+/// Signed decimal number with a 32-bit coefficient, which takes 8 bytes.
+///
+/// `CompactDecimal` is meant for storing large amounts of values with a few
+/// significant digits, such as prices. Its value is `digits` ×
+/// 10<sup>-scale</sup>, just like [Decimal], and it has the same special
+/// values, [Context] and signals. The arithmetic is carried out in [`D128`]
+/// and the result is rounded to the 32-bit coefficient once.
+///
+/// Conversion into any [Decimal] is lossless.
+///
+/// [`D128`]: crate::D128
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{dec32, dec128, D32, D128};
+///
+/// const PRICE: D32 = dec32!(19.99);
+///
+/// assert_eq!(size_of::<D32>(), 8);
+/// assert_eq!(PRICE * dec32!(3), dec32!(59.97));
+/// assert_eq!(D128::from(PRICE), dec128!(19.99));
+/// ```
+#[derive(Copy, Clone)]
+pub struct CompactDecimal {
+    /// A 32-bit unsigned integer coefficient. Represent significant decimal
+    /// digits.
+    digits: u32,
+
+    /// Scale, the number of fractional digits by which the coefficient is
+    /// multiplied.
+    scale: i16,
+
+    /// Special values and signaling flags.
+    flags: Flags,
+}
+
+consts_impl!();
+
+impl CompactDecimal {
+    /// Creates and initializes a compact decimal from string.
+    #[track_caller]
+    #[inline]
+    pub const fn from_str(s: &str) -> Result<Self, ParseError> {
+        match Decimal::<1>::from_str(s) {
+            Ok(d) => math::try_from_decimal(d),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates and initializes a compact decimal from string.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `CompactDecimal` cannot be constructed
+    /// from given string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{D32, dec32};
+    ///
+    /// assert_eq!(D32::parse_str("1.2345"), dec32!(1.2345));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub const fn parse_str(s: &str) -> Self {
+        match Self::from_str(s) {
+            Ok(n) => n,
+            Err(e) => panic!("{}", e.description()),
+        }
+    }
+
+    /// Converts the given [Decimal] into a compact decimal, rounding the
+    /// least significant digits which don't fit into the 32-bit coefficient
+    /// according to the given [Context].
+    ///
+    /// Rounding raises [Signal::OP_ROUNDED], and also [Signal::OP_INEXACT]
+    /// if any of the discarded digits is nonzero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, dec128, D32, decimal::Context};
+    ///
+    /// assert_eq!(D32::from_decimal(dec128!(1.5), Context::default()), dec32!(1.5));
+    ///
+    /// let d = D32::from_decimal(dec128!(3.14159265358979), Context::default());
+    /// assert_eq!(d, dec32!(3.141592654));
+    /// assert!(d.is_op_inexact());
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn from_decimal<const N: usize>(d: Decimal<N>, ctx: Context) -> Self {
+        math::from_decimal(d, ctx).unwrap_signals(ctx)
+    }
+
+    /// Converts the compact decimal into [Decimal]. This conversion is
+    /// lossless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, dec64, dec256};
+    ///
+    /// assert_eq!(dec32!(-1.25).to_decimal::<1>(), dec64!(-1.25));
+    /// assert_eq!(dec32!(-1.25).to_decimal::<4>(), dec256!(-1.25));
+    /// ```
+    #[inline]
+    pub const fn to_decimal<const N: usize>(self) -> Decimal<N> {
+        Decimal::new(UInt::from_digit(self.digits as u64), self.scale, self.flags)
+    }
+
+    /// Returns the coefficient, representing the significant decimal digits
+    /// of the compact decimal, including significant trailing zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::dec32;
+    ///
+    /// assert_eq!(dec32!(-123.45).digits(), 12345);
+    /// assert_eq!(dec32!(-1.0).digits(), 10);
+    /// ```
+    #[inline]
+    pub const fn digits(&self) -> u32 {
+        self.digits
+    }
+
+    /// Returns the number of fractional digits, the scale of the compact
+    /// decimal.
+    #[inline]
+    pub const fn fractional_digits_count(&self) -> i16 {
+        self.scale
+    }
+
+    /// Return the sign of the compact decimal as [Sign].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{decimal::Sign, dec32};
+    ///
+    /// assert_eq!(dec32!(-1.0).sign(), Sign::Minus);
+    /// assert_eq!(dec32!(0.0).sign(),  Sign::Plus);
+    /// ```
+    #[inline]
+    pub const fn sign(&self) -> Sign {
+        if self.flags.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        }
+    }
+
+    #[inline]
+    pub const fn is_op_div_by_zero(&self) -> bool {
+        self.flags.has_signal(Signal::OP_DIV_BY_ZERO)
+    }
+
+    #[inline]
+    pub const fn is_op_invalid(&self) -> bool {
+        self.flags.has_signal(Signal::OP_INVALID)
+    }
+
+    #[inline]
+    pub const fn is_op_inexact(&self) -> bool {
+        self.flags.has_signal(Signal::OP_INEXACT)
+    }
+
+    #[inline]
+    pub const fn is_op_rounded(&self) -> bool {
+        self.flags.has_signal(Signal::OP_ROUNDED)
+    }
+
+    #[inline]
+    pub const fn is_op_overflow(&self) -> bool {
+        self.flags.has_signal(Signal::OP_OVERFLOW)
+    }
+
+    #[inline]
+    pub const fn is_op_underflow(&self) -> bool {
+        self.flags.has_signal(Signal::OP_UNDERFLOW)
+    }
+
+    #[inline]
+    pub const fn is_op_ok(&self) -> bool {
+        !self.flags.has_signals()
+    }
+
+    /// Returns `true` if this number is neither infinite nor NaN.
+    #[inline]
+    pub const fn is_finite(&self) -> bool {
+        !self.flags.is_special()
+    }
+
+    /// Returns `true` if this value is positive infinity or negative
+    /// infinity, and `false` otherwise.
+    #[inline]
+    pub const fn is_infinite(&self) -> bool {
+        self.flags.is_infinity()
+    }
+
+    /// Returns `true` if this value is NaN and `false` otherwise.
+    #[inline]
+    pub const fn is_nan(&self) -> bool {
+        self.flags.is_nan()
+    }
+
+    /// Returns `true` if the compact decimal is finite and has zero value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, D32};
+    ///
+    /// assert!(dec32!(-0.00).is_zero());
+    /// assert!(!dec32!(0.01).is_zero());
+    /// assert!(!D32::INFINITY.is_zero());
+    /// ```
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.digits == 0 && !self.flags.is_special()
+    }
+
+    /// Returns true if the sign of the compact decimal is positive.
+    #[inline]
+    pub const fn is_positive(&self) -> bool {
+        !self.flags.is_negative()
+    }
+
+    /// Returns true if the sign of the compact decimal is negative
+    /// (including `-0`).
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.flags.is_negative()
+    }
+
+    /// Invert sign of given compact decimal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::dec32;
+    ///
+    /// assert_eq!(dec32!(1.5).neg(), dec32!(-1.5));
+    /// ```
+    #[inline]
+    pub const fn neg(mut self) -> Self {
+        self.flags = self.flags.neg();
+        self
+    }
+
+    /// Get the absolute value of the compact decimal (non-negative sign).
+    #[inline]
+    pub const fn abs(mut self) -> Self {
+        self.flags = self.flags.abs();
+        self
+    }
+
+    /// Get the absolute value of the compact decimal as
+    /// [UnsignedCompactDecimal].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, udec32};
+    ///
+    /// assert_eq!(dec32!(-1.5).unsigned_abs(), udec32!(1.5));
+    /// ```
+    #[inline]
+    pub const fn unsigned_abs(self) -> UnsignedCompactDecimal {
+        UnsignedCompactDecimal::new(self.abs())
+    }
+
+    /// Creates a compact decimal from the raw parts: coefficient, scale and
+    /// sign.
+    ///
+    /// The value of the result is `sign` × `digits` × 10<sup>-scale</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, D32, decimal::Sign};
+    ///
+    /// assert_eq!(D32::from_parts(12345, 2, Sign::Minus), dec32!(-123.45));
+    /// ```
+    #[inline]
+    pub const fn from_parts(digits: u32, scale: i16, sign: Sign) -> Self {
+        let flags = match sign {
+            Sign::Plus => Flags::default(),
+            Sign::Minus => Flags::NEG,
+        };
+
+        Self::new(digits, scale, flags)
+    }
+
+    /// Decomposes the compact decimal into the raw parts: coefficient, scale
+    /// and sign, so that the value is `sign` × `digits` ×
+    /// 10<sup>-scale</sup>.
+    ///
+    /// The coefficient of special values (`NaN` and `±Infinity`) is zero, so
+    /// they should be checked for with [Self::is_finite] beforehand.
+    #[inline]
+    pub const fn into_parts(self) -> (u32, i16, Sign) {
+        (self.digits, self.scale, self.sign())
+    }
+
+    /// Tests for `self` and `other` values to be equal, and is used by `==`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn eq(&self, other: &Self) -> bool {
+        self.to_decimal::<1>().eq(&other.to_decimal::<1>())
+    }
+
+    /// Tests for `self` and `other` values not to be equal, and is used by
+    /// `!=` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ne(&self, other: &Self) -> bool {
+        !self.eq(other)
+    }
+
+    /// Compares and returns the maximum of two compact decimal values.
+    ///
+    /// Returns the second argument if the comparison determines them to be
+    /// equal.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn max(self, other: Self) -> Self {
+        match self.cmp(&other) {
+            Ordering::Greater => self,
+            _ => other,
+        }
+    }
+
+    /// Compares and returns the minimum of two compact decimal values.
+    ///
+    /// Returns the first argument if the comparison determines them to be
+    /// equal.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn min(self, other: Self) -> Self {
+        match self.cmp(&other) {
+            Ordering::Greater => other,
+            _ => self,
+        }
+    }
+
+    /// Tests `self` less than `other` and is used by the `<` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn lt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Less)
+    }
+
+    /// Tests `self` less than or equal to `other` and is used by the `<=`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn le(&self, other: &Self) -> bool {
+        !matches!(self.cmp(other), Ordering::Greater)
+    }
+
+    /// Tests `self` greater than `other` and is used by the `>` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn gt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Greater)
+    }
+
+    /// Tests `self` greater than or equal to `other` and is used by the `>=`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ge(&self, other: &Self) -> bool {
+        !matches!(self.cmp(other), Ordering::Less)
+    }
+
+    /// This method returns an [Ordering] between `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::dec32;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(dec32!(5).cmp(&dec32!(10)), Ordering::Less);
+    /// assert_eq!(dec32!(-0.5).cmp(&dec32!(-1)), Ordering::Greater);
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        self.to_decimal::<1>().cmp(&other.to_decimal::<1>())
+    }
+
+    /// Calculates `self` + `rhs`.
+    ///
+    /// Is internally used by the `+` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, decimal::Context};
+    ///
+    /// let c = dec32!(0.1).add(dec32!(0.2), Context::default());
+    /// assert_eq!(c, dec32!(0.3));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn add(self, rhs: Self, ctx: Context) -> Self {
+        math::add(self, rhs, ctx).unwrap_signals(ctx)
+    }
+
+    /// Calculates `self` - `rhs`.
+    ///
+    /// Is internally used by the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, decimal::Context};
+    ///
+    /// let c = dec32!(0.1).sub(dec32!(0.2), Context::default());
+    /// assert_eq!(c, dec32!(-0.1));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sub(self, rhs: Self, ctx: Context) -> Self {
+        math::sub(self, rhs, ctx).unwrap_signals(ctx)
+    }
+
+    /// Calculates `self` × `rhs`.
+    ///
+    /// Is internally used by the `*` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, decimal::Context};
+    ///
+    /// let c = dec32!(65536).mul(dec32!(65537), Context::default());
+    /// assert_eq!(c, dec32!(429503283e1));
+    /// assert!(c.is_op_rounded());
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn mul(self, rhs: Self, ctx: Context) -> Self {
+        math::mul(self, rhs, ctx).unwrap_signals(ctx)
+    }
+
+    /// Calculates `self` ÷ `rhs`.
+    ///
+    /// Is internally used by the `/` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, decimal::Context};
+    ///
+    /// let c = dec32!(2).div(dec32!(3), Context::default());
+    /// assert_eq!(c, dec32!(0.666666667));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fastnum::dec32;
+    ///
+    /// let c = dec32!(1) / dec32!(0);
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn div(self, rhs: Self, ctx: Context) -> Self {
+        math::div(self, rhs, ctx).unwrap_signals(ctx)
+    }
+
+    /// Calculates `self` % `rhs`.
+    ///
+    /// Is internally used by the `%` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, decimal::Context};
+    ///
+    /// let c = dec32!(10).rem(dec32!(3), Context::default());
+    /// assert_eq!(c, dec32!(1));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn rem(self, rhs: Self, ctx: Context) -> Self {
+        math::rem(self, rhs, ctx).unwrap_signals(ctx)
+    }
+
+    /// Returns the result of rounding given compact decimal number to
+    /// `digits` precision after the decimal point using given
+    /// [RoundingMode].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, decimal::RoundingMode};
+    ///
+    /// let n = dec32!(129.41675);
+    ///
+    /// assert_eq!(n.round(2, RoundingMode::Up), dec32!(129.42));
+    /// assert_eq!(n.round(-1, RoundingMode::Down), dec32!(120));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn round(self, digits: i16, rounding_mode: RoundingMode) -> Self {
+        self.with_scale(digits, Context::default().with_rounding_mode(rounding_mode))
+    }
+
+    /// Returns the result of rounding given compact decimal number to
+    /// `new_scale` precision after the decimal point using given [Context].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec32, decimal::{Context, RoundingMode}};
+    ///
+    /// let ctx = Context::default().with_rounding_mode(RoundingMode::HalfEven);
+    ///
+    /// assert_eq!(dec32!(129.41675).with_scale(4, ctx), dec32!(129.4168));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn with_scale(self, new_scale: i16, ctx: Context) -> Self {
+        math::with_scale(self, new_scale, ctx).unwrap_signals(ctx)
+    }
+
+    /// Returns `Some(self)` if no special value and no signals were raised
+    /// during computations, or `None` otherwise.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ok(self) -> Option<Self> {
+        if self.flags.is_special() || self.flags.has_signals() {
+            None
+        } else {
+            Some(self)
+        }
+    }
+}
+
+#[doc(hidden)]
+impl CompactDecimal {
+    /// The maximum coefficient.
+    pub(crate) const MAX_DIGITS: u32 = u32::MAX;
+
+    /// The largest power of ten which fits into the coefficient.
+    pub(crate) const MAX_POWER: u32 = 9;
+
+    #[inline]
+    pub(crate) const fn new(digits: u32, scale: i16, flags: Flags) -> Self {
+        Self {
+            digits,
+            scale,
+            flags,
+        }
+    }
+
+    /// Rounds the given decimal to the 32-bit coefficient.
+    #[inline]
+    pub(crate) const fn narrow<const N: usize>(d: Decimal<N>, ctx: Context) -> Self {
+        if !d.is_finite() {
+            return Self::new(0, 0, d.flags());
+        }
+
+        let max = UInt::from_digit(Self::MAX_DIGITS as u64);
+        let d = reduce(d, max, Self::MAX_POWER, ctx);
+
+        if !d.is_finite() {
+            return Self::new(0, 0, d.flags());
+        }
+
+        Self::new(
+            d.digits().digits()[0] as u32,
+            d.fractional_digits_count(),
+            d.flags(),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn type_name() -> &'static str {
+        "D32"
+    }
+
+    #[inline]
+    pub(crate) const fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[inline]
+    pub(crate) const fn with_signals_from_and(mut self, other: &Self, signal: Signal) -> Self {
+        self.flags = self.flags.with_signals_from_and(other.flags, signal);
+        self
+    }
+
+    #[inline]
+    pub(crate) const fn unwrap_signals(self, ctx: Context) -> Self {
+        #[cfg(debug_assertions)]
+        ctx.trap_signals(self.flags.signals());
+        self
+    }
+}
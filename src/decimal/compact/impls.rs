@@ -0,0 +1,11 @@
+pub(super) mod consts;
+
+// Trait implementations
+mod cmp;
+mod default;
+mod fmt;
+mod from;
+mod from_str;
+mod hash;
+mod ops;
+mod ord;
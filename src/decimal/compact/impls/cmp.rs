@@ -0,0 +1,16 @@
+use crate::decimal::CompactDecimal;
+
+impl PartialEq for CompactDecimal {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.eq(other)
+    }
+
+    #[allow(clippy::partialeq_ne_impl)]
+    #[inline]
+    fn ne(&self, other: &Self) -> bool {
+        self.ne(other)
+    }
+}
+
+impl Eq for CompactDecimal {}
@@ -0,0 +1,8 @@
+use crate::decimal::CompactDecimal;
+
+impl Default for CompactDecimal {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
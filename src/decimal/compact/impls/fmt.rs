@@ -0,0 +1,57 @@
+use core::fmt::{self, Debug, Display, Formatter, LowerExp, UpperExp};
+
+use crate::decimal::CompactDecimal;
+
+impl Display for CompactDecimal {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.to_decimal::<1>(), f)
+    }
+}
+
+impl LowerExp for CompactDecimal {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerExp::fmt(&self.to_decimal::<1>(), f)
+    }
+}
+
+impl UpperExp for CompactDecimal {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        UpperExp::fmt(&self.to_decimal::<1>(), f)
+    }
+}
+
+impl Debug for CompactDecimal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            if self.is_nan() {
+                return write!(f, "{}(NaN)", Self::type_name());
+            } else if self.is_infinite() {
+                return write!(f, "{}({}Inf)", Self::type_name(), self.sign());
+            }
+
+            let alert = if self.flags().has_signals() { "! " } else { "" };
+            write!(
+                f,
+                "{}({}{}{}e{})",
+                Self::type_name(),
+                alert,
+                self.sign(),
+                self.digits(),
+                (self.fractional_digits_count() as i32).saturating_neg()
+            )
+        } else {
+            write!(
+                f,
+                "{}(digits=[{:?}], exp=[{}], flags=[{}], signals=[{}])",
+                Self::type_name(),
+                self.digits(),
+                (self.fractional_digits_count() as i32).saturating_neg(),
+                self.flags(),
+                self.flags().signals()
+            )
+        }
+    }
+}
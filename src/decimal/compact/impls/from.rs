@@ -0,0 +1,81 @@
+use crate::decimal::{CompactDecimal, Decimal, Flags, ParseError};
+
+impl<const N: usize> From<CompactDecimal> for Decimal<N> {
+    #[inline]
+    fn from(d: CompactDecimal) -> Self {
+        d.to_decimal()
+    }
+}
+
+impl<const N: usize> TryFrom<Decimal<N>> for CompactDecimal {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(d: Decimal<N>) -> Result<Self, Self::Error> {
+        super::super::math::try_from_decimal(d)
+    }
+}
+
+macro_rules! from_uint {
+    ($($int: tt),*) => {
+        $(
+            impl From<$int> for CompactDecimal {
+                #[inline]
+                fn from(int: $int) -> Self {
+                    Self::new(int as u32, 0, Flags::default())
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! from_int {
+    ($($int: tt),*) => {
+        $(
+            impl From<$int> for CompactDecimal {
+                #[inline]
+                fn from(int: $int) -> Self {
+                    let flags = if int < 0 { Flags::NEG } else { Flags::default() };
+                    Self::new(int.unsigned_abs() as u32, 0, flags)
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! try_from_int {
+    ($($int: tt),*) => {
+        $(
+            impl TryFrom<$int> for CompactDecimal {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(int: $int) -> Result<Self, Self::Error> {
+                    Self::try_from(Decimal::<1>::from(int))
+                }
+            }
+        )*
+    }
+}
+
+from_uint!(u8, u16, u32);
+from_int!(i8, i16, i32);
+try_from_int!(u64, usize, i64, isize);
+
+impl TryFrom<u128> for CompactDecimal {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(int: u128) -> Result<Self, Self::Error> {
+        Self::try_from(Decimal::<2>::try_from_u128(int)?)
+    }
+}
+
+impl TryFrom<i128> for CompactDecimal {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(int: i128) -> Result<Self, Self::Error> {
+        Self::try_from(Decimal::<2>::try_from_i128(int)?)
+    }
+}
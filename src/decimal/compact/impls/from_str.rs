@@ -0,0 +1,12 @@
+use core::str::FromStr;
+
+use crate::decimal::{CompactDecimal, ParseError};
+
+impl FromStr for CompactDecimal {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        CompactDecimal::from_str(s)
+    }
+}
@@ -0,0 +1,11 @@
+use core::hash::{Hash, Hasher};
+
+use crate::decimal::CompactDecimal;
+
+impl Hash for CompactDecimal {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal compact decimals have equal decimal values.
+        self.to_decimal::<1>().hash(state);
+    }
+}
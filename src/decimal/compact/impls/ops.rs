@@ -0,0 +1,45 @@
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::decimal::{CompactDecimal, Context};
+
+macro_rules! macro_impl {
+    ($($Op: ident $op: ident $OpAssign: ident $op_assign: ident), *) => {
+        $(
+            impl $Op for CompactDecimal {
+                type Output = CompactDecimal;
+
+                #[inline]
+                fn $op(self, rhs: Self) -> CompactDecimal {
+                    self.$op(rhs, Context::default())
+                }
+            }
+
+            impl $OpAssign for CompactDecimal {
+                #[inline]
+                fn $op_assign(&mut self, rhs: Self) {
+                    let res = $Op::<CompactDecimal>::$op(*self, rhs);
+                    *self = res;
+                }
+            }
+        )*
+    };
+}
+
+macro_impl!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign,
+    Rem rem RemAssign rem_assign
+);
+
+impl Neg for CompactDecimal {
+    type Output = CompactDecimal;
+
+    #[inline]
+    fn neg(self) -> CompactDecimal {
+        self.neg()
+    }
+}
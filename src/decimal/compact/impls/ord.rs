@@ -0,0 +1,17 @@
+use core::cmp::Ordering;
+
+use crate::decimal::CompactDecimal;
+
+impl PartialOrd for CompactDecimal {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(core::cmp::Ord::cmp(self, rhs))
+    }
+}
+
+impl Ord for CompactDecimal {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.cmp(rhs)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::decimal::{
+    dec::{
+        math::{
+            add::add as dec_add, div::div as dec_div, mul::mul as dec_mul, rem::rem as dec_rem,
+            sub::sub as dec_sub,
+        },
+        scale::with_scale as dec_with_scale,
+    },
+    CompactDecimal, Context, Decimal, ParseError,
+};
+
+type CD = CompactDecimal;
+
+// Operations are carried out in `D128`, where the sum, difference and product
+// of two 32-bit coefficients are exact, and the result is rounded to the
+// 32-bit coefficient once.
+
+#[inline]
+pub(crate) const fn add(lhs: CD, rhs: CD, ctx: Context) -> CD {
+    CD::narrow(dec_add(lhs.to_decimal::<2>(), rhs.to_decimal(), ctx), ctx)
+}
+
+#[inline]
+pub(crate) const fn sub(lhs: CD, rhs: CD, ctx: Context) -> CD {
+    CD::narrow(dec_sub(lhs.to_decimal::<2>(), rhs.to_decimal(), ctx), ctx)
+}
+
+#[inline]
+pub(crate) const fn mul(lhs: CD, rhs: CD, ctx: Context) -> CD {
+    CD::narrow(dec_mul(lhs.to_decimal::<2>(), rhs.to_decimal(), ctx), ctx)
+}
+
+#[inline]
+pub(crate) const fn div(lhs: CD, rhs: CD, ctx: Context) -> CD {
+    CD::narrow(dec_div(lhs.to_decimal::<2>(), rhs.to_decimal(), ctx), ctx)
+}
+
+#[inline]
+pub(crate) const fn rem(lhs: CD, rhs: CD, ctx: Context) -> CD {
+    CD::narrow(dec_rem(lhs.to_decimal::<2>(), rhs.to_decimal(), ctx), ctx)
+}
+
+#[inline]
+pub(crate) const fn with_scale(d: CD, new_scale: i16, ctx: Context) -> CD {
+    CD::narrow(dec_with_scale(d.to_decimal::<2>(), new_scale, ctx), ctx)
+}
+
+#[inline]
+pub(crate) const fn from_decimal<const N: usize>(d: Decimal<N>, ctx: Context) -> CD {
+    CD::narrow(d, ctx)
+}
+
+#[inline]
+pub(crate) const fn try_from_decimal<const N: usize>(d: Decimal<N>) -> Result<CD, ParseError> {
+    let res = CD::narrow(d, Context::default());
+
+    if res.is_op_overflow() {
+        if res.is_negative() {
+            Err(ParseError::NegOverflow)
+        } else {
+            Err(ParseError::PosOverflow)
+        }
+    } else if res.is_op_inexact() {
+        Err(ParseError::Inexact)
+    } else {
+        Ok(res)
+    }
+}
@@ -1,13 +1,18 @@
 //! # Signed Decimal
 
+pub(crate) mod batch;
+
 mod cmp;
 mod extras;
 mod format;
+mod ieee754;
 mod impls;
-mod math;
+pub(crate) mod math;
 mod normalize;
+mod ordered;
 mod parse;
-mod scale;
+pub(crate) mod resize;
+pub(crate) mod scale;
 
 use impls::consts::consts_impl;
 
@@ -15,9 +20,10 @@ use core::{cmp::Ordering, fmt};
 
 use crate::{
     decimal::{
-        doc, Category, Context, Flags, ParseError, RoundingMode, Sign, Signal, UnsignedDecimal,
+        doc, Category, Context, DecimalBytes, Flags, Interchange, OrderedBytes, ParseError,
+        RoundingMode, Sign, Signal, UnsignedDecimal,
     },
-    int::UInt,
+    int::{math::resize, pow10::Pow10, UInt},
 };
 
 /// # Decimal
@@ -99,7 +105,7 @@ impl<const N: usize> Decimal<N> {
         if self.is_zero() {
             return 1;
         }
-        self.digits.ilog10() as usize + 1
+        Pow10::<N>::digits_count(self.digits) as usize
     }
 
     /// Returns the scale of the `Decimal`, the total number of
@@ -179,6 +185,16 @@ impl<const N: usize> Decimal<N> {
         self.flags.has_signal(Signal::OP_CLAMPED)
     }
 
+    #[inline]
+    pub const fn is_op_overflow(&self) -> bool {
+        self.flags.has_signal(Signal::OP_OVERFLOW)
+    }
+
+    #[inline]
+    pub const fn is_op_underflow(&self) -> bool {
+        self.flags.has_signal(Signal::OP_UNDERFLOW)
+    }
+
     #[inline]
     pub const fn is_op_ok(&self) -> bool {
         !self.flags.has_signals()
@@ -483,6 +499,549 @@ impl<const N: usize> Decimal<N> {
         Self::new(UInt::ONE, -exp, Flags::default())
     }
 
+    /// Creates a decimal from the raw parts: coefficient, scale and sign.
+    ///
+    /// The value of the result is `sign` × `digits` × 10<sup>-scale</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec256, u256, D256, decimal::Sign};
+    ///
+    /// assert_eq!(D256::from_parts(u256!(12345), 2, Sign::Minus), dec256!(-123.45));
+    /// assert_eq!(D256::from_parts(u256!(5), -3, Sign::Plus), dec256!(5000));
+    /// ```
+    #[inline]
+    pub const fn from_parts(digits: UInt<N>, scale: i16, sign: Sign) -> Self {
+        let flags = match sign {
+            Sign::Plus => Flags::default(),
+            Sign::Minus => Flags::NEG,
+        };
+
+        Self::new(digits, scale, flags)
+    }
+
+    /// Decomposes the decimal into the raw parts: coefficient, scale and
+    /// sign, so that the value is `sign` × `digits` × 10<sup>-scale</sup>.
+    ///
+    /// The coefficient of special values (`NaN` and `±Infinity`) is zero, so
+    /// they should be checked for with [Self::is_finite] beforehand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec256, u256, D256, decimal::Sign};
+    ///
+    /// let (digits, scale, sign) = dec256!(-123.45).into_parts();
+    ///
+    /// assert_eq!(digits, u256!(12345));
+    /// assert_eq!(scale, 2);
+    /// assert_eq!(sign, Sign::Minus);
+    /// assert_eq!(D256::from_parts(digits, scale, sign), dec256!(-123.45));
+    /// ```
+    #[inline]
+    pub const fn into_parts(self) -> (UInt<N>, i16, Sign) {
+        (self.digits, self.scale, self.sign())
+    }
+
+    /// Returns the memory representation of the decimal in little-endian
+    /// byte order: the coefficient, scale, flags and raised signals, as
+    /// described in [DecimalBytes].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, D128};
+    ///
+    /// let bytes = dec128!(-1.25).to_le_bytes();
+    ///
+    /// assert_eq!(bytes.as_ref(), [125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0]);
+    /// assert_eq!(D128::from_le_bytes(&bytes), dec128!(-1.25));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_le_bytes(&self) -> DecimalBytes<N> {
+        DecimalBytes::encode(&self.digits, self.scale, self.flags, false)
+    }
+
+    /// Returns the memory representation of the decimal in big-endian byte
+    /// order: the coefficient, scale, flags and raised signals, as described
+    /// in [DecimalBytes].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, D128};
+    ///
+    /// let bytes = dec128!(-1.25).to_be_bytes();
+    ///
+    /// assert_eq!(bytes.as_ref(), [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 2, 1, 0]);
+    /// assert_eq!(D128::from_be_bytes(&bytes), dec128!(-1.25));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_be_bytes(&self) -> DecimalBytes<N> {
+        DecimalBytes::encode(&self.digits, self.scale, self.flags, true)
+    }
+
+    /// Creates a decimal from its memory representation in little-endian
+    /// byte order, including the raised signals.
+    ///
+    /// Any bytes decode to a valid decimal: unknown flag bits are ignored,
+    /// and the coefficient and scale of `NaN` and `±Infinity` are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec256, D256};
+    ///
+    /// let bytes = D256::NEG_INFINITY.to_le_bytes();
+    /// assert_eq!(D256::from_le_bytes(&bytes), D256::NEG_INFINITY);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_le_bytes(bytes: &DecimalBytes<N>) -> Self {
+        let (digits, scale, flags) = bytes.decode(false);
+        Self::new(digits, scale, flags)
+    }
+
+    /// Creates a decimal from its memory representation in big-endian byte
+    /// order, including the raised signals.
+    ///
+    /// Any bytes decode to a valid decimal: unknown flag bits are ignored,
+    /// and the coefficient and scale of `NaN` and `±Infinity` are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec256, D256};
+    ///
+    /// let bytes = dec256!(0.0001).to_be_bytes();
+    /// assert_eq!(D256::from_be_bytes(&bytes).fractional_digits_count(), 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_be_bytes(bytes: &DecimalBytes<N>) -> Self {
+        let (digits, scale, flags) = bytes.decode(true);
+        Self::new(digits, scale, flags)
+    }
+
+    /// Returns the order-preserving key of the decimal, described in
+    /// [OrderedBytes].
+    ///
+    /// Byte-wise comparison of the keys agrees with [Self::cmp], with
+    /// `-Infinity` first and `NaN` last, and decimals that compare equal have
+    /// equal keys. This makes them suitable as keys of ordered key-value
+    /// stores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec256, D256};
+    ///
+    /// let a = dec256!(-12.5).to_ordered_bytes();
+    /// let b = dec256!(0.003).to_ordered_bytes();
+    /// let c = dec256!(1e10).to_ordered_bytes();
+    ///
+    /// assert!(a < b && b < c);
+    /// assert!(c < D256::INFINITY.to_ordered_bytes());
+    /// assert_eq!(dec256!(1.50).to_ordered_bytes(), dec256!(1.5).to_ordered_bytes());
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_ordered_bytes(&self) -> OrderedBytes<N> {
+        ordered::to_ordered_bytes(self)
+    }
+
+    /// Creates a decimal from its order-preserving key returned by
+    /// [Self::to_ordered_bytes].
+    ///
+    /// The key doesn't keep the trailing zeros of the coefficient, so the
+    /// result is normalized. Signals and the sign of `NaN` aren't kept
+    /// either.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ParseError] if the bytes are not a valid key of
+    /// `Decimal<N>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec256, D256};
+    ///
+    /// let key = dec256!(-1.500).to_ordered_bytes();
+    /// let d = D256::from_ordered_bytes(&key).unwrap();
+    ///
+    /// assert_eq!(d, dec256!(-1.5));
+    /// assert_eq!(d.fractional_digits_count(), 1);
+    /// ```
+    #[inline]
+    pub const fn from_ordered_bytes(bytes: &OrderedBytes<N>) -> Result<Self, ParseError> {
+        ordered::from_ordered_bytes(bytes)
+    }
+
+    /// Rounds the decimal to the precision and exponent range of the IEEE
+    /// 754 decimal interchange `format`, which makes it exactly encodable in
+    /// the format.
+    ///
+    /// Coefficients with too many digits are rounded according to `ctx`,
+    /// raising [OP_ROUNDED](Signal::OP_ROUNDED) and, if non-zero digits were
+    /// discarded, [OP_INEXACT](Signal::OP_INEXACT). Tiny values are rounded to
+    /// the minimum exponent of the format, and values too large overflow to
+    /// infinity with [OP_OVERFLOW](Signal::OP_OVERFLOW). The signals are
+    /// trapped according to `ctx`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value overflows the format and the
+    /// given [Context] traps overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, D128, decimal::{Context, Interchange}};
+    ///
+    /// let ctx = Context::default();
+    ///
+    /// let d = dec128!(123456789).round_to_interchange(Interchange::Decimal32, ctx);
+    /// assert_eq!(d, dec128!(1.234568e8));
+    /// assert!(d.is_op_rounded() && d.is_op_inexact());
+    ///
+    /// let d = dec128!(1.5).round_to_interchange(Interchange::Decimal64, ctx);
+    /// assert!(d.is_op_ok());
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn round_to_interchange(self, format: Interchange, ctx: Context) -> Self {
+        ieee754::round(self, format, ctx).unwrap_signals(ctx)
+    }
+
+    /// Encodes the decimal in the IEEE 754 `decimal32` interchange format
+    /// with the coefficient in binary integer decimal (BID).
+    ///
+    /// The value is rounded first as by [Self::round_to_interchange], and the
+    /// raised signals are trapped according to `ctx`. `NaN` is encoded as a
+    /// quiet `NaN`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value overflows the format and the
+    /// given [Context] traps overflow.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_bid32(&self, ctx: Context) -> u32 {
+        ieee754::encode(*self, Interchange::Decimal32, false, ctx) as u32
+    }
+
+    /// Decodes a decimal from the IEEE 754 `decimal32` interchange format
+    /// with the coefficient in binary integer decimal (BID).
+    ///
+    /// Signaling `NaN` is decoded as `NaN`, and the `NaN` payload is ignored.
+    /// Non-canonical coefficients are decoded as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, D128, decimal::Context};
+    ///
+    /// let bits = dec128!(-7.50).to_bid32(Context::default());
+    ///
+    /// assert_eq!(bits, 0xb180_02ee);
+    /// assert_eq!(D128::from_bid32(bits), dec128!(-7.50));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_bid32(bits: u32) -> Self {
+        ieee754::decode(bits as u128, Interchange::Decimal32, false, Context::default())
+    }
+
+    /// Encodes the decimal in the IEEE 754 `decimal64` interchange format
+    /// with the coefficient in binary integer decimal (BID).
+    ///
+    /// The value is rounded first as by [Self::round_to_interchange], and the
+    /// raised signals are trapped according to `ctx`. `NaN` is encoded as a
+    /// quiet `NaN`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value overflows the format and the
+    /// given [Context] traps overflow.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_bid64(&self, ctx: Context) -> u64 {
+        ieee754::encode(*self, Interchange::Decimal64, false, ctx) as u64
+    }
+
+    /// Decodes a decimal from the IEEE 754 `decimal64` interchange format
+    /// with the coefficient in binary integer decimal (BID).
+    ///
+    /// Signaling `NaN` is decoded as `NaN`, and the `NaN` payload is ignored.
+    /// Non-canonical coefficients are decoded as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, D128, decimal::Context};
+    ///
+    /// let bits = dec128!(1).to_bid64(Context::default());
+    ///
+    /// assert_eq!(bits, 0x31c0_0000_0000_0001);
+    /// assert_eq!(D128::from_bid64(bits), dec128!(1));
+    /// assert!(D128::from_bid64(0x7c00_0000_0000_0000).is_nan());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_bid64(bits: u64) -> Self {
+        ieee754::decode(bits as u128, Interchange::Decimal64, false, Context::default())
+    }
+
+    /// Encodes the decimal in the IEEE 754 `decimal128` interchange format
+    /// with the coefficient in binary integer decimal (BID).
+    ///
+    /// The value is rounded first as by [Self::round_to_interchange], and the
+    /// raised signals are trapped according to `ctx`. `NaN` is encoded as a
+    /// quiet `NaN`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value overflows the format and the
+    /// given [Context] traps overflow.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_bid128(&self, ctx: Context) -> u128 {
+        ieee754::encode(*self, Interchange::Decimal128, false, ctx)
+    }
+
+    /// Decodes a decimal from the IEEE 754 `decimal128` interchange format
+    /// with the coefficient in binary integer decimal (BID).
+    ///
+    /// Signaling `NaN` is decoded as `NaN`, and the `NaN` payload is ignored.
+    /// Non-canonical coefficients are decoded as zero.
+    ///
+    /// The coefficient is rounded according to `ctx` if it doesn't fit into
+    /// `Decimal<N>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, D128, decimal::Context};
+    ///
+    /// let ctx = Context::default();
+    /// let bits = dec128!(0.1).to_bid128(ctx);
+    ///
+    /// assert_eq!(bits, 0x303e_0000_0000_0000_0000_0000_0000_0001);
+    /// assert_eq!(D128::from_bid128(bits, ctx), dec128!(0.1));
+    /// assert_eq!(D128::INFINITY.to_bid128(ctx), 0x7800_0000_0000_0000_0000_0000_0000_0000);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_bid128(bits: u128, ctx: Context) -> Self {
+        ieee754::decode(bits, Interchange::Decimal128, false, ctx)
+    }
+
+    /// Encodes the decimal in the IEEE 754 `decimal32` interchange format
+    /// with the coefficient in densely packed decimal (DPD).
+    ///
+    /// The value is rounded first as by [Self::round_to_interchange], and the
+    /// raised signals are trapped according to `ctx`. `NaN` is encoded as a
+    /// quiet `NaN`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value overflows the format and the
+    /// given [Context] traps overflow.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_dpd32(&self, ctx: Context) -> u32 {
+        ieee754::encode(*self, Interchange::Decimal32, true, ctx) as u32
+    }
+
+    /// Decodes a decimal from the IEEE 754 `decimal32` interchange format
+    /// with the coefficient in densely packed decimal (DPD).
+    ///
+    /// Signaling `NaN` is decoded as `NaN`, and the `NaN` payload is ignored.
+    /// Non-canonical declets are decoded as the digits they represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, D128, decimal::Context};
+    ///
+    /// let bits = dec128!(-7.50).to_dpd32(Context::default());
+    ///
+    /// assert_eq!(bits, 0xa230_03d0);
+    /// assert_eq!(D128::from_dpd32(bits), dec128!(-7.50));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_dpd32(bits: u32) -> Self {
+        ieee754::decode(bits as u128, Interchange::Decimal32, true, Context::default())
+    }
+
+    /// Encodes the decimal in the IEEE 754 `decimal64` interchange format
+    /// with the coefficient in densely packed decimal (DPD).
+    ///
+    /// The value is rounded first as by [Self::round_to_interchange], and the
+    /// raised signals are trapped according to `ctx`. `NaN` is encoded as a
+    /// quiet `NaN`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value overflows the format and the
+    /// given [Context] traps overflow.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_dpd64(&self, ctx: Context) -> u64 {
+        ieee754::encode(*self, Interchange::Decimal64, true, ctx) as u64
+    }
+
+    /// Decodes a decimal from the IEEE 754 `decimal64` interchange format
+    /// with the coefficient in densely packed decimal (DPD).
+    ///
+    /// Signaling `NaN` is decoded as `NaN`, and the `NaN` payload is ignored.
+    /// Non-canonical declets are decoded as the digits they represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, D128, decimal::Context};
+    ///
+    /// let bits = dec128!(1).to_dpd64(Context::default());
+    ///
+    /// assert_eq!(bits, 0x2238_0000_0000_0001);
+    /// assert_eq!(D128::from_dpd64(bits), dec128!(1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_dpd64(bits: u64) -> Self {
+        ieee754::decode(bits as u128, Interchange::Decimal64, true, Context::default())
+    }
+
+    /// Encodes the decimal in the IEEE 754 `decimal128` interchange format
+    /// with the coefficient in densely packed decimal (DPD).
+    ///
+    /// The value is rounded first as by [Self::round_to_interchange], and the
+    /// raised signals are trapped according to `ctx`. `NaN` is encoded as a
+    /// quiet `NaN`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the value overflows the format and the
+    /// given [Context] traps overflow.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_dpd128(&self, ctx: Context) -> u128 {
+        ieee754::encode(*self, Interchange::Decimal128, true, ctx)
+    }
+
+    /// Decodes a decimal from the IEEE 754 `decimal128` interchange format
+    /// with the coefficient in densely packed decimal (DPD).
+    ///
+    /// Signaling `NaN` is decoded as `NaN`, and the `NaN` payload is ignored.
+    /// Non-canonical declets are decoded as the digits they represent.
+    ///
+    /// The coefficient is rounded according to `ctx` if it doesn't fit into
+    /// `Decimal<N>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, D128, decimal::Context};
+    ///
+    /// let ctx = Context::default();
+    /// let bits = dec128!(0.1).to_dpd128(ctx);
+    ///
+    /// assert_eq!(bits, 0x2207_c000_0000_0000_0000_0000_0000_0001);
+    /// assert_eq!(D128::from_dpd128(bits, ctx), dec128!(0.1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_dpd128(bits: u128, ctx: Context) -> Self {
+        ieee754::decode(bits, Interchange::Decimal128, true, ctx)
+    }
+
+    /// Creates a decimal from the integer mantissa and scale, so that the
+    /// value is `mantissa` × 10<sup>-scale</sup>.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the mantissa doesn't fit into the
+    /// coefficient of `Decimal<N>` and the default [Context] traps overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, D128};
+    ///
+    /// assert_eq!(D128::from_i128_with_scale(-12345, 2), dec128!(-123.45));
+    /// assert_eq!(D128::from_i128_with_scale(i128::MIN, 0).to_string(), i128::MIN.to_string());
+    /// ```
+    #[inline]
+    pub const fn from_i128_with_scale(mantissa: i128, scale: i16) -> Self {
+        let sign = if mantissa < 0 { Sign::Minus } else { Sign::Plus };
+        Self::from_u128_with_scale(mantissa.unsigned_abs(), scale).with_sign(sign)
+    }
+
+    /// Creates a decimal from the integer mantissa and scale, so that the
+    /// value is `mantissa` × 10<sup>-scale</sup>.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the mantissa doesn't fit into the
+    /// coefficient of `Decimal<N>` and the default [Context] traps overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec64, D64};
+    ///
+    /// assert_eq!(D64::from_u128_with_scale(12345, 4), dec64!(1.2345));
+    /// ```
+    #[inline]
+    pub const fn from_u128_with_scale(mantissa: u128, scale: i16) -> Self {
+        let wide = UInt::<2>::from_digits([mantissa as u64, (mantissa >> 64) as u64]);
+        let (digits, overflow) = resize::<2, N>(wide);
+
+        if overflow {
+            return Self::INFINITY
+                .raise_signal(Signal::overflow())
+                .unwrap_signals(Context::default());
+        }
+
+        Self::new(digits, scale, Flags::default())
+    }
+
+    /// Creates a decimal from the integer mantissa and scale, so that the
+    /// value is `mantissa` × 10<sup>-scale</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec64, D64};
+    ///
+    /// assert_eq!(D64::from_i64_with_scale(-5, 1), dec64!(-0.5));
+    /// ```
+    #[inline]
+    pub const fn from_i64_with_scale(mantissa: i64, scale: i16) -> Self {
+        Self::from_i128_with_scale(mantissa as i128, scale)
+    }
+
+    /// Creates a decimal from the integer mantissa and scale, so that the
+    /// value is `mantissa` × 10<sup>-scale</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec64, D64};
+    ///
+    /// assert_eq!(D64::from_u64_with_scale(u64::MAX, 19), dec64!(1.8446744073709551615));
+    /// ```
+    #[inline]
+    pub const fn from_u64_with_scale(mantissa: u64, scale: i16) -> Self {
+        Self::from_u128_with_scale(mantissa as u128, scale)
+    }
+
     /// Returns a number that represents the sign of `self`.
     ///
     /// - `1.0` if the number is positive, `+0.0` or
@@ -947,6 +1506,32 @@ impl<const N: usize> Decimal<N> {
         // }
     }
 
+    /// Converts the decimal into a decimal with the other bit width.
+    ///
+    /// Widening is always lossless. When narrowing, the least significant
+    /// digits that don't fit into the target coefficient are rounded
+    /// according to the given [Context], raising [Signal::OP_ROUNDED], and
+    /// also [Signal::OP_INEXACT] if any of them is nonzero, or
+    /// [Signal::OP_OVERFLOW] if the value cannot be represented at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, dec256, decimal::Context, D128, D256};
+    ///
+    /// let a = dec128!(1.5);
+    /// assert_eq!(a.resize::<4>(Context::default()), dec256!(1.5));
+    ///
+    /// let b = dec256!(340282366920938463463374607431768211456).resize::<2>(Context::default());
+    /// assert_eq!(b, dec128!(3.4028236692093846346337460743176821146e38));
+    /// assert!(b.is_op_rounded());
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn resize<const M: usize>(self, ctx: Context) -> Decimal<M> {
+        resize::resize(self, ctx).unwrap_signals(ctx)
+    }
+
     #[must_use = doc::must_use_op!()]
     #[inline]
     pub const fn ok(self) -> Option<Self> {
@@ -957,6 +1542,44 @@ impl<const N: usize> Decimal<N> {
         }
     }
 
+    /// Writes this decimal into `buf` and returns the written part of `buf`
+    /// as a string slice.
+    ///
+    /// The output is the same as of [`Display`](fmt::Display) without any
+    /// formatting options, but no memory is allocated, so it can be used in
+    /// `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `buf` is too short to hold the string.
+    /// A buffer of [`Self::MAX_STR_LEN`] bytes is always sufficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec256, D256};
+    ///
+    /// let mut buf = [0; D256::MAX_STR_LEN];
+    ///
+    /// assert_eq!(dec256!(-1.2300).to_str_buf(&mut buf), "-1.2300");
+    /// assert_eq!(dec256!(1e-10).to_str_buf(&mut buf), "1E-10");
+    /// ```
+    #[inline]
+    pub const fn to_str_buf<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        let len = if self.is_nan() {
+            format::write_bytes(buf, 0, b"NaN")
+        } else if self.is_infinite() {
+            let pos = match self.sign() {
+                Sign::Minus => format::write_bytes(buf, 0, b"-"),
+                Sign::Plus => 0,
+            };
+            format::write_bytes(buf, pos, b"Inf")
+        } else {
+            format::write_str_buf(self.digits, self.scale, self.sign(), buf)
+        };
+        format::as_str(buf, len)
+    }
+
     /// Create string of this decimal in scientific notation.
     ///
     /// # Examples
@@ -1010,6 +1633,36 @@ impl<const N: usize> Decimal<N> {
         }
     }
 
+    #[inline]
+    pub(crate) const fn try_from_u128(n: u128) -> Result<Self, ParseError> {
+        let wide = UInt::<2>::from_digits([n as u64, (n >> 64) as u64]);
+        let (digits, overflow) = resize::<2, N>(wide);
+
+        if overflow {
+            return Err(ParseError::PosOverflow);
+        }
+
+        Ok(Self::new(digits, 0, Flags::default()))
+    }
+
+    #[inline]
+    pub(crate) const fn try_from_i128(n: i128) -> Result<Self, ParseError> {
+        match Self::try_from_u128(n.unsigned_abs()) {
+            Ok(dec) if n < 0 => Ok(dec.with_sign(Sign::Minus)),
+            Ok(dec) => Ok(dec),
+            Err(_) if n < 0 => Err(ParseError::NegOverflow),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn with_sign(mut self, sign: Sign) -> Self {
+        if let Sign::Minus = sign {
+            self.flags = self.flags.neg();
+        }
+        self
+    }
+
     #[inline]
     pub(crate) fn type_name() -> String {
         format!("D{}", N * 64)
@@ -1070,7 +1723,8 @@ impl<const N: usize> Decimal<N> {
             return w.write_str("0e0");
         }
 
-        let digits = self.digits.to_str_radix(10);
+        let mut buf = format::digits_buf();
+        let digits = format::ascii_digits(self.digits, &mut buf);
         let scale = self.scale;
         format::write_scientific_notation(digits, scale, w)
     }
@@ -1093,7 +1747,8 @@ impl<const N: usize> Decimal<N> {
             return w.write_str("0e0");
         }
 
-        let digits = self.digits.to_str_radix(10);
+        let mut buf = format::digits_buf();
+        let digits = format::ascii_digits(self.digits, &mut buf);
         let scale = self.scale;
         format::write_engineering_notation(digits, scale, w)
     }
@@ -0,0 +1,381 @@
+use core::cmp::Ordering;
+
+use crate::{
+    decimal::{
+        dec::math::{
+            add::add,
+            mul::{mul, round_wide},
+            utils::overflow_scale,
+        },
+        Context, Decimal, Flags,
+    },
+    int::{div::Reciprocal, mul::widening_mul, UInt},
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// Returns the sum of all `values`.
+///
+/// If all values are finite and share the same scale, the coefficients are
+/// accumulated exactly in a double-width integer and the result is rounded at
+/// most once. Otherwise, the values are added one by one in order.
+///
+/// The sum of an empty slice is zero. A zero sum is negative only if all
+/// `values` are negative.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use fastnum::{dec256, D256, decimal::{batch, Context}};
+///
+/// let values = [dec256!(1.25), dec256!(-0.50), dec256!(3.00)];
+///
+/// assert_eq!(batch::sum_slice(&values, Context::default()), dec256!(3.75));
+/// assert_eq!(batch::sum_slice::<4>(&[], Context::default()), D256::ZERO);
+/// ```
+#[must_use]
+pub fn sum_slice<const N: usize>(values: &[D<N>], ctx: Context) -> D<N> {
+    let Some(scale) = common_scale(values) else {
+        return values
+            .iter()
+            .copied()
+            .reduce(|acc, value| add(acc, value, ctx))
+            .unwrap_or(D::ZERO)
+            .unwrap_signals(ctx);
+    };
+
+    // Positive and negative coefficients are accumulated separately.
+    let mut lanes = [Lanes::<N>::ZERO; 2];
+    let mut flags = Flags::default();
+    let mut all_negative = !values.is_empty();
+
+    for value in values {
+        let negative = value.flags.is_negative();
+
+        lanes[negative as usize].add_low(value.digits.digits());
+        flags = flags.with_signals_from(value.flags);
+        all_negative &= negative;
+    }
+
+    difference(lanes, all_negative, scale, flags, ctx).unwrap_signals(ctx)
+}
+
+/// Returns the dot product of `lhs` and `rhs`: the sum of the pairwise products
+/// of their elements.
+///
+/// If all values of each slice are finite and share the same scale, the
+/// products are accumulated exactly in a double-width integer without
+/// rounding the individual products, and the result is rounded at most once.
+/// Otherwise, the products are calculated and added one by one in order.
+///
+/// # Panics
+///
+/// This function will panic if the slices have different lengths.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use fastnum::{dec256, decimal::{batch, Context}};
+///
+/// let prices = [dec256!(1.50), dec256!(0.25), dec256!(10.00)];
+/// let quantities = [dec256!(2), dec256!(4), dec256!(-1)];
+///
+/// assert_eq!(batch::dot(&prices, &quantities, Context::default()), dec256!(-6.00));
+/// ```
+#[must_use]
+#[track_caller]
+pub fn dot<const N: usize>(lhs: &[D<N>], rhs: &[D<N>], ctx: Context) -> D<N> {
+    assert_eq!(lhs.len(), rhs.len(), "slices must have the same length");
+
+    let scale = match (common_scale(lhs), common_scale(rhs)) {
+        (Some(lhs), Some(rhs)) => lhs.checked_add(rhs),
+        _ => None,
+    };
+
+    let Some(scale) = scale else {
+        return lhs
+            .iter()
+            .zip(rhs)
+            .map(|(lhs, rhs)| mul(*lhs, *rhs, ctx))
+            .reduce(|acc, product| add(acc, product, ctx))
+            .unwrap_or(D::ZERO)
+            .unwrap_signals(ctx);
+    };
+
+    let mut lanes = [Lanes::<N>::ZERO; 2];
+    let mut flags = Flags::default();
+    let mut all_negative = !lhs.is_empty();
+
+    for (lhs, rhs) in lhs.iter().zip(rhs) {
+        let (low, high) = widening_mul(lhs.digits, rhs.digits);
+        let negative = lhs.flags.is_negative() ^ rhs.flags.is_negative();
+
+        lanes[negative as usize].add(low.digits(), high.digits());
+        flags = flags
+            .with_signals_from(lhs.flags)
+            .with_signals_from(rhs.flags);
+        all_negative &= negative;
+    }
+
+    difference(lanes, all_negative, scale, flags, ctx).unwrap_signals(ctx)
+}
+
+/// Multiplies every element of `values` by `factor` in place.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use fastnum::{dec256, decimal::{batch, Context}};
+///
+/// let mut values = [dec256!(1.5), dec256!(-2), dec256!(0.1)];
+/// batch::scale_slice(&mut values, dec256!(2), Context::default());
+///
+/// assert_eq!(values, [dec256!(3.0), dec256!(-4), dec256!(0.2)]);
+/// ```
+pub fn scale_slice<const N: usize>(values: &mut [D<N>], factor: D<N>, ctx: Context) {
+    for value in values {
+        *value = mul(*value, factor, ctx).unwrap_signals(ctx);
+    }
+}
+
+/// Stores the elementwise sums of `lhs` and `rhs` into `out`.
+///
+/// Pairs of finite values with the same sign and scale are added directly,
+/// without any alignment.
+///
+/// # Panics
+///
+/// This function will panic if the slices have different lengths.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use fastnum::{dec256, D256, decimal::{batch, Context}};
+///
+/// let a = [dec256!(1.5), dec256!(2), dec256!(0.1)];
+/// let b = [dec256!(2.5), dec256!(-3), dec256!(0.02)];
+/// let mut out = [D256::ZERO; 3];
+///
+/// batch::add_slices(&a, &b, &mut out, Context::default());
+///
+/// assert_eq!(out, [dec256!(4.0), dec256!(-1), dec256!(0.12)]);
+/// ```
+#[track_caller]
+pub fn add_slices<const N: usize>(lhs: &[D<N>], rhs: &[D<N>], out: &mut [D<N>], ctx: Context) {
+    assert_eq!(lhs.len(), rhs.len(), "slices must have the same length");
+    assert_eq!(lhs.len(), out.len(), "slices must have the same length");
+
+    for ((lhs, rhs), out) in lhs.iter().zip(rhs).zip(out) {
+        *out = add_aligned(*lhs, *rhs, ctx).unwrap_signals(ctx);
+    }
+}
+
+#[inline]
+fn add_aligned<const N: usize>(lhs: D<N>, rhs: D<N>, ctx: Context) -> D<N> {
+    if lhs.scale == rhs.scale
+        && !lhs.flags.is_special()
+        && !rhs.flags.is_special()
+        && lhs.flags.is_negative() == rhs.flags.is_negative()
+    {
+        let (digits, overflow) = lhs.digits.overflowing_add(rhs.digits);
+
+        if !overflow {
+            return D::new(digits, lhs.scale, lhs.flags.with_signals_from(rhs.flags));
+        }
+    }
+
+    add(lhs, rhs, ctx)
+}
+
+/// Returns the scale shared by all `values` if they are all finite.
+#[inline]
+fn common_scale<const N: usize>(values: &[D<N>]) -> Option<i16> {
+    let scale = values.first()?.scale;
+
+    values
+        .iter()
+        .all(|value| value.scale == scale && !value.flags.is_special())
+        .then_some(scale)
+}
+
+/// Subtracts the negative accumulator from the positive one and rounds the
+/// result to `N` digits.
+///
+/// A zero result is negative only if every term was negative, as it is when
+/// the terms are added one by one.
+#[inline]
+fn difference<const N: usize>(
+    [positive, negative]: [Lanes<N>; 2],
+    all_negative: bool,
+    scale: i16,
+    mut flags: Flags,
+    ctx: Context,
+) -> D<N> {
+    let positive = positive.resolve();
+    let negative = negative.resolve();
+
+    let magnitude = match positive.cmp(&negative) {
+        Ordering::Less => {
+            flags = flags.neg();
+            negative.sub(&positive)
+        }
+        Ordering::Equal if all_negative => {
+            flags = flags.neg();
+            positive.sub(&negative)
+        }
+        _ => positive.sub(&negative),
+    };
+
+    magnitude.into_decimal(scale, flags, ctx)
+}
+
+/// Carry-save accumulator of double-width coefficients.
+///
+/// Each lane sums up the 64-bit limbs at the same position, so that adding a
+/// value never propagates carries across limbs and all lanes are updated
+/// independently of each other. Carries are resolved once at the end.
+///
+/// A slice can't hold more than `2^63` elements, so a lane can't overflow.
+#[derive(Copy, Clone)]
+struct Lanes<const N: usize> {
+    low: [u128; N],
+    high: [u128; N],
+}
+
+impl<const N: usize> Lanes<N> {
+    const ZERO: Self = Self {
+        low: [0; N],
+        high: [0; N],
+    };
+
+    #[inline(always)]
+    fn add_low(&mut self, low: &[u64; N]) {
+        for (lane, digit) in self.low.iter_mut().zip(low) {
+            *lane += *digit as u128;
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, low: &[u64; N], high: &[u64; N]) {
+        self.add_low(low);
+
+        for (lane, digit) in self.high.iter_mut().zip(high) {
+            *lane += *digit as u128;
+        }
+    }
+
+    #[inline]
+    fn resolve(&self) -> Wide<N> {
+        let mut wide = Wide {
+            low: [0; N],
+            high: [0; N],
+            top: 0,
+        };
+        let mut carry = 0;
+
+        for (digit, lane) in wide.low.iter_mut().zip(&self.low) {
+            let sum = lane + carry;
+            *digit = sum as u64;
+            carry = sum >> 64;
+        }
+
+        for (digit, lane) in wide.high.iter_mut().zip(&self.high) {
+            let sum = lane + carry;
+            *digit = sum as u64;
+            carry = sum >> 64;
+        }
+
+        wide.top = carry as u64;
+        wide
+    }
+}
+
+/// Unsigned integer of `2 * N + 1` limbs.
+#[derive(Copy, Clone)]
+struct Wide<const N: usize> {
+    low: [u64; N],
+    high: [u64; N],
+    top: u64,
+}
+
+impl<const N: usize> Wide<N> {
+    #[inline]
+    fn limbs(&self) -> impl DoubleEndedIterator<Item = u64> + '_ {
+        self.low
+            .iter()
+            .chain(&self.high)
+            .copied()
+            .chain(Some(self.top))
+    }
+
+    #[inline]
+    fn limbs_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut u64> {
+        self.low
+            .iter_mut()
+            .chain(&mut self.high)
+            .chain(Some(&mut self.top))
+    }
+
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs().rev().cmp(other.limbs().rev())
+    }
+
+    /// Returns `self - other`, where `other` must not be greater than `self`.
+    #[inline]
+    fn sub(mut self, other: &Self) -> Self {
+        let mut borrow = false;
+
+        for (digit, rhs) in self.limbs_mut().zip(other.limbs()) {
+            let (diff, b1) = digit.overflowing_sub(rhs);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            *digit = diff;
+            borrow = b1 | b2;
+        }
+
+        debug_assert!(!borrow);
+        self
+    }
+
+    /// Drops 19 digits at a time until the value fits into `2 * N` limbs, then
+    /// rounds it to `N` digits.
+    #[inline]
+    fn into_decimal(mut self, mut scale: i16, flags: Flags, ctx: Context) -> D<N> {
+        let mut sticky = 0;
+
+        while self.top != 0 {
+            let mut rem = 0;
+
+            for digit in self.limbs_mut().rev() {
+                (*digit, rem) = Reciprocal::TEN_POW_19.div_rem(*digit, rem);
+            }
+
+            sticky |= rem;
+
+            let overflow;
+            (scale, overflow) = scale.overflowing_sub(19);
+
+            if overflow {
+                return overflow_scale(scale, flags);
+            }
+        }
+
+        let low = UInt::from_digits(self.low);
+        let high = UInt::from_digits(self.high);
+
+        if high.is_zero() {
+            debug_assert!(sticky == 0);
+            D::new(low, scale, flags)
+        } else {
+            round_wide(low, high, sticky, scale, flags, ctx)
+        }
+    }
+}
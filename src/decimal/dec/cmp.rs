@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use crate::{
     decimal::{dec::normalize::normalize, Decimal},
-    int::UInt,
+    int::pow10::Pow10,
 };
 
 type D<const N: usize> = Decimal<N>;
@@ -68,34 +68,33 @@ const fn cmp_magnitude<const N: usize>(lhs: &D<N>, rhs: &D<N>) -> Ordering {
         (_, _) => {}
     }
 
-    let a = normalize(*lhs);
-    let b = normalize(*rhs);
+    let (a, b) = (lhs, rhs);
 
     if a.scale == b.scale {
         return a.digits.cmp(&b.digits);
     }
 
-    let a_exp = a.digits.ilog10() as i16 - a.scale;
-    let b_exp = b.digits.ilog10() as i16 - b.scale;
+    // The exponent of the most significant digit doesn't depend on the
+    // trailing zeros, so there is no need to normalize the operands.
+    let a_exp = Pow10::<N>::digits_count(a.digits) as i32 - a.scale as i32;
+    let b_exp = Pow10::<N>::digits_count(b.digits) as i32 - b.scale as i32;
 
     if a_exp == b_exp {
+        // Both coefficients have the same number of digits after alignment,
+        // so the scaled one can only overflow if it is the greater one.
         if a.scale > b.scale {
-            let (mul, false) = UInt::TEN.overflowing_pow((a.scale - b.scale) as u32) else {
-                return Ordering::Less;
-            };
+            let power = Pow10::<N>::power((a.scale - b.scale) as u32);
 
-            let (value, false) = b.digits.overflowing_mul(mul) else {
+            let (value, false) = b.digits.overflowing_mul(power) else {
                 return Ordering::Less;
             };
 
             a.digits.cmp(&value)
         } else {
-            let (mul, false) = UInt::TEN.overflowing_pow((b.scale - a.scale) as u32) else {
-                return Ordering::Less;
-            };
+            let power = Pow10::<N>::power((b.scale - a.scale) as u32);
 
-            let (value, false) = a.digits.overflowing_mul(mul) else {
-                return Ordering::Less;
+            let (value, false) = a.digits.overflowing_mul(power) else {
+                return Ordering::Greater;
             };
 
             value.cmp(&b.digits)
@@ -1,6 +1,9 @@
 #[cfg(feature = "diesel")]
 mod diesel;
 
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
 #[cfg(feature = "serde")]
 mod serde;
 
@@ -4,6 +4,9 @@ mod mysql;
 #[cfg(feature = "diesel_postgres")]
 mod pg;
 
+#[cfg(feature = "diesel_sqlite")]
+mod sqlite;
+
 use diesel::{
     self,
     backend::Backend,
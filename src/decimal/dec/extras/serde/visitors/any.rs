@@ -29,7 +29,7 @@ impl<'de, const N: usize> de::Visitor<'de> for Visitor<N> {
     where
         E: de::Error,
     {
-        Ok(D::<N>::from(value))
+        D::<N>::try_from_i128(value).map_err(|err| E::custom(format!("{}", err)))
     }
 
     fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
@@ -43,7 +43,7 @@ impl<'de, const N: usize> de::Visitor<'de> for Visitor<N> {
     where
         E: de::Error,
     {
-        Ok(D::<N>::from(value))
+        D::<N>::try_from_u128(value).map_err(|err| E::custom(format!("{}", err)))
     }
 
     fn visit_f32<E>(self, value: f32) -> Result<Self::Value, E>
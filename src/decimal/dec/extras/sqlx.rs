@@ -0,0 +1,8 @@
+#[cfg(feature = "sqlx_mysql")]
+mod mysql;
+
+#[cfg(feature = "sqlx_postgres")]
+mod pg;
+
+#[cfg(feature = "sqlx_sqlite")]
+mod sqlite;
@@ -0,0 +1,42 @@
+use sqlx::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    mysql::{MySql, MySqlTypeInfo, MySqlValueRef},
+    Type, TypeInfo,
+};
+
+use crate::decimal::{errors::parse::pretty_error_msg, extras::sqlx::mysql, Decimal};
+
+type D<const N: usize> = Decimal<N>;
+
+impl<const N: usize> Type<MySql> for D<N> {
+    #[inline]
+    fn type_info() -> MySqlTypeInfo {
+        <str as Type<MySql>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        ty.name() == "DECIMAL"
+    }
+}
+
+impl<const N: usize> Encode<'_, MySql> for D<N> {
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        if !self.is_finite() {
+            return Err(format!("{self} is not valid MySQL decimal number").into());
+        }
+
+        <&str as Encode<MySql>>::encode(self.to_string().as_str(), buf)
+    }
+}
+
+impl<const N: usize> Decode<'_, MySql> for D<N> {
+    #[inline]
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = <&[u8] as Decode<MySql>>::decode(value)?;
+        Ok(mysql::decode(bytes).map_err(|e| pretty_error_msg(Self::type_name().as_str(), e))?)
+    }
+}
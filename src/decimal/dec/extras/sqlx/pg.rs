@@ -0,0 +1,48 @@
+use sqlx::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    postgres::{
+        types::Oid, PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef,
+        Postgres,
+    },
+    Type,
+};
+
+use crate::decimal::{errors::parse::pretty_error_msg, extras::sqlx::pg, Decimal};
+
+type D<const N: usize> = Decimal<N>;
+
+impl<const N: usize> Type<Postgres> for D<N> {
+    #[inline]
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_oid(Oid(1700))
+    }
+}
+
+impl<const N: usize> PgHasArrayType for D<N> {
+    #[inline]
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_oid(Oid(1231))
+    }
+}
+
+impl<const N: usize> Encode<'_, Postgres> for D<N> {
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        pg::encode(*self, buf).map_err(|e| pretty_error_msg(Self::type_name().as_str(), e))?;
+        Ok(IsNull::No)
+    }
+}
+
+impl<const N: usize> Decode<'_, Postgres> for D<N> {
+    #[inline]
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let dec = match value.format() {
+            PgValueFormat::Binary => pg::decode(value.as_bytes()?),
+            PgValueFormat::Text => D::from_str(value.as_str()?),
+        };
+
+        Ok(dec.map_err(|e| pretty_error_msg(Self::type_name().as_str(), e))?)
+    }
+}
@@ -0,0 +1,48 @@
+use sqlx::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    sqlite::{Sqlite, SqliteArgumentsBuffer, SqliteTypeInfo, SqliteValueRef},
+    Type, TypeInfo, ValueRef,
+};
+
+use crate::decimal::{
+    errors::parse::pretty_error_msg, extras::utils::db::sqlite::from_real, Decimal,
+};
+
+type D<const N: usize> = Decimal<N>;
+
+// Decimals are stored as `TEXT`, which keeps the exact value and scale.
+// `INTEGER` values are read exactly and `REAL` values via their shortest
+// round-trip decimal representation.
+impl<const N: usize> Type<Sqlite> for D<N> {
+    #[inline]
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(ty.name(), "TEXT" | "REAL" | "INTEGER" | "NUMERIC")
+    }
+}
+
+impl<const N: usize> Encode<'_, Sqlite> for D<N> {
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut SqliteArgumentsBuffer) -> Result<IsNull, BoxDynError> {
+        <String as Encode<Sqlite>>::encode(self.to_string(), buf)
+    }
+}
+
+impl<const N: usize> Decode<'_, Sqlite> for D<N> {
+    #[inline]
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        let dec = match value.type_info().name() {
+            "INTEGER" => Ok(D::from(<i64 as Decode<Sqlite>>::decode(value)?)),
+            "REAL" => from_real(<f64 as Decode<Sqlite>>::decode(value)?),
+            _ => D::from_str(<&str as Decode<Sqlite>>::decode(value)?),
+        };
+
+        Ok(dec.map_err(|e| pretty_error_msg(Self::type_name().as_str(), e))?)
+    }
+}
@@ -6,26 +6,321 @@ use crate::decimal::utils::cast::ToPrimitive;
 #[cfg(feature = "numtraits")]
 use num_traits::ToPrimitive;
 
-use crate::decimal::{RoundingMode, Sign};
+use crate::{
+    decimal::{RoundingMode, Sign},
+    int::{format::write_digits, pow10::Pow10, UInt},
+};
 
 include!(concat!(env!("OUT_DIR"), "/exponential_format_threshold.rs"));
 
+/// Upper bound of the number of decimal digits per 64-bit limb.
+const LIMB_DIGITS: usize = 20;
+
+/// Exponent suffix length: symbol, sign and up to 39 digits of `i128`.
+const EXP_BUF_LEN: usize = 41;
+
+/// Stack buffer for the ASCII digits of a `UInt<N>` coefficient.
+///
+/// Array lengths can't be computed from `N` on stable Rust, so the buffer is
+/// laid out as `N` rows of [`LIMB_DIGITS`] bytes.
+pub(crate) type DigitsBuf<const N: usize> = [[u8; LIMB_DIGITS]; N];
+
+/// Returns a zeroed [`DigitsBuf`].
+#[inline(always)]
+pub(crate) const fn digits_buf<const N: usize>() -> DigitsBuf<N> {
+    [[0; LIMB_DIGITS]; N]
+}
+
+/// Returns the maximum length of the string written by [`write_str_buf`].
+pub(crate) const fn max_str_len<const N: usize>() -> usize {
+    // Decimal point and exponent: `.E-32767`.
+    let mut extra = 8;
+
+    // Leading zeros: `0.00000`.
+    if EXPONENTIAL_FORMAT_LEADING_ZERO_THRESHOLD + 2 > extra {
+        extra = EXPONENTIAL_FORMAT_LEADING_ZERO_THRESHOLD + 2;
+    }
+
+    // Trailing zeros of an integer value.
+    if EXPONENTIAL_FORMAT_TRAILING_ZERO_THRESHOLD > extra {
+        extra = EXPONENTIAL_FORMAT_TRAILING_ZERO_THRESHOLD;
+    }
+
+    1 + Pow10::<N>::MAX_POWER as usize + 1 + extra
+}
+
+/// Writes the ASCII digits of `value` into `buf` and returns them.
+pub(crate) fn ascii_digits<const N: usize>(value: UInt<N>, buf: &mut DigitsBuf<N>) -> &mut [u8] {
+    let buf = buf.as_flattened_mut();
+    let len = write_digits(value, buf);
+    &mut buf[..len]
+}
+
+/// Writes `bytes` into `buf` at `pos` and returns the position after them.
+pub(crate) const fn write_bytes(buf: &mut [u8], mut pos: usize, bytes: &[u8]) -> usize {
+    let mut i = 0;
+    while i < bytes.len() {
+        buf[pos] = bytes[i];
+        pos += 1;
+        i += 1;
+    }
+    pos
+}
+
+/// Returns the first `len` bytes of `buf` written by this module as `&str`.
+pub(crate) const fn as_str(buf: &[u8], len: usize) -> &str {
+    // SAFETY: only ASCII bytes are ever written into formatting buffers.
+    #[allow(unsafe_code)]
+    let str = unsafe { core::str::from_utf8_unchecked(buf.split_at(len).0) };
+    str
+}
+
+/// Writes a decimal into `buf` exactly as [`Display`](fmt::Display) without
+/// formatting options does and returns the length of the written string.
+///
+/// `buf` must hold at least [`max_str_len`] bytes.
+pub(crate) const fn write_str_buf<const N: usize>(
+    digits: UInt<N>,
+    scale: i16,
+    sign: Sign,
+    buf: &mut [u8],
+) -> usize {
+    let pos = match sign {
+        Sign::Minus => write_bytes(buf, 0, b"-"),
+        Sign::Plus => 0,
+    };
+
+    let len = write_digits(digits, buf.split_at_mut(pos).1);
+    let end = pos + len;
+
+    // number of zeros between the most significant digit and decimal point
+    let leading_zero_count = if scale > 0 && scale as usize > len {
+        scale as usize - len
+    } else {
+        0
+    };
+
+    // number of zeros between last significant digit and decimal point
+    let trailing_zeros = match scale.checked_neg() {
+        Some(zeros) if zeros > 0 => zeros as usize,
+        _ => 0,
+    };
+
+    let mut exp_buf = [0; EXP_BUF_LEN];
+
+    if EXPONENTIAL_FORMAT_LEADING_ZERO_THRESHOLD < leading_zero_count {
+        let exponent = len as i128 - scale as i128 - 1;
+        let end = if len > 1 {
+            shift_right(buf, pos + 1, end, 1);
+            buf[pos + 1] = b'.';
+            end + 1
+        } else {
+            end
+        };
+        write_bytes(buf, end, exp_suffix(&mut exp_buf, b'E', exponent))
+    } else if EXPONENTIAL_FORMAT_TRAILING_ZERO_THRESHOLD < trailing_zeros {
+        write_bytes(buf, end, exp_suffix(&mut exp_buf, b'e', trailing_zeros as i128))
+    } else if scale <= 0 {
+        let exp = -(scale as i128);
+        if exp as usize > FMT_MAX_INTEGER_PADDING || exp > 20 {
+            write_bytes(buf, end, exp_suffix(&mut exp_buf, b'e', exp))
+        } else {
+            write_zeros(buf, end, exp as usize)
+        }
+    } else if (scale as usize) < len {
+        let point = end - scale as usize;
+        shift_right(buf, point, end, 1);
+        buf[point] = b'.';
+        end + 1
+    } else {
+        let shift = 2 + leading_zero_count;
+        shift_right(buf, pos, end, shift);
+        let zeros = write_bytes(buf, pos, b"0.");
+        write_zeros(buf, zeros, leading_zero_count);
+        end + shift
+    }
+}
+
+const fn write_zeros(buf: &mut [u8], mut pos: usize, count: usize) -> usize {
+    let end = pos + count;
+    while pos < end {
+        buf[pos] = b'0';
+        pos += 1;
+    }
+    end
+}
+
+/// Moves `buf[start..end]` right by `shift` bytes.
+const fn shift_right(buf: &mut [u8], start: usize, mut end: usize, shift: usize) {
+    while end > start {
+        end -= 1;
+        buf[end + shift] = buf[end];
+    }
+}
+
+/// Formats an exponent suffix such as `e+5` or `E-12` into `buf`.
+const fn exp_suffix(buf: &mut [u8; EXP_BUF_LEN], symbol: u8, exp: i128) -> &[u8] {
+    let mut value = exp.unsigned_abs();
+    let mut start = EXP_BUF_LEN;
+
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+
+    start -= 1;
+    buf[start] = if exp < 0 { b'-' } else { b'+' };
+
+    start -= 1;
+    buf[start] = symbol;
+
+    buf.split_at(start).1
+}
+
+/// A piece of formatted output.
+#[derive(Copy, Clone)]
+enum Part<'a> {
+    /// Given number of `0` digits.
+    Zero(usize),
+
+    /// ASCII bytes to be copied as is.
+    Copy(&'a [u8]),
+}
+
+impl Part<'_> {
+    #[inline]
+    const fn len(&self) -> usize {
+        match self {
+            Part::Zero(count) => *count,
+            Part::Copy(bytes) => bytes.len(),
+        }
+    }
+
+    fn write<W: Write>(&self, w: &mut W) -> fmt::Result {
+        const ZEROS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+        match *self {
+            Part::Zero(mut count) => {
+                while count > ZEROS.len() {
+                    w.write_str(ZEROS)?;
+                    count -= ZEROS.len();
+                }
+                w.write_str(&ZEROS[..count])
+            }
+            Part::Copy(bytes) => w.write_str(as_str(bytes, bytes.len())),
+        }
+    }
+}
+
+/// Fixed capacity list of formatted [`Part`]s.
+struct Parts<'a> {
+    parts: [Part<'a>; 6],
+    len: usize,
+}
+
+impl<'a> Parts<'a> {
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            parts: [Part::Zero(0); 6],
+            len: 0,
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, part: Part<'a>) {
+        self.parts[self.len] = part;
+        self.len += 1;
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[Part<'a>] {
+        &self.parts[..self.len]
+    }
+}
+
+/// Writes `parts` to the formatter the same way as
+/// [`Formatter::pad_integral`](fmt::Formatter::pad_integral) writes a single
+/// string without a prefix.
+fn pad_parts(f: &mut fmt::Formatter, non_negative: bool, parts: &[Part]) -> fmt::Result {
+    let sign = if !non_negative {
+        Some('-')
+    } else if f.sign_plus() {
+        Some('+')
+    } else {
+        None
+    };
+
+    let mut width = parts.iter().map(Part::len).sum::<usize>();
+    if sign.is_some() {
+        width += 1;
+    }
+
+    let write_parts = |f: &mut fmt::Formatter| -> fmt::Result {
+        for part in parts {
+            part.write(f)?;
+        }
+        Ok(())
+    };
+
+    let padding = match f.width() {
+        Some(min) if width < min => min - width,
+        _ => {
+            if let Some(sign) = sign {
+                f.write_char(sign)?;
+            }
+            return write_parts(f);
+        }
+    };
+
+    if f.sign_aware_zero_pad() {
+        if let Some(sign) = sign {
+            f.write_char(sign)?;
+        }
+        Part::Zero(padding).write(f)?;
+        return write_parts(f);
+    }
+
+    let (pre, post) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    if let Some(sign) = sign {
+        f.write_char(sign)?;
+    }
+    write_parts(f)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
 pub(crate) fn write_scientific_notation<W: Write>(
-    digits: String,
+    digits: &[u8],
     scale: i16,
     w: &mut W,
 ) -> fmt::Result {
-    let (first_digit, remaining_digits) = digits.as_str().split_at(1);
-    w.write_str(first_digit)?;
+    let (first_digit, remaining_digits) = digits.split_at(1);
+    Part::Copy(first_digit).write(w)?;
     if !remaining_digits.is_empty() {
         w.write_str(".")?;
-        w.write_str(remaining_digits)?;
+        Part::Copy(remaining_digits).write(w)?;
     }
     write!(w, "e{}", remaining_digits.len() as i32 - scale as i32)
 }
 
 pub(crate) fn write_engineering_notation<W: Write>(
-    digits: String,
+    digits: &[u8],
     scale: i16,
     out: &mut W,
 ) -> fmt::Result {
@@ -42,27 +337,26 @@ pub(crate) fn write_engineering_notation<W: Write>(
 
     // handle adding zero padding
     if let Some(padding_zero_count) = shift_amount.checked_sub(digits.len()) {
-        let zeros = &"000"[..padding_zero_count];
-        out.write_str(&digits)?;
-        out.write_str(zeros)?;
+        Part::Copy(digits).write(out)?;
+        Part::Zero(padding_zero_count).write(out)?;
         return write!(out, "e{}", exp);
     }
 
     let (head, rest) = digits.split_at(shift_amount);
     debug_assert_eq!(exp % 3, 0);
 
-    out.write_str(head)?;
+    Part::Copy(head).write(out)?;
 
     if !rest.is_empty() {
         out.write_char('.')?;
-        out.write_str(rest)?;
+        Part::Copy(rest).write(out)?;
     }
 
     write!(out, "e{}", exp)
 }
 
 pub(crate) fn format(
-    digits: String,
+    digits: &mut [u8],
     scale: i16,
     sign: Sign,
     f: &mut fmt::Formatter,
@@ -89,51 +383,56 @@ pub(crate) fn format(
     // use exponential form if decimal point is outside
     // the upper and lower thresholds of the decimal
     if leading_zero_threshold < leading_zero_count {
-        format_exponential(digits, scale, sign, f, "E")
+        format_exponential(digits, scale, sign, f, b'E')
     } else if trailing_zero_threshold < trailing_zeros {
         // non-scientific notation
-        format_dotless_exponential(digits, scale, sign, f, "e")
+        format_dotless_exponential(digits, scale, sign, f, b'e')
     } else {
         format_full_scale(digits, scale, sign, f)
     }
 }
 
 pub(crate) fn format_exponential(
-    digits: String,
+    digits: &mut [u8],
     scale: i16,
     sign: Sign,
     f: &mut fmt::Formatter,
-    e_symbol: &str,
+    e_symbol: u8,
 ) -> fmt::Result {
     let exp = (scale as i128).neg();
     format_exponential_be_ascii_digits(digits, exp, sign, f, e_symbol)
 }
 
 fn format_dotless_exponential(
-    mut digits: String,
+    digits: &[u8],
     scale: i16,
     sign: Sign,
     f: &mut fmt::Formatter,
-    e_symbol: &str,
+    e_symbol: u8,
 ) -> fmt::Result {
     debug_assert!(scale <= 0);
-    write!(digits, "{}{:+}", e_symbol, -scale)?;
+    let mut exp_buf = [0; EXP_BUF_LEN];
+    let parts = [
+        Part::Copy(digits),
+        Part::Copy(exp_suffix(&mut exp_buf, e_symbol, (scale as i128).neg())),
+    ];
     let non_negative = matches!(sign, Sign::Plus);
-    f.pad_integral(non_negative, "", &digits)
+    pad_parts(f, non_negative, &parts)
 }
 
 fn format_full_scale(
-    digits: String,
+    digits: &mut [u8],
     scale: i16,
     sign: Sign,
     f: &mut fmt::Formatter,
 ) -> fmt::Result {
-    let mut digits = digits.into_bytes();
     let mut exp = (scale as i128).neg();
+    let mut parts = Parts::new();
 
     if scale <= 0 {
         // formatting an integer value (add trailing zeros to the right)
-        zero_right_pad_integer_ascii_digits(&mut digits, &mut exp, f.precision());
+        parts.push(Part::Copy(digits));
+        zero_right_pad_integer_ascii_digits(&mut parts, &mut exp, f.precision());
     } else {
         let scale = scale as u64;
         // no-precision behaves the same as precision matching scale (i.e. no padding or
@@ -145,34 +444,31 @@ fn format_full_scale(
 
         if scale < digits.len() as u64 {
             // format both integer and fractional digits (always 'trim' to precision)
-            trim_ascii_digits(&mut digits, scale, sign, prec, &mut exp);
+            trim_ascii_digits(&mut parts, digits, scale, sign, prec, &mut exp);
         } else {
             // format only fractional digits
-            shift_or_trim_fractional_digits(&mut digits, scale, sign, prec, &mut exp);
+            shift_or_trim_fractional_digits(&mut parts, digits, scale, sign, prec, &mut exp);
         }
         // never print exp when in this branch
         exp = 0;
     }
 
-    // move digits back into String form
-    let mut buf = String::from_utf8(digits).unwrap();
-
-    // add exp part to buffer (if not zero)
+    // add exp part (if not zero)
+    let mut exp_buf = [0; EXP_BUF_LEN];
     if exp != 0 {
-        write!(buf, "e{:+}", exp)?;
+        parts.push(Part::Copy(exp_suffix(&mut exp_buf, b'e', exp)));
     }
 
-    // write buffer to formatter
+    // write parts to formatter
     let non_negative = matches!(sign, Sign::Plus);
-    f.pad_integral(non_negative, "", &buf)
+    pad_parts(f, non_negative, parts.as_slice())
 }
 
-/// Fill appropriate number of zeros and decimal point into Vec of (ascii/utf-8)
-/// digits
+/// Append appropriate number of zeros and decimal point to the integer digits
 ///
 /// Exponent is set to zero if zeros were added
 fn zero_right_pad_integer_ascii_digits(
-    digits: &mut Vec<u8>,
+    parts: &mut Parts,
     exp: &mut i128,
     precision: Option<usize>,
 ) {
@@ -193,44 +489,58 @@ fn zero_right_pad_integer_ascii_digits(
     match precision {
         None if trailing_zero_count > 20 => {}
         None | Some(0) => {
-            digits.resize(digits.len() + trailing_zero_count, b'0');
+            parts.push(Part::Zero(trailing_zero_count));
             *exp = 0;
         }
         Some(prec) => {
-            digits.resize(digits.len() + trailing_zero_count, b'0');
-            digits.push(b'.');
-            digits.resize(digits.len() + prec, b'0');
+            parts.push(Part::Zero(trailing_zero_count));
+            parts.push(Part::Copy(b"."));
+            parts.push(Part::Zero(prec));
             *exp = 0;
         }
     }
 }
 
-fn trim_ascii_digits(digits: &mut Vec<u8>, scale: u64, sign: Sign, prec: u64, exp: &mut i128) {
+fn trim_ascii_digits<'a>(
+    parts: &mut Parts<'a>,
+    digits: &'a mut [u8],
+    scale: u64,
+    sign: Sign,
+    prec: u64,
+    exp: &mut i128,
+) {
     debug_assert!(scale < digits.len() as u64);
     // there are both integer and fractional digits
     let integer_digit_count = (digits.len() as u64 - scale)
         .to_usize()
         .expect("Number of digits exceeds maximum usize");
 
+    let mut len = digits.len();
+
     if prec < scale {
         let prec = prec.to_usize().expect("Precision exceeds maximum usize");
-        apply_rounding_to_ascii_digits(digits, exp, sign, integer_digit_count + prec);
+        len = apply_rounding_to_ascii_digits(digits, exp, sign, integer_digit_count + prec);
     }
 
+    let (integer, fractional) = digits[..len].split_at(integer_digit_count);
+    parts.push(Part::Copy(integer));
+
     if prec != 0 {
-        digits.insert(integer_digit_count, b'.');
+        parts.push(Part::Copy(b"."));
+        parts.push(Part::Copy(fractional));
     }
 
     if scale < prec {
         let trailing_zero_count = (prec - scale).to_usize().expect("Too Big");
 
         // precision required beyond scale
-        digits.resize(digits.len() + trailing_zero_count, b'0');
+        parts.push(Part::Zero(trailing_zero_count));
     }
 }
 
-fn shift_or_trim_fractional_digits(
-    digits: &mut Vec<u8>,
+fn shift_or_trim_fractional_digits<'a>(
+    parts: &mut Parts<'a>,
+    digits: &'a mut [u8],
     scale: u64,
     sign: Sign,
     prec: u64,
@@ -242,11 +552,10 @@ fn shift_or_trim_fractional_digits(
 
     match prec.checked_sub(leading_zeros) {
         None => {
-            digits.clear();
-            digits.push(b'0');
+            parts.push(Part::Copy(b"0"));
             if prec > 0 {
-                digits.push(b'.');
-                digits.resize(2 + prec as usize, b'0');
+                parts.push(Part::Copy(b"."));
+                parts.push(Part::Zero(prec as usize));
             }
         }
         Some(0) => {
@@ -261,13 +570,12 @@ fn shift_or_trim_fractional_digits(
                 trailing_zeros,
             );
 
-            digits.clear();
             if leading_zeros != 0 {
-                digits.push(b'0');
-                digits.push(b'.');
-                digits.resize(1 + leading_zeros as usize, b'0');
+                parts.push(Part::Copy(b"0."));
+                parts.push(Part::Zero(leading_zeros as usize - 1));
             }
-            digits.push(rounded_value + b'0');
+            digits[0] = rounded_value + b'0';
+            parts.push(Part::Copy(&digits[..1]));
         }
         Some(digit_prec) => {
             let digit_prec = digit_prec as usize;
@@ -275,27 +583,28 @@ fn shift_or_trim_fractional_digits(
                 .to_usize()
                 .expect("Number of leading zeros exceeds max usize");
             let trailing_zeros = digit_prec.saturating_sub(digits.len());
-            if digit_prec < digits.len() {
-                apply_rounding_to_ascii_digits(digits, exp, sign, digit_prec);
+            let mut len = digits.len();
+            if digit_prec < len {
+                len = apply_rounding_to_ascii_digits(digits, exp, sign, digit_prec);
             }
-            digits.extend_from_slice(b"0.");
-            digits.resize(digits.len() + leading_zeros, b'0');
-            digits.rotate_right(leading_zeros + 2);
+            parts.push(Part::Copy(b"0."));
+            parts.push(Part::Zero(leading_zeros));
+            parts.push(Part::Copy(&digits[..len]));
 
             // add any extra trailing zeros
-            digits.resize(digits.len() + trailing_zeros, b'0');
+            parts.push(Part::Zero(trailing_zeros));
         }
     }
 }
 
 fn format_exponential_be_ascii_digits(
-    digits: String,
+    digits: &mut [u8],
     mut exp: i128,
     sign: Sign,
     f: &mut fmt::Formatter,
-    e_symbol: &str,
+    e_symbol: u8,
 ) -> fmt::Result {
-    let mut digits = digits.into_bytes();
+    let mut len = digits.len();
 
     // how many zeros to pad at the end of the decimal
     let mut extra_trailing_zero_count = 0;
@@ -303,26 +612,25 @@ fn format_exponential_be_ascii_digits(
     if let Some(prec) = f.precision() {
         // 'prec' is number of digits after the decimal point
         let total_prec = prec + 1;
-        let digit_count = digits.len();
 
-        match total_prec.cmp(&digit_count) {
+        match total_prec.cmp(&len) {
             Ordering::Equal => {
                 // digit count is one more than precision - do nothing
             }
             Ordering::Less => {
                 // round to smaller precision
-                apply_rounding_to_ascii_digits(&mut digits, &mut exp, sign, total_prec);
+                len = apply_rounding_to_ascii_digits(digits, &mut exp, sign, total_prec);
             }
             Ordering::Greater => {
                 // increase number of zeros to add to end of digits
-                extra_trailing_zero_count = total_prec - digit_count;
+                extra_trailing_zero_count = total_prec - len;
             }
         }
     }
 
-    let needs_decimal_point = digits.len() > 1 || extra_trailing_zero_count > 0;
+    let needs_decimal_point = len > 1 || extra_trailing_zero_count > 0;
 
-    let mut abs_int = String::from_utf8(digits).unwrap();
+    let abs_int = &digits[..len];
 
     // Determine the exponent value based on the scale
     //
@@ -367,31 +675,38 @@ fn format_exponential_be_ascii_digits(
     //     come up with an example
     let exponent = abs_int.len() as i128 + exp - 1;
 
+    let mut parts = Parts::new();
+    let (first, rest) = abs_int.split_at(1);
+    parts.push(Part::Copy(first));
+
     if needs_decimal_point {
         // only add decimal point if there is more than 1 decimal digit
-        abs_int.insert(1, '.');
+        parts.push(Part::Copy(b"."));
+        parts.push(Part::Copy(rest));
     }
 
     if extra_trailing_zero_count > 0 {
-        abs_int.extend(core::iter::repeat('0').take(extra_trailing_zero_count));
+        parts.push(Part::Zero(extra_trailing_zero_count));
     }
 
     // always print exponent in exponential mode
-    write!(abs_int, "{}{:+}", e_symbol, exponent)?;
+    let mut exp_buf = [0; EXP_BUF_LEN];
+    parts.push(Part::Copy(exp_suffix(&mut exp_buf, e_symbol, exponent)));
 
     let non_negative = matches!(sign, Sign::Plus);
-    f.pad_integral(non_negative, "", &abs_int)
+    pad_parts(f, non_negative, parts.as_slice())
 }
 
-/// Round big-endian digits in ascii
+/// Round big-endian digits in ascii in place and return the new number of
+/// digits
 fn apply_rounding_to_ascii_digits(
-    ascii_digits: &mut Vec<u8>,
+    ascii_digits: &mut [u8],
     exp: &mut i128,
     sign: Sign,
     prec: usize,
-) {
-    if ascii_digits.len() < prec {
-        return;
+) -> usize {
+    if ascii_digits.len() <= prec {
+        return ascii_digits.len();
     }
 
     // shift exp to align with new length of digits
@@ -411,18 +726,18 @@ fn apply_rounding_to_ascii_digits(
     );
 
     // remove insignificant digits
-    ascii_digits.truncate(prec - 1);
+    let ascii_digits = &mut ascii_digits[..prec];
 
     // push rounded value
     if rounded_digit < 10 {
-        ascii_digits.push(rounded_digit + b'0');
-        return;
+        ascii_digits[prec - 1] = rounded_digit + b'0';
+        return prec;
     }
 
     debug_assert_eq!(rounded_digit, 10);
 
     // push zero and carry-the-one
-    ascii_digits.push(b'0');
+    ascii_digits[prec - 1] = b'0';
 
     // loop through digits in reverse order (skip the 0 we just pushed)
     let digits = ascii_digits.iter_mut().rev().skip(1);
@@ -430,7 +745,7 @@ fn apply_rounding_to_ascii_digits(
         if *digit < b'9' {
             // we've carried the one as far as it will go
             *digit += 1;
-            return;
+            return prec;
         }
 
         debug_assert_eq!(*digit, b'9');
@@ -447,4 +762,5 @@ fn apply_rounding_to_ascii_digits(
     //
     ascii_digits[0] = b'1';
     *exp += 1;
+    prec
 }
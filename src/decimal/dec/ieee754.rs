@@ -0,0 +1,382 @@
+use core::cmp::Ordering;
+
+use crate::{
+    decimal::{
+        dec::resize::resize, round::round_discarded, Context, Decimal, Flags, Interchange, Signal,
+    },
+    int::{
+        math::{div_rem, div_rem_digit},
+        pow10::Pow10,
+        UInt,
+    },
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// Combination field prefixes of the special values.
+const INFINITY: u128 = 0b11110;
+const NAN: u128 = 0b11111;
+
+/// Rounds `d` to the precision and exponent range of `format`.
+///
+/// Values with too many digits are rounded according to `ctx`, tiny values
+/// are rounded to the minimum exponent and values too large for the format
+/// overflow to infinity. Finite values whose exponent exceeds the maximum
+/// exponent of the format are padded with zeros when they fit.
+pub(crate) const fn round<const N: usize>(d: D<N>, format: Interchange, ctx: Context) -> D<N> {
+    if d.flags.is_special() {
+        return d;
+    }
+
+    let p = format.precision() as i32;
+    let qmin = -format.bias();
+    let qmax = format.qmax();
+
+    let mut digits = d.digits;
+    let mut flags = d.flags;
+    let mut q = -(d.scale as i32);
+
+    if digits.is_zero() {
+        let clamped = if q < qmin {
+            qmin
+        } else if q > qmax {
+            qmax
+        } else {
+            q
+        };
+
+        if clamped != q {
+            flags = flags.raise_signal(Signal::OP_CLAMPED);
+        }
+
+        return D::new(digits, -clamped as i16, flags);
+    }
+
+    let len = Pow10::<N>::digits_count(digits) as i32;
+    let tiny = q + len - 1 < format.emin();
+
+    if tiny {
+        flags = flags.raise_signal(Signal::OP_SUBNORMAL);
+    }
+
+    let mut k = len - p;
+
+    if qmin - q > k {
+        k = qmin - q;
+    }
+
+    if k > 0 {
+        let (quotient, discarded) = shift_right(digits, k as u32, len as u32);
+        flags = flags.raise_signal(Signal::OP_ROUNDED);
+
+        digits = match discarded {
+            Some(discarded) => {
+                flags = flags.raise_signal(Signal::OP_INEXACT);
+
+                if tiny {
+                    flags = flags.raise_signal(Signal::OP_UNDERFLOW);
+                }
+
+                round_discarded(quotient, discarded, d.sign(), ctx).0
+            }
+            None => quotient,
+        };
+
+        q += k;
+
+        // Rounding up `99…9` carries into one more digit.
+        if Pow10::<N>::digits_count(digits) as i32 > p {
+            digits = div_rem(digits, UInt::TEN).0;
+            q += 1;
+        }
+    }
+
+    if q > qmax && !digits.is_zero() {
+        let len = Pow10::<N>::digits_count(digits) as i32;
+
+        if len + (q - qmax) > p {
+            return D::INFINITY.with_flags(flags.raise_signal(Signal::overflow()));
+        }
+
+        flags = flags.raise_signal(Signal::OP_CLAMPED);
+
+        // The padded coefficient may not fit into `UInt<N>` of the narrowest
+        // decimals, the encoding pads it then.
+        let k = (q - qmax) as u32;
+
+        if k <= Pow10::<N>::MAX_POWER {
+            if let (padded, false) = digits.overflowing_mul(Pow10::<N>::power(k)) {
+                digits = padded;
+                q = qmax;
+            }
+        }
+    }
+
+    D::new(digits, -q as i16, flags)
+}
+
+/// Divides `value` of `len` digits by `10^k` and returns the quotient along
+/// with the ordering of the discarded fraction against one half, or `None`
+/// if it is zero.
+const fn shift_right<const N: usize>(
+    value: UInt<N>,
+    k: u32,
+    len: u32,
+) -> (UInt<N>, Option<Ordering>) {
+    if k > len {
+        return (UInt::ZERO, Some(Ordering::Less));
+    }
+
+    // Split off the most significant discarded digit, which decides the
+    // rounding direction together with the rest.
+    let (value, rest) = if k > 1 {
+        div_rem(value, Pow10::<N>::power(k - 1))
+    } else {
+        (value, UInt::ZERO)
+    };
+
+    let (quotient, digit) = div_rem_digit(*value.digits(), 10);
+    let quotient = UInt::from_digits(quotient);
+
+    let discarded = match digit {
+        0 if rest.is_zero() => return (quotient, None),
+        0..5 => Ordering::Less,
+        5 if rest.is_zero() => Ordering::Equal,
+        _ => Ordering::Greater,
+    };
+
+    (quotient, Some(discarded))
+}
+
+/// Encodes `d` in `format`, rounding it first.
+pub(crate) const fn encode<const N: usize>(
+    d: D<N>,
+    format: Interchange,
+    dpd: bool,
+    ctx: Context,
+) -> u128 {
+    let d = round(d, format, ctx);
+    ctx.trap_signals(d.flags.signals());
+
+    let w = format.exp_bits();
+    let t = format.trailing_bits();
+    let sign = (d.flags.is_negative() as u128) << (format.bits() - 1);
+
+    if d.flags.is_nan() {
+        return sign | NAN << (w + t);
+    }
+
+    if d.flags.is_infinity() {
+        return sign | INFINITY << (w + t);
+    }
+
+    let mut coefficient = to_u128(d.digits);
+    let mut q = -(d.scale as i32);
+
+    if q > format.qmax() {
+        coefficient *= 10u128.pow((q - format.qmax()) as u32);
+        q = format.qmax();
+    }
+
+    let exp = (q + format.bias()) as u128;
+
+    let combination = if dpd {
+        let split = 10u128.pow(3 * t / 10);
+        let leading = coefficient / split;
+        coefficient = to_dpd(coefficient % split, t / 10);
+
+        // The two most significant exponent bits and the leading digit.
+        let msb = exp >> w;
+        let prefix = if leading < 8 {
+            msb << 3 | leading
+        } else {
+            0b11000 | msb << 1 | (leading & 1)
+        };
+
+        prefix << w | (exp & ((1 << w) - 1))
+    } else if coefficient >> (t + 3) == 0 {
+        exp << 3 | coefficient >> t
+    } else {
+        0b11 << (w + 3) | exp << 1 | (coefficient >> t & 1)
+    };
+
+    sign | combination << t | (coefficient & ((1 << t) - 1))
+}
+
+/// Decodes `bits` in `format`, rounding the coefficient if it doesn't fit
+/// into `UInt<N>`.
+///
+/// Signaling `NaN` is decoded as `NaN`, and non-canonical coefficients as
+/// zero.
+pub(crate) const fn decode<const N: usize>(
+    bits: u128,
+    format: Interchange,
+    dpd: bool,
+    ctx: Context,
+) -> D<N> {
+    let w = format.exp_bits();
+    let t = format.trailing_bits();
+
+    let flags = if bits >> (format.bits() - 1) & 1 == 1 {
+        Flags::NEG
+    } else {
+        Flags::EMPTY
+    };
+
+    let combination = bits >> t & ((1 << (w + 5)) - 1);
+    let trailing = bits & ((1 << t) - 1);
+    let prefix = combination >> w;
+
+    if prefix == NAN {
+        return D::NAN.with_flags(flags);
+    }
+
+    if prefix == INFINITY {
+        return D::INFINITY.with_flags(flags);
+    }
+
+    let (exp, coefficient) = if dpd {
+        let (msb, leading) = if prefix >> 3 != 0b11 {
+            (prefix >> 3, prefix & 0b111)
+        } else {
+            (prefix >> 1 & 0b11, 8 | (prefix & 1))
+        };
+
+        let exp = msb << w | (combination & ((1 << w) - 1));
+        let coefficient = leading * 10u128.pow(3 * t / 10) + from_dpd(trailing, t / 10);
+
+        (exp, coefficient)
+    } else {
+        let (exp, coefficient) = if combination >> (w + 3) != 0b11 {
+            (combination >> 3, (combination & 0b111) << t | trailing)
+        } else {
+            (
+                combination >> 1 & ((1 << (w + 2)) - 1),
+                (0b1000 | (combination & 1)) << t | trailing,
+            )
+        };
+
+        if coefficient >= 10u128.pow(format.precision()) {
+            (exp, 0)
+        } else {
+            (exp, coefficient)
+        }
+    };
+
+    let scale = -(exp as i32 - format.bias()) as i16;
+    let digits = UInt::<2>::from_digits([coefficient as u64, (coefficient >> 64) as u64]);
+
+    resize(D::<2>::new(digits, scale, flags), ctx).unwrap_signals(ctx)
+}
+
+/// Converts a coefficient of at most 34 digits to `u128`.
+const fn to_u128<const N: usize>(value: UInt<N>) -> u128 {
+    let digits = value.digits();
+    let mut result = 0;
+
+    let mut i = 0;
+    while i < N && i < 2 {
+        result |= (digits[i] as u128) << (64 * i);
+        i += 1;
+    }
+
+    result
+}
+
+/// Encodes `count` groups of three digits of `value` in densely packed
+/// decimal declets, the least significant group first.
+const fn to_dpd(mut value: u128, count: u32) -> u128 {
+    let mut result = 0;
+
+    let mut i = 0;
+    while i < count {
+        result |= (DPD_ENCODE[(value % 1000) as usize] as u128) << (10 * i);
+        value /= 1000;
+        i += 1;
+    }
+
+    result
+}
+
+/// Decodes `count` densely packed decimal declets.
+const fn from_dpd(declets: u128, count: u32) -> u128 {
+    let mut result = 0;
+
+    let mut i = count;
+    while i > 0 {
+        i -= 1;
+        result = result * 1000 + DPD_DECODE[(declets >> (10 * i) & 0x3ff) as usize] as u128;
+    }
+
+    result
+}
+
+/// Densely packed decimal declets of `0..1000`.
+static DPD_ENCODE: [u16; 1000] = {
+    let mut table = [0; 1000];
+
+    let mut n = 0;
+    while n < 1000 {
+        table[n] = dpd_declet(n as u16);
+        n += 1;
+    }
+
+    table
+};
+
+/// Values of all densely packed decimal declets, including the
+/// non-canonical ones.
+static DPD_DECODE: [u16; 1024] = {
+    let mut table = [0; 1024];
+
+    let mut n = 0;
+    while n < 1024 {
+        table[n] = dpd_value(n as u16);
+        n += 1;
+    }
+
+    table
+};
+
+/// Encodes three digits into a declet, see IEEE 754-2008 table 3.4.
+const fn dpd_declet(n: u16) -> u16 {
+    let (d2, d1, d0) = (n / 100, n / 10 % 10, n % 10);
+
+    // Low three bits of the digits and their indicators of being 8 or 9.
+    let (b2, b1, b0) = (d2 & 0b111, d1 & 0b111, d0 & 0b111);
+    let (low2, low1, low0) = (d2 & 1, d1 & 1, d0 & 1);
+
+    match (d2 > 7, d1 > 7, d0 > 7) {
+        (false, false, false) => b2 << 7 | b1 << 4 | b0,
+        (false, false, true) => b2 << 7 | b1 << 4 | 0b1000 | low0,
+        (false, true, false) => b2 << 7 | (b0 >> 1) << 5 | low1 << 4 | 0b1010 | low0,
+        (false, true, true) => b2 << 7 | 0b10 << 5 | low1 << 4 | 0b1110 | low0,
+        (true, false, false) => (b0 >> 1) << 8 | low2 << 7 | b1 << 4 | 0b1100 | low0,
+        (true, false, true) => (b1 >> 1) << 8 | low2 << 7 | 0b01 << 5 | low1 << 4 | 0b1110 | low0,
+        (true, true, false) => (b0 >> 1) << 8 | low2 << 7 | low1 << 4 | 0b1110 | low0,
+        (true, true, true) => low2 << 7 | 0b11 << 5 | low1 << 4 | 0b1110 | low0,
+    }
+}
+
+/// Decodes a declet into three digits, see IEEE 754-2008 table 3.3.
+const fn dpd_value(declet: u16) -> u16 {
+    let (pqr, stu, wxy) = (declet >> 7, declet >> 4 & 0b111, declet & 0b111);
+    let (pq, st) = (declet >> 8, declet >> 5 & 0b11);
+    let (r, u, y) = (declet >> 7 & 1, declet >> 4 & 1, declet & 1);
+
+    let (d2, d1, d0) = if declet >> 3 & 1 == 0 {
+        (pqr, stu, wxy)
+    } else {
+        match (wxy >> 1, st) {
+            (0b00, _) => (pqr, stu, 8 | y),
+            (0b01, _) => (pqr, 8 | u, st << 1 | y),
+            (0b10, _) => (8 | r, stu, pq << 1 | y),
+            (_, 0b00) => (8 | r, 8 | u, pq << 1 | y),
+            (_, 0b01) => (8 | r, pq << 1 | u, 8 | y),
+            (_, 0b10) => (pqr, 8 | u, 8 | y),
+            _ => (8 | r, 8 | u, 8 | y),
+        }
+    };
+
+    d2 * 100 + d1 * 10 + d0
+}
@@ -15,6 +15,9 @@ macro_rules! consts_impl {
             
             /// The maximum value that this type can represent 2<sup>N</sup> × 10<sup>32768</sup>.
             pub const MAX: Self = Self::new(UInt::MAX, i16::MIN, Flags::default());
+
+            /// The maximum length of the string written by [`Self::to_str_buf`].
+            pub const MAX_STR_LEN: usize = format::max_str_len::<N>();
     
             consts_impl!(CONSTS ZERO 0, ONE 1, TWO 2, THREE 3, FOUR 4, FIVE 5, SIX 6, SEVEN 7, EIGHT 8, NINE 9, TEN 10);
         }
@@ -10,19 +10,26 @@ impl<const N: usize> Display for Decimal<N> {
         } else if self.is_infinite() {
             return write!(f, "{}Inf", self.sign());
         }
-        format::format(self.digits.to_str_radix(10), self.scale, self.sign(), f)
+        let mut buf = format::digits_buf();
+        format::format(
+            format::ascii_digits(self.digits, &mut buf),
+            self.scale,
+            self.sign(),
+            f,
+        )
     }
 }
 
 impl<const N: usize> LowerExp for Decimal<N> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut buf = format::digits_buf();
         format::format_exponential(
-            self.digits.to_str_radix(10),
+            format::ascii_digits(self.digits, &mut buf),
             self.scale,
             self.sign(),
             f,
-            "e",
+            b'e',
         )
     }
 }
@@ -30,12 +37,13 @@ impl<const N: usize> LowerExp for Decimal<N> {
 impl<const N: usize> UpperExp for Decimal<N> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut buf = format::digits_buf();
         format::format_exponential(
-            self.digits.to_str_radix(10),
+            format::ascii_digits(self.digits, &mut buf),
             self.scale,
             self.sign(),
             f,
-            "E",
+            b'E',
         )
     }
 }
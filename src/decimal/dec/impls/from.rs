@@ -1,5 +1,5 @@
 use crate::{
-    decimal::{dec::parse, Decimal, Flags, ParseError},
+    decimal::{dec::parse, Context, Decimal, Flags, ParseError, SignalsTraps},
     int::UInt,
 };
 
@@ -37,8 +37,93 @@ macro_rules! from_int {
     }
 }
 
-from_uint!(u8, u16, u32, u64, u128, usize);
-from_int!(i8, i16, i32, i64, i128, isize);
+// `u128` and `i128` fit into the coefficient of every decimal type except
+// the 64-bit one, which gets `TryFrom` instead.
+macro_rules! from_wide {
+    ($($n: literal),*) => {
+        $(
+            impl From<u128> for Decimal<$n> {
+                #[inline]
+                fn from(n: u128) -> Self {
+                    Self::new(UInt::from(n), 0, Flags::default())
+                }
+            }
+
+            impl From<i128> for Decimal<$n> {
+                #[inline]
+                fn from(n: i128) -> Self {
+                    let flags = if n.is_negative() {
+                        Flags::default().neg()
+                    } else {
+                        Flags::default()
+                    };
+
+                    Self::new(UInt::from(n.unsigned_abs()), 0, flags)
+                }
+            }
+        )*
+    }
+}
+
+impl TryFrom<u128> for Decimal<1> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(n: u128) -> Result<Self, Self::Error> {
+        Self::try_from_u128(n)
+    }
+}
+
+impl TryFrom<i128> for Decimal<1> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(n: i128) -> Result<Self, Self::Error> {
+        Self::try_from_i128(n)
+    }
+}
+
+macro_rules! resize_impl {
+    ($n: literal $(, $m: literal)*) => {
+        $(
+            impl From<Decimal<$n>> for Decimal<$m> {
+                #[inline]
+                fn from(d: Decimal<$n>) -> Self {
+                    d.resize(Context::default())
+                }
+            }
+
+            impl TryFrom<Decimal<$m>> for Decimal<$n> {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(d: Decimal<$m>) -> Result<Self, Self::Error> {
+                    let res = d.resize(Context::default().with_signal_traps(SignalsTraps::empty()));
+
+                    if res.is_op_overflow() {
+                        if res.is_negative() {
+                            Err(ParseError::NegOverflow)
+                        } else {
+                            Err(ParseError::PosOverflow)
+                        }
+                    } else if res.is_op_inexact() {
+                        Err(ParseError::Inexact)
+                    } else {
+                        Ok(res)
+                    }
+                }
+            }
+        )*
+
+        resize_impl!($($m),*);
+    };
+    () => {};
+}
+
+from_uint!(u8, u16, u32, u64, usize);
+from_int!(i8, i16, i32, i64, isize);
+from_wide!(2, 4, 8, 16, 32, 64, 128);
+resize_impl!(1, 2, 4, 8, 16, 32, 64, 128);
 
 impl<const N: usize> TryFrom<f32> for Decimal<N> {
     type Error = ParseError;
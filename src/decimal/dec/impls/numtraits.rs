@@ -1,9 +1,9 @@
 mod from_primitive;
 mod to_primitive;
 
-use num_traits::{ConstOne, ConstZero, Num, One, Signed, Zero};
+use num_traits::{ConstOne, ConstZero, FromBytes, Num, One, Signed, ToBytes, Zero};
 
-use crate::decimal::{Decimal, ParseError};
+use crate::decimal::{Decimal, DecimalBytes, ParseError};
 
 impl<const N: usize> One for Decimal<N> {
     #[inline]
@@ -75,6 +75,34 @@ impl<const N: usize> Signed for Decimal<N> {
     }
 }
 
+impl<const N: usize> ToBytes for Decimal<N> {
+    type Bytes = DecimalBytes<N>;
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        self.to_be_bytes()
+    }
+
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        self.to_le_bytes()
+    }
+}
+
+impl<const N: usize> FromBytes for Decimal<N> {
+    type Bytes = DecimalBytes<N>;
+
+    #[inline]
+    fn from_be_bytes(bytes: &Self::Bytes) -> Self {
+        Self::from_be_bytes(bytes)
+    }
+
+    #[inline]
+    fn from_le_bytes(bytes: &Self::Bytes) -> Self {
+        Self::from_le_bytes(bytes)
+    }
+}
+
 // TODO:
 // impl<const N: usize> Float for Decimal<N> {
 //
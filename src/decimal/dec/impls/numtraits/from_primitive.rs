@@ -30,7 +30,7 @@ impl<const N: usize> FromPrimitive for Decimal<N> {
 
     #[inline]
     fn from_i128(n: i128) -> Option<Self> {
-        Some(Self::from(n))
+        Self::try_from_i128(n).ok()
     }
 
     #[inline]
@@ -60,7 +60,7 @@ impl<const N: usize> FromPrimitive for Decimal<N> {
 
     #[inline]
     fn from_u128(n: u128) -> Option<Self> {
-        Some(Self::from(n))
+        Self::try_from_u128(n).ok()
     }
 
     #[inline]
@@ -3,4 +3,5 @@ mod div;
 mod add;
 mod sub;
 mod neg;
-mod rem;
\ No newline at end of file
+mod rem;
+mod mixed;
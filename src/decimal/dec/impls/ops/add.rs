@@ -1,6 +1,6 @@
 use core::ops::{Add, AddAssign};
 
-use crate::decimal::{Decimal, Context};
+use crate::decimal::{utils::operand::FromOperand, Decimal, Context};
 
 impl<const N: usize> Add for Decimal<N> {
     type Output = Decimal<N>;
@@ -27,7 +27,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn add(self, rhs: $ty) -> Decimal<N> {
-                    let rhs = Decimal::from(rhs);
+                    let rhs = Decimal::from_operand(rhs);
                     Add::<Decimal<N>>::add(self, rhs)
                 }
             }
@@ -35,7 +35,7 @@ macro_rules! macro_impl {
             impl<const N: usize> AddAssign<$ty> for Decimal<N> {
                 #[inline]
                 fn add_assign(&mut self, rhs: $ty) {
-                    let rhs = Decimal::from(rhs);
+                    let rhs = Decimal::from_operand(rhs);
                     self.add_assign(rhs);
                 }
             }
@@ -1,6 +1,6 @@
 use core::ops::{Div, DivAssign};
 
-use crate::decimal::{Context, Decimal};
+use crate::decimal::{utils::operand::FromOperand, Context, Decimal};
 
 impl<const N: usize> Div for Decimal<N> {
     type Output = Decimal<N>;
@@ -27,7 +27,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn div(self, rhs: $ty) -> Decimal<N> {
-                    let rhs = Decimal::from(rhs);
+                    let rhs = Decimal::from_operand(rhs);
                     Div::<Decimal<N>>::div(self, rhs)
                 }
             }
@@ -35,7 +35,7 @@ macro_rules! macro_impl {
             impl<const N: usize> DivAssign<$ty> for Decimal<N> {
                 #[inline]
                 fn div_assign(&mut self, rhs: $ty) {
-                    let rhs = Decimal::from(rhs);
+                    let rhs = Decimal::from_operand(rhs);
                     self.div_assign(rhs);
                 }
             }
@@ -0,0 +1,111 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+use crate::decimal::{Decimal, UnsignedDecimal};
+
+macro_rules! mixed_impl {
+    ($Op: ident $op: ident $OpAssign: ident $op_assign: ident; $n: literal $(, $m: literal)*) => {
+        $(
+            impl $Op<Decimal<$m>> for Decimal<$n> {
+                type Output = Decimal<$m>;
+
+                #[inline]
+                fn $op(self, rhs: Decimal<$m>) -> Decimal<$m> {
+                    $Op::$op(Decimal::<$m>::from(self), rhs)
+                }
+            }
+
+            impl $Op<Decimal<$n>> for Decimal<$m> {
+                type Output = Decimal<$m>;
+
+                #[inline]
+                fn $op(self, rhs: Decimal<$n>) -> Decimal<$m> {
+                    $Op::$op(self, Decimal::<$m>::from(rhs))
+                }
+            }
+
+            impl $OpAssign<Decimal<$n>> for Decimal<$m> {
+                #[inline]
+                fn $op_assign(&mut self, rhs: Decimal<$n>) {
+                    self.$op_assign(Decimal::<$m>::from(rhs));
+                }
+            }
+
+            impl $Op<UnsignedDecimal<$m>> for Decimal<$n> {
+                type Output = Decimal<$m>;
+
+                #[inline]
+                fn $op(self, rhs: UnsignedDecimal<$m>) -> Decimal<$m> {
+                    $Op::$op(Decimal::<$m>::from(self), rhs.to_signed())
+                }
+            }
+
+            impl $Op<Decimal<$m>> for UnsignedDecimal<$n> {
+                type Output = Decimal<$m>;
+
+                #[inline]
+                fn $op(self, rhs: Decimal<$m>) -> Decimal<$m> {
+                    $Op::$op(Decimal::<$m>::from(self.to_signed()), rhs)
+                }
+            }
+
+            impl $Op<UnsignedDecimal<$n>> for Decimal<$m> {
+                type Output = Decimal<$m>;
+
+                #[inline]
+                fn $op(self, rhs: UnsignedDecimal<$n>) -> Decimal<$m> {
+                    $Op::$op(self, Decimal::<$m>::from(rhs.to_signed()))
+                }
+            }
+
+            impl $Op<Decimal<$n>> for UnsignedDecimal<$m> {
+                type Output = Decimal<$m>;
+
+                #[inline]
+                fn $op(self, rhs: Decimal<$n>) -> Decimal<$m> {
+                    $Op::$op(self.to_signed(), Decimal::<$m>::from(rhs))
+                }
+            }
+
+            impl $OpAssign<UnsignedDecimal<$n>> for Decimal<$m> {
+                #[inline]
+                fn $op_assign(&mut self, rhs: UnsignedDecimal<$n>) {
+                    self.$op_assign(Decimal::<$m>::from(rhs.to_signed()));
+                }
+            }
+        )*
+
+        mixed_impl!($Op $op $OpAssign $op_assign; $($m),*);
+    };
+    ($Op: ident $op: ident $OpAssign: ident $op_assign: ident;) => {
+        impl<const N: usize> $Op<UnsignedDecimal<N>> for Decimal<N> {
+            type Output = Decimal<N>;
+
+            #[inline]
+            fn $op(self, rhs: UnsignedDecimal<N>) -> Decimal<N> {
+                $Op::$op(self, rhs.to_signed())
+            }
+        }
+
+        impl<const N: usize> $Op<Decimal<N>> for UnsignedDecimal<N> {
+            type Output = Decimal<N>;
+
+            #[inline]
+            fn $op(self, rhs: Decimal<N>) -> Decimal<N> {
+                $Op::$op(self.to_signed(), rhs)
+            }
+        }
+
+        impl<const N: usize> $OpAssign<UnsignedDecimal<N>> for Decimal<N> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: UnsignedDecimal<N>) {
+                self.$op_assign(rhs.to_signed());
+            }
+        }
+    };
+}
+
+mixed_impl!(Add add AddAssign add_assign; 1, 2, 4, 8, 16, 32, 64, 128);
+mixed_impl!(Sub sub SubAssign sub_assign; 1, 2, 4, 8, 16, 32, 64, 128);
+mixed_impl!(Mul mul MulAssign mul_assign; 1, 2, 4, 8, 16, 32, 64, 128);
+mixed_impl!(Div div DivAssign div_assign; 1, 2, 4, 8, 16, 32, 64, 128);
+mixed_impl!(Rem rem RemAssign rem_assign; 1, 2, 4, 8, 16, 32, 64, 128);
@@ -1,6 +1,6 @@
 use core::ops::{Mul, MulAssign};
 
-use crate::decimal::{Context, Decimal};
+use crate::decimal::{utils::operand::FromOperand, Context, Decimal};
 
 impl<const N: usize> Mul for Decimal<N> {
     type Output = Decimal<N>;
@@ -26,7 +26,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn mul(self, rhs: $ty) -> Self::Output {
-                    let rhs = Decimal::from(rhs);
+                    let rhs = Decimal::from_operand(rhs);
                     Mul::<Decimal<N>>::mul(self, rhs)
                 }
             }
@@ -34,7 +34,7 @@ macro_rules! macro_impl {
             impl<const N: usize> MulAssign<$ty> for Decimal<N> {
                 #[inline]
                 fn mul_assign(&mut self, rhs: $ty) {
-                    let rhs = Decimal::from(rhs);
+                    let rhs = Decimal::from_operand(rhs);
                     self.mul_assign(rhs);
                 }
             }
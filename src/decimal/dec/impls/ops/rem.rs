@@ -1,6 +1,6 @@
 use core::ops::{Rem, RemAssign};
 
-use crate::decimal::{Decimal, Context};
+use crate::decimal::{utils::operand::FromOperand, Decimal, Context};
 
 impl<const N: usize> Rem for Decimal<N> {
     type Output = Decimal<N>;
@@ -27,7 +27,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn rem(self, rhs: $ty) -> Decimal<N> {
-                    let rhs = Decimal::from(rhs);
+                    let rhs = Decimal::from_operand(rhs);
                     Rem::<Decimal<N>>::rem(self, rhs)
                 }
             }
@@ -35,7 +35,7 @@ macro_rules! macro_impl {
             impl<const N: usize> RemAssign<$ty> for Decimal<N> {
                 #[inline]
                 fn rem_assign(&mut self, rhs: $ty) {
-                    let rhs = Decimal::from(rhs);
+                    let rhs = Decimal::from_operand(rhs);
                     self.rem_assign(rhs);
                 }
             }
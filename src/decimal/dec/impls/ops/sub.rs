@@ -1,6 +1,6 @@
 use core::ops::{Sub, SubAssign};
 
-use crate::decimal::{Context, Decimal};
+use crate::decimal::{utils::operand::FromOperand, Context, Decimal};
 
 impl<const N: usize> Sub for Decimal<N> {
     type Output = Decimal<N>;
@@ -27,7 +27,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn sub(self, rhs: $ty) -> Decimal<N> {
-                    let rhs = Decimal::from(rhs);
+                    let rhs = Decimal::from_operand(rhs);
                     Sub::<Decimal<N>>::sub(self, rhs)
                 }
             }
@@ -37,7 +37,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn sub(self, rhs: Decimal<N>) -> Decimal<N> {
-                    let this = Decimal::from(self);
+                    let this = Decimal::from_operand(self);
                     Sub::<Decimal<N>>::sub(this, rhs)
                 }
             }
@@ -45,7 +45,7 @@ macro_rules! macro_impl {
             impl<const N: usize> SubAssign<$ty> for Decimal<N> {
                 #[inline]
                 fn sub_assign(&mut self, rhs: $ty) {
-                    let rhs = Decimal::from(rhs);
+                    let rhs = Decimal::from_operand(rhs);
                     self.sub_assign(rhs);
                 }
             }
@@ -1,14 +1,22 @@
 use crate::{
     decimal::{
         dec::math::utils::overflow_scale,
-        round::{scale_round, RoundConsts},
-        Context, Decimal, Signal,
+        round::{round_remainder, RoundConsts},
+        Context, Decimal, Sign, Signal,
+    },
+    int::{
+        math::{div_rem, mul_add_digit, mul_div_rem_digit},
+        pow10::Pow10,
+        UInt,
     },
-    int::{math::div_rem, UInt},
 };
 
 type D<const N: usize> = Decimal<N>;
 
+/// Maximum number of decimal digits produced by a single long division step:
+/// `10^19` is the largest power of ten which fits into a 64-bit limb.
+const CHUNK_DIGITS: u32 = 19;
+
 #[inline]
 pub(crate) const fn div<const N: usize>(dividend: D<N>, divisor: D<N>, ctx: Context) -> D<N> {
     if dividend.is_nan() {
@@ -40,62 +48,89 @@ pub(crate) const fn div<const N: usize>(dividend: D<N>, divisor: D<N>, ctx: Cont
 
         let (mut digits, mut remainder) = div_rem(dividend.digits, divisor.digits);
 
-        if !remainder.is_zero() {
-            let mut quotient;
-
-            while !remainder.is_zero() {
-                (remainder, ofw) = remainder.overflowing_mul(UInt::TEN);
-
-                if ofw {
-                    return D::new(
-                        digits,
-                        scale,
-                        flags
-                            .raise_signal(Signal::OP_INEXACT)
-                            .raise_signal(Signal::OP_ROUNDED),
-                    );
+        // Number of decimal digits in the quotient, tracked incrementally.
+        let mut len = if remainder.is_zero() {
+            0
+        } else {
+            Pow10::<N>::digits_count(digits)
+        };
+
+        while !remainder.is_zero() {
+            // Number of decimal digits which can be appended to the quotient
+            // without any overflow check.
+            let available = Pow10::<N>::MAX_POWER.saturating_sub(len);
+
+            let mut chunk = if available > 0 {
+                if available < CHUNK_DIGITS {
+                    available
+                } else {
+                    CHUNK_DIGITS
                 }
+            } else if digits.le(&RoundConsts::MAX) {
+                1
+            } else {
+                break;
+            };
+
+            let (mut quotient, rem) =
+                mul_div_rem_digit(remainder, 10_u64.pow(chunk), divisor.digits);
+
+            if rem.is_zero() {
+                // The division terminates within this chunk: keep the
+                // quotient as short as the digit-by-digit division would.
+                while quotient % 10 == 0 {
+                    quotient /= 10;
+                    chunk -= 1;
+                }
+            }
 
-                (quotient, remainder) = div_rem(remainder, divisor.digits);
+            let (next, carry) = mul_add_digit(digits, 10_u64.pow(chunk), quotient);
 
-                if digits.gt(&RoundConsts::MAX) {
-                    // TODO: performance optimizations
-                    let (digit, _) = scale_round(quotient, ctx);
+            if carry != 0 {
+                break;
+            }
 
-                    if digit.is_one() {
-                        digits = digits.saturating_add(digit);
-                    }
+            (scale, ofw) = scale.overflowing_add(chunk as i16);
+            if ofw {
+                return overflow_scale(scale, flags);
+            }
 
-                    return D::new(
-                        digits,
-                        scale,
-                        flags
-                            .raise_signal(Signal::OP_INEXACT)
-                            .raise_signal(Signal::OP_ROUNDED),
-                    );
+            len = if digits.is_zero() {
+                if quotient == 0 {
+                    0
+                } else {
+                    quotient.ilog10() + 1
                 }
+            } else {
+                len + chunk
+            };
 
-                digits = digits.strict_mul(UInt::TEN);
+            digits = next;
+            remainder = rem;
+        }
 
-                (scale, ofw) = scale.overflowing_add(1);
-                if ofw {
-                    return overflow_scale(scale, flags);
-                }
+        if remainder.is_zero() {
+            return D::new(digits, scale, flags);
+        }
 
-                if digits.gt(&UInt::MAX.strict_sub(quotient)) {
-                    return D::new(
-                        UInt::MAX,
-                        scale,
-                        flags
-                            .raise_signal(Signal::OP_INEXACT)
-                            .raise_signal(Signal::OP_ROUNDED),
-                    );
-                }
+        let sign = if flags.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
 
-                digits = digits.strict_add(quotient);
-            }
+        (digits, ofw) = round_remainder(digits, remainder, divisor.digits, sign, ctx);
+
+        if ofw {
+            digits = UInt::MAX;
         }
 
-        D::new(digits, scale, flags)
+        D::new(
+            digits,
+            scale,
+            flags
+                .raise_signal(Signal::OP_INEXACT)
+                .raise_signal(Signal::OP_ROUNDED),
+        )
     }
 }
@@ -1,10 +1,18 @@
+use core::cmp::Ordering;
+
 use crate::{
     decimal::{
         dec::{math::utils::overflow_scale, scale::extend_scale_to},
-        round::round,
-        Context, Decimal, Signal,
+        round::{round_discarded, RoundConsts},
+        Context, Decimal, Flags, Sign, Signal,
+    },
+    int::{
+        div::Reciprocal,
+        math::div_rem_double_fitting,
+        mul::widening_mul,
+        pow10::Pow10,
+        UInt,
     },
-    int::{math::div_rem_wide, UInt},
 };
 
 type D<const N: usize> = Decimal<N>;
@@ -19,7 +27,7 @@ pub(crate) const fn mul<const N: usize>(lhs: D<N>, rhs: D<N>, ctx: Context) -> D
         return rhs.with_signals_from_and(&lhs, Signal::OP_INVALID);
     }
 
-    let mut flags = lhs.flags.mul(rhs.flags);
+    let flags = lhs.flags.mul(rhs.flags);
 
     if lhs.is_zero() {
         return extend_scale_to(
@@ -37,58 +45,98 @@ pub(crate) const fn mul<const N: usize>(lhs: D<N>, rhs: D<N>, ctx: Context) -> D
         );
     }
 
-    let (mut scale, mut overflow) = lhs.scale.overflowing_add(rhs.scale);
+    let (scale, overflow) = lhs.scale.overflowing_add(rhs.scale);
 
     if overflow {
         return overflow_scale(scale, flags);
     }
 
-    let (mut low, mut high) = lhs.digits.widening_mul(rhs.digits);
-
-    let mut out;
-    let mut rem;
+    let (low, high) = widening_mul(lhs.digits, rhs.digits);
 
     if high.is_zero() {
         return D::new(low, scale, flags);
     }
 
-    while !high.is_zero() {
-        (scale, overflow) = scale.overflowing_sub(1);
+    round_wide(low, high, 0, scale, flags, ctx)
+}
 
-        if overflow {
-            return overflow_scale(scale, flags);
-        }
+/// Rounds the double-width coefficient `high:low` to fit into `N` digits.
+///
+/// A nonzero `sticky` means that nonzero digits below `low` have already been
+/// discarded. `high` must not be zero.
+#[inline]
+pub(crate) const fn round_wide<const N: usize>(
+    mut low: UInt<N>,
+    mut high: UInt<N>,
+    mut sticky: u64,
+    mut scale: i16,
+    mut flags: Flags,
+    ctx: Context,
+) -> D<N> {
+    debug_assert!(!high.is_zero());
+
+    let mut overflow;
+
+    // The coefficient has to be shortened by `k` decimal digits, the number
+    // of digits in `high`.
+    let mut k = Pow10::<N>::digits_count(high);
+    let mut dropped = 0;
+
+    if k > Pow10::<N>::MAX_POWER {
+        // `10^k` doesn't fit: drop a single digit from both halves first.
+        let (rem, digit);
+        (high, rem) = div_rem_digit(high, 0);
+        (low, digit) = div_rem_digit(low, rem);
+        sticky |= digit;
+        dropped = 1;
+        k -= 1;
+    }
 
-        out = [0; N];
-        rem = 0;
+    // `high < 10^k`, so the quotient always fits into `N` digits.
+    let (mut digits, remainder) = div_rem_double_fitting(low, high, Pow10::<N>::power(k));
 
-        let mut i = N;
-        while i > 0 {
-            i -= 1;
-            let (q, r) = div_rem_wide(high.digits()[i], rem, 10);
-            rem = r;
-            out[i] = q;
-        }
+    (scale, overflow) = scale.overflowing_sub((k + dropped) as i16);
+
+    if overflow {
+        return overflow_scale(scale, flags);
+    }
 
-        high = UInt::from_digits(out);
+    if !remainder.is_zero() || sticky != 0 {
+        flags = flags.raise_signal(Signal::OP_INEXACT);
 
-        i = N;
-        out = [0; N];
+        let mut discarded = RoundConsts::<N>::cmp_half(remainder, k);
 
-        while i > 0 {
-            i -= 1;
-            let (q, r) = div_rem_wide(low.digits()[i], rem, 10);
-            rem = r;
-            out[i] = q;
+        if sticky != 0 && matches!(discarded, Ordering::Equal) {
+            discarded = Ordering::Greater;
         }
 
-        low = UInt::from_digits(out);
+        let sign = if flags.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+
+        (digits, overflow) = round_discarded(digits, discarded, sign, ctx);
+
+        if overflow {
+            // The rounded coefficient is `UInt::MAX + 1`, which ends with `6`.
+            (digits, _) = round_discarded(RoundConsts::MAX, Ordering::Greater, sign, ctx);
+
+            (scale, overflow) = scale.overflowing_sub(1);
 
-        if rem != 0 {
-            flags = flags.raise_signal(Signal::OP_INEXACT);
-            low = round(low, UInt::from_digit(rem), ctx);
+            if overflow {
+                return overflow_scale(scale, flags);
+            }
         }
     }
 
-    D::new(low, scale, flags.raise_signal(Signal::OP_ROUNDED))
+    D::new(digits, scale, flags.raise_signal(Signal::OP_ROUNDED))
+}
+
+/// Divides `value` by ten, taking `rem` as the remainder carried in from the
+/// higher digits.
+#[inline]
+const fn div_rem_digit<const N: usize>(value: UInt<N>, rem: u64) -> (UInt<N>, u64) {
+    let (digits, rem) = Reciprocal::TEN.div_rem_digits(value.digits(), rem);
+    (UInt::from_digits(digits), rem)
 }
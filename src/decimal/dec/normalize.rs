@@ -1,6 +1,10 @@
 use crate::{
     decimal::{Decimal, Signal},
-    int::{math::div_rem, UInt},
+    int::{
+        div::Reciprocal,
+        math::{div_rem_digit, to_u64},
+        UInt,
+    },
 };
 
 type D<const N: usize> = Decimal<N>;
@@ -13,23 +17,90 @@ pub(crate) const fn normalize<const N: usize>(mut d: D<N>) -> D<N> {
 
     if d.digits.is_zero() {
         d.scale = 0;
+        return d;
+    }
+
+    // `10^t` divides the coefficient only if `2^t` does.
+    if d.digits.trailing_zeros() == 0 {
+        return d;
+    }
+
+    let mut room = (d.scale as i32 - i16::MIN as i32) as u32;
+    let mut stripped;
+
+    if let Some(value) = to_u64(d.digits) {
+        // Fast path for coefficients which fit into a single limb.
+        let (value, count) = strip_u64(value, room);
+        d.digits = UInt::from_digit(value);
+        stripped = count;
     } else {
-        let mut digits;
-        let mut remainder;
-        while !d.digits.is_zero() {
-            (digits, remainder) = div_rem(d.digits, UInt::TEN);
-            if remainder.is_zero() {
-                if d.scale > i16::MIN {
-                    d.digits = digits;
-                    d.scale -= 1;
-                } else {
-                    return d.raise_signal(Signal::OP_SUBNORMAL);
+        stripped = 0;
+
+        // Strip whole chunks of `CHUNK` zeros while possible.
+        loop {
+            let (digits, remainder) = Reciprocal::TEN_POW_19.div_rem_digits(d.digits.digits(), 0);
+
+            if remainder != 0 {
+                // Strip the rest by the remainder, which keeps the trailing
+                // zeros of the coefficient.
+                let (_, count) = strip_u64(remainder, room);
+
+                if count > 0 {
+                    (d.digits, _) = div_rem_u64(d.digits, 10_u64.pow(count));
+                    stripped += count;
                 }
-            } else {
                 break;
             }
+
+            if room < CHUNK {
+                (d.digits, _) = div_rem_u64(d.digits, 10_u64.pow(room));
+                stripped += room;
+                break;
+            }
+
+            d.digits = UInt::from_digits(digits);
+            stripped += CHUNK;
+            room -= CHUNK;
         }
     }
 
+    d.scale = (d.scale as i32 - stripped as i32) as i16;
+
+    if d.scale == i16::MIN && div_rem_u64(d.digits, 10).1 == 0 {
+        return d.raise_signal(Signal::OP_SUBNORMAL);
+    }
+
     d
 }
+
+/// Number of decimal digits in a chunk: `10^19` is the largest power of ten
+/// which fits into a single limb.
+const CHUNK: u32 = 19;
+
+/// Strips at most `room` trailing zeros from `value` binary searching
+/// through the powers of ten.
+///
+/// Returns the stripped value along with the number of removed zeros.
+#[inline]
+const fn strip_u64(mut value: u64, room: u32) -> (u64, u32) {
+    let mut stripped = 0;
+    let mut step = 16;
+
+    while step > 0 {
+        let power = 10_u64.pow(step);
+
+        if stripped + step <= room && value % power == 0 {
+            value /= power;
+            stripped += step;
+        }
+        step >>= 1;
+    }
+
+    (value, stripped)
+}
+
+#[inline]
+const fn div_rem_u64<const N: usize>(value: UInt<N>, rhs: u64) -> (UInt<N>, u64) {
+    let (digits, remainder) = div_rem_digit(*value.digits(), rhs);
+    (UInt::from_digits(digits), remainder)
+}
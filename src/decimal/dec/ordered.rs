@@ -0,0 +1,234 @@
+use crate::{
+    decimal::{
+        bytes::{OrderedBytes, LIMB_BCD_BYTES},
+        Decimal, Flags, ParseError,
+    },
+    int::{div::Reciprocal, math::mul_add_digit, pow10::Pow10, UInt},
+};
+
+type D<const N: usize> = Decimal<N>;
+
+/// Key classes, in ascending order.
+const NEG_INFINITY: u8 = 0;
+const NEGATIVE: u8 = 1;
+const NEG_ZERO: u8 = 2;
+const ZERO: u8 = 3;
+const POSITIVE: u8 = 4;
+const INFINITY: u8 = 5;
+const NAN: u8 = 6;
+
+/// Bias of the adjusted exponent, which makes it compare as unsigned.
+const EXP_BIAS: u32 = 1 << 31;
+
+/// Decimal digits per chunk converted at once, the most fitting into `u64`.
+const CHUNK: usize = 19;
+
+/// Packed BCD digits per limb.
+const LIMB_BCD_DIGITS: usize = 2 * LIMB_BCD_BYTES;
+
+type Bcd<const N: usize> = [[u8; LIMB_BCD_BYTES]; N];
+
+#[inline]
+pub(crate) const fn to_ordered_bytes<const N: usize>(d: &D<N>) -> OrderedBytes<N> {
+    let mut key = OrderedBytes::ZERO;
+    let negative = d.flags.is_negative();
+
+    key.head[0] = if d.flags.is_nan() {
+        NAN
+    } else if d.flags.is_infinity() {
+        if negative {
+            NEG_INFINITY
+        } else {
+            INFINITY
+        }
+    } else if d.digits.is_zero() {
+        if negative {
+            NEG_ZERO
+        } else {
+            ZERO
+        }
+    } else if negative {
+        NEGATIVE
+    } else {
+        POSITIVE
+    };
+
+    if key.head[0] != NEGATIVE && key.head[0] != POSITIVE {
+        return key;
+    }
+
+    let len = Pow10::<N>::digits_count(d.digits) as usize;
+
+    // The exponent of the most significant digit. Coefficients of equal
+    // magnitude have the same leading digit position, so comparing the
+    // exponents first and then the left aligned digits orders the values.
+    let exp = len as i32 - 1 - d.scale as i32;
+    let [e0, e1, e2, e3] = (exp as u32 ^ EXP_BIAS).to_be_bytes();
+    key.head = [key.head[0], e0, e1, e2, e3];
+
+    // Peel off chunks of digits from the least significant one.
+    let mut digits = *d.digits.digits();
+    let mut pos = len;
+
+    while pos > 0 {
+        let (quotient, mut remainder) = Reciprocal::TEN_POW_19.div_rem_digits(&digits, 0);
+        digits = quotient;
+
+        let mut k = 0;
+        while k < CHUNK && pos > 0 {
+            pos -= 1;
+            set_digit(&mut key.digits, pos, (remainder % 10) as u8);
+            remainder /= 10;
+            k += 1;
+        }
+    }
+
+    // The greater the magnitude of a negative number, the less it is.
+    if negative {
+        let bytes = key.as_bytes_mut();
+
+        let mut i = 1;
+        while i < bytes.len() {
+            bytes[i] = !bytes[i];
+            i += 1;
+        }
+    }
+
+    key
+}
+
+#[inline]
+pub(crate) const fn from_ordered_bytes<const N: usize>(
+    key: &OrderedBytes<N>,
+) -> Result<D<N>, ParseError> {
+    let class = key.head[0];
+
+    let flags = match class {
+        NEG_INFINITY => Flags::NEG_INFINITY,
+        NEGATIVE | NEG_ZERO => Flags::NEG,
+        ZERO | POSITIVE => Flags::EMPTY,
+        INFINITY => Flags::INFINITY,
+        NAN => Flags::NAN,
+        _ => return Err(ParseError::InvalidLiteral),
+    };
+
+    if class != NEGATIVE && class != POSITIVE {
+        // Zeros and special values have no exponent and digits.
+        if !is_zero(key.as_bytes().split_at(1).1) {
+            return Err(ParseError::InvalidLiteral);
+        }
+
+        return Ok(D::new(UInt::ZERO, 0, flags));
+    }
+
+    let negative = class == NEGATIVE;
+    let overflow = if negative {
+        ParseError::NegOverflow
+    } else {
+        ParseError::PosOverflow
+    };
+
+    let mut key = *key;
+
+    if negative {
+        let bytes = key.as_bytes_mut();
+
+        let mut i = 1;
+        while i < bytes.len() {
+            bytes[i] = !bytes[i];
+            i += 1;
+        }
+    }
+
+    let [_, e0, e1, e2, e3] = key.head;
+    let exp = (u32::from_be_bytes([e0, e1, e2, e3]) ^ EXP_BIAS) as i32;
+
+    let mut len = 0;
+
+    let mut i = 0;
+    while i < N * LIMB_BCD_DIGITS {
+        let value = digit(&key.digits, i);
+
+        if value > 9 || (i == 0 && value == 0) {
+            return Err(ParseError::InvalidLiteral);
+        }
+
+        if value != 0 {
+            len = i + 1;
+        }
+
+        i += 1;
+    }
+
+    let mut scale = len as i64 - 1 - exp as i64;
+
+    if scale > i16::MAX as i64 {
+        return Err(ParseError::ExponentOverflow);
+    }
+
+    // Restore the trailing zeros which don't fit into the scale.
+    if scale < i16::MIN as i64 {
+        len += (i16::MIN as i64 - scale) as usize;
+        scale = i16::MIN as i64;
+
+        if len > N * LIMB_BCD_DIGITS {
+            return Err(overflow);
+        }
+    }
+
+    let mut digits = UInt::ZERO;
+
+    let mut i = 0;
+    while i < len {
+        let mut chunk = 0;
+        let mut power = 1;
+
+        let mut k = 0;
+        while k < CHUNK && i < len {
+            chunk = chunk * 10 + digit(&key.digits, i) as u64;
+            power *= 10;
+            i += 1;
+            k += 1;
+        }
+
+        let carry;
+        (digits, carry) = mul_add_digit(digits, power, chunk);
+
+        if carry != 0 {
+            return Err(overflow);
+        }
+    }
+
+    Ok(D::new(digits, scale as i16, flags))
+}
+
+/// Returns the `i`-th packed BCD digit.
+#[inline]
+const fn digit<const N: usize>(bcd: &Bcd<N>, i: usize) -> u8 {
+    let byte = bcd[i / LIMB_BCD_DIGITS][i % LIMB_BCD_DIGITS / 2];
+
+    if i % 2 == 0 {
+        byte >> 4
+    } else {
+        byte & 0x0f
+    }
+}
+
+/// Sets the `i`-th packed BCD digit, which must be zero.
+#[inline]
+const fn set_digit<const N: usize>(bcd: &mut Bcd<N>, i: usize, digit: u8) {
+    bcd[i / LIMB_BCD_DIGITS][i % LIMB_BCD_DIGITS / 2] |=
+        if i % 2 == 0 { digit << 4 } else { digit };
+}
+
+#[inline]
+const fn is_zero(bytes: &[u8]) -> bool {
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
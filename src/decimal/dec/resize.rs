@@ -0,0 +1,67 @@
+use crate::{
+    decimal::{round::round_remainder, Context, Decimal, Signal},
+    int::{
+        math::{div_rem, resize as resize_digits},
+        pow10::Pow10,
+        UInt,
+    },
+};
+
+type D<const N: usize> = Decimal<N>;
+
+#[inline]
+pub(crate) const fn resize<const N: usize, const M: usize>(d: D<N>, ctx: Context) -> D<M> {
+    let (digits, truncated) = resize_digits::<N, M>(d.digits);
+
+    if !truncated {
+        return D::new(digits, d.scale, d.flags);
+    }
+
+    // The coefficient doesn't fit into UInt<M>, so the least significant
+    // digits have to be discarded.
+    let max = resize_digits::<M, N>(UInt::<M>::MAX).0;
+    let d = reduce(d, max, Pow10::<M>::MAX_POWER, ctx);
+
+    D::new(resize_digits::<N, M>(d.digits).0, d.scale, d.flags)
+}
+
+/// Rounds the coefficient of a finite `d` to at most `max`, a number of
+/// `max_power + 1` digits, discarding the least significant digits.
+#[inline]
+pub(crate) const fn reduce<const N: usize>(
+    d: D<N>,
+    max: UInt<N>,
+    max_power: u32,
+    ctx: Context,
+) -> D<N> {
+    if d.digits.le(&max) {
+        return d;
+    }
+
+    let mut k = Pow10::<N>::digits_count(d.digits) - max_power - 1;
+
+    loop {
+        let divisor = Pow10::<N>::power(k);
+        let (quotient, remainder) = div_rem(d.digits, divisor);
+        let (quotient, _) = round_remainder(quotient, remainder, divisor, d.sign(), ctx);
+
+        if quotient.gt(&max) {
+            k += 1;
+            continue;
+        }
+
+        let scale = d.scale as i32 - k as i32;
+        if scale < i16::MIN as i32 {
+            return D::INFINITY.with_flags(d.flags.raise_signal(Signal::overflow()));
+        }
+
+        // Discarded digits always round, and lose information only when
+        // some of them are nonzero.
+        let mut flags = d.flags.raise_signal(Signal::OP_ROUNDED);
+        if !remainder.is_zero() {
+            flags = flags.raise_signal(Signal::OP_INEXACT);
+        }
+
+        return D::new(quotient, scale as i16, flags);
+    }
+}
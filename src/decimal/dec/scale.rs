@@ -1,9 +1,9 @@
 use crate::{
     decimal::{
-        round::{scale_round, RoundConsts},
-        Context, Decimal, Flags, Signal,
+        round::{round_discarded, RoundConsts},
+        Context, Decimal, Signal,
     },
-    int::UInt,
+    int::{math::div_rem, pow10::Pow10, UInt},
 };
 
 type D<const N: usize> = Decimal<N>;
@@ -32,34 +32,57 @@ pub(crate) const fn with_scale<const N: usize>(mut d: D<N>, new_scale: i16, ctx:
         d
     } else if new_scale > d.scale {
         // increase the number of zeros if it possible
-        while new_scale > d.scale {
-            if d.digits.gt(&RoundConsts::<N>::MAX) {
-                return d.raise_signal(Signal::OP_CLAMPED);
-            } else {
-                d.digits = d.digits.strict_mul(UInt::<N>::TEN);
-                d.scale += 1;
-            }
+        let k = (new_scale as i32 - d.scale as i32) as u32;
+
+        // `10^max` is either the largest power of ten the coefficient can be
+        // multiplied by or just one more.
+        let mut max = Pow10::<N>::MAX_POWER + 1 - Pow10::<N>::digits_count(d.digits);
+
+        if k < max {
+            d.digits = d.digits.strict_mul(Pow10::<N>::power(k));
+            d.scale = new_scale;
+            return d;
         }
-        d
+
+        let (mut digits, overflow) = d.digits.overflowing_mul(Pow10::<N>::power(max));
+
+        if !overflow && k == max {
+            d.digits = digits;
+            d.scale = new_scale;
+            return d;
+        }
+
+        // Extend the scale as far as possible.
+        if overflow {
+            max -= 1;
+            digits = d.digits.strict_mul(Pow10::<N>::power(max));
+        }
+
+        d.digits = digits;
+        d.scale += max as i16;
+        d.raise_signal(Signal::OP_CLAMPED)
     } else {
         // round
-        let mut flags = Flags::default();
-        let mut is_rounded;
-        while new_scale < d.scale {
-            (d.digits, is_rounded) = scale_round(d.digits, ctx);
-            d.scale -= 1;
+        let k = (d.scale as i32 - new_scale as i32) as u32;
+        let sign = d.sign();
 
-            if is_rounded {
-                flags = flags
-                    .raise_signal(Signal::OP_ROUNDED)
-                    .raise_signal(Signal::OP_INEXACT);
-            }
+        // `10^k` exceeding the coefficient discards all of its digits.
+        let (digits, remainder) = if k < Pow10::<N>::digits_count(d.digits) {
+            div_rem(d.digits, Pow10::<N>::power(k))
+        } else {
+            (UInt::ZERO, d.digits)
+        };
+
+        d.scale = new_scale;
 
-            if d.digits.is_zero() {
-                d.scale = new_scale;
-                return d.with_flags(flags);
-            }
+        if remainder.is_zero() {
+            d.digits = digits;
+            d
+        } else {
+            let discarded = RoundConsts::<N>::cmp_half(remainder, k);
+            (d.digits, _) = round_discarded(digits, discarded, sign, ctx);
+            d.raise_signal(Signal::OP_ROUNDED)
+                .raise_signal(Signal::OP_INEXACT)
         }
-        d.with_flags(flags)
     }
 }
@@ -12,4 +12,11 @@ macro_rules! decimal_type_doc {
     };
 }
 
-pub(crate) use decimal_type_doc;
\ No newline at end of file
+pub(crate) use decimal_type_doc;
+macro_rules! fixed_decimal_type_doc {
+    ($bits: literal, $sign: literal) => {
+        concat!("Fixed-scale ", $sign, " decimal number with ", $bits, "-bit integer for decimal digits and `SCALE` fractional digits.")
+    };
+}
+
+pub(crate) use fixed_decimal_type_doc;
@@ -52,6 +52,12 @@ pub enum ParseError {
     /// Invalid radix.
     InvalidRadix,
 
+    /// The number cannot be represented in target type without rounding.
+    ///
+    /// This variant will be emitted when the parsing string has more
+    /// fractional digits than fixed-scale target type can hold.
+    Inexact,
+
     /// Unknown error
     Unknown,
 }
@@ -66,6 +72,7 @@ impl ParseError {
             NegOverflow => "number too small to fit in target type",
             Signed => "number would be signed for unsigned type",
             InvalidRadix => "radix for decimal must be 10",
+            Inexact => "number cannot be represented exactly in target type",
             ExponentOverflow => "exponent is too large to fit in target type",
             Unknown => "unknown error",
         }
@@ -119,6 +126,7 @@ pub(crate) fn pretty_error_msg(ty: &str, e: ParseError) -> String {
         NegOverflow => "negative overflow",
         Signed => "does not support negative values",
         InvalidRadix => "radix MUST be 10",
+        Inexact => "cannot hold the number without rounding",
         ExponentOverflow => "exponent overflow",
         Unknown => "decimal unknown error",
     };
@@ -4,4 +4,7 @@ pub mod diesel;
 #[cfg(feature = "serde")]
 pub mod serde;
 
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+
 pub(crate) mod utils;
@@ -0,0 +1,13 @@
+#[cfg(all(feature = "sqlx_mysql", feature = "test-util"))]
+#[doc(hidden)]
+pub mod mysql;
+
+#[cfg(all(feature = "sqlx_mysql", not(feature = "test-util")))]
+pub(crate) mod mysql;
+
+#[cfg(all(feature = "sqlx_postgres", feature = "test-util"))]
+#[doc(hidden)]
+pub mod pg;
+
+#[cfg(all(feature = "sqlx_postgres", not(feature = "test-util")))]
+pub(crate) mod pg;
@@ -0,0 +1,14 @@
+//! Text `DECIMAL` format of the MySQL protocol.
+//!
+//! MySQL sends and receives `DECIMAL` values as text, in both the text and
+//! the binary protocol.
+
+use crate::decimal::{Decimal, ParseError};
+
+type D<const N: usize> = Decimal<N>;
+
+/// Reads a decimal from the text of a `DECIMAL` value.
+pub fn decode<const N: usize>(bytes: &[u8]) -> Result<D<N>, ParseError> {
+    let s = core::str::from_utf8(bytes).map_err(|_| ParseError::InvalidLiteral)?;
+    D::from_str(s)
+}
@@ -0,0 +1,108 @@
+//! Binary `NUMERIC` format of the PostgreSQL wire protocol.
+//!
+//! A header of four big-endian 16-bit words – the number of base-10000
+//! digits, the weight of the first digit, the sign and the display scale –
+//! followed by the digits, the most significant first. Besides `NaN`,
+//! PostgreSQL 14 and later support `Infinity` and `-Infinity` values with
+//! the sign words of their own and no digits.
+
+use crate::decimal::{extras::utils::db::postgres::NBase, Decimal, ParseError};
+
+type D<const N: usize> = Decimal<N>;
+
+const SIGN_POS: u16 = 0x0000;
+const SIGN_NEG: u16 = 0x4000;
+const SIGN_NAN: u16 = 0xC000;
+const SIGN_PINF: u16 = 0xD000;
+const SIGN_NINF: u16 = 0xF000;
+
+const NBASE: i16 = 10_000;
+
+/// Appends the binary `NUMERIC` representation of `dec` to `buf`.
+///
+/// `buf` is left unchanged if `dec` can't be represented.
+pub fn encode<const N: usize>(dec: D<N>, buf: &mut Vec<u8>) -> Result<(), ParseError> {
+    if dec.is_infinite() {
+        let sign = if dec.is_negative() {
+            SIGN_NINF
+        } else {
+            SIGN_PINF
+        };
+
+        write(buf, 0, sign, 0, &[]);
+        return Ok(());
+    }
+
+    match NBase::try_from(dec)? {
+        NBase::Positive {
+            weight,
+            scale,
+            digits,
+        } => write(buf, weight, SIGN_POS, scale, &digits),
+        NBase::Negative {
+            weight,
+            scale,
+            digits,
+        } => write(buf, weight, SIGN_NEG, scale, &digits),
+        NBase::NaN => write(buf, 0, SIGN_NAN, 0, &[]),
+    }
+
+    Ok(())
+}
+
+/// Reads a decimal from its binary `NUMERIC` representation.
+pub fn decode<const N: usize>(bytes: &[u8]) -> Result<D<N>, ParseError> {
+    let [n0, n1, w0, w1, s0, s1, d0, d1, bytes @ ..] = bytes else {
+        return Err(ParseError::InvalidLiteral);
+    };
+
+    let count = i16::from_be_bytes([*n0, *n1]);
+    let weight = i16::from_be_bytes([*w0, *w1]);
+    let sign = u16::from_be_bytes([*s0, *s1]);
+    let scale = u16::from_be_bytes([*d0, *d1]);
+
+    if count < 0 || bytes.len() != 2 * count as usize {
+        return Err(ParseError::InvalidLiteral);
+    }
+
+    let digits: Vec<i16> = bytes
+        .chunks_exact(2)
+        .map(|digit| i16::from_be_bytes([digit[0], digit[1]]))
+        .collect();
+
+    if digits.iter().any(|digit| !(0..NBASE).contains(digit)) {
+        return Err(ParseError::InvalidLiteral);
+    }
+
+    let nbase = match sign {
+        SIGN_POS => NBase::Positive {
+            weight,
+            scale,
+            digits,
+        },
+        SIGN_NEG => NBase::Negative {
+            weight,
+            scale,
+            digits,
+        },
+        SIGN_NAN => NBase::NaN,
+        SIGN_PINF => return Ok(D::INFINITY),
+        SIGN_NINF => return Ok(D::NEG_INFINITY),
+        _ => return Err(ParseError::InvalidLiteral),
+    };
+
+    nbase.try_into()
+}
+
+fn write(buf: &mut Vec<u8>, weight: i16, sign: u16, scale: u16, digits: &[i16]) {
+    buf.reserve(8 + 2 * digits.len());
+
+    buf.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    buf.extend_from_slice(&weight.to_be_bytes());
+    buf.extend_from_slice(&sign.to_be_bytes());
+    buf.extend_from_slice(&scale.to_be_bytes());
+
+    for digit in digits {
+        buf.extend_from_slice(&digit.to_be_bytes());
+    }
+}
@@ -1,2 +1,5 @@
 #[cfg(any(feature = "diesel_postgres", feature = "sqlx_postgres"))]
 pub(crate) mod postgres;
+
+#[cfg(any(feature = "diesel_sqlite", feature = "sqlx_sqlite"))]
+pub(crate) mod sqlite;
@@ -3,7 +3,7 @@ use core::cmp::Ordering;
 use crate::{
     decimal::{Decimal, Flags, ParseError},
     int::{
-        math::{div_rem, to_i16},
+        div::Reciprocal,
         UInt,
     },
 };
@@ -33,6 +33,9 @@ pub enum NBase {
     NaN,
 }
 
+/// Reciprocal of `NBASE` for splitting a coefficient into base-10000 digits.
+const NBASE_RECIPROCAL: Reciprocal = Reciprocal::new(10_000);
+
 struct Consts<const N: usize>;
 
 impl<const N: usize> Consts<N> {
@@ -102,13 +105,13 @@ impl<const N: usize> TryFrom<D<N>> for NBase {
         let mut weight = 0;
 
         while !uint.is_zero() {
-            let (div, rem) = div_rem(uint, Consts::<N>::NBASE);
+            let (div, rem) = NBASE_RECIPROCAL.div_rem_digits(uint.digits(), 0);
 
-            if !digits.is_empty() || !rem.is_zero() {
-                digits.push(to_i16(rem).expect("10000 always fits in an i16"));
+            if !digits.is_empty() || rem != 0 {
+                digits.push(rem as i16);
             }
 
-            uint = div;
+            uint = UInt::from_digits(div);
             weight += 1;
         }
 
@@ -0,0 +1,29 @@
+//! SQLite has no decimal storage class. Decimals written as `TEXT` keep the
+//! exact value and scale, `TEXT` and `INTEGER` values are read exactly.
+//! `REAL` values follow the float policy below in both directions:
+//!
+//! - a `REAL` is read as the shortest decimal representation which converts
+//!   back to the same `f64`, so `0.1` becomes `0.1` and not the exact binary
+//!   expansion `0.1000000000000000055511151231257827...`;
+//! - a decimal is written as the `f64` nearest to its exact value, and `NaN`
+//!   is rejected since SQLite would silently store it as `NULL`.
+
+use crate::decimal::{Decimal, ParseError};
+
+type D<const N: usize> = Decimal<N>;
+
+/// Converts a SQLite `REAL` value to a decimal.
+pub(crate) fn from_real<const N: usize>(real: f64) -> Result<D<N>, ParseError> {
+    D::from_str(&format!("{real:e}"))
+}
+
+/// Converts a decimal to the nearest SQLite `REAL` value, or `None` for
+/// `NaN`.
+#[cfg(feature = "diesel_sqlite")]
+pub(crate) fn to_real<const N: usize>(dec: D<N>) -> Option<f64> {
+    if dec.is_nan() {
+        return None;
+    }
+
+    dec.to_string().parse().ok()
+}
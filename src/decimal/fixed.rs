@@ -0,0 +1,521 @@
+//! # Fixed-scale Decimal
+
+mod cmp;
+mod impls;
+mod math;
+
+use impls::consts::consts_impl;
+
+use core::cmp::Ordering;
+
+use crate::{
+    decimal::{doc, Context, Decimal, Flags, ParseError, Sign, Signal, UnsignedFixedDecimal},
+    int::UInt,
+    utils::err_msg,
+};
+
+/// # Fixed-scale Decimal
+///
+/// Generic signed N-bits decimal number with the number of fractional digits
+/// fixed at compile time by the `SCALE` parameter.
+///
+/// The value of `FixedDecimal<N, SCALE>` is always `digits` ×
+/// 10<sup>-SCALE</sup>, so addition and subtraction are exact and never
+/// need rescaling, while multiplication and division round their result back
+/// to `SCALE` fractional digits using the [RoundingMode](crate::decimal::RoundingMode) of the given
+/// [Context].
+///
+/// `SCALE` must be non-negative and `10`<sup>SCALE</sup> must fit into
+/// `UInt<N>`, otherwise using the type fails to compile.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{fdec128, FD128};
+///
+/// const PRICE: FD128<2> = fdec128!(19.99, 2);
+///
+/// assert_eq!(PRICE * fdec128!(3, 2), fdec128!(59.97, 2));
+/// assert_eq!(PRICE / fdec128!(3, 2), fdec128!(6.66, 2));
+/// ```
+#[derive(Copy, Clone)]
+pub struct FixedDecimal<const N: usize, const SCALE: i16> {
+    /// An N-bit unsigned integer coefficient. Represent significant decimal
+    /// digits scaled by 10<sup>SCALE</sup>.
+    digits: UInt<N>,
+
+    /// Special values and signaling flags.
+    flags: Flags,
+}
+
+consts_impl!();
+
+impl<const N: usize, const SCALE: i16> FixedDecimal<N, SCALE> {
+    /// Creates and initializes a fixed-scale decimal from string.
+    ///
+    /// Returns [ParseError::Inexact] if the string has more significant
+    /// fractional digits than `SCALE`.
+    #[track_caller]
+    #[inline]
+    pub const fn from_str(s: &str) -> Result<Self, ParseError> {
+        match Decimal::<N>::from_str(s) {
+            Ok(d) => math::try_from_decimal(d),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates and initializes a fixed-scale decimal from string.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `FixedDecimal<N, SCALE>` cannot be
+    /// constructed from given string without loss of precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{FD128, fdec128};
+    ///
+    /// assert_eq!(FD128::<4>::parse_str("1.2345"), fdec128!(1.2345, 4));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub const fn parse_str(s: &str) -> Self {
+        match Self::from_str(s) {
+            Ok(n) => n,
+            Err(e) => panic!("{}", e.description()),
+        }
+    }
+
+    /// Converts the given [Decimal] into a fixed-scale decimal, rounding it to
+    /// `SCALE` fractional digits if necessary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, fdec128, FD128, decimal::{Context, RoundingMode}};
+    ///
+    /// let ctx = Context::default().with_rounding_mode(RoundingMode::Down);
+    ///
+    /// assert_eq!(FD128::<2>::from_decimal(dec128!(1.5), ctx), fdec128!(1.50, 2));
+    /// assert_eq!(FD128::<2>::from_decimal(dec128!(1.239), ctx), fdec128!(1.23, 2));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn from_decimal(d: Decimal<N>, ctx: Context) -> Self {
+        math::from_decimal(d, ctx).unwrap_signals(ctx)
+    }
+
+    /// Converts the fixed-scale decimal into [Decimal] with the scale
+    /// `SCALE`. This conversion is lossless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec128, fdec128};
+    ///
+    /// let d = fdec128!(1.5, 3).to_decimal();
+    ///
+    /// assert_eq!(d, dec128!(1.5));
+    /// assert_eq!(d.fractional_digits_count(), 3);
+    /// ```
+    #[inline]
+    pub const fn to_decimal(self) -> Decimal<N> {
+        Decimal::new(self.digits, SCALE, self.flags)
+    }
+
+    /// Returns the internal big integer, representing the value of the
+    /// fixed-scale decimal multiplied by 10<sup>SCALE</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{fdec128, u128};
+    ///
+    /// assert_eq!(fdec128!(-123.45, 4).digits(), u128!(1234500));
+    /// ```
+    #[inline]
+    pub const fn digits(&self) -> UInt<N> {
+        self.digits
+    }
+
+    /// Returns the scale of the fixed-scale decimal, which is always `SCALE`.
+    #[inline]
+    pub const fn fractional_digits_count(&self) -> i16 {
+        SCALE
+    }
+
+    /// Return the sign of the fixed-scale decimal as [Sign].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{decimal::Sign, fdec128};
+    ///
+    /// assert_eq!(fdec128!(-1.0, 2).sign(), Sign::Minus);
+    /// assert_eq!(fdec128!(0.0, 2).sign(),  Sign::Plus);
+    /// ```
+    #[inline]
+    pub const fn sign(&self) -> Sign {
+        if self.flags.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        }
+    }
+
+    #[inline]
+    pub const fn is_op_div_by_zero(&self) -> bool {
+        self.flags.has_signal(Signal::OP_DIV_BY_ZERO)
+    }
+
+    #[inline]
+    pub const fn is_op_invalid(&self) -> bool {
+        self.flags.has_signal(Signal::OP_INVALID)
+    }
+
+    #[inline]
+    pub const fn is_op_inexact(&self) -> bool {
+        self.flags.has_signal(Signal::OP_INEXACT)
+    }
+
+    #[inline]
+    pub const fn is_op_rounded(&self) -> bool {
+        self.flags.has_signal(Signal::OP_ROUNDED)
+    }
+
+    #[inline]
+    pub const fn is_op_overflow(&self) -> bool {
+        self.flags.has_signal(Signal::OP_OVERFLOW)
+    }
+
+    #[inline]
+    pub const fn is_op_ok(&self) -> bool {
+        !self.flags.has_signals()
+    }
+
+    /// Returns `true` if this number is neither infinite nor NaN.
+    #[inline]
+    pub const fn is_finite(&self) -> bool {
+        !self.flags.is_special()
+    }
+
+    /// Returns `true` if this value is positive infinity or negative
+    /// infinity, and `false` otherwise.
+    #[inline]
+    pub const fn is_infinite(&self) -> bool {
+        self.flags.is_infinity()
+    }
+
+    /// Returns `true` if this value is NaN and `false` otherwise.
+    #[inline]
+    pub const fn is_nan(&self) -> bool {
+        self.flags.is_nan()
+    }
+
+    /// Returns `true` if the fixed-scale decimal is finite and has zero value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{fdec128, FD128};
+    ///
+    /// assert!(fdec128!(-0.00, 2).is_zero());
+    /// assert!(!fdec128!(0.01, 2).is_zero());
+    /// assert!(!FD128::<2>::INFINITY.is_zero());
+    /// ```
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.digits.is_zero() && !self.flags.is_special()
+    }
+
+    /// Returns true if the sign of the fixed-scale decimal is positive.
+    #[inline]
+    pub const fn is_positive(&self) -> bool {
+        !self.flags.is_negative()
+    }
+
+    /// Returns true if the sign of the fixed-scale decimal is negative
+    /// (including `-0`).
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.flags.is_negative()
+    }
+
+    /// Invert sign of given fixed-scale decimal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::fdec128;
+    ///
+    /// assert_eq!(fdec128!(1.5, 2).neg(), fdec128!(-1.5, 2));
+    /// ```
+    #[inline]
+    pub const fn neg(mut self) -> Self {
+        self.flags = self.flags.neg();
+        self
+    }
+
+    /// Get the absolute value of the fixed-scale decimal (non-negative sign).
+    #[inline]
+    pub const fn abs(mut self) -> Self {
+        self.flags = self.flags.abs();
+        self
+    }
+
+    /// Get the absolute value of the fixed-scale decimal as
+    /// [UnsignedFixedDecimal].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{fdec128, ufdec128};
+    ///
+    /// assert_eq!(fdec128!(-1.5, 2).unsigned_abs(), ufdec128!(1.5, 2));
+    /// ```
+    #[inline]
+    pub const fn unsigned_abs(self) -> UnsignedFixedDecimal<N, SCALE> {
+        UnsignedFixedDecimal::new(self.abs())
+    }
+
+    /// Tests for `self` and `other` values to be equal, and is used by `==`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn eq(&self, other: &Self) -> bool {
+        cmp::eq(self, other)
+    }
+
+    /// Tests for `self` and `other` values not to be equal, and is used by
+    /// `!=` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ne(&self, other: &Self) -> bool {
+        !cmp::eq(self, other)
+    }
+
+    /// Compares and returns the maximum of two fixed-scale decimal values.
+    ///
+    /// Returns the second argument if the comparison determines them to be
+    /// equal.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn max(self, other: Self) -> Self {
+        match self.cmp(&other) {
+            Ordering::Greater => self,
+            _ => other,
+        }
+    }
+
+    /// Compares and returns the minimum of two fixed-scale decimal values.
+    ///
+    /// Returns the first argument if the comparison determines them to be
+    /// equal.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn min(self, other: Self) -> Self {
+        match self.cmp(&other) {
+            Ordering::Greater => other,
+            _ => self,
+        }
+    }
+
+    /// Tests `self` less than `other` and is used by the `<` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn lt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Less)
+    }
+
+    /// Tests `self` less than or equal to `other` and is used by the `<=`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn le(&self, other: &Self) -> bool {
+        !matches!(self.cmp(other), Ordering::Greater)
+    }
+
+    /// Tests `self` greater than `other` and is used by the `>` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn gt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Greater)
+    }
+
+    /// Tests `self` greater than or equal to `other` and is used by the `>=`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ge(&self, other: &Self) -> bool {
+        !matches!(self.cmp(other), Ordering::Less)
+    }
+
+    /// This method returns an [Ordering] between `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::fdec128;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(fdec128!(5, 2).cmp(&fdec128!(10, 2)), Ordering::Less);
+    /// assert_eq!(fdec128!(-0.5, 2).cmp(&fdec128!(-1, 2)), Ordering::Greater);
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        cmp::cmp(self, other)
+    }
+
+    /// Calculates `self` + `rhs`.
+    ///
+    /// The result is always exact unless it overflows. Is internally used by
+    /// the `+` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{fdec128, decimal::Context};
+    ///
+    /// let c = fdec128!(0.1, 2).add(fdec128!(0.2, 2), Context::default());
+    /// assert_eq!(c, fdec128!(0.3, 2));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn add(self, rhs: Self, ctx: Context) -> Self {
+        math::add(self, rhs).unwrap_signals(ctx)
+    }
+
+    /// Calculates `self` - `rhs`.
+    ///
+    /// The result is always exact unless it overflows. Is internally used by
+    /// the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{fdec128, decimal::Context};
+    ///
+    /// let c = fdec128!(0.1, 2).sub(fdec128!(0.2, 2), Context::default());
+    /// assert_eq!(c, fdec128!(-0.1, 2));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sub(self, rhs: Self, ctx: Context) -> Self {
+        math::sub(self, rhs).unwrap_signals(ctx)
+    }
+
+    /// Calculates `self` × `rhs`, rounding the result to `SCALE` fractional
+    /// digits.
+    ///
+    /// Is internally used by the `*` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{fdec128, decimal::{Context, RoundingMode}};
+    ///
+    /// let ctx = Context::default().with_rounding_mode(RoundingMode::HalfEven);
+    ///
+    /// let c = fdec128!(0.25, 2).mul(fdec128!(0.5, 2), ctx);
+    /// assert_eq!(c, fdec128!(0.12, 2));
+    /// assert!(c.is_op_rounded());
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn mul(self, rhs: Self, ctx: Context) -> Self {
+        math::mul(self, rhs, ctx).unwrap_signals(ctx)
+    }
+
+    /// Calculates `self` ÷ `rhs`, rounding the result to `SCALE` fractional
+    /// digits.
+    ///
+    /// Is internally used by the `/` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{fdec128, decimal::Context};
+    ///
+    /// let c = fdec128!(2, 4).div(fdec128!(3, 4), Context::default());
+    /// assert_eq!(c, fdec128!(0.6667, 4));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use fastnum::fdec128;
+    ///
+    /// let c = fdec128!(1, 2) / fdec128!(0, 2);
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn div(self, rhs: Self, ctx: Context) -> Self {
+        math::div(self, rhs, ctx).unwrap_signals(ctx)
+    }
+
+    /// Returns `Some(self)` if no special value and no signals were raised
+    /// during computations, or `None` otherwise.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ok(self) -> Option<Self> {
+        if self.flags.is_special() || self.flags.has_signals() {
+            None
+        } else {
+            Some(self)
+        }
+    }
+}
+
+#[doc(hidden)]
+impl<const N: usize, const SCALE: i16> FixedDecimal<N, SCALE> {
+    /// `10`<sup>SCALE</sup>, checked at compile time.
+    pub(crate) const MULTIPLIER: UInt<N> = {
+        assert!(SCALE >= 0, err_msg!("fixed decimal scale must be non-negative"));
+
+        let (multiplier, overflow) = UInt::TEN.overflowing_pow(SCALE as u32);
+        assert!(!overflow, err_msg!("fixed decimal scale is too large for the given bit width"));
+
+        multiplier
+    };
+
+    #[inline]
+    pub(crate) const fn new(digits: UInt<N>, flags: Flags) -> Self {
+        let _ = Self::MULTIPLIER;
+        Self { digits, flags }
+    }
+
+    #[inline]
+    pub(crate) fn type_name() -> String {
+        format!("FD{}<{}>", N * 64, SCALE)
+    }
+
+    #[inline]
+    pub(crate) const fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    #[inline]
+    pub(crate) const fn with_flags(mut self, flags: Flags) -> Self {
+        self.flags = self.flags.combine(flags);
+        self
+    }
+
+    #[inline]
+    pub(crate) const fn with_signals_from(mut self, other: &Self) -> Self {
+        self.flags = self.flags.with_signals_from(other.flags);
+        self
+    }
+
+    #[inline]
+    pub(crate) const fn with_signals_from_and(mut self, other: &Self, signal: Signal) -> Self {
+        self.flags = self.flags.with_signals_from_and(other.flags, signal);
+        self
+    }
+
+    #[inline]
+    pub(crate) const fn unwrap_signals(self, ctx: Context) -> Self {
+        #[cfg(debug_assertions)]
+        ctx.trap_signals(self.flags.signals());
+        self
+    }
+}
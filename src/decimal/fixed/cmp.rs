@@ -0,0 +1,45 @@
+use core::cmp::Ordering;
+
+use crate::decimal::FixedDecimal;
+
+type FD<const N: usize, const S: i16> = FixedDecimal<N, S>;
+
+#[inline]
+pub(crate) const fn eq<const N: usize, const S: i16>(lhs: &FD<N, S>, rhs: &FD<N, S>) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        return false;
+    }
+
+    matches!(cmp(lhs, rhs), Ordering::Equal)
+}
+
+#[inline]
+pub(crate) const fn cmp<const N: usize, const S: i16>(lhs: &FD<N, S>, rhs: &FD<N, S>) -> Ordering {
+    match (lhs.is_nan(), rhs.is_nan()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+
+    // Zero is equal to negative zero.
+    let lhs_negative = lhs.is_negative() && !lhs.is_zero();
+    let rhs_negative = rhs.is_negative() && !rhs.is_zero();
+
+    match (lhs_negative, rhs_negative) {
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+        (true, true) => cmp_magnitude(lhs, rhs).reverse(),
+        (false, false) => cmp_magnitude(lhs, rhs),
+    }
+}
+
+#[inline]
+const fn cmp_magnitude<const N: usize, const S: i16>(lhs: &FD<N, S>, rhs: &FD<N, S>) -> Ordering {
+    match (lhs.is_infinite(), rhs.is_infinite()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => lhs.digits.cmp(&rhs.digits),
+    }
+}
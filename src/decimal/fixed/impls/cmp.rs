@@ -0,0 +1,16 @@
+use crate::decimal::FixedDecimal;
+
+impl<const N: usize, const SCALE: i16> PartialEq for FixedDecimal<N, SCALE> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.eq(other)
+    }
+
+    #[allow(clippy::partialeq_ne_impl)]
+    #[inline]
+    fn ne(&self, other: &Self) -> bool {
+        self.ne(other)
+    }
+}
+
+impl<const N: usize, const SCALE: i16> Eq for FixedDecimal<N, SCALE> {}
@@ -0,0 +1,33 @@
+macro_rules! consts_impl {
+    () => {
+        impl<const N: usize, const SCALE: i16> FixedDecimal<N, SCALE> {
+            /// Not a Number (NaN).
+            pub const NAN: Self = Self::new(UInt::ZERO, Flags::NAN);
+
+            /// Infinity (∞).
+            pub const INFINITY: Self = Self::new(UInt::ZERO, Flags::INFINITY);
+
+            /// Negative infinity (−∞).
+            pub const NEG_INFINITY: Self = Self::new(UInt::ZERO, Flags::NEG_INFINITY);
+
+            /// The smallest value that can be represented by this fixed-scale decimal type - −(2<sup>N</sup> − 1) × 10<sup>-SCALE</sup>.
+            pub const MIN: Self = Self::new(UInt::MAX, Flags::NEG);
+
+            /// The maximum value that this fixed-scale decimal type can represent - (2<sup>N</sup> − 1) × 10<sup>-SCALE</sup>.
+            pub const MAX: Self = Self::new(UInt::MAX, Flags::default());
+
+            /// The smallest positive value that this fixed-scale decimal type can represent - 10<sup>-SCALE</sup>.
+            pub const EPSILON: Self = Self::new(UInt::ONE, Flags::default());
+
+            consts_impl!(CONSTS ZERO 0, ONE 1, TWO 2, THREE 3, FOUR 4, FIVE 5, SIX 6, SEVEN 7, EIGHT 8, NINE 9, TEN 10);
+        }
+    };
+    (CONSTS $($name: ident $num: literal), *) => {
+        $(
+            #[doc = concat!("The value of `", $num, "` represented by this fixed-scale decimal type.")]
+            pub const $name: Self = Self::new(UInt::$name.strict_mul(Self::MULTIPLIER), Flags::default());
+        )*
+    }
+}
+
+pub(crate) use consts_impl;
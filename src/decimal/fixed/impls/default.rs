@@ -0,0 +1,8 @@
+use crate::decimal::FixedDecimal;
+
+impl<const N: usize, const SCALE: i16> Default for FixedDecimal<N, SCALE> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
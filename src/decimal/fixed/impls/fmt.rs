@@ -0,0 +1,48 @@
+use core::fmt::{self, Debug, Display, Formatter, LowerExp, UpperExp};
+
+use crate::decimal::FixedDecimal;
+
+impl<const N: usize, const SCALE: i16> Display for FixedDecimal<N, SCALE> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.to_decimal(), f)
+    }
+}
+
+impl<const N: usize, const SCALE: i16> LowerExp for FixedDecimal<N, SCALE> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerExp::fmt(&self.to_decimal(), f)
+    }
+}
+
+impl<const N: usize, const SCALE: i16> UpperExp for FixedDecimal<N, SCALE> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        UpperExp::fmt(&self.to_decimal(), f)
+    }
+}
+
+impl<const N: usize, const SCALE: i16> Debug for FixedDecimal<N, SCALE> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            if self.is_nan() {
+                return write!(f, "{}(NaN)", Self::type_name());
+            } else if self.is_infinite() {
+                return write!(f, "{}({}Inf)", Self::type_name(), self.sign());
+            }
+
+            let alert = if self.flags().has_signals() { "! " } else { "" };
+            write!(f, "{}({}{})", Self::type_name(), alert, self)
+        } else {
+            write!(
+                f,
+                "{}(digits=[{:?}], flags=[{}], signals=[{}])",
+                Self::type_name(),
+                self.digits,
+                self.flags(),
+                self.flags().signals()
+            )
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::decimal::{Decimal, FixedDecimal, ParseError};
+
+impl<const N: usize, const SCALE: i16> From<FixedDecimal<N, SCALE>> for Decimal<N> {
+    #[inline]
+    fn from(d: FixedDecimal<N, SCALE>) -> Self {
+        d.to_decimal()
+    }
+}
+
+impl<const N: usize, const SCALE: i16> TryFrom<Decimal<N>> for FixedDecimal<N, SCALE> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(d: Decimal<N>) -> Result<Self, Self::Error> {
+        super::super::math::try_from_decimal(d)
+    }
+}
+
+macro_rules! from_int {
+    ($($int: tt),*) => {
+        $(
+            impl<const N: usize, const SCALE: i16> TryFrom<$int> for FixedDecimal<N, SCALE> {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(int: $int) -> Result<Self, Self::Error> {
+                    Self::try_from(Decimal::<N>::from(int))
+                }
+            }
+        )*
+    }
+}
+
+from_int!(u8, u16, u32, u64, usize);
+from_int!(i8, i16, i32, i64, isize);
+
+impl<const N: usize, const SCALE: i16> TryFrom<u128> for FixedDecimal<N, SCALE> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(int: u128) -> Result<Self, Self::Error> {
+        Self::try_from(Decimal::<N>::try_from_u128(int)?)
+    }
+}
+
+impl<const N: usize, const SCALE: i16> TryFrom<i128> for FixedDecimal<N, SCALE> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(int: i128) -> Result<Self, Self::Error> {
+        Self::try_from(Decimal::<N>::try_from_i128(int)?)
+    }
+}
@@ -0,0 +1,12 @@
+use core::str::FromStr;
+
+use crate::decimal::{FixedDecimal, ParseError};
+
+impl<const N: usize, const SCALE: i16> FromStr for FixedDecimal<N, SCALE> {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        FixedDecimal::from_str(s)
+    }
+}
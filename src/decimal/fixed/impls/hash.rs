@@ -0,0 +1,16 @@
+use core::hash::{Hash, Hasher};
+
+use crate::decimal::FixedDecimal;
+
+impl<const N: usize, const SCALE: i16> Hash for FixedDecimal<N, SCALE> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.digits.hash(state);
+        self.is_infinite().hash(state);
+
+        // Zero is equal to negative zero.
+        if !self.is_zero() {
+            self.is_negative().hash(state);
+        }
+    }
+}
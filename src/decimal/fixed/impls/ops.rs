@@ -0,0 +1,42 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::decimal::{Context, FixedDecimal};
+
+macro_rules! macro_impl {
+    ($($Op: ident $op: ident $OpAssign: ident $op_assign: ident), *) => {
+        $(
+            impl<const N: usize, const SCALE: i16> $Op for FixedDecimal<N, SCALE> {
+                type Output = FixedDecimal<N, SCALE>;
+
+                #[inline]
+                fn $op(self, rhs: Self) -> FixedDecimal<N, SCALE> {
+                    self.$op(rhs, Context::default())
+                }
+            }
+
+            impl<const N: usize, const SCALE: i16> $OpAssign for FixedDecimal<N, SCALE> {
+                #[inline]
+                fn $op_assign(&mut self, rhs: Self) {
+                    let res = $Op::<FixedDecimal<N, SCALE>>::$op(*self, rhs);
+                    *self = res;
+                }
+            }
+        )*
+    };
+}
+
+macro_impl!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign
+);
+
+impl<const N: usize, const SCALE: i16> Neg for FixedDecimal<N, SCALE> {
+    type Output = FixedDecimal<N, SCALE>;
+
+    #[inline]
+    fn neg(self) -> FixedDecimal<N, SCALE> {
+        self.neg()
+    }
+}
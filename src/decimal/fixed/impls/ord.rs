@@ -0,0 +1,17 @@
+use core::cmp::Ordering;
+
+use crate::decimal::FixedDecimal;
+
+impl<const N: usize, const SCALE: i16> PartialOrd for FixedDecimal<N, SCALE> {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(std::cmp::Ord::cmp(self, rhs))
+    }
+}
+
+impl<const N: usize, const SCALE: i16> Ord for FixedDecimal<N, SCALE> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.cmp(rhs)
+    }
+}
@@ -0,0 +1,286 @@
+use core::cmp::Ordering;
+
+use crate::{
+    decimal::{
+        round::{round_discarded, round_remainder},
+        Context, Decimal, FixedDecimal, Flags, ParseError, Sign, Signal,
+    },
+    int::{
+        math::{div_rem, div_rem_double},
+        mul::widening_mul,
+        UInt,
+    },
+};
+
+type FD<const N: usize, const S: i16> = FixedDecimal<N, S>;
+
+#[inline]
+pub(crate) const fn add<const N: usize, const S: i16>(lhs: FD<N, S>, rhs: FD<N, S>) -> FD<N, S> {
+    if lhs.is_nan() {
+        return lhs.with_signals_from_and(&rhs, Signal::OP_INVALID);
+    }
+
+    if rhs.is_nan() {
+        return rhs.with_signals_from_and(&lhs, Signal::OP_INVALID);
+    }
+
+    if lhs.is_negative() == rhs.is_negative() {
+        add_abs(lhs, rhs)
+    } else {
+        sub_abs(lhs, rhs)
+    }
+}
+
+#[inline]
+pub(crate) const fn sub<const N: usize, const S: i16>(lhs: FD<N, S>, rhs: FD<N, S>) -> FD<N, S> {
+    add(lhs, rhs.neg())
+}
+
+#[inline]
+pub(crate) const fn mul<const N: usize, const S: i16>(
+    lhs: FD<N, S>,
+    rhs: FD<N, S>,
+    ctx: Context,
+) -> FD<N, S> {
+    if lhs.is_nan() {
+        return lhs.with_signals_from_and(&rhs, Signal::OP_INVALID);
+    }
+
+    if rhs.is_nan() {
+        return rhs.with_signals_from_and(&lhs, Signal::OP_INVALID);
+    }
+
+    let flags = lhs.flags.mul(rhs.flags);
+
+    if lhs.is_infinite() || rhs.is_infinite() {
+        if lhs.is_zero() || rhs.is_zero() {
+            return FD::NAN
+                .with_signals_from(&lhs)
+                .with_signals_from_and(&rhs, Signal::OP_INVALID);
+        }
+
+        return FD::INFINITY.with_flags(flags);
+    }
+
+    let (low, high) = widening_mul(lhs.digits, rhs.digits);
+    quantize(low, high, FD::<N, S>::MULTIPLIER, flags, ctx)
+}
+
+#[inline]
+pub(crate) const fn div<const N: usize, const S: i16>(
+    dividend: FD<N, S>,
+    divisor: FD<N, S>,
+    ctx: Context,
+) -> FD<N, S> {
+    if dividend.is_nan() {
+        return dividend.with_signals_from_and(&divisor, Signal::OP_INVALID);
+    }
+
+    if divisor.is_nan() {
+        return divisor.with_signals_from_and(&dividend, Signal::OP_INVALID);
+    }
+
+    let flags = dividend.flags.mul(divisor.flags);
+
+    match (dividend.is_infinite(), divisor.is_infinite()) {
+        (true, true) => {
+            return FD::NAN
+                .with_signals_from(&dividend)
+                .with_signals_from_and(&divisor, Signal::OP_INVALID);
+        }
+        (true, false) => {
+            return FD::INFINITY.with_flags(flags);
+        }
+        (false, true) => {
+            return FD::ZERO.with_flags(flags);
+        }
+        (false, false) => {}
+    }
+
+    if divisor.is_zero() {
+        return FD::INFINITY.with_flags(flags.raise_signal(Signal::div_by_zero()));
+    }
+
+    if dividend.is_zero() {
+        return FD::new(UInt::ZERO, flags);
+    }
+
+    let (low, high) = widening_mul(dividend.digits, FD::<N, S>::MULTIPLIER);
+    quantize(low, high, divisor.digits, flags, ctx)
+}
+
+#[inline]
+pub(crate) const fn from_decimal<const N: usize, const S: i16>(
+    d: Decimal<N>,
+    ctx: Context,
+) -> FD<N, S> {
+    let flags = d.flags();
+
+    if flags.is_special() {
+        return FD::new(UInt::ZERO, flags);
+    }
+
+    let scale = d.fractional_digits_count();
+
+    if scale <= S {
+        if d.is_zero() {
+            return FD::new(UInt::ZERO, flags);
+        }
+
+        let (multiplier, overflow) = UInt::TEN.overflowing_pow((S as i32 - scale as i32) as u32);
+        if overflow {
+            return overflow_result(flags);
+        }
+
+        let (digits, overflow) = d.digits().overflowing_mul(multiplier);
+        if overflow {
+            return overflow_result(flags);
+        }
+
+        return FD::new(digits, flags);
+    }
+
+    let digits = d.digits();
+    let sign = sign(flags);
+    let (divisor, overflow) = UInt::TEN.overflowing_pow((scale as i32 - S as i32) as u32);
+
+    let (digits, inexact, overflow) = if !overflow {
+        let (quotient, remainder) = div_rem(digits, divisor);
+        let (quotient, overflow) = round_remainder(quotient, remainder, divisor, sign, ctx);
+        (quotient, !remainder.is_zero(), overflow)
+    } else if digits.is_zero() {
+        (UInt::ZERO, false, false)
+    } else {
+        // 10^k doesn't fit into UInt<N>, so all the digits are discarded and
+        // only have to be compared against 5 × 10^(k-1).
+        let (power, overflow) =
+            UInt::TEN.overflowing_pow((scale as i32 - S as i32 - 1) as u32);
+        let discarded = if overflow {
+            Ordering::Less
+        } else {
+            let (half, overflow) = power.overflowing_mul(UInt::FIVE);
+            if overflow {
+                Ordering::Less
+            } else {
+                digits.cmp(&half)
+            }
+        };
+        let (quotient, overflow) = round_discarded(UInt::ZERO, discarded, sign, ctx);
+        (quotient, true, overflow)
+    };
+
+    if overflow {
+        return overflow_result(flags);
+    }
+
+    if inexact {
+        FD::new(digits, flags.raise_signal(Signal::OP_INEXACT).raise_signal(Signal::OP_ROUNDED))
+    } else {
+        FD::new(digits, flags)
+    }
+}
+
+/// Converts the given decimal without loss of precision.
+#[inline]
+pub(crate) const fn try_from_decimal<const N: usize, const S: i16>(
+    d: Decimal<N>,
+) -> Result<FD<N, S>, ParseError> {
+    let res = from_decimal(d, Context::default());
+
+    if res.is_op_overflow() {
+        if res.is_negative() {
+            Err(ParseError::NegOverflow)
+        } else {
+            Err(ParseError::PosOverflow)
+        }
+    } else if res.is_op_inexact() {
+        Err(ParseError::Inexact)
+    } else {
+        Ok(res)
+    }
+}
+
+#[inline]
+const fn add_abs<const N: usize, const S: i16>(lhs: FD<N, S>, rhs: FD<N, S>) -> FD<N, S> {
+    if lhs.is_infinite() {
+        return lhs.with_signals_from(&rhs);
+    }
+
+    if rhs.is_infinite() {
+        return rhs.with_signals_from(&lhs);
+    }
+
+    let flags = lhs.flags.combine(rhs.flags);
+    let (digits, overflow) = lhs.digits.overflowing_add(rhs.digits);
+
+    if overflow {
+        overflow_result(flags)
+    } else {
+        FD::new(digits, flags)
+    }
+}
+
+#[inline]
+const fn sub_abs<const N: usize, const S: i16>(lhs: FD<N, S>, rhs: FD<N, S>) -> FD<N, S> {
+    match (lhs.is_infinite(), rhs.is_infinite()) {
+        (true, true) => {
+            return FD::NAN
+                .with_signals_from(&lhs)
+                .with_signals_from_and(&rhs, Signal::OP_INVALID);
+        }
+        (true, false) => {
+            return lhs.with_signals_from(&rhs);
+        }
+        (false, true) => {
+            return rhs.with_signals_from(&lhs);
+        }
+        (false, false) => {}
+    }
+
+    match lhs.digits.cmp(&rhs.digits) {
+        Ordering::Less => FD::new(rhs.digits.strict_sub(lhs.digits), rhs.flags).with_signals_from(&lhs),
+        Ordering::Equal => FD::ZERO.with_signals_from(&lhs).with_signals_from(&rhs),
+        Ordering::Greater => FD::new(lhs.digits.strict_sub(rhs.digits), lhs.flags).with_signals_from(&rhs),
+    }
+}
+
+/// Divides the double-width `high:low` by `divisor` and rounds the quotient
+/// to the fixed scale.
+#[inline]
+const fn quantize<const N: usize, const S: i16>(
+    low: UInt<N>,
+    high: UInt<N>,
+    divisor: UInt<N>,
+    flags: Flags,
+    ctx: Context,
+) -> FD<N, S> {
+    let Some((digits, remainder)) = div_rem_double(low, high, divisor) else {
+        return overflow_result(flags);
+    };
+
+    if remainder.is_zero() {
+        return FD::new(digits, flags);
+    }
+
+    let (digits, overflow) = round_remainder(digits, remainder, divisor, sign(flags), ctx);
+
+    if overflow {
+        return overflow_result(flags);
+    }
+
+    FD::new(digits, flags.raise_signal(Signal::OP_INEXACT).raise_signal(Signal::OP_ROUNDED))
+}
+
+#[inline]
+const fn overflow_result<const N: usize, const S: i16>(flags: Flags) -> FD<N, S> {
+    FD::INFINITY.with_flags(flags.raise_signal(Signal::overflow()))
+}
+
+#[inline]
+const fn sign(flags: Flags) -> Sign {
+    if flags.is_negative() {
+        Sign::Minus
+    } else {
+        Sign::Plus
+    }
+}
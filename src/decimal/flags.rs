@@ -120,6 +120,27 @@ impl Flags {
     pub const fn has_signals(&self) -> bool {
         !self.signals.is_empty()
     }
+
+    #[inline(always)]
+    pub(crate) const fn to_bits(self) -> [u8; 2] {
+        [self.flags.0, self.signals.to_bits()]
+    }
+
+    /// Unknown flag bits are ignored, and `NaN` takes precedence over
+    /// infinity.
+    #[inline(always)]
+    pub(crate) const fn from_bits(bits: [u8; 2]) -> Self {
+        let mut flags = FlagsInt(bits[0] & FlagsInt::MASK);
+
+        if flags.is_nan() {
+            flags = flags.unset(FlagsInt::INFINITY);
+        }
+
+        Self {
+            flags,
+            signals: Signal::from_bits(bits[1]),
+        }
+    }
 }
 
 /// Operation flags
@@ -141,6 +162,8 @@ impl FlagsInt {
 
     const MASK_IS_SPECIAL: u8 = Self::NAN.0 | Self::INFINITY.0;
 
+    const MASK: u8 = Self::SIGN.0 | Self::MASK_IS_SPECIAL;
+
     #[inline(always)]
     pub const fn combine(self, other: Self) -> Self {
         self.set(other)
@@ -0,0 +1,97 @@
+/// IEEE 754-2008 decimal interchange format.
+///
+/// A decimal interchange format stores a sign, a coefficient of at most
+/// [`precision`](Self::precision) decimal digits and a biased exponent in a
+/// 32, 64 or 128-bit word. The coefficient is encoded either as a binary
+/// integer (BID) or in densely packed decimal (DPD), see the `to_bid*` and
+/// `to_dpd*` methods of [Decimal](crate::decimal::Decimal).
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{dec128, decimal::{Context, Interchange}};
+///
+/// let d = dec128!(1.2345678).round_to_interchange(Interchange::Decimal32, Context::default());
+///
+/// assert_eq!(d, dec128!(1.234568));
+/// assert!(d.is_op_inexact());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Interchange {
+    /// 32-bit `decimal32` with 7 digits.
+    Decimal32,
+
+    /// 64-bit `decimal64` with 16 digits.
+    Decimal64,
+
+    /// 128-bit `decimal128` with 34 digits.
+    Decimal128,
+}
+
+impl Interchange {
+    /// Returns the number of bits of the format.
+    #[inline]
+    pub const fn bits(self) -> u32 {
+        match self {
+            Self::Decimal32 => 32,
+            Self::Decimal64 => 64,
+            Self::Decimal128 => 128,
+        }
+    }
+
+    /// Returns the maximum number of digits of the coefficient.
+    #[inline]
+    pub const fn precision(self) -> u32 {
+        match self {
+            Self::Decimal32 => 7,
+            Self::Decimal64 => 16,
+            Self::Decimal128 => 34,
+        }
+    }
+
+    /// Returns the maximum adjusted exponent, the exponent of the most
+    /// significant digit of the largest finite value.
+    #[inline]
+    pub const fn emax(self) -> i32 {
+        match self {
+            Self::Decimal32 => 96,
+            Self::Decimal64 => 384,
+            Self::Decimal128 => 6144,
+        }
+    }
+
+    /// Returns the minimum adjusted exponent of normal values.
+    #[inline]
+    pub const fn emin(self) -> i32 {
+        1 - self.emax()
+    }
+
+    /// Exponent bias, which is also the negated minimum exponent of the
+    /// least significant digit.
+    #[inline]
+    pub(crate) const fn bias(self) -> i32 {
+        self.emax() + self.precision() as i32 - 2
+    }
+
+    /// Maximum exponent of the least significant digit.
+    #[inline]
+    pub(crate) const fn qmax(self) -> i32 {
+        self.emax() - self.precision() as i32 + 1
+    }
+
+    /// Number of exponent continuation bits in the combination field.
+    #[inline]
+    pub(crate) const fn exp_bits(self) -> u32 {
+        match self {
+            Self::Decimal32 => 6,
+            Self::Decimal64 => 8,
+            Self::Decimal128 => 12,
+        }
+    }
+
+    /// Number of bits of the trailing significand field.
+    #[inline]
+    pub(crate) const fn trailing_bits(self) -> u32 {
+        self.bits() - 6 - self.exp_bits()
+    }
+}
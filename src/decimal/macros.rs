@@ -1,4 +1,5 @@
 mod dec;
+mod fixed;
 mod context;
 
 
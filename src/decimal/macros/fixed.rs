@@ -0,0 +1,57 @@
+macro_rules! macro_impl {
+    ($DEC: ident, $bits: literal, $sign: ident, $name: ident) => {
+        #[macro_export]
+        #[doc = concat!("A macro to construct ", $bits, "-bit ", stringify!($sign), " [crate::", stringify!($DEC), "] fixed-scale decimal from literal and scale in compile time.")]
+        ///
+        /// The literal is checked at compile time to be representable with
+        /// the given scale without rounding.
+        ///
+        /// # Examples:
+        ///
+        /// ```
+        #[doc = concat!("use fastnum::{", stringify!($name), ", ", stringify!($DEC), "};")]
+        ///
+        #[doc = concat!("const N: ", stringify!($DEC), "<2> = ", stringify!($name), "!(1.5, 2);")]
+        /// assert_eq!(N.digits().to_string(), "150");
+        /// ```
+        ///
+        /// ## Static assertions:
+        ///
+        /// ```compile_fail
+        /// // The below example will fail to compile, as the literal has more fractional digits than the scale:
+        #[doc = concat!("use fastnum::{", stringify!($name), ", ", stringify!($DEC), "};")]
+        ///
+        #[doc = concat!("const N: ", stringify!($DEC), "<2> = ", stringify!($name), "!(1.234, 2);")]
+        /// ```
+        macro_rules! $name {
+            ($lit: literal, $scale: expr) => {{
+                const __DECIMAL: $crate::$DEC<{ $scale }> = $crate::$DEC::<{ $scale }>::parse_str($crate::const_str::replace!($crate::const_str::squish!(stringify!($lit)), " ", ""));
+                __DECIMAL
+            }};
+            (+ $lit: literal, $scale: expr) => {{
+                const __DECIMAL: $crate::$DEC<{ $scale }> = $crate::$DEC::<{ $scale }>::parse_str($crate::const_str::concat!("+", $crate::const_str::replace!($crate::const_str::squish!(stringify!($lit)), " ", "")));
+                __DECIMAL
+            }};
+            ($lit: expr, $scale: expr) => {{
+                const __DECIMAL: $crate::$DEC<{ $scale }> = $crate::$DEC::<{ $scale }>::parse_str($crate::const_str::replace!($crate::const_str::squish!(stringify!($lit)), " ", ""));
+                __DECIMAL
+            }};
+        }
+    };
+}
+
+macro_impl!(UFD128, 128, unsigned, ufdec128);
+macro_impl!(UFD256, 256, unsigned, ufdec256);
+macro_impl!(UFD512, 512, unsigned, ufdec512);
+macro_impl!(UFD1024, 1024, unsigned, ufdec1024);
+macro_impl!(UFD2048, 2048, unsigned, ufdec2048);
+macro_impl!(UFD4096, 4096, unsigned, ufdec4096);
+macro_impl!(UFD8192, 8192, unsigned, ufdec8192);
+
+macro_impl!(FD128, 128, signed, fdec128);
+macro_impl!(FD256, 256, signed, fdec256);
+macro_impl!(FD512, 512, signed, fdec512);
+macro_impl!(FD1024, 1024, signed, fdec1024);
+macro_impl!(FD2048, 2048, signed, fdec2048);
+macro_impl!(FD4096, 4096, signed, fdec4096);
+macro_impl!(FD8192, 8192, signed, fdec8192);
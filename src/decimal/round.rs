@@ -1,4 +1,4 @@
-use core::cmp::Ordering::{Equal, Greater, Less};
+use core::cmp::Ordering::{self, Equal, Greater, Less};
 
 use crate::{
     decimal::{
@@ -6,61 +6,84 @@ use crate::{
         RoundingMode::{self, Ceiling, Down, Floor, HalfDown, HalfEven, HalfUp, Up},
         Sign,
     },
-    int::{math::div_rem, UInt},
+    int::{
+        math::{div_rem, mul_add_digit},
+        pow10::Pow10,
+        UInt,
+    },
 };
 
 pub(crate) struct RoundConsts<const N: usize>;
 
 impl<const N: usize> RoundConsts<N> {
     pub const MAX: UInt<N> = div_rem(UInt::<N>::MAX, UInt::<N>::TEN).0;
+
+    /// Compares the `remainder` of a division by `10^k` against one half of
+    /// the divisor, i.e. `5 * 10^(k - 1)`.
+    #[inline]
+    pub const fn cmp_half(remainder: UInt<N>, k: u32) -> Ordering {
+        debug_assert!(k > 0);
+
+        if k > Pow10::<N>::MAX_POWER + 1 {
+            return Less;
+        }
+
+        match mul_add_digit(Pow10::<N>::power(k - 1), 5, 0) {
+            (half, 0) => remainder.cmp(&half),
+            _ => Less,
+        }
+    }
 }
 
+/// Rounds the truncated quotient `value` of some division by `divisor`, using
+/// the whole `remainder` instead of a single discarded digit.
+///
+/// Returns the rounded value along with a boolean indicating whether the
+/// increment has overflowed.
 #[inline]
-pub(crate) const fn scale_round<const N: usize>(
-    mut value: UInt<N>,
+pub(crate) const fn round_remainder<const N: usize>(
+    value: UInt<N>,
+    remainder: UInt<N>,
+    divisor: UInt<N>,
+    sign: Sign,
     ctx: Context,
 ) -> (UInt<N>, bool) {
-    let remainder;
+    debug_assert!(remainder.lt(&divisor));
 
-    (value, remainder) = div_rem(value, UInt::<N>::TEN);
-
-    if !remainder.is_zero() {
-        (round(value, remainder, ctx), true)
-    } else {
-        (value, false)
+    if remainder.is_zero() {
+        return (value, false);
     }
+
+    round_discarded(value, remainder.cmp(&divisor.strict_sub(remainder)), sign, ctx)
 }
 
+/// Rounds the truncated `value` given the ordering of the non-zero discarded
+/// fraction against one half.
+///
+/// Returns the rounded value along with a boolean indicating whether the
+/// increment has overflowed.
 #[inline]
-pub(crate) const fn round<const N: usize>(
-    mut value: UInt<N>,
-    remainder: UInt<N>,
+pub(crate) const fn round_discarded<const N: usize>(
+    value: UInt<N>,
+    discarded: Ordering,
+    sign: Sign,
     ctx: Context,
-) -> UInt<N> {
-    // TODO: performance optimization
-    match (ctx.rounding_mode(), remainder.cmp(&UInt::FIVE)) {
-        (Up, _) | (Ceiling, _) => {
-            value = value.strict_add(UInt::ONE);
-        }
-        (Down, _) | (Floor, _) => {
-            // Do nothing
-        }
-        (_, Greater) | (HalfUp, Equal) => {
-            value = value.strict_add(UInt::ONE);
-        }
-        (_, Less) | (HalfDown, Equal) => {
-            // Do nothing
-        }
-        (HalfEven, Equal) => {
-            // TODO: performance optimization
-            if value.strict_rem(UInt::TWO).is_zero() {
-                // Do nothing
-            } else {
-                value = value.strict_add(UInt::ONE);
-            }
-        }
+) -> (UInt<N>, bool) {
+    let up = match (ctx.rounding_mode(), discarded) {
+        (Up, _) => true,
+        (Down, _) => false,
+        (Ceiling, _) => matches!(sign, Sign::Plus),
+        (Floor, _) => matches!(sign, Sign::Minus),
+        (_, Greater) | (HalfUp, Equal) => true,
+        (_, Less) | (HalfDown, Equal) => false,
+        (HalfEven, Equal) => value.bit(0),
+    };
+
+    if up {
+        value.overflowing_add(UInt::ONE)
+    } else {
+        (value, false)
     }
-    value
 }
 
 // TODO: refactor
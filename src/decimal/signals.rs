@@ -109,6 +109,16 @@ impl Signal {
     pub const fn is_raised(&self, other: Self) -> bool {
         self.0 & other.0 != 0
     }
+
+    #[inline(always)]
+    pub(crate) const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    #[inline(always)]
+    pub(crate) const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
 }
 
 macro_rules! display {
@@ -0,0 +1,369 @@
+//! # Unsigned Compact Decimal
+
+mod impls;
+
+use core::cmp::Ordering;
+
+use crate::decimal::{doc, CompactDecimal, Context, ParseError, RoundingMode, UnsignedDecimal};
+
+/// # Unsigned Compact Decimal
+///
+/// Unsigned decimal number with a 32-bit coefficient, which takes 8 bytes.
+///
+/// See [CompactDecimal] for details.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct UnsignedCompactDecimal(CompactDecimal);
+
+macro_rules! consts_impl {
+    ($($name: ident $num: literal), *) => {
+        impl UnsignedCompactDecimal {
+            $(
+                #[doc = concat!("The value of `", $num, "` represented by this compact decimal type.")]
+                pub const $name: Self = Self(CompactDecimal::$name);
+            )*
+        }
+    };
+}
+
+consts_impl!(ZERO 0, ONE 1, TWO 2, THREE 3, FOUR 4, FIVE 5, SIX 6, SEVEN 7, EIGHT 8, NINE 9, TEN 10);
+
+impl UnsignedCompactDecimal {
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self(CompactDecimal::NAN);
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Self(CompactDecimal::INFINITY);
+
+    /// The smallest value that can be represented by this compact decimal type.
+    pub const MIN: Self = Self(CompactDecimal::ZERO);
+
+    /// The maximum value that this compact decimal type can represent.
+    pub const MAX: Self = Self(CompactDecimal::MAX);
+
+    /// Creates and initializes an unsigned compact decimal from string.
+    #[track_caller]
+    #[inline]
+    pub const fn from_str(s: &str) -> Result<Self, ParseError> {
+        match CompactDecimal::from_str(s) {
+            Ok(d) => {
+                if d.is_negative() {
+                    Err(ParseError::Signed)
+                } else {
+                    Ok(Self::new(d))
+                }
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates and initializes an unsigned compact decimal from string.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `UnsignedCompactDecimal` cannot be
+    /// constructed from given string without loss of precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{UD32, udec32};
+    ///
+    /// assert_eq!(UD32::parse_str("1.2345"), udec32!(1.2345));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub const fn parse_str(s: &str) -> Self {
+        match Self::from_str(s) {
+            Ok(n) => n,
+            Err(e) => panic!("{}", e.description()),
+        }
+    }
+
+    /// Converts the given [UnsignedDecimal] into an unsigned compact decimal,
+    /// rounding the least significant digits which don't fit into the 32-bit
+    /// coefficient according to the given [Context].
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn from_decimal<const N: usize>(d: UnsignedDecimal<N>, ctx: Context) -> Self {
+        Self::new(CompactDecimal::from_decimal(d.to_signed(), ctx))
+    }
+
+    /// Converts the unsigned compact decimal into [UnsignedDecimal]. This
+    /// conversion is lossless.
+    #[inline]
+    pub const fn to_decimal<const N: usize>(self) -> UnsignedDecimal<N> {
+        UnsignedDecimal::new(self.0.to_decimal())
+    }
+
+    /// Converts the unsigned compact decimal into signed [CompactDecimal].
+    #[inline]
+    pub const fn to_signed(self) -> CompactDecimal {
+        self.0
+    }
+
+    /// Returns the coefficient, representing the significant decimal digits
+    /// of the compact decimal, including significant trailing zeros.
+    #[inline]
+    pub const fn digits(&self) -> u32 {
+        self.0.digits()
+    }
+
+    /// Returns the scale of the compact decimal, the total number of digits
+    /// to the right of the decimal point (including insignificant leading
+    /// zeros).
+    #[inline]
+    pub const fn fractional_digits_count(&self) -> i16 {
+        self.0.fractional_digits_count()
+    }
+
+    #[inline]
+    pub const fn is_op_div_by_zero(&self) -> bool {
+        self.0.is_op_div_by_zero()
+    }
+
+    #[inline]
+    pub const fn is_op_invalid(&self) -> bool {
+        self.0.is_op_invalid()
+    }
+
+    #[inline]
+    pub const fn is_op_inexact(&self) -> bool {
+        self.0.is_op_inexact()
+    }
+
+    #[inline]
+    pub const fn is_op_rounded(&self) -> bool {
+        self.0.is_op_rounded()
+    }
+
+    #[inline]
+    pub const fn is_op_overflow(&self) -> bool {
+        self.0.is_op_overflow()
+    }
+
+    #[inline]
+    pub const fn is_op_underflow(&self) -> bool {
+        self.0.is_op_underflow()
+    }
+
+    #[inline]
+    pub const fn is_op_ok(&self) -> bool {
+        self.0.is_op_ok()
+    }
+
+    /// Returns `true` if this number is neither infinite nor NaN.
+    #[inline]
+    pub const fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Returns `true` if this value is positive infinity, and `false`
+    /// otherwise.
+    #[inline]
+    pub const fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    /// Returns `true` if this value is NaN and `false` otherwise.
+    #[inline]
+    pub const fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// Returns `true` if the compact decimal is finite and has zero value.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Tests for `self` and `other` values to be equal, and is used by `==`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+
+    /// Tests for `self` and `other` values not to be equal, and is used by
+    /// `!=` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ne(&self, other: &Self) -> bool {
+        self.0.ne(&other.0)
+    }
+
+    /// Compares and returns the maximum of two unsigned compact decimal
+    /// values.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0))
+    }
+
+    /// Compares and returns the minimum of two unsigned compact decimal
+    /// values.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn min(self, other: Self) -> Self {
+        Self(self.0.min(other.0))
+    }
+
+    /// Tests `self` less than `other` and is used by the `<` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn lt(&self, other: &Self) -> bool {
+        self.0.lt(&other.0)
+    }
+
+    /// Tests `self` less than or equal to `other` and is used by the `<=`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn le(&self, other: &Self) -> bool {
+        self.0.le(&other.0)
+    }
+
+    /// Tests `self` greater than `other` and is used by the `>` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn gt(&self, other: &Self) -> bool {
+        self.0.gt(&other.0)
+    }
+
+    /// Tests `self` greater than or equal to `other` and is used by the `>=`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ge(&self, other: &Self) -> bool {
+        self.0.ge(&other.0)
+    }
+
+    /// This method returns an [Ordering] between `self` and `other`.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+
+    /// Calculates `self` + `rhs`.
+    ///
+    /// Is internally used by the `+` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn add(self, rhs: Self, ctx: Context) -> Self {
+        Self::new(self.0.add(rhs.0, ctx))
+    }
+
+    /// Calculates `self` - `rhs`.
+    ///
+    /// Is internally used by the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// use fastnum::udec32;
+    ///
+    /// let c = udec32!(1) - udec32!(2);
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sub(self, rhs: Self, ctx: Context) -> Self {
+        Self::from_signed(self.0.sub(rhs.0, ctx), ctx)
+    }
+
+    /// Calculates `self` × `rhs`.
+    ///
+    /// Is internally used by the `*` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn mul(self, rhs: Self, ctx: Context) -> Self {
+        Self::new(self.0.mul(rhs.0, ctx))
+    }
+
+    /// Calculates `self` ÷ `rhs`.
+    ///
+    /// Is internally used by the `/` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{udec32, decimal::Context};
+    ///
+    /// let c = udec32!(2).div(udec32!(3), Context::default());
+    /// assert_eq!(c, udec32!(0.666666667));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn div(self, rhs: Self, ctx: Context) -> Self {
+        Self::new(self.0.div(rhs.0, ctx))
+    }
+
+    /// Calculates `self` % `rhs`.
+    ///
+    /// Is internally used by the `%` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn rem(self, rhs: Self, ctx: Context) -> Self {
+        Self::new(self.0.rem(rhs.0, ctx))
+    }
+
+    /// Returns the result of rounding given unsigned compact decimal number
+    /// to `digits` precision after the decimal point using given
+    /// [RoundingMode].
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn round(self, digits: i16, rounding_mode: RoundingMode) -> Self {
+        Self::new(self.0.round(digits, rounding_mode))
+    }
+
+    /// Returns the result of rounding given unsigned compact decimal number
+    /// to `new_scale` precision after the decimal point using given
+    /// [Context].
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn with_scale(self, new_scale: i16, ctx: Context) -> Self {
+        Self::new(self.0.with_scale(new_scale, ctx))
+    }
+
+    /// Returns `Some(self)` if no special value and no signals were raised
+    /// during computations, or `None` otherwise.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ok(self) -> Option<Self> {
+        match self.0.ok() {
+            Some(d) => Some(Self(d)),
+            None => None,
+        }
+    }
+}
+
+#[doc(hidden)]
+impl UnsignedCompactDecimal {
+    #[inline]
+    pub(crate) const fn new(dec: CompactDecimal) -> Self {
+        debug_assert!(!dec.is_negative());
+        Self(dec)
+    }
+
+    #[inline]
+    pub(crate) const fn from_signed(dec: CompactDecimal, _ctx: Context) -> Self {
+        if dec.is_negative() && !dec.is_zero() {
+            #[cfg(debug_assertions)]
+            panic!(crate::utils::err_msg!(
+                "operation has negative result for unsigned type"
+            ));
+            #[cfg(not(debug_assertions))]
+            Self::new(
+                CompactDecimal::NAN.with_signals_from_and(&dec, crate::decimal::Signal::OP_INVALID),
+            )
+        } else {
+            Self::new(dec.abs())
+        }
+    }
+
+    #[inline]
+    pub(crate) fn type_name() -> &'static str {
+        "UD32"
+    }
+}
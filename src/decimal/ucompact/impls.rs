@@ -0,0 +1,9 @@
+// Trait implementations
+mod cmp;
+mod default;
+mod fmt;
+mod from;
+mod from_str;
+mod hash;
+mod ops;
+mod ord;
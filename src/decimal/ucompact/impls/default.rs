@@ -0,0 +1,8 @@
+use crate::decimal::UnsignedCompactDecimal;
+
+impl Default for UnsignedCompactDecimal {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
@@ -0,0 +1,54 @@
+use core::fmt::{self, Debug, Display, Formatter, LowerExp, UpperExp};
+
+use crate::decimal::UnsignedCompactDecimal;
+
+impl Display for UnsignedCompactDecimal {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl LowerExp for UnsignedCompactDecimal {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerExp::fmt(&self.0, f)
+    }
+}
+
+impl UpperExp for UnsignedCompactDecimal {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        UpperExp::fmt(&self.0, f)
+    }
+}
+
+impl Debug for UnsignedCompactDecimal {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            if self.is_nan() {
+                return write!(f, "{}(NaN)", Self::type_name());
+            } else if self.is_infinite() {
+                return write!(f, "{}(Inf)", Self::type_name());
+            }
+
+            let alert = if self.0.flags().has_signals() {
+                "! "
+            } else {
+                ""
+            };
+            write!(f, "{}({}{})", Self::type_name(), alert, self)
+        } else {
+            write!(
+                f,
+                "{}(digits=[{:?}], exp=[{}], flags=[{}], signals=[{}])",
+                Self::type_name(),
+                self.digits(),
+                (self.fractional_digits_count() as i32).saturating_neg(),
+                self.0.flags(),
+                self.0.flags().signals()
+            )
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::decimal::{CompactDecimal, ParseError, UnsignedCompactDecimal, UnsignedDecimal};
+
+impl<const N: usize> From<UnsignedCompactDecimal> for UnsignedDecimal<N> {
+    #[inline]
+    fn from(d: UnsignedCompactDecimal) -> Self {
+        d.to_decimal()
+    }
+}
+
+impl From<UnsignedCompactDecimal> for CompactDecimal {
+    #[inline]
+    fn from(d: UnsignedCompactDecimal) -> Self {
+        d.to_signed()
+    }
+}
+
+impl<const N: usize> TryFrom<UnsignedDecimal<N>> for UnsignedCompactDecimal {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(d: UnsignedDecimal<N>) -> Result<Self, Self::Error> {
+        Ok(Self::new(CompactDecimal::try_from(d.to_signed())?))
+    }
+}
+
+impl TryFrom<CompactDecimal> for UnsignedCompactDecimal {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(d: CompactDecimal) -> Result<Self, Self::Error> {
+        if d.is_negative() {
+            return Err(ParseError::Signed);
+        }
+        Ok(Self::new(d))
+    }
+}
+
+macro_rules! from_uint {
+    ($($int: tt),*) => {
+        $(
+            impl From<$int> for UnsignedCompactDecimal {
+                #[inline]
+                fn from(int: $int) -> Self {
+                    Self::new(CompactDecimal::from(int))
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! try_from_uint {
+    ($($int: tt),*) => {
+        $(
+            impl TryFrom<$int> for UnsignedCompactDecimal {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(int: $int) -> Result<Self, Self::Error> {
+                    Ok(Self::new(CompactDecimal::try_from(int)?))
+                }
+            }
+        )*
+    }
+}
+
+from_uint!(u8, u16, u32);
+try_from_uint!(u64, usize, u128);
@@ -0,0 +1,12 @@
+use core::str::FromStr;
+
+use crate::decimal::{ParseError, UnsignedCompactDecimal};
+
+impl FromStr for UnsignedCompactDecimal {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        UnsignedCompactDecimal::from_str(s)
+    }
+}
@@ -0,0 +1,10 @@
+use core::hash::{Hash, Hasher};
+
+use crate::decimal::UnsignedCompactDecimal;
+
+impl Hash for UnsignedCompactDecimal {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
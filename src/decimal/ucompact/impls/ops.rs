@@ -0,0 +1,34 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+use crate::decimal::{Context, UnsignedCompactDecimal};
+
+macro_rules! macro_impl {
+    ($($Op: ident $op: ident $OpAssign: ident $op_assign: ident), *) => {
+        $(
+            impl $Op for UnsignedCompactDecimal {
+                type Output = UnsignedCompactDecimal;
+
+                #[inline]
+                fn $op(self, rhs: Self) -> UnsignedCompactDecimal {
+                    self.$op(rhs, Context::default())
+                }
+            }
+
+            impl $OpAssign for UnsignedCompactDecimal {
+                #[inline]
+                fn $op_assign(&mut self, rhs: Self) {
+                    let res = $Op::<UnsignedCompactDecimal>::$op(*self, rhs);
+                    *self = res;
+                }
+            }
+        )*
+    };
+}
+
+macro_impl!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign,
+    Rem rem RemAssign rem_assign
+);
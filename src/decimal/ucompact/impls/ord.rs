@@ -0,0 +1,17 @@
+use core::cmp::Ordering;
+
+use crate::decimal::UnsignedCompactDecimal;
+
+impl PartialOrd for UnsignedCompactDecimal {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(core::cmp::Ord::cmp(self, rhs))
+    }
+}
+
+impl Ord for UnsignedCompactDecimal {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.cmp(rhs)
+    }
+}
@@ -6,7 +6,10 @@ use impls::consts::consts_impl;
 use core::cmp::Ordering;
 
 use crate::{
-    decimal::{doc, Category, Context, Decimal, Flags, ParseError, RoundingMode},
+    decimal::{
+        doc, Category, Context, Decimal, DecimalBytes, Flags, ParseError, RoundingMode, Sign,
+        Signal,
+    },
     int::UInt,
     utils::err_msg,
 };
@@ -185,6 +188,16 @@ impl<const N: usize> UnsignedDecimal<N> {
         self.0.is_op_clamped()
     }
 
+    #[inline]
+    pub const fn is_op_overflow(&self) -> bool {
+        self.0.is_op_overflow()
+    }
+
+    #[inline]
+    pub const fn is_op_underflow(&self) -> bool {
+        self.0.is_op_underflow()
+    }
+
     #[inline]
     pub const fn is_op_ok(&self) -> bool {
         self.0.is_op_ok()
@@ -220,6 +233,160 @@ impl<const N: usize> UnsignedDecimal<N> {
         self.0.is_nan()
     }
 
+    /// Creates an unsigned decimal from the raw parts: coefficient and scale.
+    ///
+    /// The value of the result is `digits` × 10<sup>-scale</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{udec256, u256, UD256};
+    ///
+    /// assert_eq!(UD256::from_parts(u256!(12345), 2), udec256!(123.45));
+    /// ```
+    #[inline]
+    pub const fn from_parts(digits: UInt<N>, scale: i16) -> Self {
+        Self::new(Decimal::from_parts(digits, scale, Sign::Plus))
+    }
+
+    /// Decomposes the unsigned decimal into the raw parts: coefficient and
+    /// scale, so that the value is `digits` × 10<sup>-scale</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{udec256, u256};
+    ///
+    /// assert_eq!(udec256!(123.45).into_parts(), (u256!(12345), 2));
+    /// ```
+    #[inline]
+    pub const fn into_parts(self) -> (UInt<N>, i16) {
+        let (digits, scale, _) = self.0.into_parts();
+        (digits, scale)
+    }
+
+    /// Returns the memory representation of the unsigned decimal in
+    /// little-endian byte order, as described in [DecimalBytes].
+    ///
+    /// The representation is the same as of the equal signed [Decimal].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{udec128, UD128};
+    ///
+    /// let bytes = udec128!(1.25).to_le_bytes();
+    ///
+    /// assert_eq!(bytes.as_ref(), [125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0]);
+    /// assert_eq!(UD128::from_le_bytes(&bytes), udec128!(1.25));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_le_bytes(&self) -> DecimalBytes<N> {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns the memory representation of the unsigned decimal in
+    /// big-endian byte order, as described in [DecimalBytes].
+    ///
+    /// The representation is the same as of the equal signed [Decimal].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{udec128, UD128};
+    ///
+    /// let bytes = udec128!(1.25).to_be_bytes();
+    ///
+    /// assert_eq!(bytes.as_ref(), [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 2, 0, 0]);
+    /// assert_eq!(UD128::from_be_bytes(&bytes), udec128!(1.25));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_be_bytes(&self) -> DecimalBytes<N> {
+        self.0.to_be_bytes()
+    }
+
+    /// Creates an unsigned decimal from its memory representation in
+    /// little-endian byte order, including the raised signals.
+    ///
+    /// Unknown flag bits are ignored, and the coefficient and scale of `NaN`
+    /// and `Infinity` are zero. Bytes of a negative value decode to `NaN`
+    /// with the [Signal::OP_INVALID] signal raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec256, UD256};
+    ///
+    /// let bytes = UD256::INFINITY.to_le_bytes();
+    /// assert_eq!(UD256::from_le_bytes(&bytes), UD256::INFINITY);
+    ///
+    /// let bytes = dec256!(-0.5).to_le_bytes();
+    /// assert!(UD256::from_le_bytes(&bytes).is_nan());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_le_bytes(bytes: &DecimalBytes<N>) -> Self {
+        let (digits, scale, flags) = bytes.decode(false);
+        Self::from_decoded(Decimal::new(digits, scale, flags))
+    }
+
+    /// Creates an unsigned decimal from its memory representation in
+    /// big-endian byte order, including the raised signals.
+    ///
+    /// Unknown flag bits are ignored, and the coefficient and scale of `NaN`
+    /// and `Infinity` are zero. Bytes of a negative value decode to `NaN`
+    /// with the [Signal::OP_INVALID] signal raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{dec256, udec256, UD256};
+    ///
+    /// let bytes = dec256!(0.5).to_be_bytes();
+    /// assert_eq!(UD256::from_be_bytes(&bytes), udec256!(0.5));
+    ///
+    /// let bytes = dec256!(-0.5).to_be_bytes();
+    /// assert!(UD256::from_be_bytes(&bytes).is_nan());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_be_bytes(bytes: &DecimalBytes<N>) -> Self {
+        let (digits, scale, flags) = bytes.decode(true);
+        Self::from_decoded(Decimal::new(digits, scale, flags))
+    }
+
+    /// Creates an unsigned decimal from the integer mantissa and scale, so
+    /// that the value is `mantissa` × 10<sup>-scale</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{udec128, UD128};
+    ///
+    /// assert_eq!(UD128::from_u128_with_scale(12345, 2), udec128!(123.45));
+    /// ```
+    #[inline]
+    pub const fn from_u128_with_scale(mantissa: u128, scale: i16) -> Self {
+        Self::new(Decimal::from_u128_with_scale(mantissa, scale))
+    }
+
+    /// Creates an unsigned decimal from the integer mantissa and scale, so
+    /// that the value is `mantissa` × 10<sup>-scale</sup>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{udec64, UD64};
+    ///
+    /// assert_eq!(UD64::from_u64_with_scale(12345, 2), udec64!(123.45));
+    /// ```
+    #[inline]
+    pub const fn from_u64_with_scale(mantissa: u64, scale: i16) -> Self {
+        Self::new(Decimal::from_u64_with_scale(mantissa, scale))
+    }
+
     /// Initialize unsigned decimal with `1 * 10`<sup>exp</sup> value.
     ///
     /// # Examples
@@ -675,6 +842,24 @@ impl<const N: usize> UnsignedDecimal<N> {
         Self::new(self.0.with_scale(new_scale, ctx))
     }
 
+    /// Converts the unsigned decimal into an unsigned decimal with the other
+    /// bit width.
+    ///
+    /// See [Decimal::resize] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{udec128, udec256, decimal::Context};
+    ///
+    /// assert_eq!(udec256!(1.5).resize::<2>(Context::default()), udec128!(1.5));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn resize<const M: usize>(self, ctx: Context) -> UnsignedDecimal<M> {
+        UnsignedDecimal::new(self.0.resize(ctx))
+    }
+
     #[must_use = doc::must_use_op!()]
     #[inline]
     pub const fn ok(self) -> Option<Self> {
@@ -685,6 +870,31 @@ impl<const N: usize> UnsignedDecimal<N> {
         }
     }
 
+    /// Writes this unsigned decimal into `buf` and returns the written part of
+    /// `buf` as a string slice.
+    ///
+    /// The output is the same as of [`Display`](core::fmt::Display) without
+    /// any formatting options, but no memory is allocated, so it can be used
+    /// in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `buf` is too short to hold the string.
+    /// A buffer of [`Self::MAX_STR_LEN`] bytes is always sufficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{udec256, UD256};
+    ///
+    /// let mut buf = [0; UD256::MAX_STR_LEN];
+    /// assert_eq!(udec256!(12.50).to_str_buf(&mut buf), "12.50");
+    /// ```
+    #[inline]
+    pub const fn to_str_buf<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        self.0.to_str_buf(buf)
+    }
+
     /// Create a string of this unsigned decimal in scientific notation.
     ///
     /// # Examples
@@ -737,6 +947,20 @@ impl<const N: usize> UnsignedDecimal<N> {
         }
     }
 
+    #[inline]
+    const fn from_decoded(dec: Decimal<N>) -> Self {
+        if dec.is_negative() {
+            Self::new(Decimal::NAN.with_signals_from_and(&dec, Signal::OP_INVALID))
+        } else {
+            Self::new(dec)
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn to_signed(self) -> Decimal<N> {
+        self.0
+    }
+
     #[inline]
     pub(crate) fn type_name() -> String {
         format!("UD{}", N * 64)
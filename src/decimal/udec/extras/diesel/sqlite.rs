@@ -0,0 +1,160 @@
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    expression::AsExpression,
+    internal::derives::as_expression::Bound,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::{Double, Nullable, Numeric, Text},
+    sqlite::{Sqlite, SqliteType, SqliteValue},
+};
+
+use crate::decimal::{
+    errors::parse::pretty_error_msg,
+    extras::utils::db::sqlite::{from_real, to_real},
+    ParseError, UnsignedDecimal,
+};
+
+type UD<const N: usize> = UnsignedDecimal<N>;
+
+// Diesel binds `Numeric` as `REAL` on SQLite, so the exact value and scale
+// are kept only by `Text`.
+impl<const N: usize> ToSql<Numeric, Sqlite> for UD<N> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        ToSql::<Double, Sqlite>::to_sql(self, out)
+    }
+}
+
+impl<const N: usize> ToSql<Text, Sqlite> for UD<N> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.to_string());
+        Ok(IsNull::No)
+    }
+}
+
+impl<const N: usize> ToSql<Double, Sqlite> for UD<N> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        let real = to_real(self.0).ok_or_else(|| format!("{self} is not valid SQLite REAL"))?;
+        out.set_value(real);
+        Ok(IsNull::No)
+    }
+}
+
+impl<const N: usize> FromSql<Numeric, Sqlite> for UD<N> {
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        from_sql(value)
+    }
+}
+
+impl<const N: usize> FromSql<Text, Sqlite> for UD<N> {
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        from_sql(value)
+    }
+}
+
+impl<const N: usize> FromSql<Double, Sqlite> for UD<N> {
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        from_sql(value)
+    }
+}
+
+// Whatever the declared SQL type is, SQLite may return any storage class for
+// a column, so the value is converted according to its actual type.
+fn from_sql<const N: usize>(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<UD<N>> {
+    let dec = match value.value_type() {
+        Some(SqliteType::Long) => UD::try_from(value.read_long()),
+        Some(SqliteType::Double) => from_real(value.read_double()).and_then(|dec| {
+            if dec.is_negative() {
+                Err(ParseError::Signed)
+            } else {
+                Ok(UD::new(dec))
+            }
+        }),
+        _ => UD::from_str(value.read_text()),
+    };
+
+    Ok(dec.map_err(|e| pretty_error_msg(UD::<N>::type_name().as_str(), e))?)
+}
+
+impl<DB, const N: usize> ToSql<Nullable<Text>, DB> for UD<N>
+where
+    DB: Backend,
+    Self: ToSql<Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        ToSql::<Text, DB>::to_sql(self, out)
+    }
+}
+
+impl<DB, const N: usize> ToSql<Nullable<Double>, DB> for UD<N>
+where
+    DB: Backend,
+    Self: ToSql<Double, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        ToSql::<Double, DB>::to_sql(self, out)
+    }
+}
+
+impl<const N: usize> AsExpression<Text> for &UD<N> {
+    type Expression = Bound<Text, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<const N: usize> AsExpression<Nullable<Text>> for &UD<N> {
+    type Expression = Bound<Nullable<Text>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<const N: usize> AsExpression<Text> for UD<N> {
+    type Expression = Bound<Text, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<const N: usize> AsExpression<Nullable<Text>> for UD<N> {
+    type Expression = Bound<Nullable<Text>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<const N: usize> AsExpression<Double> for &UD<N> {
+    type Expression = Bound<Double, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<const N: usize> AsExpression<Nullable<Double>> for &UD<N> {
+    type Expression = Bound<Nullable<Double>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<const N: usize> AsExpression<Double> for UD<N> {
+    type Expression = Bound<Double, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<const N: usize> AsExpression<Nullable<Double>> for UD<N> {
+    type Expression = Bound<Nullable<Double>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
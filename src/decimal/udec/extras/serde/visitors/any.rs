@@ -1,6 +1,8 @@
 use core::fmt;
 use serde::de;
 
+use crate::decimal::Decimal;
+
 type UD<const N: usize> = crate::decimal::UnsignedDecimal<N>;
 
 pub struct Visitor<const N: usize>;
@@ -43,7 +45,9 @@ impl<'de, const N: usize> de::Visitor<'de> for Visitor<N> {
     where
         E: de::Error,
     {
-        Ok(UD::<N>::from(value))
+        Decimal::try_from_u128(value)
+            .map(UD::<N>::new)
+            .map_err(|err| E::custom(format!("{}", err)))
     }
 
     fn visit_f32<E>(self, value: f32) -> Result<Self::Value, E>
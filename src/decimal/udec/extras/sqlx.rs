@@ -3,3 +3,6 @@ mod mysql;
 
 #[cfg(feature = "sqlx_postgres")]
 mod pg;
+
+#[cfg(feature = "sqlx_sqlite")]
+mod sqlite;
@@ -12,6 +12,9 @@ macro_rules! consts_impl {
             
             /// The largest value that can be represented by this decimal type (2<sup>N</sup> − 1)×10<sup>32'768</sup>.
             pub const MAX: Self = Self::new(Decimal::MAX);
+
+            /// The maximum length of the string written by [`Self::to_str_buf`].
+            pub const MAX_STR_LEN: usize = Decimal::<N>::MAX_STR_LEN;
             
             consts_impl!(CONSTS ZERO 0, ONE 1, TWO 2, THREE 3, FOUR 4, FIVE 5, SIX 6, SEVEN 7, EIGHT 8, NINE 9, TEN 10);
         }
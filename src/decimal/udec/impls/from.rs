@@ -31,8 +31,71 @@ macro_rules! try_from_int {
     }
 }
 
-from_uint!(u8, u16, u32, u64, u128, usize);
-try_from_int!(i8, i16, i32, i64, i128, isize);
+// `u128` fits into the coefficient of every decimal type except the 64-bit
+// one, which gets `TryFrom` instead.
+macro_rules! from_wide {
+    ($($n: literal),*) => {
+        $(
+            impl From<u128> for UnsignedDecimal<$n> {
+                #[inline]
+                fn from(int: u128) -> Self {
+                    Self::new(Decimal::from(int))
+                }
+            }
+        )*
+    }
+}
+
+impl TryFrom<u128> for UnsignedDecimal<1> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(int: u128) -> Result<Self, Self::Error> {
+        Ok(Self::new(Decimal::try_from_u128(int)?))
+    }
+}
+
+impl<const N: usize> TryFrom<i128> for UnsignedDecimal<N> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(int: i128) -> Result<Self, Self::Error> {
+        if int.is_negative() {
+            return Err(ParseError::Signed);
+        }
+        Ok(Self::new(Decimal::try_from_i128(int)?))
+    }
+}
+
+macro_rules! resize_impl {
+    ($n: literal $(, $m: literal)*) => {
+        $(
+            impl From<UnsignedDecimal<$n>> for UnsignedDecimal<$m> {
+                #[inline]
+                fn from(d: UnsignedDecimal<$n>) -> Self {
+                    Self::new(Decimal::from(d.to_signed()))
+                }
+            }
+
+            impl TryFrom<UnsignedDecimal<$m>> for UnsignedDecimal<$n> {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(d: UnsignedDecimal<$m>) -> Result<Self, Self::Error> {
+                    Ok(Self::new(Decimal::try_from(d.to_signed())?))
+                }
+            }
+        )*
+
+        resize_impl!($($m),*);
+    };
+    () => {};
+}
+
+from_uint!(u8, u16, u32, u64, usize);
+try_from_int!(i8, i16, i32, i64, isize);
+from_wide!(2, 4, 8, 16, 32, 64, 128);
+resize_impl!(1, 2, 4, 8, 16, 32, 64, 128);
 
 impl<const N: usize> TryFrom<f32> for UnsignedDecimal<N> {
     type Error = ParseError;
@@ -1,6 +1,8 @@
-use num_traits::{ConstOne, ConstZero, FromPrimitive, Num, One, ToPrimitive, Zero};
+use num_traits::{
+    ConstOne, ConstZero, FromBytes, FromPrimitive, Num, One, ToBytes, ToPrimitive, Zero,
+};
 
-use crate::decimal::{ParseError, UnsignedDecimal};
+use crate::decimal::{Decimal, DecimalBytes, ParseError, UnsignedDecimal};
 
 impl<const N: usize> One for UnsignedDecimal<N> {
     #[inline]
@@ -171,7 +173,7 @@ impl<const N: usize> FromPrimitive for UnsignedDecimal<N> {
 
     #[inline]
     fn from_u128(n: u128) -> Option<Self> {
-        Some(Self::from(n))
+        Decimal::try_from_u128(n).ok().map(Self::new)
     }
 
     #[inline]
@@ -184,3 +186,31 @@ impl<const N: usize> FromPrimitive for UnsignedDecimal<N> {
         Self::try_from(n).ok()
     }
 }
+
+impl<const N: usize> ToBytes for UnsignedDecimal<N> {
+    type Bytes = DecimalBytes<N>;
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        self.to_be_bytes()
+    }
+
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        self.to_le_bytes()
+    }
+}
+
+impl<const N: usize> FromBytes for UnsignedDecimal<N> {
+    type Bytes = DecimalBytes<N>;
+
+    #[inline]
+    fn from_be_bytes(bytes: &Self::Bytes) -> Self {
+        Self::from_be_bytes(bytes)
+    }
+
+    #[inline]
+    fn from_le_bytes(bytes: &Self::Bytes) -> Self {
+        Self::from_le_bytes(bytes)
+    }
+}
@@ -1,6 +1,6 @@
 use core::ops::{Add, AddAssign};
 
-use crate::decimal::{Context, UnsignedDecimal};
+use crate::decimal::{utils::operand::FromOperand, Context, UnsignedDecimal};
 
 impl<const N: usize> Add for UnsignedDecimal<N> {
     type Output = UnsignedDecimal<N>;
@@ -27,7 +27,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn add(self, rhs: $ty) -> UnsignedDecimal<N> {
-                    let rhs = UnsignedDecimal::from(rhs);
+                    let rhs = UnsignedDecimal::from_operand(rhs);
                     Add::<UnsignedDecimal<N>>::add(self, rhs)
                 }
             }
@@ -35,7 +35,7 @@ macro_rules! macro_impl {
             impl<const N: usize> AddAssign<$ty> for UnsignedDecimal<N> {
                 #[inline]
                 fn add_assign(&mut self, rhs: $ty) {
-                    let rhs = UnsignedDecimal::from(rhs);
+                    let rhs = UnsignedDecimal::from_operand(rhs);
                     self.add_assign(rhs);
                 }
             }
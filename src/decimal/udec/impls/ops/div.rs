@@ -1,6 +1,6 @@
 use core::ops::{Div, DivAssign};
 
-use crate::decimal::{UnsignedDecimal, Context};
+use crate::decimal::{utils::operand::FromOperand, UnsignedDecimal, Context};
 
 impl<const N: usize> Div for UnsignedDecimal<N> {
     type Output = UnsignedDecimal<N>;
@@ -27,7 +27,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn div(self, rhs: $ty) -> UnsignedDecimal<N> {
-                    let rhs = UnsignedDecimal::from(rhs);
+                    let rhs = UnsignedDecimal::from_operand(rhs);
                     Div::<UnsignedDecimal<N>>::div(self, rhs)
                 }
             }
@@ -35,7 +35,7 @@ macro_rules! macro_impl {
             impl<const N: usize> DivAssign<$ty> for UnsignedDecimal<N> {
                 #[inline]
                 fn div_assign(&mut self, rhs: $ty) {
-                    let rhs = UnsignedDecimal::from(rhs);
+                    let rhs = UnsignedDecimal::from_operand(rhs);
                     self.div_assign(rhs);
                 }
             }
@@ -0,0 +1,43 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+use crate::decimal::UnsignedDecimal;
+
+macro_rules! mixed_impl {
+    ($Op: ident $op: ident $OpAssign: ident $op_assign: ident; $n: literal $(, $m: literal)*) => {
+        $(
+            impl $Op<UnsignedDecimal<$m>> for UnsignedDecimal<$n> {
+                type Output = UnsignedDecimal<$m>;
+
+                #[inline]
+                fn $op(self, rhs: UnsignedDecimal<$m>) -> UnsignedDecimal<$m> {
+                    $Op::$op(UnsignedDecimal::<$m>::from(self), rhs)
+                }
+            }
+
+            impl $Op<UnsignedDecimal<$n>> for UnsignedDecimal<$m> {
+                type Output = UnsignedDecimal<$m>;
+
+                #[inline]
+                fn $op(self, rhs: UnsignedDecimal<$n>) -> UnsignedDecimal<$m> {
+                    $Op::$op(self, UnsignedDecimal::<$m>::from(rhs))
+                }
+            }
+
+            impl $OpAssign<UnsignedDecimal<$n>> for UnsignedDecimal<$m> {
+                #[inline]
+                fn $op_assign(&mut self, rhs: UnsignedDecimal<$n>) {
+                    self.$op_assign(UnsignedDecimal::<$m>::from(rhs));
+                }
+            }
+        )*
+
+        mixed_impl!($Op $op $OpAssign $op_assign; $($m),*);
+    };
+    ($Op: ident $op: ident $OpAssign: ident $op_assign: ident;) => {};
+}
+
+mixed_impl!(Add add AddAssign add_assign; 1, 2, 4, 8, 16, 32, 64, 128);
+mixed_impl!(Sub sub SubAssign sub_assign; 1, 2, 4, 8, 16, 32, 64, 128);
+mixed_impl!(Mul mul MulAssign mul_assign; 1, 2, 4, 8, 16, 32, 64, 128);
+mixed_impl!(Div div DivAssign div_assign; 1, 2, 4, 8, 16, 32, 64, 128);
+mixed_impl!(Rem rem RemAssign rem_assign; 1, 2, 4, 8, 16, 32, 64, 128);
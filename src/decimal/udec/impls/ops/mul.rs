@@ -1,6 +1,6 @@
 use core::ops::{Mul, MulAssign};
 
-use crate::decimal::{UnsignedDecimal, Context};
+use crate::decimal::{utils::operand::FromOperand, UnsignedDecimal, Context};
 
 impl<const N: usize> Mul for UnsignedDecimal<N> {
     type Output = UnsignedDecimal<N>;
@@ -26,7 +26,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn mul(self, rhs: $ty) -> Self::Output {
-                    let rhs = UnsignedDecimal::from(rhs);
+                    let rhs = UnsignedDecimal::from_operand(rhs);
                     Mul::<UnsignedDecimal<N>>::mul(self, rhs)
                 }
             }
@@ -34,7 +34,7 @@ macro_rules! macro_impl {
             impl<const N: usize> MulAssign<$ty> for UnsignedDecimal<N> {
                 #[inline]
                 fn mul_assign(&mut self, rhs: $ty) {
-                    let rhs = UnsignedDecimal::from(rhs);
+                    let rhs = UnsignedDecimal::from_operand(rhs);
                     self.mul_assign(rhs);
                 }
             }
@@ -1,6 +1,6 @@
 use core::ops::{Rem, RemAssign};
 
-use crate::decimal::{UnsignedDecimal, Context};
+use crate::decimal::{utils::operand::FromOperand, UnsignedDecimal, Context};
 
 impl<const N: usize> Rem for UnsignedDecimal<N> {
     type Output = UnsignedDecimal<N>;
@@ -27,7 +27,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn rem(self, rhs: $ty) -> UnsignedDecimal<N> {
-                    let rhs = UnsignedDecimal::from(rhs);
+                    let rhs = UnsignedDecimal::from_operand(rhs);
                     Rem::<UnsignedDecimal<N>>::rem(self, rhs)
                 }
             }
@@ -35,7 +35,7 @@ macro_rules! macro_impl {
             impl<const N: usize> RemAssign<$ty> for UnsignedDecimal<N> {
                 #[inline]
                 fn rem_assign(&mut self, rhs: $ty) {
-                    let rhs = UnsignedDecimal::from(rhs);
+                    let rhs = UnsignedDecimal::from_operand(rhs);
                     self.rem_assign(rhs);
                 }
             }
@@ -1,6 +1,6 @@
 use core::ops::{Sub, SubAssign};
 
-use crate::decimal::{UnsignedDecimal, Context};
+use crate::decimal::{utils::operand::FromOperand, UnsignedDecimal, Context};
 
 impl<const N: usize> Sub for UnsignedDecimal<N> {
     type Output = UnsignedDecimal<N>;
@@ -27,7 +27,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn sub(self, rhs: $ty) -> UnsignedDecimal<N> {
-                    let rhs = UnsignedDecimal::from(rhs);
+                    let rhs = UnsignedDecimal::from_operand(rhs);
                     Sub::<UnsignedDecimal<N>>::sub(self, rhs)
                 }
             }
@@ -37,7 +37,7 @@ macro_rules! macro_impl {
 
                 #[inline]
                 fn sub(self, rhs: UnsignedDecimal<N>) -> UnsignedDecimal<N> {
-                    let this = UnsignedDecimal::from(self);
+                    let this = UnsignedDecimal::from_operand(self);
                     Sub::<UnsignedDecimal<N>>::sub(this, rhs)
                 }
             }
@@ -45,7 +45,7 @@ macro_rules! macro_impl {
             impl<const N: usize> SubAssign<$ty> for UnsignedDecimal<N> {
                 #[inline]
                 fn sub_assign(&mut self, rhs: $ty) {
-                    let rhs = UnsignedDecimal::from(rhs);
+                    let rhs = UnsignedDecimal::from_operand(rhs);
                     self.sub_assign(rhs);
                 }
             }
@@ -0,0 +1,340 @@
+//! # Unsigned Fixed-scale Decimal
+
+mod impls;
+
+use core::cmp::Ordering;
+
+use crate::{
+    decimal::{doc, Context, FixedDecimal, ParseError, UnsignedDecimal},
+    int::UInt,
+};
+
+/// # Unsigned Fixed-scale Decimal
+///
+/// Generic unsigned N-bits decimal number with the number of fractional
+/// digits fixed at compile time by the `SCALE` parameter.
+///
+/// See [FixedDecimal] for details.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct UnsignedFixedDecimal<const N: usize, const SCALE: i16>(FixedDecimal<N, SCALE>);
+
+macro_rules! consts_impl {
+    ($($name: ident $num: literal), *) => {
+        impl<const N: usize, const SCALE: i16> UnsignedFixedDecimal<N, SCALE> {
+            $(
+                #[doc = concat!("The value of `", $num, "` represented by this fixed-scale decimal type.")]
+                pub const $name: Self = Self(FixedDecimal::$name);
+            )*
+        }
+    };
+}
+
+consts_impl!(ZERO 0, ONE 1, TWO 2, THREE 3, FOUR 4, FIVE 5, SIX 6, SEVEN 7, EIGHT 8, NINE 9, TEN 10);
+
+impl<const N: usize, const SCALE: i16> UnsignedFixedDecimal<N, SCALE> {
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self(FixedDecimal::NAN);
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Self(FixedDecimal::INFINITY);
+
+    /// The smallest value that can be represented by this fixed-scale decimal type.
+    pub const MIN: Self = Self(FixedDecimal::ZERO);
+
+    /// The maximum value that this fixed-scale decimal type can represent.
+    pub const MAX: Self = Self(FixedDecimal::MAX);
+
+    /// The smallest positive value that this fixed-scale decimal type can represent - 10<sup>-SCALE</sup>.
+    pub const EPSILON: Self = Self(FixedDecimal::EPSILON);
+
+    /// Creates and initializes an unsigned fixed-scale decimal from string.
+    #[track_caller]
+    #[inline]
+    pub const fn from_str(s: &str) -> Result<Self, ParseError> {
+        match FixedDecimal::<N, SCALE>::from_str(s) {
+            Ok(d) => {
+                if d.is_negative() {
+                    Err(ParseError::Signed)
+                } else {
+                    Ok(Self::new(d))
+                }
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates and initializes an unsigned fixed-scale decimal from string.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `UnsignedFixedDecimal<N, SCALE>` cannot be
+    /// constructed from given string without loss of precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{UFD128, ufdec128};
+    ///
+    /// assert_eq!(UFD128::<4>::parse_str("1.2345"), ufdec128!(1.2345, 4));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub const fn parse_str(s: &str) -> Self {
+        match Self::from_str(s) {
+            Ok(n) => n,
+            Err(e) => panic!("{}", e.description()),
+        }
+    }
+
+    /// Converts the given [UnsignedDecimal] into an unsigned fixed-scale
+    /// decimal, rounding it to `SCALE` fractional digits if necessary.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn from_decimal(d: UnsignedDecimal<N>, ctx: Context) -> Self {
+        Self::new(FixedDecimal::from_decimal(d.to_signed(), ctx))
+    }
+
+    /// Converts the unsigned fixed-scale decimal into [UnsignedDecimal] with
+    /// the scale `SCALE`. This conversion is lossless.
+    #[inline]
+    pub const fn to_decimal(self) -> UnsignedDecimal<N> {
+        UnsignedDecimal::new(self.0.to_decimal())
+    }
+
+    /// Converts the unsigned fixed-scale decimal into signed
+    /// [FixedDecimal].
+    #[inline]
+    pub const fn to_signed(self) -> FixedDecimal<N, SCALE> {
+        self.0
+    }
+
+    /// Returns the internal big integer, representing the value of the
+    /// fixed-scale decimal multiplied by 10<sup>SCALE</sup>.
+    #[inline]
+    pub const fn digits(&self) -> UInt<N> {
+        self.0.digits()
+    }
+
+    /// Returns the scale of the fixed-scale decimal, which is always `SCALE`.
+    #[inline]
+    pub const fn fractional_digits_count(&self) -> i16 {
+        SCALE
+    }
+
+    #[inline]
+    pub const fn is_op_div_by_zero(&self) -> bool {
+        self.0.is_op_div_by_zero()
+    }
+
+    #[inline]
+    pub const fn is_op_invalid(&self) -> bool {
+        self.0.is_op_invalid()
+    }
+
+    #[inline]
+    pub const fn is_op_inexact(&self) -> bool {
+        self.0.is_op_inexact()
+    }
+
+    #[inline]
+    pub const fn is_op_rounded(&self) -> bool {
+        self.0.is_op_rounded()
+    }
+
+    #[inline]
+    pub const fn is_op_overflow(&self) -> bool {
+        self.0.is_op_overflow()
+    }
+
+    #[inline]
+    pub const fn is_op_ok(&self) -> bool {
+        self.0.is_op_ok()
+    }
+
+    /// Returns `true` if this number is neither infinite nor NaN.
+    #[inline]
+    pub const fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Returns `true` if this value is positive infinity, and `false`
+    /// otherwise.
+    #[inline]
+    pub const fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    /// Returns `true` if this value is NaN and `false` otherwise.
+    #[inline]
+    pub const fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// Returns `true` if the fixed-scale decimal is finite and has zero value.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Tests for `self` and `other` values to be equal, and is used by `==`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+
+    /// Tests for `self` and `other` values not to be equal, and is used by
+    /// `!=` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ne(&self, other: &Self) -> bool {
+        self.0.ne(&other.0)
+    }
+
+    /// Compares and returns the maximum of two unsigned fixed-scale decimal
+    /// values.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0))
+    }
+
+    /// Compares and returns the minimum of two unsigned fixed-scale decimal
+    /// values.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn min(self, other: Self) -> Self {
+        Self(self.0.min(other.0))
+    }
+
+    /// Tests `self` less than `other` and is used by the `<` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn lt(&self, other: &Self) -> bool {
+        self.0.lt(&other.0)
+    }
+
+    /// Tests `self` less than or equal to `other` and is used by the `<=`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn le(&self, other: &Self) -> bool {
+        self.0.le(&other.0)
+    }
+
+    /// Tests `self` greater than `other` and is used by the `>` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn gt(&self, other: &Self) -> bool {
+        self.0.gt(&other.0)
+    }
+
+    /// Tests `self` greater than or equal to `other` and is used by the `>=`
+    /// operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ge(&self, other: &Self) -> bool {
+        self.0.ge(&other.0)
+    }
+
+    /// This method returns an [Ordering] between `self` and `other`.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+
+    /// Calculates `self` + `rhs`.
+    ///
+    /// Is internally used by the `+` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn add(self, rhs: Self, ctx: Context) -> Self {
+        Self::new(self.0.add(rhs.0, ctx))
+    }
+
+    /// Calculates `self` - `rhs`.
+    ///
+    /// Is internally used by the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// use fastnum::ufdec128;
+    ///
+    /// let c = ufdec128!(1, 2) - ufdec128!(2, 2);
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sub(self, rhs: Self, ctx: Context) -> Self {
+        Self::from_signed(self.0.sub(rhs.0, ctx), ctx)
+    }
+
+    /// Calculates `self` × `rhs`, rounding the result to `SCALE` fractional
+    /// digits.
+    ///
+    /// Is internally used by the `*` operator.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn mul(self, rhs: Self, ctx: Context) -> Self {
+        Self::new(self.0.mul(rhs.0, ctx))
+    }
+
+    /// Calculates `self` ÷ `rhs`, rounding the result to `SCALE` fractional
+    /// digits.
+    ///
+    /// Is internally used by the `/` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{ufdec128, decimal::Context};
+    ///
+    /// let c = ufdec128!(2, 4).div(ufdec128!(3, 4), Context::default());
+    /// assert_eq!(c, ufdec128!(0.6667, 4));
+    /// ```
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn div(self, rhs: Self, ctx: Context) -> Self {
+        Self::new(self.0.div(rhs.0, ctx))
+    }
+
+    /// Returns `Some(self)` if no special value and no signals were raised
+    /// during computations, or `None` otherwise.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ok(self) -> Option<Self> {
+        match self.0.ok() {
+            Some(d) => Some(Self(d)),
+            None => None,
+        }
+    }
+}
+
+#[doc(hidden)]
+impl<const N: usize, const SCALE: i16> UnsignedFixedDecimal<N, SCALE> {
+    #[inline]
+    pub(crate) const fn new(dec: FixedDecimal<N, SCALE>) -> Self {
+        debug_assert!(!dec.is_negative());
+        Self(dec)
+    }
+
+    #[inline]
+    pub(crate) const fn from_signed(dec: FixedDecimal<N, SCALE>, _ctx: Context) -> Self {
+        if dec.is_negative() && !dec.is_zero() {
+            #[cfg(debug_assertions)]
+            panic!(crate::utils::err_msg!("operation has negative result for unsigned type"));
+            #[cfg(not(debug_assertions))]
+            Self::new(FixedDecimal::NAN.with_signals_from_and(&dec, crate::decimal::Signal::OP_INVALID))
+        } else {
+            Self::new(dec.abs())
+        }
+    }
+
+    #[inline]
+    pub(crate) fn type_name() -> String {
+        format!("UFD{}<{}>", N * 64, SCALE)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::decimal::UnsignedFixedDecimal;
+
+impl<const N: usize, const SCALE: i16> PartialEq for UnsignedFixedDecimal<N, SCALE> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.eq(other)
+    }
+
+    #[allow(clippy::partialeq_ne_impl)]
+    #[inline]
+    fn ne(&self, other: &Self) -> bool {
+        self.ne(other)
+    }
+}
+
+impl<const N: usize, const SCALE: i16> Eq for UnsignedFixedDecimal<N, SCALE> {}
@@ -0,0 +1,8 @@
+use crate::decimal::UnsignedFixedDecimal;
+
+impl<const N: usize, const SCALE: i16> Default for UnsignedFixedDecimal<N, SCALE> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
@@ -0,0 +1,38 @@
+use crate::decimal::{
+    FixedDecimal, ParseError, UnsignedDecimal, UnsignedFixedDecimal,
+};
+
+impl<const N: usize, const SCALE: i16> From<UnsignedFixedDecimal<N, SCALE>> for UnsignedDecimal<N> {
+    #[inline]
+    fn from(d: UnsignedFixedDecimal<N, SCALE>) -> Self {
+        d.to_decimal()
+    }
+}
+
+impl<const N: usize, const SCALE: i16> From<UnsignedFixedDecimal<N, SCALE>> for FixedDecimal<N, SCALE> {
+    #[inline]
+    fn from(d: UnsignedFixedDecimal<N, SCALE>) -> Self {
+        d.to_signed()
+    }
+}
+
+impl<const N: usize, const SCALE: i16> TryFrom<UnsignedDecimal<N>> for UnsignedFixedDecimal<N, SCALE> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(d: UnsignedDecimal<N>) -> Result<Self, Self::Error> {
+        Ok(Self::new(FixedDecimal::try_from(d.to_signed())?))
+    }
+}
+
+impl<const N: usize, const SCALE: i16> TryFrom<FixedDecimal<N, SCALE>> for UnsignedFixedDecimal<N, SCALE> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(d: FixedDecimal<N, SCALE>) -> Result<Self, Self::Error> {
+        if d.is_negative() {
+            return Err(ParseError::Signed);
+        }
+        Ok(Self::new(d))
+    }
+}
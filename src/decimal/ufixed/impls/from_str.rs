@@ -0,0 +1,12 @@
+use core::str::FromStr;
+
+use crate::decimal::{ParseError, UnsignedFixedDecimal};
+
+impl<const N: usize, const SCALE: i16> FromStr for UnsignedFixedDecimal<N, SCALE> {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        UnsignedFixedDecimal::from_str(s)
+    }
+}
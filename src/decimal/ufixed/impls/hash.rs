@@ -0,0 +1,10 @@
+use core::hash::{Hash, Hasher};
+
+use crate::decimal::UnsignedFixedDecimal;
+
+impl<const N: usize, const SCALE: i16> Hash for UnsignedFixedDecimal<N, SCALE> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
@@ -0,0 +1,33 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::decimal::{Context, UnsignedFixedDecimal};
+
+macro_rules! macro_impl {
+    ($($Op: ident $op: ident $OpAssign: ident $op_assign: ident), *) => {
+        $(
+            impl<const N: usize, const SCALE: i16> $Op for UnsignedFixedDecimal<N, SCALE> {
+                type Output = UnsignedFixedDecimal<N, SCALE>;
+
+                #[inline]
+                fn $op(self, rhs: Self) -> UnsignedFixedDecimal<N, SCALE> {
+                    self.$op(rhs, Context::default())
+                }
+            }
+
+            impl<const N: usize, const SCALE: i16> $OpAssign for UnsignedFixedDecimal<N, SCALE> {
+                #[inline]
+                fn $op_assign(&mut self, rhs: Self) {
+                    let res = $Op::<UnsignedFixedDecimal<N, SCALE>>::$op(*self, rhs);
+                    *self = res;
+                }
+            }
+        )*
+    };
+}
+
+macro_impl!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign
+);
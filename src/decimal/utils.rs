@@ -1,4 +1,5 @@
 pub(crate) mod types;
 
 #[cfg(not(feature = "numtraits"))]
-pub(crate) mod cast;
\ No newline at end of file
+pub(crate) mod cast;
+pub(crate) mod operand;
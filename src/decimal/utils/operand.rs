@@ -0,0 +1,50 @@
+use crate::decimal::{Decimal, UnsignedDecimal};
+
+/// Converts a primitive integer operand of an arithmetic operator into a
+/// decimal of any width.
+///
+/// `From<u128>` and `From<i128>` are only implemented for the decimal types
+/// wide enough to hold them, so here the operands that do not fit overflow
+/// the same way as the result of the operation itself.
+pub(crate) trait FromOperand<T> {
+    fn from_operand(n: T) -> Self;
+}
+
+macro_rules! from_operand_impl {
+    ($($ty: tt),*) => {
+        $(
+            impl<const N: usize> FromOperand<$ty> for Decimal<N> {
+                #[inline]
+                fn from_operand(n: $ty) -> Self {
+                    Self::from(n)
+                }
+            }
+        )*
+    };
+}
+
+from_operand_impl!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl<const N: usize> FromOperand<u128> for Decimal<N> {
+    #[inline]
+    fn from_operand(n: u128) -> Self {
+        Self::from_u128_with_scale(n, 0)
+    }
+}
+
+impl<const N: usize> FromOperand<i128> for Decimal<N> {
+    #[inline]
+    fn from_operand(n: i128) -> Self {
+        Self::from_i128_with_scale(n, 0)
+    }
+}
+
+impl<T, const N: usize> FromOperand<T> for UnsignedDecimal<N>
+where
+    Decimal<N>: FromOperand<T>,
+{
+    #[inline]
+    fn from_operand(n: T) -> Self {
+        Self::new(Decimal::from_operand(n))
+    }
+}
@@ -1,15 +1,20 @@
 //! # Big Integers
 //!
-//! Under the hood [bnum](https://docs.rs/bnum/latest/bnum/) is currently used as the backend as most meeting the
-//! requirements.
-//! Subsequently, the implementation can be replaced in favor of its own
-//! implementation, which enables `SIMD`.
+//! Fixed size unsigned [UInt] and signed [Int] integers stored as arrays of
+//! 64-bit digits. Conversions from and to [bnum](https://docs.rs/bnum/latest/bnum/)
+//! integers are available with the `bnum` feature.
+//!
+//! The [ct] module provides constant-time arithmetic for values that must not
+//! leak through timing.
 
 #[macro_use]
 mod macros;
 
+pub mod ct;
+
 mod doc;
 mod error;
+mod extras;
 #[allow(clippy::module_inception)]
 mod int;
 mod uint;
@@ -21,3 +26,6 @@ pub use int::*;
 pub use uint::*;
 
 pub use error::ParseError;
+
+#[cfg(feature = "serde")]
+pub use extras::serde;
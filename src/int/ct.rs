@@ -0,0 +1,370 @@
+//! # Constant-time arithmetic
+//!
+//! Functions on [`UInt`] whose running time and memory access pattern depend
+//! only on the width `N`, not on the values, for code handling secrets such
+//! as private keys and blinding factors.
+//!
+//! The regular methods of [`UInt`] are optimized for speed and take shortcuts
+//! depending on the values: additions stop propagating carries, divisions
+//! skip leading zero digits and comparisons return at the first differing
+//! digit. The functions of this module always run over all the digits and
+//! return conditions as a [`Mask`] instead of a `bool`, so that the caller
+//! can keep selecting results without branches.
+//!
+//! Decimal arithmetic normalizes and rounds depending on the values and is
+//! never constant-time.
+//!
+//! With the `subtle` feature [`UInt`] and [`Int`](crate::int::Int) implement
+//! the [`subtle`](https://docs.rs/subtle/latest/subtle/) traits
+//! `ConstantTimeEq`, `ConstantTimeGreater`, `ConstantTimeLess` and
+//! `ConditionallySelectable`, and [`Mask`] converts from and into
+//! `subtle::Choice`.
+//!
+//! The code is written branch-free, but the compiler is free to reintroduce
+//! branches. The `dudect` benchmark in the repository measures the functions
+//! on the target machine.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastnum::{int::ct, u256};
+//!
+//! let secret = u256!(12345);
+//! let modulus = u256!(1000000007);
+//!
+//! let product = ct::mod_mul(&secret, &secret, &modulus);
+//! assert_eq!(product, u256!(152399025));
+//!
+//! let is_less = ct::lt(&secret, &modulus);
+//! assert_eq!(ct::select(&u256!(0), &u256!(1), is_less), u256!(1));
+//! ```
+
+use crate::int::{mul, UInt};
+
+type Digit = u64;
+type DoubleDigit = u128;
+
+const DIGIT_BITS: u32 = Digit::BITS;
+
+/// Condition returned by the constant-time functions: a word of all ones for
+/// `true` or all zeros for `false`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Mask(Digit);
+
+impl Mask {
+    /// The `true` condition.
+    pub const TRUE: Self = Self(Digit::MAX);
+
+    /// The `false` condition.
+    pub const FALSE: Self = Self(0);
+
+    /// Creates the condition from a `bool`.
+    #[inline(always)]
+    pub const fn from_bool(value: bool) -> Self {
+        Self::from_bit(value as Digit)
+    }
+
+    /// Creates the condition from the lowest bit of `bit`, which must be
+    /// either `0` or `1`.
+    #[inline(always)]
+    pub const fn from_bit(bit: Digit) -> Self {
+        Self(bit.wrapping_neg())
+    }
+
+    /// Converts the condition into a `bool`.
+    ///
+    /// Branching on the result reveals the condition.
+    #[inline(always)]
+    pub const fn to_bool(self) -> bool {
+        self.0 != 0
+    }
+
+    /// Returns the condition as a word of all ones or all zeros.
+    #[inline(always)]
+    pub const fn to_word(self) -> Digit {
+        self.0
+    }
+
+    /// Logical negation.
+    #[inline(always)]
+    pub const fn not(self) -> Self {
+        Self(!self.0)
+    }
+
+    /// Logical conjunction.
+    #[inline(always)]
+    pub const fn and(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+
+    /// Logical disjunction.
+    #[inline(always)]
+    pub const fn or(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Returns `b` if `choice` is true and `a` otherwise.
+#[inline]
+pub const fn select<const N: usize>(a: &UInt<N>, b: &UInt<N>, choice: Mask) -> UInt<N> {
+    let mut out = *a;
+    select_digits(out.digits_mut(), b.digits(), choice);
+    out
+}
+
+/// Swaps `a` and `b` if `choice` is true.
+#[inline]
+pub const fn swap<const N: usize>(a: &mut UInt<N>, b: &mut UInt<N>, choice: Mask) {
+    let a = a.digits_mut();
+    let b = b.digits_mut();
+    let mut i = 0;
+
+    while i < N {
+        let t = (a[i] ^ b[i]) & choice.0;
+        a[i] ^= t;
+        b[i] ^= t;
+        i += 1;
+    }
+}
+
+/// Returns whether `a` is zero.
+#[inline]
+pub const fn is_zero<const N: usize>(a: &UInt<N>) -> Mask {
+    let a = a.digits();
+    let mut acc = 0;
+    let mut i = 0;
+
+    while i < N {
+        acc |= a[i];
+        i += 1;
+    }
+
+    nonzero(acc).not()
+}
+
+/// Returns whether `a == b`.
+#[inline]
+pub const fn eq<const N: usize>(a: &UInt<N>, b: &UInt<N>) -> Mask {
+    let a = a.digits();
+    let b = b.digits();
+    let mut acc = 0;
+    let mut i = 0;
+
+    while i < N {
+        acc |= a[i] ^ b[i];
+        i += 1;
+    }
+
+    nonzero(acc).not()
+}
+
+/// Returns whether `a < b`.
+#[inline]
+pub const fn lt<const N: usize>(a: &UInt<N>, b: &UInt<N>) -> Mask {
+    overflowing_sub(a, b).1
+}
+
+/// Returns whether `a > b`.
+#[inline]
+pub const fn gt<const N: usize>(a: &UInt<N>, b: &UInt<N>) -> Mask {
+    lt(b, a)
+}
+
+/// Returns whether `a <= b`.
+#[inline]
+pub const fn le<const N: usize>(a: &UInt<N>, b: &UInt<N>) -> Mask {
+    lt(b, a).not()
+}
+
+/// Returns whether `a >= b`.
+#[inline]
+pub const fn ge<const N: usize>(a: &UInt<N>, b: &UInt<N>) -> Mask {
+    lt(a, b).not()
+}
+
+/// Calculates `a + b` and returns the wrapped sum along with the carry out.
+#[inline]
+pub const fn overflowing_add<const N: usize>(a: &UInt<N>, b: &UInt<N>) -> (UInt<N>, Mask) {
+    let mut out = *a;
+    let carry = add_digits(out.digits_mut(), b.digits(), Mask::TRUE);
+    (out, Mask::from_bit(carry))
+}
+
+/// Calculates `a - b` and returns the wrapped difference along with the
+/// borrow.
+#[inline]
+pub const fn overflowing_sub<const N: usize>(a: &UInt<N>, b: &UInt<N>) -> (UInt<N>, Mask) {
+    let mut out = *a;
+    let borrow = sub_digits(out.digits_mut(), b.digits(), Mask::TRUE);
+    (out, Mask::from_bit(borrow))
+}
+
+/// Calculates the complete product `a * b` and returns its low and high
+/// halves.
+#[inline]
+pub const fn widening_mul<const N: usize>(a: &UInt<N>, b: &UInt<N>) -> (UInt<N>, UInt<N>) {
+    // Schoolbook multiplication by all the digits, with no skipping of the
+    // leading zero ones.
+    mul::schoolbook_widening_mul(a.digits(), b.digits(), N)
+}
+
+/// Calculates `a * b` and returns the low half of the product.
+#[inline]
+pub const fn wrapping_mul<const N: usize>(a: &UInt<N>, b: &UInt<N>) -> UInt<N> {
+    widening_mul(a, b).0
+}
+
+/// Calculates `(a + b) mod modulus` for `a, b < modulus`.
+#[inline]
+pub const fn mod_add<const N: usize>(a: &UInt<N>, b: &UInt<N>, modulus: &UInt<N>) -> UInt<N> {
+    let (sum, carry) = overflowing_add(a, b);
+    let (diff, borrow) = overflowing_sub(&sum, modulus);
+
+    // The sum is reduced if it overflowed or is not less than the modulus.
+    select(&sum, &diff, carry.or(borrow.not()))
+}
+
+/// Calculates `(a - b) mod modulus` for `a, b < modulus`.
+#[inline]
+pub const fn mod_sub<const N: usize>(a: &UInt<N>, b: &UInt<N>, modulus: &UInt<N>) -> UInt<N> {
+    let (mut diff, borrow) = overflowing_sub(a, b);
+    add_digits(diff.digits_mut(), modulus.digits(), borrow);
+    diff
+}
+
+/// Calculates `a mod modulus` for a nonzero `modulus`.
+#[inline]
+pub const fn rem<const N: usize>(a: &UInt<N>, modulus: &UInt<N>) -> UInt<N> {
+    let mut r = UInt::ZERO;
+    reduce_into(&mut r, a, modulus);
+    r
+}
+
+/// Calculates `(high:low) mod modulus` of the double-width integer for a
+/// nonzero `modulus`.
+#[inline]
+pub const fn rem_wide<const N: usize>(low: &UInt<N>, high: &UInt<N>, modulus: &UInt<N>) -> UInt<N> {
+    let mut r = UInt::ZERO;
+    reduce_into(&mut r, high, modulus);
+    reduce_into(&mut r, low, modulus);
+    r
+}
+
+/// Calculates `(a * b) mod modulus` for a nonzero `modulus`.
+#[inline]
+pub const fn mod_mul<const N: usize>(a: &UInt<N>, b: &UInt<N>, modulus: &UInt<N>) -> UInt<N> {
+    let (low, high) = widening_mul(a, b);
+    rem_wide(&low, &high, modulus)
+}
+
+/// Shifts the bits of `value` into the remainder `r < modulus` from the most
+/// significant one, reducing after every bit.
+///
+/// This is the binary long division with the quotient bits dropped. Every
+/// step shifts, subtracts and selects all the digits regardless of the
+/// values, at the cost of `BITS` steps instead of a digit-wise division.
+#[inline]
+const fn reduce_into<const N: usize>(r: &mut UInt<N>, value: &UInt<N>, modulus: &UInt<N>) {
+    let value = value.digits();
+    let modulus = modulus.digits();
+    let r = r.digits_mut();
+
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+
+        let mut bit = DIGIT_BITS;
+        while bit > 0 {
+            bit -= 1;
+
+            // `r < modulus`, so the shifted remainder is less than `2 *
+            // modulus` and fits into `N` digits and the bit shifted out.
+            let mut top = (value[i] >> bit) & 1;
+            let mut j = 0;
+            while j < N {
+                let next = r[j] >> (DIGIT_BITS - 1);
+                r[j] = (r[j] << 1) | top;
+                top = next;
+                j += 1;
+            }
+
+            let mut diff = *r;
+            let borrow = sub_digits(&mut diff, modulus, Mask::TRUE);
+
+            // Subtract if the shifted out bit is set or there is no borrow.
+            select_digits(r, &diff, Mask::from_bit(top | (borrow ^ 1)));
+        }
+    }
+}
+
+/// Adds `rhs & mask` to `lhs` in place and returns the carry.
+#[inline(always)]
+const fn add_digits<const N: usize>(lhs: &mut [Digit; N], rhs: &[Digit; N], mask: Mask) -> Digit {
+    let mut carry = 0;
+    let mut i = 0;
+
+    while i < N {
+        let s = lhs[i] as DoubleDigit + (rhs[i] & mask.0) as DoubleDigit + carry;
+        lhs[i] = s as Digit;
+        carry = s >> DIGIT_BITS;
+        i += 1;
+    }
+
+    carry as Digit
+}
+
+/// Subtracts `rhs & mask` from `lhs` in place and returns the borrow.
+#[inline(always)]
+const fn sub_digits<const N: usize>(lhs: &mut [Digit; N], rhs: &[Digit; N], mask: Mask) -> Digit {
+    let mut borrow = 0;
+    let mut i = 0;
+
+    while i < N {
+        let d = (lhs[i] as DoubleDigit)
+            .wrapping_sub((rhs[i] & mask.0) as DoubleDigit)
+            .wrapping_sub(borrow);
+        lhs[i] = d as Digit;
+        borrow = (d >> (2 * DIGIT_BITS - 1)) as DoubleDigit;
+        i += 1;
+    }
+
+    borrow as Digit
+}
+
+/// Overwrites `lhs` by `rhs` if `choice` is true.
+#[inline(always)]
+const fn select_digits<const N: usize>(lhs: &mut [Digit; N], rhs: &[Digit; N], choice: Mask) {
+    let mut i = 0;
+
+    while i < N {
+        lhs[i] ^= (lhs[i] ^ rhs[i]) & choice.0;
+        i += 1;
+    }
+}
+
+/// Returns whether `word` is nonzero.
+#[inline(always)]
+const fn nonzero(word: Digit) -> Mask {
+    Mask::from_bit((word | word.wrapping_neg()) >> (DIGIT_BITS - 1))
+}
+
+#[cfg(feature = "subtle")]
+mod subtle_impl {
+    use subtle::Choice;
+
+    use super::Mask;
+
+    impl From<Mask> for Choice {
+        #[inline]
+        fn from(mask: Mask) -> Self {
+            Choice::from((mask.0 & 1) as u8)
+        }
+    }
+
+    impl From<Choice> for Mask {
+        #[inline]
+        fn from(choice: Choice) -> Self {
+            Mask::from_bit(choice.unwrap_u8() as u64)
+        }
+    }
+}
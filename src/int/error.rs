@@ -88,6 +88,7 @@ impl From<ParseIntError> for ParseError {
     }
 }
 
+#[cfg(feature = "bnum")]
 impl From<bnum::errors::ParseIntError> for ParseError {
     fn from(e: bnum::errors::ParseIntError) -> Self {
         from_int_error_kind(e.kind())
@@ -0,0 +1,5 @@
+#[cfg(feature = "bnum")]
+mod bnum;
+
+#[cfg(feature = "serde")]
+pub mod serde;
@@ -0,0 +1,31 @@
+use bnum::{BInt, BUint};
+
+use crate::int::{Int, UInt};
+
+impl<const N: usize> From<BUint<N>> for UInt<N> {
+    #[inline]
+    fn from(n: BUint<N>) -> Self {
+        Self::from_digits(*n.digits())
+    }
+}
+
+impl<const N: usize> From<UInt<N>> for BUint<N> {
+    #[inline]
+    fn from(n: UInt<N>) -> Self {
+        Self::from_digits(*n.digits())
+    }
+}
+
+impl<const N: usize> From<BInt<N>> for Int<N> {
+    #[inline]
+    fn from(n: BInt<N>) -> Self {
+        Self::from_bits(n.to_bits().into())
+    }
+}
+
+impl<const N: usize> From<Int<N>> for BInt<N> {
+    #[inline]
+    fn from(n: Int<N>) -> Self {
+        Self::from_bits(n.to_bits().into())
+    }
+}
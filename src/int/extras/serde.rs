@@ -0,0 +1,299 @@
+//! Serialization and deserialization of big integers.
+//!
+//! [UInt] and [Int] are serialized in the same layout as `bnum` integers: a
+//! `BUint` struct holding the little-endian `digits` as a tuple of `u64`, and
+//! a `BInt` struct holding its two's complement `bits` as a `BUint`. Values
+//! serialized with earlier versions of `fastnum` can be read back.
+//!
+//! In self-describing human-readable formats such as JSON, integers and
+//! decimal strings are accepted on deserialization too.
+//!
+//! To serialize big integers as decimal strings, opt in with the [string]
+//! module.
+
+use core::{fmt, marker::PhantomData, str::FromStr};
+
+use serde::{
+    de,
+    ser::{self, SerializeStruct, SerializeTuple},
+};
+
+use crate::int::{Int, UInt};
+
+/// Serializes and deserializes big integers as decimal strings.
+///
+/// Use with `#[serde(with = "fastnum::int::serde::string")]`.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{u256, U256};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Balance {
+///     #[serde(with = "fastnum::int::serde::string")]
+///     amount: U256,
+/// }
+///
+/// let balance = Balance { amount: u256!(12345678901234567890123) };
+/// let json = serde_json::to_string(&balance).unwrap();
+///
+/// assert_eq!(json, r#"{"amount":"12345678901234567890123"}"#);
+/// assert_eq!(serde_json::from_str::<Balance>(&json).unwrap(), balance);
+/// ```
+pub mod string {
+    use super::*;
+
+    /// Serializes `value` as a decimal string.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: ser::Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes a value from a decimal string.
+    pub fn deserialize<'de, T, D>(d: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+        D: de::Deserializer<'de>,
+    {
+        struct Visitor<T>(PhantomData<T>);
+
+        impl<T> de::Visitor<'_> for Visitor<T>
+        where
+            T: FromStr,
+            T::Err: fmt::Display,
+        {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a formatted integer string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        d.deserialize_str(Visitor(PhantomData))
+    }
+}
+
+/// Little-endian digits, serialized as a tuple like `bnum` does.
+struct Digits<const N: usize>([u64; N]);
+
+impl<const N: usize> ser::Serialize for Digits<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for digit in &self.0 {
+            tuple.serialize_element(digit)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, const N: usize> de::Deserialize<'de> for Digits<N> {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct Visitor<const N: usize>;
+
+        impl<'de, const N: usize> de::Visitor<'de> for Visitor<N> {
+            type Value = Digits<N>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "an array of length {N}")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let mut digits = [0; N];
+                for (i, digit) in digits.iter_mut().enumerate() {
+                    *digit = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok(Digits(digits))
+            }
+        }
+
+        d.deserialize_tuple(N, Visitor::<N>)
+    }
+}
+
+/// The single field name of a `bnum` integer struct.
+struct Field<const INT: bool>;
+
+impl<const INT: bool> Field<INT> {
+    const NAME: &'static str = if INT { "bits" } else { "digits" };
+}
+
+impl<'de, const INT: bool> de::Deserialize<'de> for Field<INT> {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct Visitor<const INT: bool>;
+
+        impl<const INT: bool> de::Visitor<'_> for Visitor<INT> {
+            type Value = Field<INT>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "`{}`", Field::<INT>::NAME)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                if value == Field::<INT>::NAME {
+                    Ok(Field)
+                } else {
+                    Err(E::unknown_field(value, &[Field::<INT>::NAME]))
+                }
+            }
+        }
+
+        d.deserialize_identifier(Visitor::<INT>)
+    }
+}
+
+impl<const N: usize> ser::Serialize for UInt<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let mut state = serializer.serialize_struct("BUint", 1)?;
+        state.serialize_field("digits", &Digits(*self.digits()))?;
+        state.end()
+    }
+}
+
+impl<const N: usize> ser::Serialize for Int<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let mut state = serializer.serialize_struct("BInt", 1)?;
+        state.serialize_field("bits", &self.to_bits())?;
+        state.end()
+    }
+}
+
+macro_rules! deserialize_impl {
+    ($Ty: ident, $name: literal, $INT: literal, $Field: ty, $from: expr, $expecting: literal) => {
+        impl<'de, const N: usize> de::Deserialize<'de> for $Ty<N> {
+            fn deserialize<D>(d: D) -> Result<Self, D::Error>
+            where
+                D: de::Deserializer<'de>,
+            {
+                struct Visitor<const N: usize>;
+
+                impl<'de, const N: usize> de::Visitor<'de> for Visitor<N> {
+                    type Value = $Ty<N>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        write!(formatter, $expecting)
+                    }
+
+                    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: de::SeqAccess<'de>,
+                    {
+                        let field: $Field = seq
+                            .next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                        Ok($from(field))
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: de::MapAccess<'de>,
+                    {
+                        let mut value = None;
+                        while map.next_key::<Field<$INT>>()?.is_some() {
+                            if value.is_some() {
+                                return Err(de::Error::duplicate_field(Field::<$INT>::NAME));
+                            }
+                            value = Some(map.next_value::<$Field>()?);
+                        }
+                        value
+                            .map($from)
+                            .ok_or_else(|| de::Error::missing_field(Field::<$INT>::NAME))
+                    }
+
+                    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        $Ty::<N>::try_from(value).map_err(E::custom)
+                    }
+
+                    fn visit_i128<E>(self, value: i128) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        $Ty::<N>::try_from(value).map_err(E::custom)
+                    }
+
+                    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        $Ty::<N>::try_from(value).map_err(E::custom)
+                    }
+
+                    fn visit_u128<E>(self, value: u128) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        $Ty::<N>::try_from(value).map_err(E::custom)
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        $Ty::<N>::from_str_radix(value, 10).map_err(E::custom)
+                    }
+                }
+
+                if d.is_human_readable() {
+                    d.deserialize_any(Visitor::<N>)
+                } else {
+                    d.deserialize_struct($name, &[Field::<$INT>::NAME], Visitor::<N>)
+                }
+            }
+        }
+    };
+}
+
+deserialize_impl!(
+    UInt,
+    "BUint",
+    false,
+    Digits<N>,
+    |digits: Digits<N>| UInt::from_digits(digits.0),
+    "a `BUint` struct, an unsigned integer or formatted integer string"
+);
+deserialize_impl!(
+    Int,
+    "BInt",
+    true,
+    UInt<N>,
+    Int::from_bits,
+    "a `BInt` struct, an integer or formatted integer string"
+);
@@ -1,8 +1,1204 @@
+mod impls;
+
+use core::cmp::Ordering;
+
+use crate::{
+    int::{
+        doc::int_type_doc,
+        format,
+        from_str::{from_digits_radix, from_literal},
+        ParseError, UInt,
+    },
+    utils::err_msg,
+};
+
+type Digit = u64;
+
 /// Big signed integer generic type, of fixed size which must be known at
 /// compile time.
-pub type Int<const N: usize> = bnum::BInt<N>;
+///
+/// The value is stored in two's complement as the bits of a [`UInt`] of the
+/// same size, so that additions, subtractions, multiplications and bitwise
+/// operations share the unsigned implementation.
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Int<const N: usize> {
+    bits: UInt<N>,
+}
+
+macro_rules! consts {
+    ($($name: ident = $value: literal;)*) => {
+        $(
+            #[doc = concat!("The value of `", $value, "`.")]
+            pub const $name: Self = Self::from_bits(UInt::$name);
+        )*
+    };
+}
+
+impl<const N: usize> Int<N> {
+    /// The size of this integer type in bits.
+    pub const BITS: u32 = UInt::<N>::BITS;
+
+    /// The smallest value that can be represented by this integer type,
+    /// `-2^(BITS - 1)`.
+    pub const MIN: Self = Self::from_bits(UInt::ONE.wrapping_shl(Self::BITS - 1));
+
+    /// The largest value that can be represented by this integer type,
+    /// `2^(BITS - 1) - 1`.
+    pub const MAX: Self = Self::from_bits(UInt::MAX.wrapping_shr(1));
+
+    /// The value of `-1`.
+    pub const NEG_ONE: Self = Self::from_bits(UInt::MAX);
+
+    /// The maximum length of the string representation in any radix, which
+    /// is the length of [`Self::MIN`] in binary.
+    pub const MAX_STR_LEN: usize = Self::BITS as usize + 1;
+
+    consts! {
+        ZERO = 0;
+        ONE = 1;
+        TWO = 2;
+        THREE = 3;
+        FOUR = 4;
+        FIVE = 5;
+        SIX = 6;
+        SEVEN = 7;
+        EIGHT = 8;
+        NINE = 9;
+        TEN = 10;
+    }
+
+    /// Creates an integer from its two's complement bits.
+    #[inline(always)]
+    pub const fn from_bits(bits: UInt<N>) -> Self {
+        Self { bits }
+    }
+
+    /// Returns the two's complement bits of the integer.
+    #[inline(always)]
+    pub const fn to_bits(self) -> UInt<N> {
+        self.bits
+    }
+
+    /// Creates an integer from the little-endian 64-bit digits of its two's
+    /// complement representation.
+    #[inline(always)]
+    pub const fn from_digits(digits: [Digit; N]) -> Self {
+        Self::from_bits(UInt::from_digits(digits))
+    }
+
+    /// Returns the little-endian 64-bit digits of the two's complement
+    /// representation of the integer.
+    #[inline(always)]
+    pub const fn digits(&self) -> &[Digit; N] {
+        self.bits.digits()
+    }
+
+    /// Creates an integer from `i64`.
+    #[inline]
+    pub const fn from_i64(n: i64) -> Self {
+        let fill = if n < 0 { Digit::MAX } else { 0 };
+        let mut digits = [fill; N];
+        digits[0] = n as Digit;
+
+        Self::from_digits(digits)
+    }
+
+    /// Reinterprets the integer as an unsigned one of the same size.
+    #[inline(always)]
+    pub const fn cast_unsigned(self) -> UInt<N> {
+        self.bits
+    }
+
+    /// Parses an integer from a string in the given `radix`.
+    ///
+    /// The string may start with a `+` or `-` sign. Digits above `9` are
+    /// `a`-`z` or `A`-`Z`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::I256;
+    ///
+    /// assert_eq!(I256::from_str_radix("-ff", 16), Ok(I256::from_i64(-255)));
+    /// assert!(I256::from_str_radix("", 10).is_err());
+    /// ```
+    #[inline]
+    pub const fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        let (negative, digits) = match s.as_bytes() {
+            [] => return Err(ParseError::Empty),
+            [b'-', digits @ ..] => (true, digits),
+            [b'+', digits @ ..] => (false, digits),
+            digits => (false, digits),
+        };
+
+        Self::from_magnitude(negative, from_digits_radix(digits, radix))
+    }
+
+    /// Parses an integer literal of the [`i256!`](crate::i256)-style macros.
+    #[inline]
+    pub(crate) const fn from_literal(s: &str) -> Result<Self, ParseError> {
+        let (negative, digits) = match s.as_bytes() {
+            [] => return Err(ParseError::Empty),
+            [b'-', digits @ ..] => (true, digits),
+            [b'+', digits @ ..] => (false, digits),
+            digits => (false, digits),
+        };
+
+        Self::from_magnitude(negative, from_literal(digits))
+    }
+
+    /// Applies the sign to the parsed magnitude.
+    #[inline]
+    const fn from_magnitude(
+        negative: bool,
+        magnitude: Result<UInt<N>, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let magnitude = match magnitude {
+            Ok(magnitude) => magnitude,
+            Err(ParseError::PosOverflow) if negative => return Err(ParseError::NegOverflow),
+            Err(e) => return Err(e),
+        };
+
+        if negative {
+            if magnitude.gt(&Self::MIN.bits) {
+                Err(ParseError::NegOverflow)
+            } else {
+                Ok(Self::from_bits(magnitude).wrapping_neg())
+            }
+        } else if magnitude.gt(&Self::MAX.bits) {
+            Err(ParseError::PosOverflow)
+        } else {
+            Ok(Self::from_bits(magnitude))
+        }
+    }
+
+    /// Parses a decimal integer from a string.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the string is not a valid integer.
+    #[track_caller]
+    #[inline]
+    pub const fn parse_str(s: &str) -> Self {
+        match Self::from_str_radix(s, 10) {
+            Ok(n) => n,
+            Err(e) => panic!("{}", e.description()),
+        }
+    }
+
+    /// Returns the string representation of the integer in the given
+    /// `radix`, with a leading `-` for negative values. Digits above `9` are
+    /// lowercase letters.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::I256;
+    ///
+    /// assert_eq!(I256::from_i64(-255).to_str_radix(16), "-ff");
+    /// ```
+    pub fn to_str_radix(&self, radix: u32) -> alloc::string::String {
+        let digits = self.unsigned_abs().to_str_radix(radix);
+
+        if self.is_negative() {
+            alloc::format!("-{digits}")
+        } else {
+            digits
+        }
+    }
+
+    /// Writes the string representation of the integer in the given `radix`
+    /// into `buf` and returns the written part of `buf` as a string slice.
+    /// Negative values have a leading `-` and digits above `9` are lowercase
+    /// letters.
+    ///
+    /// The output is the same as of [`Self::to_str_radix`], but no memory is
+    /// allocated, so it can be used in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36 or
+    /// `buf` is too short to hold the string. A buffer of
+    /// [`Self::MAX_STR_LEN`] bytes is always sufficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{i256, I256};
+    ///
+    /// let mut buf = [0; I256::MAX_STR_LEN];
+    ///
+    /// assert_eq!(i256!(-0xff).to_str_radix_buf(16, &mut buf), "-ff");
+    /// assert_eq!(i256!(5).to_str_radix_buf(2, &mut buf), "101");
+    /// ```
+    #[inline]
+    pub const fn to_str_radix_buf<'a>(&self, radix: u32, buf: &'a mut [u8]) -> &'a str {
+        let sign = if self.is_negative() {
+            buf[0] = b'-';
+            1
+        } else {
+            0
+        };
+
+        let len = format::write_radix(self.unsigned_abs(), radix, false, buf.split_at_mut(sign).1);
+        let buf: &'a [u8] = buf;
+        format::as_str(buf.split_at(sign + len).0)
+    }
+
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.bits.is_zero()
+    }
+
+    #[inline]
+    pub const fn is_one(&self) -> bool {
+        self.bits.is_one()
+    }
+
+    /// Returns `true` if `self` is less than zero.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        (self.bits.digits()[N - 1] as i64) < 0
+    }
+
+    /// Returns `true` if `self` is greater than zero.
+    #[inline]
+    pub const fn is_positive(&self) -> bool {
+        !self.is_negative() && !self.is_zero()
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of `self`.
+    #[inline]
+    pub const fn signum(self) -> Self {
+        if self.is_negative() {
+            Self::NEG_ONE
+        } else if self.is_zero() {
+            Self::ZERO
+        } else {
+            Self::ONE
+        }
+    }
+
+    /// Returns the absolute value of `self` as an unsigned integer, which
+    /// never overflows.
+    #[inline]
+    pub const fn unsigned_abs(self) -> UInt<N> {
+        if self.is_negative() {
+            self.wrapping_neg().bits
+        } else {
+            self.bits
+        }
+    }
+
+    /// Calculates the absolute value of `self` and returns it along with a
+    /// boolean indicating whether an overflow would occur, which is only the
+    /// case for [`Self::MIN`].
+    #[inline]
+    pub const fn overflowing_abs(self) -> (Self, bool) {
+        if self.is_negative() {
+            self.overflowing_neg()
+        } else {
+            (self, false)
+        }
+    }
+
+    #[inline]
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.overflowing_abs() {
+            (abs, false) => Some(abs),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_abs(self) -> Self {
+        self.overflowing_abs().0
+    }
+
+    #[inline]
+    pub const fn saturating_abs(self) -> Self {
+        match self.checked_abs() {
+            Some(abs) => abs,
+            None => Self::MAX,
+        }
+    }
+
+    /// Calculates the absolute value of `self`, panicking on overflow.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_abs(self) -> Self {
+        match self.checked_abs() {
+            Some(abs) => abs,
+            None => panic!(err_msg!("attempt to negate with overflow")),
+        }
+    }
+
+    /// Calculates the absolute value of `self`.
+    ///
+    /// Same as for primitive integers, overflow panics in debug builds and
+    /// returns [`Self::MIN`] otherwise.
+    #[track_caller]
+    #[inline]
+    pub const fn abs(self) -> Self {
+        let (abs, overflow) = self.overflowing_abs();
+        debug_assert!(!overflow, err_msg!("attempt to negate with overflow"));
+        abs
+    }
+
+    /// Negates `self` and returns the result along with a boolean indicating
+    /// whether an overflow would occur, which is only the case for
+    /// [`Self::MIN`].
+    #[inline]
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        let neg = Self::from_bits(self.bits.not().wrapping_add(UInt::ONE));
+        (neg, self.eq(&Self::MIN))
+    }
+
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.overflowing_neg() {
+            (neg, false) => Some(neg),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_neg(self) -> Self {
+        self.overflowing_neg().0
+    }
+
+    #[inline]
+    pub const fn saturating_neg(self) -> Self {
+        match self.checked_neg() {
+            Some(neg) => neg,
+            None => Self::MAX,
+        }
+    }
+
+    /// Negates `self`, panicking on overflow.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_neg(self) -> Self {
+        match self.checked_neg() {
+            Some(neg) => neg,
+            None => panic!(err_msg!("attempt to negate with overflow")),
+        }
+    }
+
+    #[inline]
+    pub const fn count_ones(self) -> u32 {
+        self.bits.count_ones()
+    }
+
+    #[inline]
+    pub const fn count_zeros(self) -> u32 {
+        self.bits.count_zeros()
+    }
+
+    #[inline]
+    pub const fn leading_zeros(self) -> u32 {
+        self.bits.leading_zeros()
+    }
+
+    #[inline]
+    pub const fn trailing_zeros(self) -> u32 {
+        self.bits.trailing_zeros()
+    }
+
+    #[inline]
+    pub const fn leading_ones(self) -> u32 {
+        self.bits.leading_ones()
+    }
+
+    #[inline]
+    pub const fn trailing_ones(self) -> u32 {
+        self.bits.trailing_ones()
+    }
+
+    #[inline]
+    pub const fn not(self) -> Self {
+        Self::from_bits(self.bits.not())
+    }
+
+    #[inline]
+    pub const fn bitand(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits.bitand(rhs.bits))
+    }
+
+    #[inline]
+    pub const fn bitor(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits.bitor(rhs.bits))
+    }
+
+    #[inline]
+    pub const fn bitxor(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits.bitxor(rhs.bits))
+    }
+
+    #[inline]
+    pub const fn reverse_bits(self) -> Self {
+        Self::from_bits(self.bits.reverse_bits())
+    }
+
+    #[inline]
+    pub const fn rotate_left(self, n: u32) -> Self {
+        Self::from_bits(self.bits.rotate_left(n))
+    }
+
+    #[inline]
+    pub const fn rotate_right(self, n: u32) -> Self {
+        Self::from_bits(self.bits.rotate_right(n))
+    }
+
+    #[inline]
+    pub const fn eq(&self, other: &Self) -> bool {
+        self.bits.eq(&other.bits)
+    }
+
+    #[inline]
+    pub const fn ne(&self, other: &Self) -> bool {
+        !self.eq(other)
+    }
+
+    #[inline]
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // Two's complement bits of the same sign compare as unsigned.
+            _ => self.bits.cmp(&other.bits),
+        }
+    }
+
+    #[inline]
+    pub const fn lt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Less)
+    }
+
+    #[inline]
+    pub const fn le(&self, other: &Self) -> bool {
+        !matches!(self.cmp(other), Ordering::Greater)
+    }
+
+    #[inline]
+    pub const fn gt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Greater)
+    }
+
+    #[inline]
+    pub const fn ge(&self, other: &Self) -> bool {
+        !matches!(self.cmp(other), Ordering::Less)
+    }
+
+    #[inline]
+    pub const fn max(self, other: Self) -> Self {
+        if self.ge(&other) {
+            self
+        } else {
+            other
+        }
+    }
+
+    #[inline]
+    pub const fn min(self, other: Self) -> Self {
+        if self.le(&other) {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restricts `self` to the interval `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `min > max`.
+    #[track_caller]
+    #[inline]
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min.le(&max), err_msg!("clamp requires `min <= max`"));
+        self.max(min).min(max)
+    }
+
+    /// Returns the absolute difference between `self` and `other`, which
+    /// never overflows as an unsigned integer.
+    #[inline]
+    pub const fn abs_diff(self, other: Self) -> UInt<N> {
+        if self.lt(&other) {
+            other.bits.wrapping_sub(self.bits)
+        } else {
+            self.bits.wrapping_sub(other.bits)
+        }
+    }
+
+    /// Calculates `self + rhs` and returns the sum along with a boolean
+    /// indicating whether an arithmetic overflow would occur. If an overflow
+    /// would have occurred then the wrapped value is returned.
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let sum = Self::from_bits(self.bits.wrapping_add(rhs.bits));
 
-use crate::int::doc::int_type_doc;
+        // Overflow flips the sign of the sum of two values of the same sign.
+        let overflow =
+            self.is_negative() == rhs.is_negative() && sum.is_negative() != self.is_negative();
+        (sum, overflow)
+    }
+
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(sum) => sum,
+            None if rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Calculates `self + rhs`, panicking on overflow.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(sum) => sum,
+            None => panic!(err_msg!("attempt to add with overflow")),
+        }
+    }
+
+    /// Calculates `self - rhs` and returns the difference along with a
+    /// boolean indicating whether an arithmetic overflow would occur. If an
+    /// overflow would have occurred then the wrapped value is returned.
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let diff = Self::from_bits(self.bits.wrapping_sub(rhs.bits));
+
+        // Overflow flips the sign of the difference of values of distinct
+        // signs.
+        let overflow =
+            self.is_negative() != rhs.is_negative() && diff.is_negative() != self.is_negative();
+        (diff, overflow)
+    }
+
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (diff, false) => Some(diff),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(diff) => diff,
+            None if rhs.is_negative() => Self::MAX,
+            None => Self::MIN,
+        }
+    }
+
+    /// Calculates `self - rhs`, panicking on overflow.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(diff) => diff,
+            None => panic!(err_msg!("attempt to subtract with overflow")),
+        }
+    }
+
+    /// Calculates `self * rhs` and returns the product along with a boolean
+    /// indicating whether an arithmetic overflow would occur. If an overflow
+    /// would have occurred then the wrapped value is returned.
+    #[inline]
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let negative = self.is_negative() != rhs.is_negative();
+        let (magnitude, overflow) = self.unsigned_abs().overflowing_mul(rhs.unsigned_abs());
+
+        // The wrapped product is the same in two's complement.
+        let product = Self::from_bits(self.bits.wrapping_mul(rhs.bits));
+        (product, overflow || !fits(magnitude, negative))
+    }
+
+    #[inline]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (product, false) => Some(product),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits.wrapping_mul(rhs.bits))
+    }
+
+    #[inline]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None if self.is_negative() != rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Calculates `self * rhs`, panicking on overflow.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None => panic!(err_msg!("attempt to multiply with overflow")),
+        }
+    }
+
+    /// Calculates the quotient and the remainder of `self / rhs` rounded
+    /// towards zero and returns them along with a boolean indicating whether
+    /// an arithmetic overflow would occur, which is only the case for
+    /// `MIN / -1`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn overflowing_div_rem(self, rhs: Self) -> ((Self, Self), bool) {
+        let (quotient, remainder) = self.unsigned_abs().div_rem(rhs.unsigned_abs());
+
+        let mut quotient = Self::from_bits(quotient);
+        let mut remainder = Self::from_bits(remainder);
+
+        if self.is_negative() != rhs.is_negative() {
+            quotient = quotient.wrapping_neg();
+        }
+
+        if self.is_negative() {
+            remainder = remainder.wrapping_neg();
+        }
+
+        let overflow = self.eq(&Self::MIN) && rhs.eq(&Self::NEG_ONE);
+        ((quotient, remainder), overflow)
+    }
+
+    /// Calculates the quotient and the remainder of `self / rhs` rounded
+    /// towards zero.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero or the division overflows.
+    #[track_caller]
+    #[inline]
+    pub const fn div_rem(self, rhs: Self) -> (Self, Self) {
+        match self.overflowing_div_rem(rhs) {
+            (div_rem, false) => div_rem,
+            (_, true) => panic!(err_msg!("attempt to divide with overflow")),
+        }
+    }
+
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        match self.overflowing_div_rem(rhs) {
+            ((quotient, _), false) => Some(quotient),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        match self.overflowing_div_rem(rhs) {
+            ((_, remainder), false) => Some(remainder),
+            (_, true) => None,
+        }
+    }
+
+    /// Calculates `self / rhs` and returns the quotient along with a boolean
+    /// indicating whether an overflow would occur. On overflow `self` is
+    /// returned.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        let ((quotient, _), overflow) = self.overflowing_div_rem(rhs);
+        (quotient, overflow)
+    }
+
+    /// Calculates `self % rhs` and returns the remainder along with a
+    /// boolean indicating whether the division would overflow. On overflow
+    /// the remainder is zero.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        let ((_, remainder), overflow) = self.overflowing_div_rem(rhs);
+        (remainder, overflow)
+    }
+
+    /// Calculates `self / rhs`, wrapping `MIN / -1` to `MIN`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        self.overflowing_div(rhs).0
+    }
+
+    /// Calculates `self % rhs`, wrapping `MIN % -1` to zero.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn wrapping_rem(self, rhs: Self) -> Self {
+        self.overflowing_rem(rhs).0
+    }
+
+    /// Calculates `self / rhs`, saturating `MIN / -1` to `MAX`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        match self.overflowing_div(rhs) {
+            (quotient, false) => quotient,
+            (_, true) => Self::MAX,
+        }
+    }
+
+    /// Calculates `self / rhs`, panicking if `rhs` is zero or the division
+    /// overflows.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    /// Calculates `self % rhs`, panicking if `rhs` is zero or the division
+    /// overflows.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_rem(self, rhs: Self) -> Self {
+        if rhs.is_zero() {
+            panic!(err_msg!(
+                "attempt to calculate the remainder with a divisor of zero"
+            ));
+        }
+
+        match self.overflowing_rem(rhs) {
+            (remainder, false) => remainder,
+            (_, true) => panic!(err_msg!("attempt to calculate the remainder with overflow")),
+        }
+    }
+
+    /// Calculates the quotient of the Euclidean division of `self` by `rhs`,
+    /// for which the remainder is never negative.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero or the division overflows.
+    #[track_caller]
+    #[inline]
+    pub const fn div_euclid(self, rhs: Self) -> Self {
+        let (quotient, remainder) = self.div_rem(rhs);
+
+        if !remainder.is_negative() {
+            quotient
+        } else if rhs.is_negative() {
+            quotient.wrapping_add(Self::ONE)
+        } else {
+            quotient.wrapping_sub(Self::ONE)
+        }
+    }
+
+    /// Calculates the least nonnegative remainder of `self` divided by
+    /// `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero or the division overflows.
+    #[track_caller]
+    #[inline]
+    pub const fn rem_euclid(self, rhs: Self) -> Self {
+        let remainder = self.strict_rem(rhs);
+
+        if !remainder.is_negative() {
+            remainder
+        } else {
+            remainder.wrapping_add(Self::from_bits(rhs.unsigned_abs()))
+        }
+    }
+
+    #[inline]
+    pub const fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self.eq(&Self::MIN) && rhs.eq(&Self::NEG_ONE)) {
+            None
+        } else {
+            Some(self.div_euclid(rhs))
+        }
+    }
+
+    #[inline]
+    pub const fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self.eq(&Self::MIN) && rhs.eq(&Self::NEG_ONE)) {
+            None
+        } else {
+            Some(self.rem_euclid(rhs))
+        }
+    }
+
+    /// Raises `self` to the power of `exp` and returns the result along with
+    /// a boolean indicating whether an arithmetic overflow would occur. If an
+    /// overflow would have occurred then the wrapped value is returned.
+    #[inline]
+    pub const fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+        let negative = self.is_negative() && exp & 1 == 1;
+        let (magnitude, overflow) = self.unsigned_abs().overflowing_pow(exp);
+
+        let mut pow = Self::from_bits(magnitude);
+        if negative {
+            pow = pow.wrapping_neg();
+        }
+
+        (pow, overflow || !fits(magnitude, negative))
+    }
+
+    #[inline]
+    pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+        match self.overflowing_pow(exp) {
+            (pow, false) => Some(pow),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_pow(self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+
+    #[inline]
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Some(pow) => pow,
+            None if self.is_negative() && exp & 1 == 1 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Raises `self` to the power of `exp`, panicking on overflow.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Some(pow) => pow,
+            None => panic!(err_msg!("attempt to multiply with overflow")),
+        }
+    }
+
+    /// Raises `self` to the power of `exp`.
+    ///
+    /// Same as for primitive integers, overflow panics in debug builds and
+    /// wraps otherwise.
+    #[track_caller]
+    #[inline]
+    pub const fn pow(self, exp: u32) -> Self {
+        let (pow, overflow) = self.overflowing_pow(exp);
+        debug_assert!(!overflow, err_msg!("attempt to multiply with overflow"));
+        pow
+    }
+
+    /// Shifts `self` left by `rhs` bits and returns a boolean indicating
+    /// whether the shift value was larger than or equal to the number of
+    /// bits. The shift value is then taken modulo [`Self::BITS`].
+    #[inline]
+    pub const fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+        let (bits, overflow) = self.bits.overflowing_shl(rhs);
+        (Self::from_bits(bits), overflow)
+    }
+
+    #[inline]
+    pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+        match self.bits.checked_shl(rhs) {
+            Some(bits) => Some(Self::from_bits(bits)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        Self::from_bits(self.bits.wrapping_shl(rhs))
+    }
+
+    /// Shifts `self` left by `rhs` bits, returning zero if `rhs` is not less
+    /// than [`Self::BITS`].
+    #[inline]
+    pub const fn unbounded_shl(self, rhs: u32) -> Self {
+        Self::from_bits(self.bits.unbounded_shl(rhs))
+    }
+
+    /// Shifts `self` left by `rhs` bits, panicking if `rhs` is not less than
+    /// [`Self::BITS`].
+    #[track_caller]
+    #[inline]
+    pub const fn strict_shl(self, rhs: u32) -> Self {
+        Self::from_bits(self.bits.strict_shl(rhs))
+    }
+
+    /// Shifts `self` right by `rhs` bits, filling with the sign bit, and
+    /// returns a boolean indicating whether the shift value was larger than
+    /// or equal to the number of bits. The shift value is then taken modulo
+    /// [`Self::BITS`].
+    #[inline]
+    pub const fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+        (shr(self, rhs % Self::BITS), rhs >= Self::BITS)
+    }
+
+    #[inline]
+    pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+        if rhs < Self::BITS {
+            Some(shr(self, rhs))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        self.overflowing_shr(rhs).0
+    }
+
+    /// Shifts `self` right by `rhs` bits, filling with the sign bit. If `rhs`
+    /// is not less than [`Self::BITS`] the result is `0` or `-1` depending on
+    /// the sign.
+    #[inline]
+    pub const fn unbounded_shr(self, rhs: u32) -> Self {
+        if rhs < Self::BITS {
+            shr(self, rhs)
+        } else if self.is_negative() {
+            Self::NEG_ONE
+        } else {
+            Self::ZERO
+        }
+    }
+
+    /// Shifts `self` right by `rhs` bits, panicking if `rhs` is not less than
+    /// [`Self::BITS`].
+    #[track_caller]
+    #[inline]
+    pub const fn strict_shr(self, rhs: u32) -> Self {
+        match self.checked_shr(rhs) {
+            Some(value) => value,
+            None => panic!(err_msg!("attempt to shift right with overflow")),
+        }
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down, or `None` if
+    /// the number is not positive.
+    #[inline]
+    pub const fn checked_ilog2(self) -> Option<u32> {
+        if self.is_negative() {
+            None
+        } else {
+            self.bits.checked_ilog2()
+        }
+    }
+
+    /// Returns the base 10 logarithm of the number, rounded down, or `None`
+    /// if the number is not positive.
+    #[inline]
+    pub const fn checked_ilog10(self) -> Option<u32> {
+        if self.is_negative() {
+            None
+        } else {
+            self.bits.checked_ilog10()
+        }
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary
+    /// base, rounded down, or `None` if the number is not positive or the
+    /// base is less than 2.
+    #[inline]
+    pub const fn checked_ilog(self, base: Self) -> Option<u32> {
+        if self.is_negative() || base.is_negative() {
+            None
+        } else {
+            self.bits.checked_ilog(base.bits)
+        }
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is not positive.
+    #[track_caller]
+    #[inline]
+    pub const fn ilog2(self) -> u32 {
+        match self.checked_ilog2() {
+            Some(log) => log,
+            None => panic!(err_msg!("argument of integer logarithm must be positive")),
+        }
+    }
+
+    /// Returns the base 10 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is not positive.
+    #[track_caller]
+    #[inline]
+    pub const fn ilog10(self) -> u32 {
+        match self.checked_ilog10() {
+            Some(log) => log,
+            None => panic!(err_msg!("argument of integer logarithm must be positive")),
+        }
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base,
+    /// rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is not positive or `base` is less than
+    /// 2.
+    #[track_caller]
+    #[inline]
+    pub const fn ilog(self, base: Self) -> u32 {
+        match self.checked_ilog(base) {
+            Some(log) => log,
+            None => panic!(err_msg!(
+                "argument of integer logarithm must be positive and base at least 2"
+            )),
+        }
+    }
+
+    /// Returns the integer square root of the number, rounded down, or
+    /// `None` if the number is negative.
+    #[inline]
+    pub const fn checked_isqrt(self) -> Option<Self> {
+        if self.is_negative() {
+            None
+        } else {
+            Some(Self::from_bits(self.bits.isqrt()))
+        }
+    }
+
+    /// Returns the integer square root of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is negative.
+    #[track_caller]
+    #[inline]
+    pub const fn isqrt(self) -> Self {
+        match self.checked_isqrt() {
+            Some(sqrt) => sqrt,
+            None => panic!(err_msg!(
+                "argument of integer square root cannot be negative"
+            )),
+        }
+    }
+
+    /// Returns the greatest common divisor of the absolute values of `self`
+    /// and `other`.
+    ///
+    /// The result is unsigned since `gcd(MIN, 0) = 2^(BITS - 1)` does not
+    /// fit into `Self`.
+    #[inline]
+    pub const fn gcd(self, other: Self) -> UInt<N> {
+        self.unsigned_abs().gcd(other.unsigned_abs())
+    }
+
+    /// Returns the greatest common divisor `g` of the absolute values of
+    /// `self` and `other` along with the Bézout coefficients `x` and `y` such
+    /// that `self * x + other * y = g`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{i256, u256};
+    ///
+    /// assert_eq!(i256!(-240).extended_gcd(i256!(46)), (u256!(2), i256!(9), i256!(47)));
+    /// ```
+    #[inline]
+    pub const fn extended_gcd(self, other: Self) -> (UInt<N>, Self, Self) {
+        let (gcd, x, y) = self.unsigned_abs().extended_gcd(other.unsigned_abs());
+
+        let x = if self.is_negative() {
+            x.wrapping_neg()
+        } else {
+            x
+        };
+        let y = if other.is_negative() {
+            y.wrapping_neg()
+        } else {
+            y
+        };
+
+        (gcd, x, y)
+    }
+}
+
+/// Returns `true` if the signed value with the given `magnitude` fits.
+#[inline]
+const fn fits<const N: usize>(magnitude: UInt<N>, negative: bool) -> bool {
+    if negative {
+        magnitude.le(&Int::<N>::MIN.bits)
+    } else {
+        magnitude.le(&Int::<N>::MAX.bits)
+    }
+}
+
+/// Shifts `value` right by `rhs < BITS` bits, filling with the sign bit.
+#[inline]
+const fn shr<const N: usize>(value: Int<N>, rhs: u32) -> Int<N> {
+    if value.is_negative() {
+        value.not().bits.wrapping_shr(rhs).not().cast_signed()
+    } else {
+        value.bits.wrapping_shr(rhs).cast_signed()
+    }
+}
 
 macro_rules! int_types {
     ($($bits: literal $i: ident; ) *)  => {
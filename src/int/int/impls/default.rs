@@ -0,0 +1,8 @@
+use crate::int::Int;
+
+impl<const N: usize> Default for Int<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
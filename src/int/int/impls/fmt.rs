@@ -0,0 +1,54 @@
+use core::fmt::{
+    self, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
+};
+
+use crate::int::{
+    format::{fmt_decimal, fmt_exp, fmt_radix},
+    Int,
+};
+
+impl<const N: usize> Display for Int<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_decimal(self.unsigned_abs(), !self.is_negative(), f)
+    }
+}
+
+impl<const N: usize> Debug for Int<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+// Same as for primitive integers, power-of-two radices format the two's
+// complement bits.
+macro_rules! radix_impl {
+    ($Trait: ident, $radix: literal, $upper: literal, $prefix: literal) => {
+        impl<const N: usize> $Trait for Int<N> {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                fmt_radix(self.to_bits(), $radix, $upper, $prefix, f)
+            }
+        }
+    };
+}
+
+radix_impl!(Binary, 2, false, "0b");
+radix_impl!(Octal, 8, false, "0o");
+radix_impl!(LowerHex, 16, false, "0x");
+radix_impl!(UpperHex, 16, true, "0x");
+
+impl<const N: usize> LowerExp for Int<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_exp(self.unsigned_abs(), !self.is_negative(), b'e', f)
+    }
+}
+
+impl<const N: usize> UpperExp for Int<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_exp(self.unsigned_abs(), !self.is_negative(), b'E', f)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::int::{math, Int, ParseError, UInt};
+
+impl<const N: usize> From<bool> for Int<N> {
+    #[inline]
+    fn from(b: bool) -> Self {
+        Self::from_i64(b as i64)
+    }
+}
+
+macro_rules! from_int {
+    ($($int: ty),*) => {
+        $(
+            impl<const N: usize> From<$int> for Int<N> {
+                #[inline]
+                fn from(n: $int) -> Self {
+                    Self::from_i64(n as i64)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(u8, u16, u32, i8, i16, i32, i64, isize);
+
+macro_rules! try_from_uint {
+    ($($uint: ty),*) => {
+        $(
+            impl<const N: usize> TryFrom<$uint> for Int<N> {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(n: $uint) -> Result<Self, Self::Error> {
+                    match math::from_u128::<N>(n as u128) {
+                        (bits, false) => Self::try_from(bits),
+                        (_, true) => Err(ParseError::PosOverflow),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+try_from_uint!(u64, u128, usize);
+
+impl<const N: usize> TryFrom<i128> for Int<N> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(n: i128) -> Result<Self, Self::Error> {
+        // The value is sign-extended from the two's complement of its
+        // magnitude bits.
+        let negative = n < 0;
+        let magnitude = if negative { !n } else { n } as u128;
+
+        let value = match math::from_u128::<N>(magnitude) {
+            (bits, false) if !bits.cast_signed().is_negative() => bits.cast_signed(),
+            _ if negative => return Err(ParseError::NegOverflow),
+            _ => return Err(ParseError::PosOverflow),
+        };
+
+        if negative {
+            Ok(value.not())
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<UInt<N>> for Int<N> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(n: UInt<N>) -> Result<Self, Self::Error> {
+        let value = n.cast_signed();
+
+        if value.is_negative() {
+            Err(ParseError::PosOverflow)
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+macro_rules! try_into_uint {
+    ($($uint: ty => $to: ident),*) => {
+        $(
+            impl<const N: usize> TryFrom<Int<N>> for $uint {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(n: Int<N>) -> Result<Self, Self::Error> {
+                    if n.is_negative() {
+                        Err(ParseError::NegOverflow)
+                    } else {
+                        math::$to(n.to_bits()).ok_or(ParseError::PosOverflow)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+try_into_uint!(
+    u8 => to_u8,
+    u16 => to_u16,
+    u32 => to_u32,
+    u64 => to_u64,
+    u128 => to_u128,
+    usize => to_usize
+);
+
+macro_rules! try_into_int {
+    ($($int: ty => $to: ident),*) => {
+        $(
+            impl<const N: usize> TryFrom<Int<N>> for $int {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(n: Int<N>) -> Result<Self, Self::Error> {
+                    // A negative value `n` is converted as `!n = -n - 1`,
+                    // which is never negative.
+                    if n.is_negative() {
+                        math::$to(n.not().to_bits()).map(|n| !n).ok_or(ParseError::NegOverflow)
+                    } else {
+                        math::$to(n.to_bits()).ok_or(ParseError::PosOverflow)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+try_into_int!(
+    i8 => to_i8,
+    i16 => to_i16,
+    i32 => to_i32,
+    i64 => to_i64,
+    i128 => to_i128,
+    isize => to_isize
+);
+
+/// Converts `Int<N>` into `Int<M>` of any width, extending the sign.
+///
+/// Returns the converted value along with a boolean indicating whether the
+/// value has been truncated.
+#[inline]
+const fn resize<const N: usize, const M: usize>(n: Int<N>) -> (Int<M>, bool) {
+    let negative = n.is_negative();
+    let magnitude = if negative { n.not() } else { n };
+
+    let (bits, truncated) = math::resize::<N, M>(magnitude.to_bits());
+    let value = bits.cast_signed();
+    let overflow = truncated || value.is_negative();
+
+    if negative {
+        (value.not(), overflow)
+    } else {
+        (value, overflow)
+    }
+}
+
+macro_rules! resize_impl {
+    ($n: literal $(, $m: literal)*) => {
+        $(
+            impl From<Int<$n>> for Int<$m> {
+                #[inline]
+                fn from(n: Int<$n>) -> Self {
+                    resize(n).0
+                }
+            }
+
+            impl TryFrom<Int<$m>> for Int<$n> {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(n: Int<$m>) -> Result<Self, Self::Error> {
+                    match resize(n) {
+                        (value, false) => Ok(value),
+                        (_, true) if n.is_negative() => Err(ParseError::NegOverflow),
+                        (_, true) => Err(ParseError::PosOverflow),
+                    }
+                }
+            }
+        )*
+
+        resize_impl!($($m),*);
+    };
+    () => {};
+}
+
+resize_impl!(1, 2, 4, 8, 16, 32, 64, 128);
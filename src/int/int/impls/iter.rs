@@ -0,0 +1,31 @@
+use core::iter::{Product, Sum};
+
+use crate::int::Int;
+
+impl<const N: usize> Sum for Int<N> {
+    #[inline]
+    fn sum<I: Iterator<Item = Int<N>>>(iter: I) -> Int<N> {
+        iter.fold(Int::<N>::ZERO, |a, b| a + b)
+    }
+}
+
+impl<'a, const N: usize> Sum<&'a Int<N>> for Int<N> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Int<N>>>(iter: I) -> Int<N> {
+        iter.fold(Int::<N>::ZERO, |a, b| a + *b)
+    }
+}
+
+impl<const N: usize> Product for Int<N> {
+    #[inline]
+    fn product<I: Iterator<Item = Int<N>>>(iter: I) -> Int<N> {
+        iter.fold(Int::<N>::ONE, |a, b| a * b)
+    }
+}
+
+impl<'a, const N: usize> Product<&'a Int<N>> for Int<N> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a Int<N>>>(iter: I) -> Int<N> {
+        iter.fold(Int::<N>::ONE, |a, b| a * *b)
+    }
+}
@@ -0,0 +1,233 @@
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, ConstOne,
+    ConstZero, FromPrimitive, Num, One, Pow, SaturatingAdd, SaturatingMul, SaturatingSub, Signed,
+    ToPrimitive, WrappingAdd, WrappingMul, WrappingNeg, WrappingSub, Zero,
+};
+
+use crate::int::{math, Int, ParseError};
+
+impl<const N: usize> One for Int<N> {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        Int::is_one(self)
+    }
+}
+
+impl<const N: usize> ConstOne for Int<N> {
+    const ONE: Self = Self::ONE;
+}
+
+impl<const N: usize> Zero for Int<N> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Int::is_zero(self)
+    }
+}
+
+impl<const N: usize> ConstZero for Int<N> {
+    const ZERO: Self = Self::ZERO;
+}
+
+impl<const N: usize> Num for Int<N> {
+    type FromStrRadixErr = ParseError;
+
+    #[inline]
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Int::from_str_radix(str, radix)
+    }
+}
+
+impl<const N: usize> Signed for Int<N> {
+    #[inline]
+    fn abs(&self) -> Self {
+        Int::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self.le(other) {
+            Self::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        Int::signum(*self)
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        Int::is_positive(self)
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        Int::is_negative(self)
+    }
+}
+
+impl<const N: usize> Bounded for Int<N> {
+    #[inline]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl<const N: usize> Pow<u32> for Int<N> {
+    type Output = Self;
+
+    #[inline]
+    fn pow(self, exp: u32) -> Self {
+        Int::pow(self, exp)
+    }
+}
+
+impl<const N: usize> CheckedNeg for Int<N> {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        Int::checked_neg(*self)
+    }
+}
+
+impl<const N: usize> WrappingNeg for Int<N> {
+    #[inline]
+    fn wrapping_neg(&self) -> Self {
+        Int::wrapping_neg(*self)
+    }
+}
+
+macro_rules! checked_impl {
+    ($($Trait: ident, $method: ident;)*) => {
+        $(
+            impl<const N: usize> $Trait for Int<N> {
+                #[inline]
+                fn $method(&self, rhs: &Self) -> Option<Self> {
+                    Int::$method(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+checked_impl! {
+    CheckedAdd, checked_add;
+    CheckedSub, checked_sub;
+    CheckedMul, checked_mul;
+    CheckedDiv, checked_div;
+    CheckedRem, checked_rem;
+}
+
+macro_rules! total_impl {
+    ($($Trait: ident, $method: ident;)*) => {
+        $(
+            impl<const N: usize> $Trait for Int<N> {
+                #[inline]
+                fn $method(&self, rhs: &Self) -> Self {
+                    Int::$method(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+total_impl! {
+    WrappingAdd, wrapping_add;
+    WrappingSub, wrapping_sub;
+    WrappingMul, wrapping_mul;
+    SaturatingAdd, saturating_add;
+    SaturatingSub, saturating_sub;
+    SaturatingMul, saturating_mul;
+}
+
+impl<const N: usize> ToPrimitive for Int<N> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(*self).ok()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(*self).ok()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(*self).ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(*self).ok()
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        let f = math::to_f32(self.unsigned_abs());
+        Some(if self.is_negative() { -f } else { f })
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        let f = math::to_f64(self.unsigned_abs());
+        Some(if self.is_negative() { -f } else { f })
+    }
+}
+
+impl<const N: usize> FromPrimitive for Int<N> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Int::from_i64(n))
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::from_f64(n as f64)
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        if n >= 0.0 {
+            return math::from_f64(n).and_then(|bits| Self::try_from(bits).ok());
+        }
+
+        let magnitude = math::from_f64::<N>(-n)?;
+
+        if magnitude.gt(&Self::MIN.to_bits()) {
+            None
+        } else {
+            Some(magnitude.cast_signed().wrapping_neg())
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+use crate::{int::Int, utils::err_msg};
+
+// Same as for primitive integers, overflow panics in debug builds and wraps
+// otherwise.
+
+#[track_caller]
+#[inline]
+const fn add<const N: usize>(lhs: Int<N>, rhs: Int<N>) -> Int<N> {
+    let (sum, overflow) = lhs.overflowing_add(rhs);
+    debug_assert!(!overflow, err_msg!("attempt to add with overflow"));
+    sum
+}
+
+#[track_caller]
+#[inline]
+const fn sub<const N: usize>(lhs: Int<N>, rhs: Int<N>) -> Int<N> {
+    let (diff, overflow) = lhs.overflowing_sub(rhs);
+    debug_assert!(!overflow, err_msg!("attempt to subtract with overflow"));
+    diff
+}
+
+#[track_caller]
+#[inline]
+const fn mul<const N: usize>(lhs: Int<N>, rhs: Int<N>) -> Int<N> {
+    let (prod, overflow) = lhs.overflowing_mul(rhs);
+    debug_assert!(!overflow, err_msg!("attempt to multiply with overflow"));
+    prod
+}
+
+macro_rules! op_impl {
+    ($Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident, $f: expr) => {
+        impl<const N: usize> $Op for Int<N> {
+            type Output = Int<N>;
+
+            #[track_caller]
+            #[inline]
+            fn $op(self, rhs: Self) -> Int<N> {
+                $f(self, rhs)
+            }
+        }
+
+        impl<const N: usize> $Op<&Int<N>> for Int<N> {
+            type Output = Int<N>;
+
+            #[track_caller]
+            #[inline]
+            fn $op(self, rhs: &Int<N>) -> Int<N> {
+                $f(self, *rhs)
+            }
+        }
+
+        impl<const N: usize> $Op<Int<N>> for &Int<N> {
+            type Output = Int<N>;
+
+            #[track_caller]
+            #[inline]
+            fn $op(self, rhs: Int<N>) -> Int<N> {
+                $f(*self, rhs)
+            }
+        }
+
+        impl<const N: usize> $Op<&Int<N>> for &Int<N> {
+            type Output = Int<N>;
+
+            #[track_caller]
+            #[inline]
+            fn $op(self, rhs: &Int<N>) -> Int<N> {
+                $f(*self, *rhs)
+            }
+        }
+
+        impl<const N: usize> $OpAssign for Int<N> {
+            #[track_caller]
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = $f(*self, rhs);
+            }
+        }
+
+        impl<const N: usize> $OpAssign<&Int<N>> for Int<N> {
+            #[track_caller]
+            #[inline]
+            fn $op_assign(&mut self, rhs: &Int<N>) {
+                *self = $f(*self, *rhs);
+            }
+        }
+    };
+}
+
+op_impl!(Add, add, AddAssign, add_assign, add);
+op_impl!(Sub, sub, SubAssign, sub_assign, sub);
+op_impl!(Mul, mul, MulAssign, mul_assign, mul);
+op_impl!(Div, div, DivAssign, div_assign, Int::strict_div);
+op_impl!(Rem, rem, RemAssign, rem_assign, Int::strict_rem);
+op_impl!(BitAnd, bitand, BitAndAssign, bitand_assign, Int::bitand);
+op_impl!(BitOr, bitor, BitOrAssign, bitor_assign, Int::bitor);
+op_impl!(BitXor, bitxor, BitXorAssign, bitxor_assign, Int::bitxor);
+
+impl<const N: usize> Not for Int<N> {
+    type Output = Int<N>;
+
+    #[inline]
+    fn not(self) -> Int<N> {
+        Int::not(self)
+    }
+}
+
+impl<const N: usize> Not for &Int<N> {
+    type Output = Int<N>;
+
+    #[inline]
+    fn not(self) -> Int<N> {
+        Int::not(*self)
+    }
+}
+
+impl<const N: usize> Neg for Int<N> {
+    type Output = Int<N>;
+
+    #[track_caller]
+    #[inline]
+    fn neg(self) -> Int<N> {
+        let (neg, overflow) = self.overflowing_neg();
+        debug_assert!(!overflow, err_msg!("attempt to negate with overflow"));
+        neg
+    }
+}
+
+impl<const N: usize> Neg for &Int<N> {
+    type Output = Int<N>;
+
+    #[track_caller]
+    #[inline]
+    fn neg(self) -> Int<N> {
+        -*self
+    }
+}
+
+macro_rules! shift_impl {
+    ($($ty: ty),*) => {
+        $(
+            impl<const N: usize> Shl<$ty> for Int<N> {
+                type Output = Int<N>;
+
+                #[track_caller]
+                #[inline]
+                fn shl(self, rhs: $ty) -> Int<N> {
+                    #[allow(unused_comparisons)]
+                    let overflow = rhs < 0 || rhs as u128 >= Int::<N>::BITS as u128;
+                    debug_assert!(!overflow, err_msg!("attempt to shift left with overflow"));
+                    self.wrapping_shl(rhs as u32)
+                }
+            }
+
+            impl<const N: usize> Shr<$ty> for Int<N> {
+                type Output = Int<N>;
+
+                #[track_caller]
+                #[inline]
+                fn shr(self, rhs: $ty) -> Int<N> {
+                    #[allow(unused_comparisons)]
+                    let overflow = rhs < 0 || rhs as u128 >= Int::<N>::BITS as u128;
+                    debug_assert!(!overflow, err_msg!("attempt to shift right with overflow"));
+                    self.wrapping_shr(rhs as u32)
+                }
+            }
+
+            impl<const N: usize> Shl<$ty> for &Int<N> {
+                type Output = Int<N>;
+
+                #[track_caller]
+                #[inline]
+                fn shl(self, rhs: $ty) -> Int<N> {
+                    *self << rhs
+                }
+            }
+
+            impl<const N: usize> Shr<$ty> for &Int<N> {
+                type Output = Int<N>;
+
+                #[track_caller]
+                #[inline]
+                fn shr(self, rhs: $ty) -> Int<N> {
+                    *self >> rhs
+                }
+            }
+
+            impl<const N: usize> ShlAssign<$ty> for Int<N> {
+                #[track_caller]
+                #[inline]
+                fn shl_assign(&mut self, rhs: $ty) {
+                    *self = *self << rhs;
+                }
+            }
+
+            impl<const N: usize> ShrAssign<$ty> for Int<N> {
+                #[track_caller]
+                #[inline]
+                fn shr_assign(&mut self, rhs: $ty) {
+                    *self = *self >> rhs;
+                }
+            }
+        )*
+    };
+}
+
+shift_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,34 @@
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
+
+use crate::int::{ct, Int};
+
+impl<const N: usize> ConstantTimeEq for Int<N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        ct::eq(&self.to_bits(), &other.to_bits()).into()
+    }
+}
+
+impl<const N: usize> ConstantTimeGreater for Int<N> {
+    /// Flipping the sign bits maps the two's complement order onto the
+    /// unsigned one.
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        let sign = Int::<N>::MIN.to_bits();
+        let lhs = self.to_bits().bitxor(sign);
+        let rhs = other.to_bits().bitxor(sign);
+
+        ct::gt(&lhs, &rhs).into()
+    }
+}
+
+impl<const N: usize> ConstantTimeLess for Int<N> {}
+
+impl<const N: usize> ConditionallySelectable for Int<N> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Int::from_bits(ct::select(&a.to_bits(), &b.to_bits(), choice.into()))
+    }
+}
@@ -0,0 +1,3 @@
+use crate::int::Int;
+
+impl<const N: usize> zeroize::DefaultIsZeroes for Int<N> {}
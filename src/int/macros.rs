@@ -3,6 +3,8 @@ macro_rules! macro_impl {
         #[macro_export]
         #[doc = concat!("A macro to construct ", $bits, "-bit [crate::", stringify!($INT), "] ", stringify!($sign), " integer from literals.")]
         ///
+        /// Decimal literals and literals with the `0x`, `0o` or `0b` radix
+        /// prefix are accepted, with optional `_` separators.
         ///
         /// # Examples:
         /// 
@@ -12,6 +14,8 @@ macro_rules! macro_impl {
         #[doc = concat!("const N: ", stringify!($INT), " = ", stringify!($name), "!(100);")]
         #[doc = concat!("let x = ", stringify!($name), "!(1);")]
         #[doc = concat!("assert!(", stringify!($name), "!(0).is_zero());")]
+        #[doc = concat!("assert_eq!(", stringify!($name), "!(0xff), ", stringify!($name), "!(255));")]
+        #[doc = concat!("assert_eq!(", stringify!($name), "!(0b1111_0000), ", stringify!($name), "!(0o360));")]
         /// println!("{x}"); 
         /// ```
         /// 
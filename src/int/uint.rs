@@ -1,10 +1,1268 @@
+mod impls;
+
+pub(crate) mod add;
+pub(crate) mod div;
+pub(crate) mod format;
+pub(crate) mod from_str;
+pub(crate) mod gcd;
+pub(crate) mod math;
+pub(crate) mod mul;
+pub(crate) mod pow10;
+pub(crate) mod prime;
+
+mod montgomery;
+
+pub use montgomery::Montgomery;
+
+use core::cmp::Ordering;
+
+use crate::{
+    int::{doc::int_type_doc, math::div_rem, pow10::Pow10, Int, ParseError},
+    utils::err_msg,
+};
+
+type Digit = u64;
+
+const DIGIT_BITS: u32 = Digit::BITS;
+
 /// Big unsigned integer generic type, of fixed size which must be known at
 /// compile time.
-pub type UInt<const N: usize> = bnum::BUint<N>;
+///
+/// The value is stored as `N` 64-bit digits in little-endian order with no
+/// padding in between, so that digit-wise loops (such as the carry lookahead
+/// addition) compile into vector instructions.
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UInt<const N: usize> {
+    digits: [Digit; N],
+}
 
-pub(crate) mod math;
+macro_rules! consts {
+    ($($name: ident = $value: literal;)*) => {
+        $(
+            #[doc = concat!("The value of `", $value, "`.")]
+            pub const $name: Self = Self::from_digit($value);
+        )*
+    };
+}
+
+impl<const N: usize> UInt<N> {
+    /// The size of this integer type in bits.
+    pub const BITS: u32 = DIGIT_BITS * N as u32;
+
+    /// The smallest value that can be represented by this integer type.
+    pub const MIN: Self = Self::ZERO;
+
+    /// The largest value that can be represented by this integer type,
+    /// `2^BITS - 1`.
+    pub const MAX: Self = Self::from_digits([Digit::MAX; N]);
+
+    /// The maximum length of the string representation in any radix, which
+    /// is the length of [`Self::MAX`] in binary.
+    pub const MAX_STR_LEN: usize = Self::BITS as usize;
+
+    consts! {
+        ZERO = 0;
+        ONE = 1;
+        TWO = 2;
+        THREE = 3;
+        FOUR = 4;
+        FIVE = 5;
+        SIX = 6;
+        SEVEN = 7;
+        EIGHT = 8;
+        NINE = 9;
+        TEN = 10;
+    }
+
+    /// Creates an integer from its little-endian 64-bit digits.
+    #[inline(always)]
+    pub const fn from_digits(digits: [Digit; N]) -> Self {
+        Self { digits }
+    }
+
+    /// Creates an integer with the single least significant 64-bit digit.
+    #[inline(always)]
+    pub const fn from_digit(digit: Digit) -> Self {
+        let mut digits = [0; N];
+        digits[0] = digit;
+        Self { digits }
+    }
+
+    /// Returns the little-endian 64-bit digits of the integer.
+    #[inline(always)]
+    pub const fn digits(&self) -> &[Digit; N] {
+        &self.digits
+    }
+
+    /// Returns the mutable little-endian 64-bit digits of the integer.
+    #[inline(always)]
+    pub const fn digits_mut(&mut self) -> &mut [Digit; N] {
+        &mut self.digits
+    }
+
+    /// Parses an integer from a string in the given `radix`.
+    ///
+    /// The string may start with a `+` sign. Digits above `9` are `a`-`z` or
+    /// `A`-`Z`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::U256;
+    ///
+    /// assert_eq!(U256::from_str_radix("ff", 16), Ok(U256::from_digit(255)));
+    /// assert!(U256::from_str_radix("-1", 10).is_err());
+    /// ```
+    #[inline]
+    pub const fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        from_str::from_str_radix(s.as_bytes(), radix)
+    }
+
+    /// Parses a decimal integer from a string.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the string is not a valid integer.
+    #[track_caller]
+    #[inline]
+    pub const fn parse_str(s: &str) -> Self {
+        match Self::from_str_radix(s, 10) {
+            Ok(n) => n,
+            Err(e) => panic!("{}", e.description()),
+        }
+    }
+
+    /// Returns the string representation of the integer in the given
+    /// `radix`. Digits above `9` are lowercase letters.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::U256;
+    ///
+    /// assert_eq!(U256::from_digit(255).to_str_radix(16), "ff");
+    /// assert_eq!(U256::ZERO.to_str_radix(2), "0");
+    /// ```
+    pub fn to_str_radix(&self, radix: u32) -> alloc::string::String {
+        let mut buf = alloc::vec![0; format::radix_len(*self, radix)];
+        let len = format::write_radix(*self, radix, false, &mut buf);
+        buf.truncate(len);
+
+        // SAFETY: only ASCII digits are written.
+        #[allow(unsafe_code)]
+        unsafe {
+            alloc::string::String::from_utf8_unchecked(buf)
+        }
+    }
+
+    /// Writes the string representation of the integer in the given `radix`
+    /// into `buf` and returns the written part of `buf` as a string slice.
+    /// Digits above `9` are lowercase letters.
+    ///
+    /// The output is the same as of [`Self::to_str_radix`], but no memory is
+    /// allocated, so it can be used in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36 or
+    /// `buf` is too short to hold the string. A buffer of
+    /// [`Self::MAX_STR_LEN`] bytes is always sufficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{u256, U256};
+    ///
+    /// let mut buf = [0; U256::MAX_STR_LEN];
+    ///
+    /// assert_eq!(u256!(0xdead_beef).to_str_radix_buf(16, &mut buf), "deadbeef");
+    /// assert_eq!(u256!(255).to_str_radix_buf(2, &mut buf), "11111111");
+    /// ```
+    #[inline]
+    pub const fn to_str_radix_buf<'a>(&self, radix: u32, buf: &'a mut [u8]) -> &'a str {
+        let len = format::write_radix(*self, radix, false, buf);
+        let buf: &'a [u8] = buf;
+        format::as_str(buf.split_at(len).0)
+    }
+
+    /// Parses an integer literal of the [`u256!`](crate::u256)-style macros.
+    #[inline]
+    pub(crate) const fn from_literal(s: &str) -> Result<Self, ParseError> {
+        match s.as_bytes() {
+            [] => Err(ParseError::Empty),
+            [b'+', digits @ ..] => from_str::from_literal(digits),
+            digits => from_str::from_literal(digits),
+        }
+    }
+
+    /// Reinterprets the integer as a signed one of the same size.
+    #[inline(always)]
+    pub const fn cast_signed(self) -> Int<N> {
+        Int::from_bits(self)
+    }
+
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        let mut i = 0;
+
+        while i < N {
+            if self.digits[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    #[inline]
+    pub const fn is_one(&self) -> bool {
+        if N == 0 || self.digits[0] != 1 {
+            return false;
+        }
+
+        let mut i = 1;
+
+        while i < N {
+            if self.digits[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    /// Returns `true` if and only if `self == 2^k` for some `k`.
+    #[inline]
+    pub const fn is_power_of_two(&self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Returns the number of bits needed to represent the integer, `0` for
+    /// zero.
+    #[inline]
+    pub const fn bits(&self) -> u32 {
+        Self::BITS - self.leading_zeros()
+    }
+
+    /// Returns the bit at `index`, where `0` is the least significant bit.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not less than [`Self::BITS`].
+    #[inline]
+    pub const fn bit(&self, index: u32) -> bool {
+        (self.digits[(index / DIGIT_BITS) as usize] >> (index % DIGIT_BITS)) & 1 == 1
+    }
+
+    #[inline]
+    pub const fn count_ones(self) -> u32 {
+        let mut count = 0;
+        let mut i = 0;
+
+        while i < N {
+            count += self.digits[i].count_ones();
+            i += 1;
+        }
+
+        count
+    }
+
+    #[inline]
+    pub const fn count_zeros(self) -> u32 {
+        Self::BITS - self.count_ones()
+    }
+
+    #[inline]
+    pub const fn leading_zeros(self) -> u32 {
+        let mut zeros = 0;
+        let mut i = N;
+
+        while i > 0 {
+            i -= 1;
+            let digit = self.digits[i];
+            zeros += digit.leading_zeros();
+
+            if digit != 0 {
+                break;
+            }
+        }
+
+        zeros
+    }
+
+    #[inline]
+    pub const fn trailing_zeros(self) -> u32 {
+        let mut zeros = 0;
+        let mut i = 0;
+
+        while i < N {
+            let digit = self.digits[i];
+            zeros += digit.trailing_zeros();
+
+            if digit != 0 {
+                break;
+            }
+            i += 1;
+        }
+
+        zeros
+    }
+
+    #[inline]
+    pub const fn leading_ones(self) -> u32 {
+        self.not().leading_zeros()
+    }
+
+    #[inline]
+    pub const fn trailing_ones(self) -> u32 {
+        self.not().trailing_zeros()
+    }
+
+    #[inline]
+    pub const fn not(self) -> Self {
+        let mut digits = self.digits;
+        let mut i = 0;
+
+        while i < N {
+            digits[i] = !digits[i];
+            i += 1;
+        }
+
+        Self::from_digits(digits)
+    }
+
+    #[inline]
+    pub const fn bitand(self, rhs: Self) -> Self {
+        let mut digits = self.digits;
+        let mut i = 0;
+
+        while i < N {
+            digits[i] &= rhs.digits[i];
+            i += 1;
+        }
+
+        Self::from_digits(digits)
+    }
+
+    #[inline]
+    pub const fn bitor(self, rhs: Self) -> Self {
+        let mut digits = self.digits;
+        let mut i = 0;
+
+        while i < N {
+            digits[i] |= rhs.digits[i];
+            i += 1;
+        }
+
+        Self::from_digits(digits)
+    }
+
+    #[inline]
+    pub const fn bitxor(self, rhs: Self) -> Self {
+        let mut digits = self.digits;
+        let mut i = 0;
+
+        while i < N {
+            digits[i] ^= rhs.digits[i];
+            i += 1;
+        }
+
+        Self::from_digits(digits)
+    }
+
+    /// Reverses the order of bits in the integer.
+    #[inline]
+    pub const fn reverse_bits(self) -> Self {
+        let mut digits = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            digits[i] = self.digits[N - 1 - i].reverse_bits();
+            i += 1;
+        }
+
+        Self::from_digits(digits)
+    }
+
+    /// Shifts the bits to the left by `n`, wrapping the truncated bits to the
+    /// end of the resulting integer.
+    #[inline]
+    pub const fn rotate_left(self, n: u32) -> Self {
+        let n = n % Self::BITS;
+        self.unbounded_shl(n)
+            .bitor(self.unbounded_shr(Self::BITS - n))
+    }
+
+    /// Shifts the bits to the right by `n`, wrapping the truncated bits to the
+    /// beginning of the resulting integer.
+    #[inline]
+    pub const fn rotate_right(self, n: u32) -> Self {
+        let n = n % Self::BITS;
+        self.unbounded_shr(n)
+            .bitor(self.unbounded_shl(Self::BITS - n))
+    }
+
+    #[inline]
+    pub const fn eq(&self, other: &Self) -> bool {
+        let mut i = 0;
+
+        while i < N {
+            if self.digits[i] != other.digits[i] {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    #[inline]
+    pub const fn ne(&self, other: &Self) -> bool {
+        !self.eq(other)
+    }
+
+    #[inline]
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        let mut i = N;
+
+        while i > 0 {
+            i -= 1;
+            let (a, b) = (self.digits[i], other.digits[i]);
+
+            if a != b {
+                return if a < b {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+            }
+        }
+
+        Ordering::Equal
+    }
+
+    #[inline]
+    pub const fn lt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Less)
+    }
+
+    #[inline]
+    pub const fn le(&self, other: &Self) -> bool {
+        !self.gt(other)
+    }
+
+    #[inline]
+    pub const fn gt(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Greater)
+    }
+
+    #[inline]
+    pub const fn ge(&self, other: &Self) -> bool {
+        !self.lt(other)
+    }
+
+    #[inline]
+    pub const fn max(self, other: Self) -> Self {
+        if self.lt(&other) {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    pub const fn min(self, other: Self) -> Self {
+        if self.gt(&other) {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restricts the value to the `[min, max]` interval.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `min > max`.
+    #[inline]
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            min.le(&max),
+            err_msg!("clamp: min must not be greater than max")
+        );
+        self.max(min).min(max)
+    }
+
+    #[inline]
+    pub const fn abs_diff(self, other: Self) -> Self {
+        if self.lt(&other) {
+            other.wrapping_sub(self)
+        } else {
+            self.wrapping_sub(other)
+        }
+    }
+
+    /// Calculates `self + rhs` and returns the sum along with a boolean
+    /// indicating whether an arithmetic overflow would occur. If an overflow
+    /// would have occurred then the wrapped value is returned.
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        add::overflowing_add(&self, &rhs)
+    }
 
-use crate::int::doc::int_type_doc;
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.overflowing_add(rhs) {
+            (sum, false) => sum,
+            (_, true) => Self::MAX,
+        }
+    }
+
+    /// Calculates `self + rhs`, panicking on overflow.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_add(self, rhs: Self) -> Self {
+        match self.overflowing_add(rhs) {
+            (sum, false) => sum,
+            (_, true) => panic!(err_msg!("attempt to add with overflow")),
+        }
+    }
+
+    /// Calculates `self - rhs` and returns the difference along with a
+    /// boolean indicating whether an arithmetic overflow would occur. If an
+    /// overflow would have occurred then the wrapped value is returned.
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        add::overflowing_sub(&self, &rhs)
+    }
+
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (diff, false) => Some(diff),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.overflowing_sub(rhs) {
+            (diff, false) => diff,
+            (_, true) => Self::ZERO,
+        }
+    }
+
+    /// Calculates `self - rhs`, panicking on overflow.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_sub(self, rhs: Self) -> Self {
+        match self.overflowing_sub(rhs) {
+            (diff, false) => diff,
+            (_, true) => panic!(err_msg!("attempt to subtract with overflow")),
+        }
+    }
+
+    /// Calculates the complete product `self * rhs` without the possibility
+    /// to overflow.
+    ///
+    /// Returns the low and the high halves of the product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::U256;
+    ///
+    /// assert_eq!(U256::MAX.widening_mul(U256::TWO), (U256::MAX - U256::ONE, U256::ONE));
+    /// ```
+    #[inline]
+    pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        mul::widening_mul(self, rhs)
+    }
+
+    /// Calculates `self * rhs` and returns the product along with a boolean
+    /// indicating whether an arithmetic overflow would occur. If an overflow
+    /// would have occurred then the wrapped value is returned.
+    #[inline]
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (low, high) = mul::widening_mul(self, rhs);
+        (low, !high.is_zero())
+    }
+
+    #[inline]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (prod, false) => Some(prod),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    #[inline]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.overflowing_mul(rhs) {
+            (prod, false) => prod,
+            (_, true) => Self::MAX,
+        }
+    }
+
+    /// Calculates `self * rhs`, panicking on overflow.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_mul(self, rhs: Self) -> Self {
+        match self.overflowing_mul(rhs) {
+            (prod, false) => prod,
+            (_, true) => panic!(err_msg!("attempt to multiply with overflow")),
+        }
+    }
+
+    /// Calculates the quotient and the remainder of `self / rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn div_rem(self, rhs: Self) -> (Self, Self) {
+        if rhs.is_zero() {
+            panic!(err_msg!("attempt to divide by zero"));
+        }
+
+        div_rem(self, rhs)
+    }
+
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(div_rem(self, rhs).0)
+        }
+    }
+
+    #[inline]
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(div_rem(self, rhs).1)
+        }
+    }
+
+    /// Calculates `self / rhs`, panicking if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    /// Calculates `self % rhs`, panicking if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_rem(self, rhs: Self) -> Self {
+        if rhs.is_zero() {
+            panic!(err_msg!(
+                "attempt to calculate the remainder with a divisor of zero"
+            ));
+        }
+
+        div_rem(self, rhs).1
+    }
+
+    /// Calculates `self / rhs` as the `/` operator does in const contexts.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn div(self, rhs: Self) -> Self {
+        self.strict_div(rhs)
+    }
+
+    /// Calculates `self % rhs` as the `%` operator does in const contexts.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn rem(self, rhs: Self) -> Self {
+        self.strict_rem(rhs)
+    }
+
+    /// Calculates `self / rhs`. Unsigned division never wraps, so this is the
+    /// same as [`Self::strict_div`].
+    #[track_caller]
+    #[inline]
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        self.strict_div(rhs)
+    }
+
+    /// Calculates `self % rhs`. Unsigned division never wraps, so this is the
+    /// same as [`Self::strict_rem`].
+    #[track_caller]
+    #[inline]
+    pub const fn wrapping_rem(self, rhs: Self) -> Self {
+        self.strict_rem(rhs)
+    }
+
+    /// Calculates `self / rhs` rounded towards positive infinity.
+    #[track_caller]
+    #[inline]
+    pub const fn div_ceil(self, rhs: Self) -> Self {
+        let (quotient, remainder) = self.div_rem(rhs);
+
+        if remainder.is_zero() {
+            quotient
+        } else {
+            quotient.wrapping_add(Self::ONE)
+        }
+    }
+
+    /// Raises `self` to the power of `exp` and returns the result along with
+    /// a boolean indicating whether an arithmetic overflow would occur. If an
+    /// overflow would have occurred then the wrapped value is returned.
+    #[inline]
+    pub const fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+        if exp == 0 {
+            return (Self::ONE, false);
+        }
+
+        let mut base = self;
+        let mut acc = Self::ONE;
+        let mut overflow = false;
+        let mut o;
+
+        while exp > 1 {
+            if exp & 1 == 1 {
+                (acc, o) = acc.overflowing_mul(base);
+                overflow |= o;
+            }
+
+            exp >>= 1;
+            (base, o) = base.overflowing_mul(base);
+            overflow |= o;
+        }
+
+        (acc, o) = acc.overflowing_mul(base);
+        (acc, overflow || o)
+    }
+
+    #[inline]
+    pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+        match self.overflowing_pow(exp) {
+            (pow, false) => Some(pow),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_pow(self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+
+    #[inline]
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        match self.overflowing_pow(exp) {
+            (pow, false) => pow,
+            (_, true) => Self::MAX,
+        }
+    }
+
+    /// Raises `self` to the power of `exp`, panicking on overflow.
+    #[track_caller]
+    #[inline]
+    pub const fn strict_pow(self, exp: u32) -> Self {
+        match self.overflowing_pow(exp) {
+            (pow, false) => pow,
+            (_, true) => panic!(err_msg!("attempt to multiply with overflow")),
+        }
+    }
+
+    /// Raises `self` to the power of `exp`.
+    ///
+    /// Same as for primitive integers, overflow panics in debug builds and
+    /// wraps otherwise.
+    #[track_caller]
+    #[inline]
+    pub const fn pow(self, exp: u32) -> Self {
+        let (pow, overflow) = self.overflowing_pow(exp);
+        debug_assert!(!overflow, err_msg!("attempt to multiply with overflow"));
+        pow
+    }
+
+    /// Shifts `self` left by `rhs` bits and returns a boolean indicating
+    /// whether the shift value was larger than or equal to the number of
+    /// bits. The shift value is then taken modulo [`Self::BITS`].
+    #[inline]
+    pub const fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+        (shl(self, rhs % Self::BITS), rhs >= Self::BITS)
+    }
+
+    #[inline]
+    pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+        if rhs < Self::BITS {
+            Some(shl(self, rhs))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        self.overflowing_shl(rhs).0
+    }
+
+    /// Shifts `self` left by `rhs` bits, returning zero if `rhs` is not less
+    /// than [`Self::BITS`].
+    #[inline]
+    pub const fn unbounded_shl(self, rhs: u32) -> Self {
+        if rhs < Self::BITS {
+            shl(self, rhs)
+        } else {
+            Self::ZERO
+        }
+    }
+
+    /// Shifts `self` left by `rhs` bits, panicking if `rhs` is not less than
+    /// [`Self::BITS`].
+    #[track_caller]
+    #[inline]
+    pub const fn strict_shl(self, rhs: u32) -> Self {
+        match self.checked_shl(rhs) {
+            Some(value) => value,
+            None => panic!(err_msg!("attempt to shift left with overflow")),
+        }
+    }
+
+    /// Shifts `self` right by `rhs` bits and returns a boolean indicating
+    /// whether the shift value was larger than or equal to the number of
+    /// bits. The shift value is then taken modulo [`Self::BITS`].
+    #[inline]
+    pub const fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+        (shr(self, rhs % Self::BITS), rhs >= Self::BITS)
+    }
+
+    #[inline]
+    pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+        if rhs < Self::BITS {
+            Some(shr(self, rhs))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        self.overflowing_shr(rhs).0
+    }
+
+    /// Shifts `self` right by `rhs` bits, returning zero if `rhs` is not less
+    /// than [`Self::BITS`].
+    #[inline]
+    pub const fn unbounded_shr(self, rhs: u32) -> Self {
+        if rhs < Self::BITS {
+            shr(self, rhs)
+        } else {
+            Self::ZERO
+        }
+    }
+
+    /// Shifts `self` right by `rhs` bits, panicking if `rhs` is not less than
+    /// [`Self::BITS`].
+    #[track_caller]
+    #[inline]
+    pub const fn strict_shr(self, rhs: u32) -> Self {
+        match self.checked_shr(rhs) {
+            Some(value) => value,
+            None => panic!(err_msg!("attempt to shift right with overflow")),
+        }
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down, or `None` if
+    /// the number is zero.
+    #[inline]
+    pub const fn checked_ilog2(self) -> Option<u32> {
+        match self.bits() {
+            0 => None,
+            bits => Some(bits - 1),
+        }
+    }
+
+    /// Returns the base 10 logarithm of the number, rounded down, or `None`
+    /// if the number is zero.
+    #[inline]
+    pub const fn checked_ilog10(self) -> Option<u32> {
+        match Pow10::<N>::digits_count(self) {
+            0 => None,
+            count => Some(count - 1),
+        }
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary
+    /// base, rounded down, or `None` if the number is zero or the base is
+    /// less than 2.
+    #[inline]
+    pub const fn checked_ilog(self, base: Self) -> Option<u32> {
+        if self.is_zero() || base.lt(&Self::TWO) {
+            return None;
+        }
+
+        let mut log = 0;
+        let mut power = base;
+
+        while power.le(&self) {
+            log += 1;
+
+            power = match power.checked_mul(base) {
+                Some(power) => power,
+                None => break,
+            };
+        }
+
+        Some(log)
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn ilog2(self) -> u32 {
+        match self.checked_ilog2() {
+            Some(log) => log,
+            None => panic!(err_msg!("argument of integer logarithm must be positive")),
+        }
+    }
+
+    /// Returns the base 10 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn ilog10(self) -> u32 {
+        match self.checked_ilog10() {
+            Some(log) => log,
+            None => panic!(err_msg!("argument of integer logarithm must be positive")),
+        }
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base,
+    /// rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is zero or `base` is less than 2.
+    #[track_caller]
+    #[inline]
+    pub const fn ilog(self, base: Self) -> u32 {
+        match self.checked_ilog(base) {
+            Some(log) => log,
+            None => panic!(err_msg!(
+                "argument of integer logarithm must be positive and base at least 2"
+            )),
+        }
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`, or
+    /// `None` if it doesn't fit.
+    #[inline]
+    pub const fn checked_next_power_of_two(self) -> Option<Self> {
+        if self.le(&Self::ONE) {
+            return Some(Self::ONE);
+        }
+
+        let bits = self.wrapping_sub(Self::ONE).bits();
+        Self::ONE.checked_shl(bits)
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    ///
+    /// Same as for primitive integers, overflow panics in debug builds and
+    /// returns zero otherwise.
+    #[track_caller]
+    #[inline]
+    pub const fn next_power_of_two(self) -> Self {
+        match self.checked_next_power_of_two() {
+            Some(power) => power,
+            None => {
+                debug_assert!(false, err_msg!("attempt to add with overflow"));
+                Self::ZERO
+            }
+        }
+    }
+
+    /// Returns the integer square root of the number, rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::u256;
+    ///
+    /// assert_eq!(u256!(99).isqrt(), u256!(9));
+    /// assert_eq!(u256!(100).isqrt(), u256!(10));
+    /// ```
+    #[inline]
+    pub const fn isqrt(self) -> Self {
+        math::isqrt(self)
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    ///
+    /// The greatest common divisor of zero and `x` is `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::u256;
+    ///
+    /// assert_eq!(u256!(84).gcd(u256!(36)), u256!(12));
+    /// assert_eq!(u256!(0).gcd(u256!(7)), u256!(7));
+    /// ```
+    #[inline]
+    pub const fn gcd(self, other: Self) -> Self {
+        gcd::gcd(self, other)
+    }
+
+    /// Returns the least common multiple of `self` and `other`, or `None` if
+    /// it doesn't fit.
+    ///
+    /// The least common multiple of zero and `x` is zero.
+    #[inline]
+    pub const fn checked_lcm(self, other: Self) -> Option<Self> {
+        if self.is_zero() || other.is_zero() {
+            return Some(Self::ZERO);
+        }
+
+        div_rem(self, gcd::gcd(self, other)).0.checked_mul(other)
+    }
+
+    /// Returns the least common multiple of `self` and `other`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the result doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::u256;
+    ///
+    /// assert_eq!(u256!(4).lcm(u256!(6)), u256!(12));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub const fn lcm(self, other: Self) -> Self {
+        match self.checked_lcm(other) {
+            Some(lcm) => lcm,
+            None => panic!(err_msg!(
+                "attempt to calculate the least common multiple with overflow"
+            )),
+        }
+    }
+
+    /// Returns the greatest common divisor `g` of `self` and `other` along
+    /// with the Bézout coefficients `x` and `y` such that `self * x + other *
+    /// y = g`.
+    ///
+    /// The coefficients are the minimal ones found by the extended Euclidean
+    /// algorithm, `|x| <= other / 2g` and `|y| <= self / 2g`, so they always
+    /// fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::{i256, u256};
+    ///
+    /// assert_eq!(u256!(240).extended_gcd(u256!(46)), (u256!(2), i256!(-9), i256!(47)));
+    /// ```
+    #[inline]
+    pub const fn extended_gcd(self, other: Self) -> (Self, Int<N>, Int<N>) {
+        gcd::extended_gcd(self, other)
+    }
+
+    /// Calculates `self * rhs mod modulus` without intermediate overflow.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `modulus` is zero.
+    #[track_caller]
+    #[inline]
+    pub const fn mod_mul(self, rhs: Self, modulus: Self) -> Self {
+        let lhs = self.strict_rem(modulus);
+        let rhs = rhs.strict_rem(modulus);
+
+        math::mod_mul(lhs, rhs, modulus)
+    }
+
+    /// Calculates `self^exp mod modulus`.
+    ///
+    /// Odd moduli, which include all the moduli of cryptographic interest,
+    /// are handled in the [`Montgomery`] form. For repeated exponentiations
+    /// by the same modulus prefer creating the [`Montgomery`] context once.
+    ///
+    /// This function is not constant-time.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::u256;
+    ///
+    /// assert_eq!(u256!(4).mod_pow(u256!(13), u256!(497)), u256!(445));
+    /// assert_eq!(u256!(3).mod_pow(u256!(0), u256!(10)), u256!(1));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub const fn mod_pow(self, exp: Self, modulus: Self) -> Self {
+        if modulus.is_zero() {
+            panic!(err_msg!(
+                "attempt to calculate the remainder with a divisor of zero"
+            ));
+        }
+
+        math::mod_pow(self, exp, modulus)
+    }
+
+    /// Returns the multiplicative inverse of `self` modulo `modulus`, or
+    /// `None` if `self` and `modulus` are not coprime or `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::u256;
+    ///
+    /// assert_eq!(u256!(3).mod_inv(u256!(11)), Some(u256!(4)));
+    /// assert_eq!(u256!(6).mod_inv(u256!(9)), None);
+    /// ```
+    #[inline]
+    pub const fn mod_inv(self, modulus: Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+
+        let (gcd, x, _) = gcd::extended_gcd(div_rem(self, modulus).1, modulus);
+
+        if !gcd.is_one() {
+            None
+        } else if modulus.is_one() {
+            Some(Self::ZERO)
+        } else if x.is_negative() {
+            Some(x.to_bits().wrapping_add(modulus))
+        } else {
+            Some(x.to_bits())
+        }
+    }
+
+    /// Returns `true` if the number is prime, with a negligible probability
+    /// of error for large numbers.
+    ///
+    /// After trial division by the primes below 256 numbers below
+    /// `3.3 * 10^24` are tested with the Miller–Rabin test to the first 13
+    /// primes as bases, which is exact. Larger numbers are tested with the
+    /// Baillie–PSW test: the Miller–Rabin test to base 2 followed by the
+    /// strong Lucas test. No composite is known to pass it, but it isn't
+    /// proven exact either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastnum::u256;
+    ///
+    /// assert!(u256!(170141183460469231731687303715884105727).is_probable_prime());
+    /// assert!(!u256!(561).is_probable_prime());
+    /// ```
+    #[inline]
+    pub const fn is_probable_prime(self) -> bool {
+        prime::is_probable_prime(self)
+    }
+}
+
+/// Shifts `value` left by `rhs < BITS` bits.
+#[inline]
+const fn shl<const N: usize>(value: UInt<N>, rhs: u32) -> UInt<N> {
+    let digit_shift = (rhs / DIGIT_BITS) as usize;
+    let bit_shift = rhs % DIGIT_BITS;
+
+    let digits = value.digits();
+    let mut out = [0; N];
+    let mut i = digit_shift;
+
+    while i < N {
+        out[i] = digits[i - digit_shift] << bit_shift;
+
+        if bit_shift > 0 && i > digit_shift {
+            out[i] |= digits[i - digit_shift - 1] >> (DIGIT_BITS - bit_shift);
+        }
+
+        i += 1;
+    }
+
+    UInt::from_digits(out)
+}
+
+/// Shifts `value` right by `rhs < BITS` bits.
+#[inline]
+const fn shr<const N: usize>(value: UInt<N>, rhs: u32) -> UInt<N> {
+    let digit_shift = (rhs / DIGIT_BITS) as usize;
+    let bit_shift = rhs % DIGIT_BITS;
+
+    let digits = value.digits();
+    let mut out = [0; N];
+    let mut i = 0;
+
+    while i + digit_shift < N {
+        out[i] = digits[i + digit_shift] >> bit_shift;
+
+        if bit_shift > 0 && i + digit_shift + 1 < N {
+            out[i] |= digits[i + digit_shift + 1] << (DIGIT_BITS - bit_shift);
+        }
+
+        i += 1;
+    }
+
+    UInt::from_digits(out)
+}
 
 macro_rules! uint_types {
     ( $($bits: literal $u: ident; ) *)  => {
@@ -0,0 +1,169 @@
+use crate::int::UInt;
+
+type Digit = u64;
+type DoubleDigit = u128;
+
+/// Integers with at least this many digits are added with the carry
+/// lookahead. Shorter ones are added digit by digit, which compiles into a
+/// single chain of add-with-carry instructions.
+///
+/// The lookahead only pays off if the digit-wise sums and comparisons are
+/// vectorized, which needs 64-bit lane compares of AVX2. Without them the
+/// carry chain is always faster.
+const LOOKAHEAD_THRESHOLD: usize = if cfg!(target_feature = "avx2") {
+    32
+} else {
+    usize::MAX
+};
+
+/// Number of digits whose carries are resolved at once: one bit per digit of
+/// a carry mask.
+const LANES: usize = Digit::BITS as usize;
+
+/// Calculates `lhs + rhs` and returns the sum along with the carried out
+/// bit.
+#[inline(always)]
+pub const fn overflowing_add<const N: usize>(lhs: &UInt<N>, rhs: &UInt<N>) -> (UInt<N>, bool) {
+    let lhs = lhs.digits();
+    let rhs = rhs.digits();
+
+    // The result is written in place, which saves a copy of the digits.
+    let mut sum = UInt::ZERO;
+    let out = sum.digits_mut();
+
+    if N < LOOKAHEAD_THRESHOLD {
+        let mut carry = 0;
+        let mut i = 0;
+
+        // The double digit accumulator compiles into add-with-carry.
+        while i < N {
+            let s = lhs[i] as DoubleDigit + rhs[i] as DoubleDigit + carry;
+
+            out[i] = s as Digit;
+            carry = s >> Digit::BITS;
+            i += 1;
+        }
+
+        return (sum, carry != 0);
+    }
+
+    let mut carry = false;
+    let mut start = 0;
+
+    while start < N {
+        let len = if N - start < LANES { N - start } else { LANES };
+
+        // Digits are summed independently, so the loop vectorizes. Each digit
+        // either generates a carry or propagates the incoming one if the sum
+        // is all ones (never both).
+        let mut generate: Digit = 0;
+        let mut propagate: Digit = 0;
+        let mut i = 0;
+
+        while i < len {
+            let (s, c) = lhs[start + i].overflowing_add(rhs[start + i]);
+
+            out[start + i] = s;
+            generate |= (c as Digit) << i;
+            propagate |= ((s == Digit::MAX) as Digit) << i;
+            i += 1;
+        }
+
+        let carries;
+        (carries, carry) = resolve(generate, propagate, carry, len);
+
+        i = 0;
+        while i < len {
+            out[start + i] = out[start + i].wrapping_add((carries >> i) & 1);
+            i += 1;
+        }
+
+        start += len;
+    }
+
+    (sum, carry)
+}
+
+/// Calculates `lhs - rhs` and returns the difference along with the borrowed
+/// out bit.
+#[inline(always)]
+pub const fn overflowing_sub<const N: usize>(lhs: &UInt<N>, rhs: &UInt<N>) -> (UInt<N>, bool) {
+    let lhs = lhs.digits();
+    let rhs = rhs.digits();
+
+    let mut diff = UInt::ZERO;
+    let out = diff.digits_mut();
+
+    if N < LOOKAHEAD_THRESHOLD {
+        let mut borrow = 0;
+        let mut i = 0;
+
+        // The borrow is the low bit of the wrapped high half.
+        while i < N {
+            let d = (lhs[i] as DoubleDigit).wrapping_sub(rhs[i] as DoubleDigit + borrow);
+
+            out[i] = d as Digit;
+            borrow = (d >> Digit::BITS) & 1;
+            i += 1;
+        }
+
+        return (diff, borrow != 0);
+    }
+
+    let mut borrow = false;
+    let mut start = 0;
+
+    while start < N {
+        let len = if N - start < LANES { N - start } else { LANES };
+
+        // Same as the addition: a digit generates a borrow or propagates the
+        // incoming one if the difference is zero.
+        let mut generate: Digit = 0;
+        let mut propagate: Digit = 0;
+        let mut i = 0;
+
+        while i < len {
+            let (d, b) = lhs[start + i].overflowing_sub(rhs[start + i]);
+
+            out[start + i] = d;
+            generate |= (b as Digit) << i;
+            propagate |= ((d == 0) as Digit) << i;
+            i += 1;
+        }
+
+        let borrows;
+        (borrows, borrow) = resolve(generate, propagate, borrow, len);
+
+        i = 0;
+        while i < len {
+            out[start + i] = out[start + i].wrapping_sub((borrows >> i) & 1);
+            i += 1;
+        }
+
+        start += len;
+    }
+
+    (diff, borrow)
+}
+
+/// Resolves the carries of `len` digits at once.
+///
+/// Bit `i` of `generate` and `propagate` tells whether digit `i` generates a
+/// carry or passes the incoming one through. Returns the mask of carries into
+/// every digit along with the carry out of the last one.
+///
+/// A single addition ripples the generated carries through the runs of
+/// propagating digits.
+#[inline(always)]
+const fn resolve(generate: Digit, propagate: Digit, carry: bool, len: usize) -> (Digit, bool) {
+    let (resolved, overflow) = ((generate << 1) | carry as Digit).overflowing_add(propagate);
+    let carries = resolved ^ propagate;
+
+    let carry = if len == LANES {
+        (generate >> (LANES - 1)) != 0 || overflow
+    } else {
+        (carries >> len) & 1 != 0
+    };
+
+    (carries, carry)
+}
@@ -0,0 +1,571 @@
+use crate::int::mul::{fill_zero, flatten_mut, mul_into, significant};
+
+type Digit = u64;
+type DoubleDigit = u128;
+
+const BITS: u32 = Digit::BITS;
+
+/// Divisions where both the divisor and the quotient have at least this many
+/// digits are done recursively. Below it the schoolbook division is faster.
+const DIVIDE_AND_CONQUER_THRESHOLD: usize = 48;
+
+/// Precomputed reciprocal of a single digit divisor for the division by an
+/// invariant integer (Möller, Granlund, "Improved division by invariant
+/// integers", 2011).
+///
+/// Dividing by a reciprocal takes two multiplications instead of the hardware
+/// division, which pays off if the same divisor is used more than once.
+#[derive(Copy, Clone)]
+pub(crate) struct Reciprocal {
+    /// Divisor shifted left to have the most significant bit set.
+    divisor: Digit,
+    reciprocal: Digit,
+    shift: u32,
+}
+
+impl Reciprocal {
+    pub const TEN: Self = Self::new(10);
+    pub const TEN_POW_19: Self = Self::new(10_000_000_000_000_000_000);
+
+    /// Precomputes the reciprocal of a nonzero `divisor`.
+    #[inline]
+    pub const fn new(divisor: Digit) -> Self {
+        let shift = divisor.leading_zeros();
+        let divisor = divisor << shift;
+        let reciprocal = (DoubleDigit::MAX / divisor as DoubleDigit - (1 << BITS)) as Digit;
+
+        Self {
+            divisor,
+            reciprocal,
+            shift,
+        }
+    }
+
+    /// Divides `high:low` by the divisor. `high` must be less than the divisor.
+    #[inline(always)]
+    pub const fn div_rem(&self, low: Digit, high: Digit) -> (Digit, Digit) {
+        let (quotient, remainder) = if self.shift == 0 {
+            self.div_rem_normalized(low, high)
+        } else {
+            self.div_rem_normalized(
+                low << self.shift,
+                (high << self.shift) | (low >> (BITS - self.shift)),
+            )
+        };
+
+        (quotient, remainder >> self.shift)
+    }
+
+    /// Divides `remainder:digits` by the divisor and returns the quotient and
+    /// the remainder. `remainder` must be less than the divisor.
+    #[inline]
+    pub const fn div_rem_digits<const N: usize>(
+        &self,
+        digits: &[Digit; N],
+        remainder: Digit,
+    ) -> ([Digit; N], Digit) {
+        let mut quotient = [0; N];
+        let mut remainder = remainder << self.shift;
+        let mut i = N;
+
+        if self.shift == 0 {
+            while i > 0 {
+                i -= 1;
+                (quotient[i], remainder) = self.div_rem_normalized(digits[i], remainder);
+            }
+
+            return (quotient, remainder);
+        }
+
+        // Divide `digits << shift` by the normalized divisor.
+        remainder |= digits[N - 1] >> (BITS - self.shift);
+
+        while i > 1 {
+            i -= 1;
+            let digit = (digits[i] << self.shift) | (digits[i - 1] >> (BITS - self.shift));
+            (quotient[i], remainder) = self.div_rem_normalized(digit, remainder);
+        }
+
+        (quotient[0], remainder) = self.div_rem_normalized(digits[0] << self.shift, remainder);
+
+        (quotient, remainder >> self.shift)
+    }
+
+    /// Divides `high:low` by the normalized divisor. `high` must be less than
+    /// the divisor.
+    #[inline(always)]
+    const fn div_rem_normalized(&self, low: Digit, high: Digit) -> (Digit, Digit) {
+        debug_assert!(high < self.divisor);
+
+        let product = self.reciprocal as DoubleDigit * high as DoubleDigit;
+        let (product, _) =
+            product.overflowing_add(((high as DoubleDigit) << BITS) | low as DoubleDigit);
+
+        let mut quotient = ((product >> BITS) as Digit).wrapping_add(1);
+        let mut remainder = low.wrapping_sub(quotient.wrapping_mul(self.divisor));
+
+        if remainder > product as Digit {
+            quotient = quotient.wrapping_sub(1);
+            remainder = remainder.wrapping_add(self.divisor);
+        }
+
+        if remainder >= self.divisor {
+            quotient += 1;
+            remainder -= self.divisor;
+        }
+
+        (quotient, remainder)
+    }
+}
+
+/// Divides `dividend` by a nonzero `divisor` and returns the quotient and the
+/// remainder.
+#[inline]
+pub const fn div_rem<const N: usize>(
+    dividend: &[Digit; N],
+    divisor: &[Digit; N],
+) -> ([Digit; N], [Digit; N]) {
+    let mut quotient = [0; N];
+    let mut remainder = [0; N];
+    let mut u = [[0; N]; 2];
+
+    div_rem_slices::<N>(
+        &mut quotient,
+        &mut remainder,
+        dividend,
+        divisor,
+        flatten_mut(&mut u),
+    );
+
+    (quotient, remainder)
+}
+
+/// Divides the double-width integer `high:low` by `divisor` and returns the
+/// quotient and the remainder.
+///
+/// `high` must be less than `divisor`, so that the quotient fits into `N`
+/// digits.
+#[inline]
+pub const fn div_rem_double<const N: usize>(
+    low: &[Digit; N],
+    high: &[Digit; N],
+    divisor: &[Digit; N],
+) -> ([Digit; N], [Digit; N]) {
+    let mut dividend = [*low, *high];
+    let mut quotient = [[0; N]; 2];
+    let mut remainder = [0; N];
+    let mut u = [[0; N]; 3];
+
+    div_rem_slices::<N>(
+        flatten_mut(&mut quotient),
+        &mut remainder,
+        flatten_mut(&mut dividend),
+        divisor,
+        flatten_mut(&mut u),
+    );
+
+    debug_assert!(significant(&quotient[1]).is_empty());
+    (quotient[0], remainder)
+}
+
+/// Writes the quotient and the remainder of `dividend / divisor` into the
+/// front of `quotient` and `remainder`.
+///
+/// `u` must be at least one digit longer than `dividend`.
+const fn div_rem_slices<const N: usize>(
+    quotient: &mut [Digit],
+    remainder: &mut [Digit],
+    dividend: &[Digit],
+    divisor: &[Digit],
+    u: &mut [Digit],
+) {
+    let dividend = significant(dividend);
+    let divisor = significant(divisor);
+    let n = divisor.len();
+
+    fill_zero(quotient);
+    fill_zero(remainder);
+
+    if dividend.len() < n {
+        copy(remainder, dividend);
+        return;
+    }
+
+    if n == 1 {
+        let reciprocal = Reciprocal::new(divisor[0]);
+        let mut rem = 0;
+        let mut i = dividend.len();
+
+        while i > 0 {
+            i -= 1;
+            (quotient[i], rem) = reciprocal.div_rem(dividend[i], rem);
+        }
+
+        remainder[0] = rem;
+        return;
+    }
+
+    // Normalize the divisor to have the most significant bit set. The
+    // normalized dividend gets one more digit, so that its top `n` digits are
+    // less than the divisor.
+    let shift = divisor[n - 1].leading_zeros();
+
+    let mut v = [0; N];
+    let v = v.split_at_mut(n).0;
+    shl(v, divisor, shift);
+
+    let u = u.split_at_mut(dividend.len() + 1).0;
+    shl(u, dividend, shift);
+
+    let quotient = quotient.split_at_mut(u.len() - n).0;
+
+    let top = if n < DIVIDE_AND_CONQUER_THRESHOLD || u.len() - n < DIVIDE_AND_CONQUER_THRESHOLD {
+        basecase_div_rem(quotient, u, v)
+    } else {
+        let mut scratch = [[0; N]; 10];
+        div_rem_in_place(quotient, u, v, flatten_mut(&mut scratch))
+    };
+
+    debug_assert!(!top);
+    shr(remainder, u.split_at(n).0, shift);
+}
+
+/// Divides `u` by the normalized divisor `v` in place.
+///
+/// Writes `u.len() - v.len()` quotient digits into `q` and leaves the
+/// remainder in the low `v.len()` digits of `u`. Returns the carried out top
+/// digit of the quotient, which is either `0` or `1`.
+///
+/// `scratch` must be at least `10 * v.len()` digits long.
+const fn div_rem_in_place(
+    q: &mut [Digit],
+    u: &mut [Digit],
+    v: &[Digit],
+    scratch: &mut [Digit],
+) -> bool {
+    let n = v.len();
+    let qn = u.len() - n;
+
+    if n < DIVIDE_AND_CONQUER_THRESHOLD || qn < DIVIDE_AND_CONQUER_THRESHOLD {
+        return basecase_div_rem(q, u, v);
+    }
+
+    if qn <= n {
+        return div_rem_short(q, u, v, scratch);
+    }
+
+    // Produce the quotient by blocks of `n` digits from the top, starting with
+    // the remaining shorter block.
+    let mut pos = qn % n;
+
+    if pos == 0 {
+        pos = n;
+    }
+
+    pos = qn - pos;
+
+    let top = div_rem_short(q.split_at_mut(pos).1, u.split_at_mut(pos).1, v, scratch);
+
+    while pos > 0 {
+        pos -= n;
+
+        let carry = div_rem_short(
+            q.split_at_mut(pos).1.split_at_mut(n).0,
+            u.split_at_mut(pos).1.split_at_mut(2 * n).0,
+            v,
+            scratch,
+        );
+        debug_assert!(!carry);
+    }
+
+    top
+}
+
+/// Divides `u` of at most `2 * v.len()` digits by the normalized divisor `v`
+/// in place.
+///
+/// If the quotient is as long as the divisor, it is found by halves (Burnikel,
+/// Ziegler, "Fast recursive division", 1998).
+const fn div_rem_short(
+    q: &mut [Digit],
+    u: &mut [Digit],
+    v: &[Digit],
+    scratch: &mut [Digit],
+) -> bool {
+    let n = v.len();
+    let k = q.len();
+
+    debug_assert!(k <= n && u.len() == n + k);
+
+    if k < n {
+        return div_rem_upper(q, u, v, scratch);
+    }
+
+    let lo = n / 2;
+
+    let top = div_rem_upper(q.split_at_mut(lo).1, u.split_at_mut(lo).1, v, scratch);
+    let carry = div_rem_upper(q.split_at_mut(lo).0, u.split_at_mut(n + lo).0, v, scratch);
+    debug_assert!(!carry);
+
+    top
+}
+
+/// Divides `u` of `v.len() + q.len()` digits by the normalized divisor `v` in
+/// place, where the quotient is not longer than the divisor.
+///
+/// The quotient is estimated by dividing the top `2 * q.len()` digits of `u` by
+/// the top `q.len()` digits of `v`. Then the product of the estimate and the
+/// rest of `v` is subtracted from the remainder, which makes the estimate
+/// exact after at most two corrections.
+const fn div_rem_upper(
+    q: &mut [Digit],
+    u: &mut [Digit],
+    v: &[Digit],
+    scratch: &mut [Digit],
+) -> bool {
+    let n = v.len();
+    let k = q.len();
+
+    if k < DIVIDE_AND_CONQUER_THRESHOLD {
+        return basecase_div_rem(q, u, v);
+    }
+
+    let (t, scratch) = scratch.split_at_mut(n);
+    let (v_low, v_high) = v.split_at(n - k);
+
+    let mut top = div_rem_in_place(q, u.split_at_mut(n - k).1, v_high, scratch) as Digit;
+
+    if v_low.is_empty() {
+        return top != 0;
+    }
+
+    // Subtract `top:q * v_low` from the remainder.
+    mul_into(t, q, v_low, scratch);
+
+    let u = u.split_at_mut(n).0;
+    let mut borrow = sub_assign(u, t) as Digit;
+
+    if top != 0 {
+        borrow += sub_assign(u.split_at_mut(k).1, v_low) as Digit;
+    }
+
+    while borrow > 0 {
+        top -= decrement(q) as Digit;
+        borrow -= add_assign(u, v) as Digit;
+    }
+
+    top != 0
+}
+
+/// Divides `u` by the normalized divisor `v` of at least two digits in place
+/// digit by digit (Knuth, "The Art of Computer Programming", vol. 2, 4.3.1,
+/// algorithm D).
+const fn basecase_div_rem(q: &mut [Digit], u: &mut [Digit], v: &[Digit]) -> bool {
+    let n = v.len();
+    let m = u.len() - n;
+
+    debug_assert!(n >= 2 && v[n - 1] >> (BITS - 1) == 1);
+
+    let top = !lt(u.split_at(m).1, v);
+
+    if top {
+        sub_assign(u.split_at_mut(m).1, v);
+    }
+
+    let reciprocal = Reciprocal::new(v[n - 1]);
+    let v_1 = v[n - 1];
+    let v_0 = v[n - 2];
+
+    let mut j = m;
+
+    while j > 0 {
+        j -= 1;
+
+        // The remainder window, whose top `n` digits are less than `v`.
+        let w = u.split_at_mut(j).1.split_at_mut(n + 1).0;
+
+        let (mut q_hat, r_hat) = if w[n] < v_1 {
+            let (q_hat, r_hat) = reciprocal.div_rem_normalized(w[n - 1], w[n]);
+            (q_hat, Some(r_hat))
+        } else {
+            // The estimate `(w[n]:w[n - 1]) / v_1` doesn't fit into a digit.
+            let (r_hat, overflow) = w[n - 1].overflowing_add(v_1);
+            (Digit::MAX, if overflow { None } else { Some(r_hat) })
+        };
+
+        // Refine the estimate by the second digit of the divisor: `q_hat` is
+        // then either exact or one too large.
+        if let Some(mut r_hat) = r_hat {
+            while (q_hat as DoubleDigit * v_0 as DoubleDigit)
+                > (((r_hat as DoubleDigit) << BITS) | w[n - 2] as DoubleDigit)
+            {
+                q_hat -= 1;
+
+                let overflow;
+                (r_hat, overflow) = r_hat.overflowing_add(v_1);
+
+                if overflow {
+                    break;
+                }
+            }
+        }
+
+        if sub_mul_digit(w, v, q_hat) {
+            q_hat -= 1;
+            let carry = add_assign(w.split_at_mut(n).0, v);
+            w[n] = w[n].wrapping_add(carry as Digit);
+        }
+
+        q[j] = q_hat;
+    }
+
+    top
+}
+
+/// Subtracts `v * digit` from `u`, which is one digit longer than `v`, and
+/// returns whether the result is negative.
+#[inline]
+const fn sub_mul_digit(u: &mut [Digit], v: &[Digit], digit: Digit) -> bool {
+    let n = v.len();
+    let u = u.split_at_mut(n + 1).0;
+
+    let mut carry: Digit = 0;
+    let mut i = 0;
+
+    while i < n {
+        let product = digit as DoubleDigit * v[i] as DoubleDigit + carry as DoubleDigit;
+        let (diff, borrow) = u[i].overflowing_sub(product as Digit);
+
+        u[i] = diff;
+        carry = (product >> BITS) as Digit + borrow as Digit;
+        i += 1;
+    }
+
+    let (diff, borrow) = u[n].overflowing_sub(carry);
+    u[n] = diff;
+
+    borrow
+}
+
+/// Adds `rhs` to `lhs` of the same length in place and returns the carry.
+#[inline]
+const fn add_assign(lhs: &mut [Digit], rhs: &[Digit]) -> bool {
+    let rhs = rhs.split_at(lhs.len()).0;
+
+    let mut carry = false;
+    let mut i = 0;
+
+    while i < rhs.len() {
+        let (sum, c1) = lhs[i].overflowing_add(rhs[i]);
+        let (sum, c2) = sum.overflowing_add(carry as Digit);
+
+        lhs[i] = sum;
+        carry = c1 || c2;
+        i += 1;
+    }
+
+    carry
+}
+
+/// Subtracts `rhs` from `lhs` of the same length in place and returns the
+/// borrow.
+#[inline]
+const fn sub_assign(lhs: &mut [Digit], rhs: &[Digit]) -> bool {
+    let rhs = rhs.split_at(lhs.len()).0;
+
+    let mut borrow = false;
+    let mut i = 0;
+
+    while i < rhs.len() {
+        let (diff, b1) = lhs[i].overflowing_sub(rhs[i]);
+        let (diff, b2) = diff.overflowing_sub(borrow as Digit);
+
+        lhs[i] = diff;
+        borrow = b1 || b2;
+        i += 1;
+    }
+
+    borrow
+}
+
+/// Subtracts one from `digits` in place and returns the borrow.
+#[inline]
+const fn decrement(digits: &mut [Digit]) -> bool {
+    let mut i = 0;
+
+    while i < digits.len() {
+        let (diff, borrow) = digits[i].overflowing_sub(1);
+        digits[i] = diff;
+
+        if !borrow {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+/// Returns whether `lhs < rhs` for slices of the same length.
+#[inline]
+const fn lt(lhs: &[Digit], rhs: &[Digit]) -> bool {
+    let mut i = lhs.len();
+
+    while i > 0 {
+        i -= 1;
+
+        if lhs[i] != rhs[i] {
+            return lhs[i] < rhs[i];
+        }
+    }
+
+    false
+}
+
+/// Writes `digits << shift` into `out`, which may be one digit longer.
+#[inline]
+const fn shl(out: &mut [Digit], digits: &[Digit], shift: u32) {
+    let mut carry = 0;
+    let mut i = 0;
+
+    while i < digits.len() {
+        out[i] = (digits[i] << shift) | carry;
+        carry = if shift == 0 {
+            0
+        } else {
+            digits[i] >> (BITS - shift)
+        };
+        i += 1;
+    }
+
+    if i < out.len() {
+        out[i] = carry;
+    }
+}
+
+/// Writes `digits >> shift` into the front of `out`.
+#[inline]
+const fn shr(out: &mut [Digit], digits: &[Digit], shift: u32) {
+    let mut i = 0;
+
+    while i < digits.len() {
+        out[i] = digits[i] >> shift;
+
+        if shift > 0 && i + 1 < digits.len() {
+            out[i] |= digits[i + 1] << (BITS - shift);
+        }
+
+        i += 1;
+    }
+}
+
+#[inline]
+const fn copy(out: &mut [Digit], digits: &[Digit]) {
+    let mut i = 0;
+
+    while i < digits.len() {
+        out[i] = digits[i];
+        i += 1;
+    }
+}
@@ -0,0 +1,309 @@
+use core::fmt::{self, Formatter};
+
+use crate::{
+    int::{div::Reciprocal, math::div_rem, pow10::Pow10, UInt},
+    utils::err_msg,
+};
+
+/// Number of decimal digits extracted by a single-limb division: `10^19` is the
+/// largest power of ten which fits into a 64-bit limb.
+const CHUNK_DIGITS: usize = 19;
+const CHUNK_POWER: u64 = 10_u64.pow(CHUNK_DIGITS as u32);
+
+/// Values with more significant limbs are split in halves by a power of ten
+/// before being converted chunk by chunk. Below this size a single chunked
+/// pass is at least as fast as the division needed to split the value.
+const DIVIDE_AND_CONQUER_THRESHOLD: usize = 32;
+
+/// ASCII representations of all two-digit numbers `00..=99`.
+const DIGIT_PAIRS: [u8; 200] = digit_pairs();
+
+/// Writes the decimal digits of `value` into the front of `buf` and returns
+/// their count.
+///
+/// Zero is written as a single `0` digit.
+#[inline]
+pub const fn write_digits<const N: usize>(value: UInt<N>, buf: &mut [u8]) -> usize {
+    let len = if value.is_zero() {
+        1
+    } else {
+        Pow10::<N>::digits_count(value) as usize
+    };
+
+    write_padded(value, buf, len, len);
+    len
+}
+
+/// Formats the decimal digits of `value` with the sign given by
+/// `nonnegative`, honouring the width, fill and sign flags of `f`.
+pub fn fmt_decimal<const N: usize>(
+    value: UInt<N>,
+    nonnegative: bool,
+    f: &mut Formatter,
+) -> fmt::Result {
+    let mut buf = [[0; 20]; N];
+    let buf = buf.as_flattened_mut();
+    let len = write_digits(value, buf);
+
+    f.pad_integral(nonnegative, "", as_str(&buf[..len]))
+}
+
+/// Formats the digits of `value` in a power-of-two `radix` with the given
+/// alternate form `prefix`.
+pub fn fmt_radix<const N: usize>(
+    value: UInt<N>,
+    radix: u32,
+    upper: bool,
+    prefix: &str,
+    f: &mut Formatter,
+) -> fmt::Result {
+    // A digit takes at least a bit.
+    let mut buf = [[0; 64]; N];
+    let buf = buf.as_flattened_mut();
+    let len = write_radix(value, radix, upper, buf);
+
+    f.pad_integral(true, prefix, as_str(&buf[..len]))
+}
+
+/// Formats `value` in scientific notation with trailing zeros of the
+/// significand dropped, as primitive integers do: `1200` is `1.2e3`.
+pub fn fmt_exp<const N: usize>(
+    value: UInt<N>,
+    nonnegative: bool,
+    symbol: u8,
+    f: &mut Formatter,
+) -> fmt::Result {
+    // Digits, the decimal point and the exponent suffix of at most 5 bytes.
+    let mut buf = [[0; 24]; N];
+    let buf = buf.as_flattened_mut();
+
+    let len = write_digits(value, &mut buf[1..]);
+
+    let mut end = len;
+    while end > 1 && buf[end] == b'0' {
+        end -= 1;
+    }
+
+    // Move the leading digit before the decimal point.
+    buf[0] = buf[1];
+
+    let mut pos = if end > 1 {
+        buf[1] = b'.';
+        end + 1
+    } else {
+        1
+    };
+
+    buf[pos] = symbol;
+    pos += 1;
+    pos += write_digits(UInt::<1>::from_digit(len as u64 - 1), &mut buf[pos..]);
+
+    f.pad_integral(nonnegative, "", as_str(&buf[..pos]))
+}
+
+#[inline]
+pub const fn as_str(buf: &[u8]) -> &str {
+    // SAFETY: only ASCII digits are ever written into formatting buffers.
+    #[allow(unsafe_code)]
+    unsafe {
+        core::str::from_utf8_unchecked(buf)
+    }
+}
+
+/// Returns an upper bound of the number of digits of `value` in `radix`.
+pub const fn radix_len<const N: usize>(value: UInt<N>, radix: u32) -> usize {
+    let bits = if value.is_zero() { 1 } else { value.bits() };
+    bits.div_ceil(radix.ilog2()) as usize
+}
+
+/// Writes the digits of `value` in `radix` into the front of `buf` and
+/// returns their count. Digits above `9` are letters in the given case.
+///
+/// `buf` must hold at least [`radix_len`] bytes.
+pub const fn write_radix<const N: usize>(
+    value: UInt<N>,
+    radix: u32,
+    upper: bool,
+    buf: &mut [u8],
+) -> usize {
+    assert!(
+        radix >= 2 && radix <= 36,
+        err_msg!("radix must lie in the range `[2, 36]`")
+    );
+
+    if radix == 10 {
+        return write_digits(value, buf);
+    }
+
+    if value.is_zero() {
+        buf[0] = b'0';
+        return 1;
+    }
+
+    if radix.is_power_of_two() {
+        let width = radix.trailing_zeros();
+        let len = value.bits().div_ceil(width) as usize;
+        let mut i = 0;
+
+        // Every digit is a group of `width` bits.
+        while i < len {
+            let digit = bit_group(value.digits(), ((len - 1 - i) as u32) * width, width);
+            buf[i] = ascii_digit(digit, upper);
+            i += 1;
+        }
+
+        return len;
+    }
+
+    // Peel off the largest power of the radix fitting into a limb at a time,
+    // writing the digits from the least significant one, then reverse them.
+    let mut chunk_digits = 1;
+    let mut chunk_power = radix as u64;
+
+    while let Some(power) = chunk_power.checked_mul(radix as u64) {
+        chunk_power = power;
+        chunk_digits += 1;
+    }
+
+    let reciprocal = Reciprocal::new(chunk_power);
+    let mut digits = *value.digits();
+    let mut len = 0;
+
+    loop {
+        let (quotient, mut remainder) = reciprocal.div_rem_digits(&digits, 0);
+        let last = UInt::from_digits(quotient).is_zero();
+        let mut count = 0;
+
+        while (last && remainder != 0) || (!last && count < chunk_digits) {
+            buf[len] = ascii_digit(remainder % radix as u64, upper);
+            remainder /= radix as u64;
+            len += 1;
+            count += 1;
+        }
+
+        if last {
+            break;
+        }
+
+        digits = quotient;
+    }
+
+    let mut i = 0;
+
+    while i < len / 2 {
+        let tmp = buf[i];
+        buf[i] = buf[len - 1 - i];
+        buf[len - 1 - i] = tmp;
+        i += 1;
+    }
+
+    len
+}
+
+/// Returns `width <= 8` bits of `digits` starting at bit `start`.
+#[inline]
+const fn bit_group<const N: usize>(digits: &[u64; N], start: u32, width: u32) -> u64 {
+    let index = (start / u64::BITS) as usize;
+    let shift = start % u64::BITS;
+    let mut group = digits[index] >> shift;
+
+    if shift + width > u64::BITS && index + 1 < N {
+        group |= digits[index + 1] << (u64::BITS - shift);
+    }
+
+    group & ((1 << width) - 1)
+}
+
+#[inline]
+const fn ascii_digit(digit: u64, upper: bool) -> u8 {
+    if digit < 10 {
+        b'0' + digit as u8
+    } else if upper {
+        b'A' + (digit - 10) as u8
+    } else {
+        b'a' + (digit - 10) as u8
+    }
+}
+
+/// Writes exactly `width` decimal digits of `value` padded with leading zeros
+/// into `buf[end - width..end]`.
+///
+/// `value` must be less than `10^width`.
+const fn write_padded<const N: usize>(value: UInt<N>, buf: &mut [u8], end: usize, width: usize) {
+    let limbs = value.bits().div_ceil(u64::BITS) as usize;
+
+    if limbs > DIVIDE_AND_CONQUER_THRESHOLD {
+        let split = width / 2;
+        let (high, low) = div_rem(value, Pow10::<N>::power(split as u32));
+
+        write_padded(high, buf, end - split, width - split);
+        write_padded(low, buf, end, split);
+        return;
+    }
+
+    let start = end - width;
+    let mut end = end;
+    let mut digits = *value.digits();
+    let mut top = limbs;
+
+    // Peel off 19 digits at a time while the value spans several limbs.
+    while top > 1 {
+        let mut remainder = 0;
+        let mut i = top;
+
+        while i > 0 {
+            i -= 1;
+            (digits[i], remainder) = Reciprocal::TEN_POW_19.div_rem(digits[i], remainder);
+        }
+
+        if digits[top - 1] == 0 {
+            top -= 1;
+        }
+
+        write_chunk(remainder, buf, end, CHUNK_DIGITS);
+        end -= CHUNK_DIGITS;
+    }
+
+    // At most 20 digits of a single limb are left.
+    let mut last = digits[0];
+
+    if last >= CHUNK_POWER {
+        write_chunk(last % CHUNK_POWER, buf, end, CHUNK_DIGITS);
+        end -= CHUNK_DIGITS;
+        last /= CHUNK_POWER;
+    }
+
+    write_chunk(last, buf, end, end - start);
+}
+
+/// Writes exactly `count` least significant decimal digits of `chunk` into
+/// `buf[end - count..end]`.
+#[inline]
+const fn write_chunk(mut chunk: u64, buf: &mut [u8], mut end: usize, mut count: usize) {
+    while count >= 2 {
+        let pair = (chunk % 100) as usize * 2;
+        chunk /= 100;
+        end -= 2;
+        count -= 2;
+
+        buf[end] = DIGIT_PAIRS[pair];
+        buf[end + 1] = DIGIT_PAIRS[pair + 1];
+    }
+
+    if count == 1 {
+        buf[end - 1] = b'0' + (chunk % 10) as u8;
+    }
+}
+
+const fn digit_pairs() -> [u8; 200] {
+    let mut pairs = [0; 200];
+    let mut i = 0;
+
+    while i < 100 {
+        pairs[2 * i] = b'0' + (i / 10) as u8;
+        pairs[2 * i + 1] = b'0' + (i % 10) as u8;
+        i += 1;
+    }
+
+    pairs
+}
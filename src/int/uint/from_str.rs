@@ -0,0 +1,113 @@
+use crate::{
+    int::{math::mul_add_digit, ParseError, UInt},
+    utils::err_msg,
+};
+
+/// Parses an unsigned integer with an optional `+` sign in `radix`.
+#[inline]
+pub const fn from_str_radix<const N: usize>(
+    bytes: &[u8],
+    radix: u32,
+) -> Result<UInt<N>, ParseError> {
+    match bytes {
+        [] => Err(ParseError::Empty),
+        [b'+', digits @ ..] => from_digits_radix(digits, radix),
+        digits => from_digits_radix(digits, radix),
+    }
+}
+
+/// Parses unsigned digits in `radix`.
+#[inline]
+pub const fn from_digits_radix<const N: usize>(
+    bytes: &[u8],
+    radix: u32,
+) -> Result<UInt<N>, ParseError> {
+    parse_digits(bytes, radix, false)
+}
+
+/// Parses the digits of an unsigned integer literal: an optional `0x`, `0o`
+/// or `0b` radix prefix followed by digits, which may be separated by
+/// underscores as in Rust literals.
+#[inline]
+pub const fn from_literal<const N: usize>(bytes: &[u8]) -> Result<UInt<N>, ParseError> {
+    match bytes {
+        [b'0', b'x', digits @ ..] => parse_digits(digits, 16, true),
+        [b'0', b'o', digits @ ..] => parse_digits(digits, 8, true),
+        [b'0', b'b', digits @ ..] => parse_digits(digits, 2, true),
+        digits => parse_digits(digits, 10, true),
+    }
+}
+
+/// Parses unsigned digits in `radix`, skipping underscores if `separators`
+/// is set.
+///
+/// Digits are accumulated into a limb as long as it doesn't overflow and
+/// then added to the result by a single multiplication.
+const fn parse_digits<const N: usize>(
+    bytes: &[u8],
+    radix: u32,
+    separators: bool,
+) -> Result<UInt<N>, ParseError> {
+    assert!(
+        radix >= 2 && radix <= 36,
+        err_msg!("radix must lie in the range `[2, 36]`")
+    );
+
+    let mut value = UInt::<N>::ZERO;
+    let mut empty = true;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let mut chunk = 0;
+        let mut power: u64 = 1;
+
+        while i < bytes.len() {
+            if separators && bytes[i] == b'_' {
+                i += 1;
+                continue;
+            }
+
+            let Some(next_power) = power.checked_mul(radix as u64) else {
+                break;
+            };
+
+            let Some(digit) = digit(bytes[i], radix) else {
+                return Err(ParseError::InvalidDigit);
+            };
+
+            chunk = chunk * radix as u64 + digit;
+            power = next_power;
+            empty = false;
+            i += 1;
+        }
+
+        let carry;
+        (value, carry) = mul_add_digit(value, power, chunk);
+
+        if carry != 0 {
+            return Err(ParseError::PosOverflow);
+        }
+    }
+
+    if empty {
+        return Err(ParseError::InvalidDigit);
+    }
+
+    Ok(value)
+}
+
+#[inline]
+const fn digit(byte: u8, radix: u32) -> Option<u64> {
+    let digit = match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'z' => byte - b'a' + 10,
+        b'A'..=b'Z' => byte - b'A' + 10,
+        _ => return None,
+    };
+
+    if (digit as u32) < radix {
+        Some(digit as u64)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,56 @@
+use crate::int::{math::div_rem, Int, UInt};
+
+/// Computes the greatest common divisor with the binary (Stein's) algorithm,
+/// which needs only shifts and subtractions.
+#[inline]
+pub const fn gcd<const N: usize>(mut a: UInt<N>, mut b: UInt<N>) -> UInt<N> {
+    if a.is_zero() {
+        return b;
+    }
+
+    if b.is_zero() {
+        return a;
+    }
+
+    let shift = a.bitor(b).trailing_zeros();
+    a = a.wrapping_shr(a.trailing_zeros());
+
+    loop {
+        b = b.wrapping_shr(b.trailing_zeros());
+
+        if a.gt(&b) {
+            (a, b) = (b, a);
+        }
+
+        b = b.wrapping_sub(a);
+
+        if b.is_zero() {
+            return a.wrapping_shl(shift);
+        }
+    }
+}
+
+/// Computes the greatest common divisor `g` and the Bézout coefficients `x`
+/// and `y` such that `a * x + b * y = g`.
+///
+/// The coefficients of the extended Euclidean algorithm satisfy `|x| <= b /
+/// 2g` and `|y| <= a / 2g`, so they always fit into `Int<N>`. The
+/// intermediate ones may not, but since only additions, subtractions and
+/// multiplications are applied to them, computing them modulo `2^BITS` still
+/// gives the exact final values.
+#[inline]
+pub const fn extended_gcd<const N: usize>(a: UInt<N>, b: UInt<N>) -> (UInt<N>, Int<N>, Int<N>) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (UInt::ONE, UInt::ZERO);
+    let (mut y0, mut y1) = (UInt::ZERO, UInt::ONE);
+
+    while !r1.is_zero() {
+        let (q, r) = div_rem(r0, r1);
+
+        (r0, r1) = (r1, r);
+        (x0, x1) = (x1, x0.wrapping_sub(q.wrapping_mul(x1)));
+        (y0, y1) = (y1, y0.wrapping_sub(q.wrapping_mul(y1)));
+    }
+
+    (r0, Int::from_bits(x0), Int::from_bits(y0))
+}
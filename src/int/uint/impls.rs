@@ -0,0 +1,17 @@
+// Trait implementations
+mod default;
+mod fmt;
+mod from;
+mod from_str;
+mod iter;
+mod ops;
+mod ord;
+
+#[cfg(feature = "numtraits")]
+mod numtraits;
+
+#[cfg(feature = "subtle")]
+mod subtle;
+
+#[cfg(feature = "zeroize")]
+mod zeroize;
@@ -0,0 +1,8 @@
+use crate::int::UInt;
+
+impl<const N: usize> Default for UInt<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
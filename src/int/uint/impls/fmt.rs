@@ -0,0 +1,52 @@
+use core::fmt::{
+    self, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
+};
+
+use crate::int::{
+    format::{fmt_decimal, fmt_exp, fmt_radix},
+    UInt,
+};
+
+impl<const N: usize> Display for UInt<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_decimal(*self, true, f)
+    }
+}
+
+impl<const N: usize> Debug for UInt<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+macro_rules! radix_impl {
+    ($Trait: ident, $radix: literal, $upper: literal, $prefix: literal) => {
+        impl<const N: usize> $Trait for UInt<N> {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                fmt_radix(*self, $radix, $upper, $prefix, f)
+            }
+        }
+    };
+}
+
+radix_impl!(Binary, 2, false, "0b");
+radix_impl!(Octal, 8, false, "0o");
+radix_impl!(LowerHex, 16, false, "0x");
+radix_impl!(UpperHex, 16, true, "0x");
+
+impl<const N: usize> LowerExp for UInt<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_exp(*self, true, b'e', f)
+    }
+}
+
+impl<const N: usize> UpperExp for UInt<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_exp(*self, true, b'E', f)
+    }
+}
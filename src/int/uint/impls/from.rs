@@ -0,0 +1,149 @@
+use crate::{
+    int::{math, Int, ParseError, UInt},
+    utils::err_msg,
+};
+
+impl<const N: usize> From<bool> for UInt<N> {
+    #[inline]
+    fn from(b: bool) -> Self {
+        Self::from_digit(b as u64)
+    }
+}
+
+macro_rules! from_uint {
+    ($($uint: ty),*) => {
+        $(
+            impl<const N: usize> From<$uint> for UInt<N> {
+                #[inline]
+                fn from(n: $uint) -> Self {
+                    Self::from_digit(n as u64)
+                }
+            }
+        )*
+    };
+}
+
+from_uint!(u8, u16, u32, u64);
+
+impl<const N: usize> From<u128> for UInt<N> {
+    /// Converts `u128` into the integer.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the value doesn't fit, which is only possible
+    /// for a single 64-bit digit.
+    #[track_caller]
+    #[inline]
+    fn from(n: u128) -> Self {
+        match math::from_u128(n) {
+            (value, false) => value,
+            (_, true) => panic!(err_msg!(
+                "attempt to convert integer too large to be represented by the target type"
+            )),
+        }
+    }
+}
+
+impl<const N: usize> From<usize> for UInt<N> {
+    #[inline]
+    fn from(n: usize) -> Self {
+        Self::from_digit(n as u64)
+    }
+}
+
+macro_rules! try_from_int {
+    ($($int: ty),*) => {
+        $(
+            impl<const N: usize> TryFrom<$int> for UInt<N> {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(n: $int) -> Result<Self, Self::Error> {
+                    if n < 0 {
+                        return Err(ParseError::NegOverflow);
+                    }
+
+                    match math::from_u128(n as u128) {
+                        (value, false) => Ok(value),
+                        (_, true) => Err(ParseError::PosOverflow),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+try_from_int!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! try_into_int {
+    ($($int: ty => $to: ident),*) => {
+        $(
+            impl<const N: usize> TryFrom<UInt<N>> for $int {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(n: UInt<N>) -> Result<Self, Self::Error> {
+                    math::$to(n).ok_or(ParseError::PosOverflow)
+                }
+            }
+        )*
+    };
+}
+
+try_into_int!(
+    u8 => to_u8,
+    u16 => to_u16,
+    u32 => to_u32,
+    u64 => to_u64,
+    u128 => to_u128,
+    usize => to_usize,
+    i8 => to_i8,
+    i16 => to_i16,
+    i32 => to_i32,
+    i64 => to_i64,
+    i128 => to_i128,
+    isize => to_isize
+);
+
+impl<const N: usize> TryFrom<Int<N>> for UInt<N> {
+    type Error = ParseError;
+
+    #[inline]
+    fn try_from(n: Int<N>) -> Result<Self, Self::Error> {
+        if n.is_negative() {
+            Err(ParseError::NegOverflow)
+        } else {
+            Ok(n.cast_unsigned())
+        }
+    }
+}
+
+macro_rules! resize_impl {
+    ($n: literal $(, $m: literal)*) => {
+        $(
+            impl From<UInt<$n>> for UInt<$m> {
+                #[inline]
+                fn from(n: UInt<$n>) -> Self {
+                    math::resize(n).0
+                }
+            }
+
+            impl TryFrom<UInt<$m>> for UInt<$n> {
+                type Error = ParseError;
+
+                #[inline]
+                fn try_from(n: UInt<$m>) -> Result<Self, Self::Error> {
+                    match math::resize(n) {
+                        (value, false) => Ok(value),
+                        (_, true) => Err(ParseError::PosOverflow),
+                    }
+                }
+            }
+        )*
+
+        resize_impl!($($m),*);
+    };
+    () => {};
+}
+
+resize_impl!(1, 2, 4, 8, 16, 32, 64, 128);
@@ -0,0 +1,12 @@
+use core::str::FromStr;
+
+use crate::int::{ParseError, UInt};
+
+impl<const N: usize> FromStr for UInt<N> {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
@@ -0,0 +1,31 @@
+use core::iter::{Product, Sum};
+
+use crate::int::UInt;
+
+impl<const N: usize> Sum for UInt<N> {
+    #[inline]
+    fn sum<I: Iterator<Item = UInt<N>>>(iter: I) -> UInt<N> {
+        iter.fold(UInt::<N>::ZERO, |a, b| a + b)
+    }
+}
+
+impl<'a, const N: usize> Sum<&'a UInt<N>> for UInt<N> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a UInt<N>>>(iter: I) -> UInt<N> {
+        iter.fold(UInt::<N>::ZERO, |a, b| a + *b)
+    }
+}
+
+impl<const N: usize> Product for UInt<N> {
+    #[inline]
+    fn product<I: Iterator<Item = UInt<N>>>(iter: I) -> UInt<N> {
+        iter.fold(UInt::<N>::ONE, |a, b| a * b)
+    }
+}
+
+impl<'a, const N: usize> Product<&'a UInt<N>> for UInt<N> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a UInt<N>>>(iter: I) -> UInt<N> {
+        iter.fold(UInt::<N>::ONE, |a, b| a * *b)
+    }
+}
@@ -0,0 +1,181 @@
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, ConstOne, ConstZero,
+    FromPrimitive, Num, One, Pow, SaturatingAdd, SaturatingMul, SaturatingSub, ToPrimitive,
+    Unsigned, WrappingAdd, WrappingMul, WrappingSub, Zero,
+};
+
+use crate::int::{math, ParseError, UInt};
+
+impl<const N: usize> One for UInt<N> {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        UInt::is_one(self)
+    }
+}
+
+impl<const N: usize> ConstOne for UInt<N> {
+    const ONE: Self = Self::ONE;
+}
+
+impl<const N: usize> Zero for UInt<N> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        UInt::is_zero(self)
+    }
+}
+
+impl<const N: usize> ConstZero for UInt<N> {
+    const ZERO: Self = Self::ZERO;
+}
+
+impl<const N: usize> Num for UInt<N> {
+    type FromStrRadixErr = ParseError;
+
+    #[inline]
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        UInt::from_str_radix(str, radix)
+    }
+}
+
+impl<const N: usize> Unsigned for UInt<N> {}
+
+impl<const N: usize> Bounded for UInt<N> {
+    #[inline]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl<const N: usize> Pow<u32> for UInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn pow(self, exp: u32) -> Self {
+        UInt::pow(self, exp)
+    }
+}
+
+macro_rules! checked_impl {
+    ($($Trait: ident, $method: ident;)*) => {
+        $(
+            impl<const N: usize> $Trait for UInt<N> {
+                #[inline]
+                fn $method(&self, rhs: &Self) -> Option<Self> {
+                    UInt::$method(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+checked_impl! {
+    CheckedAdd, checked_add;
+    CheckedSub, checked_sub;
+    CheckedMul, checked_mul;
+    CheckedDiv, checked_div;
+    CheckedRem, checked_rem;
+}
+
+macro_rules! total_impl {
+    ($($Trait: ident, $method: ident;)*) => {
+        $(
+            impl<const N: usize> $Trait for UInt<N> {
+                #[inline]
+                fn $method(&self, rhs: &Self) -> Self {
+                    UInt::$method(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+total_impl! {
+    WrappingAdd, wrapping_add;
+    WrappingSub, wrapping_sub;
+    WrappingMul, wrapping_mul;
+    SaturatingAdd, saturating_add;
+    SaturatingSub, saturating_sub;
+    SaturatingMul, saturating_mul;
+}
+
+impl<const N: usize> ToPrimitive for UInt<N> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        math::to_i64(*self)
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        math::to_i128(*self)
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        math::to_u64(*self)
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        math::to_u128(*self)
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(math::to_f32(*self))
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(math::to_f64(*self))
+    }
+}
+
+impl<const N: usize> FromPrimitive for UInt<N> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from_digit(n))
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        match math::from_u128(n) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Self> {
+        math::from_f64(n as f64)
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        math::from_f64(n)
+    }
+}
@@ -0,0 +1,17 @@
+use core::cmp::Ordering;
+
+use crate::int::UInt;
+
+impl<const N: usize> PartialOrd for UInt<N> {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, rhs))
+    }
+}
+
+impl<const N: usize> Ord for UInt<N> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.cmp(rhs)
+    }
+}
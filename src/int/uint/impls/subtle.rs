@@ -0,0 +1,33 @@
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
+
+use crate::int::{ct, UInt};
+
+impl<const N: usize> ConstantTimeEq for UInt<N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        ct::eq(self, other).into()
+    }
+}
+
+impl<const N: usize> ConstantTimeGreater for UInt<N> {
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        ct::gt(self, other).into()
+    }
+}
+
+impl<const N: usize> ConstantTimeLess for UInt<N> {}
+
+impl<const N: usize> ConditionallySelectable for UInt<N> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        ct::select(a, b, choice.into())
+    }
+
+    #[inline]
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        ct::swap(a, b, choice.into())
+    }
+}
@@ -0,0 +1,3 @@
+use crate::int::UInt;
+
+impl<const N: usize> zeroize::DefaultIsZeroes for UInt<N> {}
@@ -1,5 +1,7 @@
-use bnum::BUint;
-use core::cmp::Ordering;
+use crate::int::{
+    div::{self, Reciprocal},
+    Montgomery, UInt,
+};
 
 type Digit = u64;
 type DoubleDigit = u128;
@@ -8,7 +10,6 @@ type ExpType = u32;
 type Digits<const N: usize> = [Digit; N];
 
 const BITS: ExpType = Digit::BITS;
-const BITS_MINUS_1: ExpType = BITS - 1;
 const BIT_SHIFT: ExpType = BITS.trailing_zeros();
 
 macro_rules! to_int {
@@ -16,7 +17,7 @@ macro_rules! to_int {
         $(
             #[allow(dead_code)]
             #[inline]
-            pub const fn $name<const N: usize>(this: BUint<N>) -> Option<$int> {
+            pub const fn $name<const N: usize>(this: UInt<N>) -> Option<$int> {
                 let digits = this.digits();
                 let mut out = 0;
                 let mut i = 0;
@@ -73,382 +74,455 @@ to_int! {
     to_isize -> isize
 }
 
-// This Hell is here because of the div_rem methods are not public in the bnum.
-
+/// Converts `u128` into `UInt<N>`.
+///
+/// Returns the converted value along with a boolean indicating whether the
+/// value has been truncated, which is only possible for a single digit.
 #[inline]
-pub const fn div_rem<const N: usize>(
-    dividend: BUint<N>,
-    divisor: BUint<N>,
-) -> (BUint<N>, BUint<N>) {
-    match dividend.cmp(&divisor) {
-        Ordering::Less => (BUint::<N>::ZERO, dividend),
-        Ordering::Equal => (BUint::<N>::ONE, BUint::<N>::ZERO),
-        Ordering::Greater => {
-            let ldi = last_digit_index(divisor.digits());
-            if ldi == 0 {
-                let digits = divisor.digits();
-                let (div, rem) = div_rem_digit(*(dividend.digits()), digits[0]);
-                (BUint::<N>::from_digits(div), BUint::<N>::from_digit(rem))
-            } else {
-                let (div, rem) =
-                    basecase_div_rem(*(dividend.digits()), *(divisor.digits()), ldi + 1);
-                (BUint::<N>::from_digits(div), BUint::<N>::from_digits(rem))
-            }
-        }
+pub const fn from_u128<const N: usize>(n: u128) -> (UInt<N>, bool) {
+    let mut digits = [0; N];
+    digits[0] = n as Digit;
+
+    if N > 1 {
+        digits[1] = (n >> BITS) as Digit;
+        (UInt::from_digits(digits), false)
+    } else {
+        (UInt::from_digits(digits), (n >> BITS) != 0)
     }
 }
 
+/// Converts `value` into the nearest `f64`.
+#[allow(dead_code)]
 #[inline]
-pub const fn div_rem_wide(low: Digit, high: Digit, rhs: Digit) -> (Digit, Digit) {
-    let a = to_double_digit(low, high);
-    (
-        (a / rhs as DoubleDigit) as Digit,
-        (a % rhs as DoubleDigit) as Digit,
-    )
-}
-
-const fn last_digit_index<const N: usize>(digits: &Digits<N>) -> usize {
-    let mut index = 0;
-    let mut i = 1;
+pub const fn to_f64<const N: usize>(value: UInt<N>) -> f64 {
+    let (top, exp) = top_bits(value);
 
-    while i < N {
-        if digits[i] != 0 {
-            index = i;
-        }
-        i += 1;
+    if exp > f64::MAX_EXP as u32 {
+        return f64::INFINITY;
     }
-    index
-}
 
-const fn div_rem_digit<const N: usize>(digits: Digits<N>, rhs: Digit) -> (Digits<N>, Digit) {
-    let mut out = [0; N];
+    // Scaling by a power of two is exact unless it overflows.
+    top as f64
+        * f64::from_bits(((exp + f64::MAX_EXP as u32 - 1) as u64) << (f64::MANTISSA_DIGITS - 1))
+}
 
-    let mut rem: Digit = 0;
-    let mut i = N;
+/// Converts `value` into the nearest `f32`.
+#[allow(dead_code)]
+#[inline]
+pub const fn to_f32<const N: usize>(value: UInt<N>) -> f32 {
+    let (top, exp) = top_bits(value);
 
-    while i > 0 {
-        i -= 1;
-        let (q, r) = div_rem_wide(digits[i], rem, rhs);
-        rem = r;
-        out[i] = q;
+    if exp > f32::MAX_EXP as u32 {
+        return f32::INFINITY;
     }
-    (out, rem)
-}
 
-const fn basecase_div_rem<const N: usize>(
-    digits: Digits<N>,
-    mut v: Digits<N>,
-    n: usize,
-) -> (Digits<N>, Digits<N>) {
-    let mut q = [0; N];
-    let m = last_digit_index(&digits) + 1 - n;
+    top as f32 * f32::from_bits((exp + f32::MAX_EXP as u32 - 1) << (f32::MANTISSA_DIGITS - 1))
+}
 
-    let shift = v[n - 1].leading_zeros() as ExpType;
+/// Converts `f64` into `UInt<N>` rounding towards zero.
+///
+/// Returns `None` if the value is NaN, negative or doesn't fit.
+#[allow(dead_code)]
+#[inline]
+pub const fn from_f64<const N: usize>(f: f64) -> Option<UInt<N>> {
+    if f.is_nan() || f.is_infinite() || f <= -1.0 {
+        return None;
+    }
 
-    v = unchecked_shl_internal(v, shift); // D1
+    if f < 1.0 {
+        return Some(UInt::ZERO);
+    }
 
-    let v_n_m1 = v[n - 1];
-    let v_n_m2 = v[n - 2];
+    // Values from one are normal, so the leading bit is implicit.
+    const MANTISSA_BITS: u32 = f64::MANTISSA_DIGITS - 1;
 
-    let mut u = Remainder::new(digits, shift);
+    let bits = f.to_bits();
+    let exp = ((bits >> MANTISSA_BITS) & 0x7FF) as i32 - 1023 - MANTISSA_BITS as i32;
+    let mantissa = (bits & ((1 << MANTISSA_BITS) - 1)) | (1 << MANTISSA_BITS);
 
-    let mut j = m + 1; // D2
-    while j > 0 {
-        j -= 1; // D7
+    if exp < 0 {
+        Some(UInt::from_digit(mantissa >> -exp))
+    } else if exp as u32 + f64::MANTISSA_DIGITS > UInt::<N>::BITS {
+        None
+    } else {
+        Some(UInt::from_digit(mantissa).wrapping_shl(exp as u32))
+    }
+}
 
-        let u_jn = u.digit(j + n);
+/// Returns the top 64 bits of `value` and their binary exponent.
+///
+/// The lowest returned bit is sticky: it is set if any of the dropped bits
+/// is, so that rounding the top bits to a float rounds the whole value.
+#[allow(dead_code)]
+#[inline]
+const fn top_bits<const N: usize>(value: UInt<N>) -> (Digit, u32) {
+    let bits = value.bits();
 
-        // q_hat will be either `q` or `q + 1`
-        let mut q_hat = if u_jn < v_n_m1 {
-            let (mut q_hat, r_hat) = div_rem_wide(u.digit(j + n - 1), u_jn, v_n_m1); // D3
+    if bits <= BITS {
+        return (value.digits()[0], 0);
+    }
 
-            if tuple_gt(
-                widening_mul::<N>(q_hat, v_n_m2),
-                (u.digit(j + n - 2), r_hat as Digit),
-            ) {
-                q_hat -= 1;
+    let exp = bits - BITS;
+    let top = value.wrapping_shr(exp).digits()[0];
+    let sticky = !value.wrapping_shl(UInt::<N>::BITS - exp).is_zero();
 
-                if let Some(r_hat) = r_hat.checked_add(v_n_m1) {
-                    // this checks if `r_hat <= b`, where `b` is the digit base
-                    if tuple_gt(
-                        widening_mul::<N>(q_hat, v_n_m2),
-                        (u.digit(j + n - 2), r_hat as Digit),
-                    ) {
-                        q_hat -= 1;
-                    }
-                }
-            }
-            q_hat
-        } else {
-            // `u[j + n - 1] >= v[n - 1]` so we know that estimate for q_hat would be larger
-            // than `Digit::MAX`. This is either equal to `q` or `q + 1` (very unlikely to
-            // be `q + 1`).
-            Digit::MAX
-        };
-        let (u_new, overflow) = u.sub(Mul::new(v, q_hat), j, n); // D4
-        u = u_new;
-
-        if overflow {
-            // D5 - unlikely, probability of this being true is ~ 2 / b where b is the digit
-            // base (i.e. `Digit::MAX + 1`)
-            q_hat -= 1;
-            u = u.add(v, j, n);
-        }
-        q[j] = q_hat;
-    }
-    (q, u.shr(shift))
+    (top | sticky as Digit, exp)
 }
 
+/// Converts `UInt<N>` into `UInt<M>` of any width.
+///
+/// Returns the converted value along with a boolean indicating whether the
+/// value has been truncated.
 #[inline]
-const fn carrying_add(a: Digit, b: Digit, carry: bool) -> (Digit, bool) {
-    let (s1, o1) = a.overflowing_add(b);
-    if carry {
-        let (s2, o2) = s1.overflowing_add(1);
-        (s2, o1 || o2)
-    } else {
-        (s1, o1)
+pub const fn resize<const N: usize, const M: usize>(value: UInt<N>) -> (UInt<M>, bool) {
+    let digits = value.digits();
+    let mut out: Digits<M> = [0; M];
+    let mut i = 0;
+
+    while i < N {
+        if i < M {
+            out[i] = digits[i];
+        } else if digits[i] != 0 {
+            return (UInt::from_digits(out), true);
+        }
+        i += 1;
     }
+
+    (UInt::from_digits(out), false)
 }
 
+/// Divides `dividend` by a nonzero `divisor` and returns the quotient and the
+/// remainder.
 #[inline]
-const fn carrying_mul<const N: usize>(
-    a: Digit,
-    b: Digit,
-    carry: Digit,
-    current: Digit,
-) -> (Digit, Digit) {
-    let prod =
-        carry as DoubleDigit + current as DoubleDigit + (a as DoubleDigit) * (b as DoubleDigit);
-    (prod as Digit, (prod >> BITS) as Digit)
+pub const fn div_rem<const N: usize>(dividend: UInt<N>, divisor: UInt<N>) -> (UInt<N>, UInt<N>) {
+    let (quotient, remainder) = div::div_rem(dividend.digits(), divisor.digits());
+    (UInt::from_digits(quotient), UInt::from_digits(remainder))
 }
 
+/// Divides the double-width integer `high:low` by `divisor`.
+///
+/// Returns `None` if the quotient does not fit into `UInt<N>`.
 #[inline]
-const fn borrowing_sub(a: Digit, b: Digit, borrow: bool) -> (Digit, bool) {
-    let (s1, o1) = a.overflowing_sub(b);
-    if borrow {
-        let (s2, o2) = s1.overflowing_sub(1);
-        (s2, o1 || o2)
-    } else {
-        (s1, o1)
+pub const fn div_rem_double<const N: usize>(
+    low: UInt<N>,
+    high: UInt<N>,
+    divisor: UInt<N>,
+) -> Option<(UInt<N>, UInt<N>)> {
+    if high.is_zero() {
+        return Some(div_rem(low, divisor));
+    }
+
+    if high.ge(&divisor) {
+        return None;
     }
+
+    Some(div_rem_double_fitting(low, high, divisor))
 }
 
+/// Divides the double-width integer `high:low` by `divisor`, where `high <
+/// divisor`, so the quotient always fits into `UInt<N>`.
 #[inline]
-const fn widening_mul<const N: usize>(a: Digit, b: Digit) -> (Digit, Digit) {
-    let prod = a as DoubleDigit * b as DoubleDigit;
-    (prod as Digit, (prod >> BITS) as Digit)
+pub const fn div_rem_double_fitting<const N: usize>(
+    low: UInt<N>,
+    high: UInt<N>,
+    divisor: UInt<N>,
+) -> (UInt<N>, UInt<N>) {
+    debug_assert!(high.lt(&divisor));
+
+    let (quotient, remainder) = div::div_rem_double(low.digits(), high.digits(), divisor.digits());
+    (UInt::from_digits(quotient), UInt::from_digits(remainder))
 }
 
+/// Computes `lhs * rhs mod modulus` for a nonzero `modulus` and `lhs, rhs <
+/// modulus`.
 #[inline]
-const fn to_double_digit(low: Digit, high: Digit) -> DoubleDigit {
-    ((high as DoubleDigit) << BITS) | low as DoubleDigit
+pub const fn mod_mul<const N: usize>(lhs: UInt<N>, rhs: UInt<N>, modulus: UInt<N>) -> UInt<N> {
+    let (low, high) = lhs.widening_mul(rhs);
+
+    // `high < modulus` since both factors are less than the modulus.
+    let (_, remainder) = div_rem_double_fitting(low, high, modulus);
+    remainder
 }
 
+/// Computes `base^exp mod modulus` for a nonzero `modulus`.
+///
+/// Odd moduli go through the Montgomery form. Even moduli are rare in
+/// practice and use the binary method with a division per multiplication.
 #[inline]
-const fn tuple_gt(a: (Digit, Digit), b: (Digit, Digit)) -> bool {
-    a.1 > b.1 || a.1 == b.1 && a.0 > b.0
-}
+pub const fn mod_pow<const N: usize>(base: UInt<N>, exp: UInt<N>, modulus: UInt<N>) -> UInt<N> {
+    if modulus.is_one() {
+        return UInt::ZERO;
+    }
 
-struct Remainder<const N: usize> {
-    first: Digit,
-    rest: Digits<N>,
-}
+    if let Some(ctx) = Montgomery::checked_new(modulus) {
+        return ctx.pow(base, exp);
+    }
 
-impl<const N: usize> Remainder<N> {
-    const fn digit(&self, index: usize) -> Digit {
-        if index == 0 {
-            self.first
-        } else {
-            self.rest[index - 1]
+    let mut base = div_rem(base, modulus).1;
+    let mut acc = UInt::ONE;
+    let bits = exp.bits();
+    let mut i = 0;
+
+    while i < bits {
+        if exp.bit(i) {
+            acc = mod_mul(acc, base, modulus);
         }
-    }
 
-    const fn shr(self, shift: ExpType) -> Digits<N> {
-        let mut out = [0; N];
+        i += 1;
 
-        let mut i = 0;
-        while i < N {
-            out[i] = self.digit(i) >> shift;
-            i += 1;
+        if i < bits {
+            base = mod_mul(base, base, modulus);
         }
-        if shift > 0 {
-            i = 0;
-            while i < N {
-                out[i] |= self.rest[i] << (BITS - shift);
-                i += 1;
-            }
+    }
+
+    acc
+}
+
+/// Computes the integer square root with Newton's method.
+///
+/// The iteration starts above the root and decreases monotonically until it
+/// reaches `floor(sqrt(n))`.
+#[inline]
+pub const fn isqrt<const N: usize>(n: UInt<N>) -> UInt<N> {
+    if n.le(&UInt::ONE) {
+        return n;
+    }
+
+    let mut x = UInt::ONE.wrapping_shl(n.bits().div_ceil(2));
+
+    loop {
+        let y = x.wrapping_add(div_rem(n, x).0).wrapping_shr(1);
+
+        if y.ge(&x) {
+            return x;
         }
 
-        out
+        x = y;
     }
+}
 
-    const fn new(digits: Digits<N>, shift: ExpType) -> Self {
-        let first = digits[0] << shift;
-        let rest = wrapping_shr(digits, BITS - shift);
-        Self { first, rest }
+/// Computes `(value * multiplier) / divisor` and the remainder for `value <
+/// divisor`.
+///
+/// Since `value < divisor` the quotient is always less than `multiplier`, so
+/// it fits into a single digit and one step of the basecase division is
+/// enough to find it.
+#[inline]
+pub const fn mul_div_rem_digit<const N: usize>(
+    value: UInt<N>,
+    multiplier: Digit,
+    divisor: UInt<N>,
+) -> (Digit, UInt<N>) {
+    debug_assert!(value.lt(&divisor));
+
+    let (u, u_top) = mul_digit(*value.digits(), multiplier);
+    div_rem_step(u, u_top, divisor)
+}
+
+/// Divides the `N + 1` digits integer `u_top:u` by `divisor`, where the
+/// quotient is known to fit into a single digit (i.e. `u_top:u < divisor *
+/// Digit::MAX`).
+const fn div_rem_step<const N: usize>(
+    u: Digits<N>,
+    u_top: Digit,
+    divisor: UInt<N>,
+) -> (Digit, UInt<N>) {
+    let v = *divisor.digits();
+    let n = last_digit_index(&v) + 1;
+
+    if n == 1 {
+        // The quotient fits into a single digit, so the dividend is less than
+        // `Digit::MAX^2`.
+        let (q, r) = div_rem_wide(u[0], top_digit(&u, u_top, 1), v[0]);
+        return (q, UInt::from_digit(r));
     }
 
-    const fn sub(mut self, rhs: Mul<N>, start: usize, range: usize) -> (Self, bool) {
-        let mut borrow = false;
-        let mut i = 0;
-        while i <= range {
-            let (sub, overflow) = borrowing_sub(self.digit(i + start), rhs.digit(i), borrow);
-            if start == 0 && i == 0 {
-                self.first = sub;
-            } else {
-                self.rest[i + start - 1] = sub;
+    let shift = v[n - 1].leading_zeros() as ExpType;
+
+    let v_n_m1 = shl_pair(v[n - 1], v[n - 2], shift);
+    let v_n_m2 = shl_pair(v[n - 2], if n > 2 { v[n - 3] } else { 0 }, shift);
+
+    let u_n = shl_pair(top_digit(&u, u_top, n), u[n - 1], shift);
+    let u_n_m1 = shl_pair(u[n - 1], u[n - 2], shift);
+    let u_n_m2 = shl_pair(u[n - 2], if n > 2 { u[n - 3] } else { 0 }, shift);
+
+    let mut q_hat = if u_n < v_n_m1 {
+        let (mut q_hat, r_hat) = div_rem_wide(u_n_m1, u_n, v_n_m1);
+
+        if tuple_gt(widening_mul::<N>(q_hat, v_n_m2), (u_n_m2, r_hat)) {
+            q_hat -= 1;
+
+            if let Some(r_hat) = r_hat.checked_add(v_n_m1) {
+                if tuple_gt(widening_mul::<N>(q_hat, v_n_m2), (u_n_m2, r_hat)) {
+                    q_hat -= 1;
+                }
             }
-            borrow = overflow;
-            i += 1;
         }
-        (self, borrow)
-    }
+        q_hat
+    } else {
+        Digit::MAX
+    };
+
+    let (p, p_top) = mul_digit(v, q_hat);
+    let (mut r, mut r_top) = sub_digits(u, u_top, p, p_top);
 
-    const fn add(mut self, rhs: Digits<N>, start: usize, range: usize) -> Self {
+    // `r_top` is the borrow-extended top digit: it wraps around if the
+    // estimate was one too large.
+    if r_top != 0 {
+        q_hat -= 1;
         let mut carry = false;
         let mut i = 0;
-        while i < range {
-            let (sum, overflow) = carrying_add(self.digit(i + start), rhs[i], carry);
-            if start == 0 && i == 0 {
-                self.first = sum;
-            } else {
-                self.rest[i + start - 1] = sum;
-            }
-            carry = overflow;
+        while i < N {
+            (r[i], carry) = carrying_add(r[i], v[i], carry);
             i += 1;
         }
-        if carry {
-            if start == 0 && range == 0 {
-                self.first = self.first.wrapping_add(1);
-            } else {
-                self.rest[range + start - 1] = self.rest[range + start - 1].wrapping_add(1);
-            }
-        }
-        self
+        r_top = r_top.wrapping_add(carry as Digit);
     }
-}
+    debug_assert!(r_top == 0);
 
-#[derive(Clone, Copy)]
-struct Mul<const N: usize> {
-    last: Digit,
-    rest: Digits<N>,
+    (q_hat, UInt::from_digits(r))
 }
 
-impl<const N: usize> Mul<N> {
-    const fn new(digits: Digits<N>, rhs: Digit) -> Self {
-        let mut rest = [0; N];
-
-        let mut carry: Digit = 0;
-        let mut i = 0;
-        while i < N {
-            let (prod, c) = carrying_mul::<N>(digits[i], rhs, carry, 0);
-            carry = c;
-            rest[i] = prod;
-            i += 1;
-        }
-        Self { last: carry, rest }
+/// Computes `value * multiplier + addend`, returning the result along with the
+/// carried out digit.
+#[inline]
+pub const fn mul_add_digit<const N: usize>(
+    value: UInt<N>,
+    multiplier: Digit,
+    addend: Digit,
+) -> (UInt<N>, Digit) {
+    let digits = value.digits();
+    let mut out = [0; N];
+    let mut carry = addend;
+    let mut i = 0;
+    while i < N {
+        (out[i], carry) = carrying_mul::<N>(digits[i], multiplier, carry, 0);
+        i += 1;
     }
+    (UInt::from_digits(out), carry)
+}
 
-    const fn digit(&self, index: usize) -> Digit {
-        if index == N {
-            self.last
-        } else {
-            self.rest[index]
-        }
+#[inline]
+const fn mul_digit<const N: usize>(digits: Digits<N>, rhs: Digit) -> (Digits<N>, Digit) {
+    let mut out = [0; N];
+    let mut carry = 0;
+    let mut i = 0;
+    while i < N {
+        (out[i], carry) = carrying_mul::<N>(digits[i], rhs, carry, 0);
+        i += 1;
     }
+    (out, carry)
 }
 
 #[inline]
-const fn wrapping_shr<const N: usize>(digits: Digits<N>, rhs: ExpType) -> Digits<N> {
-    overflowing_shr(digits, rhs).0
+const fn sub_digits<const N: usize>(
+    a: Digits<N>,
+    a_top: Digit,
+    b: Digits<N>,
+    b_top: Digit,
+) -> (Digits<N>, Digit) {
+    let mut out = [0; N];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < N {
+        (out[i], borrow) = borrowing_sub(a[i], b[i], borrow);
+        i += 1;
+    }
+    let (top, _) = borrowing_sub(a_top, b_top, borrow);
+    (out, top)
 }
 
 #[inline]
-const fn overflowing_shr<const N: usize>(digits: Digits<N>, rhs: ExpType) -> (Digits<N>, bool) {
-    if rhs >= BUint::<N>::BITS {
-        (
-            unchecked_shr_internal(digits, rhs & (BUint::<N>::BITS - 1)),
-            true,
-        )
+const fn top_digit<const N: usize>(digits: &Digits<N>, top: Digit, index: usize) -> Digit {
+    if index < N {
+        digits[index]
     } else {
-        (unchecked_shr_internal(digits, rhs), false)
+        top
     }
 }
 
 #[inline]
-const fn unchecked_shl_internal<const N: usize>(digits: Digits<N>, rhs: ExpType) -> Digits<N> {
-    let mut out = [0; N];
+const fn shl_pair(high: Digit, low: Digit, shift: ExpType) -> Digit {
+    if shift == 0 {
+        high
+    } else {
+        (high << shift) | (low >> (BITS - shift))
+    }
+}
 
-    let digit_shift = (rhs >> BIT_SHIFT) as usize;
-    let bit_shift = rhs & BITS_MINUS_1;
+#[inline]
+pub const fn div_rem_wide(low: Digit, high: Digit, rhs: Digit) -> (Digit, Digit) {
+    let a = to_double_digit(low, high);
+    (
+        (a / rhs as DoubleDigit) as Digit,
+        (a % rhs as DoubleDigit) as Digit,
+    )
+}
 
-    if bit_shift != 0 {
-        let carry_shift = BITS - bit_shift;
-        let mut carry = 0;
+const fn last_digit_index<const N: usize>(digits: &Digits<N>) -> usize {
+    let mut index = 0;
+    let mut i = 1;
 
-        let mut i = digit_shift;
-        while i < N {
-            let current_digit = digits[i - digit_shift];
-            out[i] = (current_digit << bit_shift) | carry;
-            carry = current_digit >> carry_shift;
-            i += 1;
-        }
-    } else {
-        let mut i = digit_shift;
-        while i < N {
-            // we start i at digit_shift, not 0, since the compiler can elide bounds checks
-            // when i < N
-            out[i] = digits[i - digit_shift];
-            i += 1;
+    while i < N {
+        if digits[i] != 0 {
+            index = i;
         }
+        i += 1;
     }
-
-    out
+    index
 }
 
 #[inline]
-const fn unchecked_shr_pad_internal<const N: usize, const NEG: bool>(
-    digits: Digits<N>,
-    rhs: ExpType,
-) -> Digits<N> {
-    let mut out = if NEG { [Digit::MAX; N] } else { [0; N] };
-
-    let digit_shift = (rhs >> BIT_SHIFT) as usize;
-    let bit_shift = rhs & BITS_MINUS_1;
-
-    let num_copies = N.saturating_sub(digit_shift);
+pub const fn div_rem_digit<const N: usize>(digits: Digits<N>, rhs: Digit) -> (Digits<N>, Digit) {
+    Reciprocal::new(rhs).div_rem_digits(&digits, 0)
+}
 
-    if bit_shift != 0 {
-        let carry_shift = BITS - bit_shift;
-        let mut carry = 0;
+#[inline]
+const fn carrying_add(a: Digit, b: Digit, carry: bool) -> (Digit, bool) {
+    let (s1, o1) = a.overflowing_add(b);
+    if carry {
+        let (s2, o2) = s1.overflowing_add(1);
+        (s2, o1 || o2)
+    } else {
+        (s1, o1)
+    }
+}
 
-        let mut i = digit_shift;
-        while i < N {
-            // we use an increment while loop because the compiler can elide the array
-            // bounds check, which results in big performance gains
-            let index = N - 1 - i;
-            let current_digit = digits[index + digit_shift];
-            out[index] = (current_digit >> bit_shift) | carry;
-            carry = current_digit << carry_shift;
-            i += 1;
-        }
+#[inline]
+const fn carrying_mul<const N: usize>(
+    a: Digit,
+    b: Digit,
+    carry: Digit,
+    current: Digit,
+) -> (Digit, Digit) {
+    let prod =
+        carry as DoubleDigit + current as DoubleDigit + (a as DoubleDigit) * (b as DoubleDigit);
+    (prod as Digit, (prod >> BITS) as Digit)
+}
 
-        if NEG {
-            out[num_copies - 1] |= Digit::MAX << carry_shift;
-        }
+#[inline]
+const fn borrowing_sub(a: Digit, b: Digit, borrow: bool) -> (Digit, bool) {
+    let (s1, o1) = a.overflowing_sub(b);
+    if borrow {
+        let (s2, o2) = s1.overflowing_sub(1);
+        (s2, o1 || o2)
     } else {
-        let mut i = digit_shift;
-        while i < N {
-            // we start i at digit_shift, not 0, since the compiler can elide bounds checks
-            // when i < N
-            out[i - digit_shift] = digits[i];
-            i += 1;
-        }
+        (s1, o1)
     }
+}
 
-    out
+#[inline]
+const fn widening_mul<const N: usize>(a: Digit, b: Digit) -> (Digit, Digit) {
+    let prod = a as DoubleDigit * b as DoubleDigit;
+    (prod as Digit, (prod >> BITS) as Digit)
 }
 
-const fn unchecked_shr_internal<const N: usize>(digits: Digits<N>, rhs: ExpType) -> Digits<N> {
-    unchecked_shr_pad_internal::<N, false>(digits, rhs)
+#[inline]
+const fn to_double_digit(low: Digit, high: Digit) -> DoubleDigit {
+    ((high as DoubleDigit) << BITS) | low as DoubleDigit
+}
+
+#[inline]
+const fn tuple_gt(a: (Digit, Digit), b: (Digit, Digit)) -> bool {
+    a.1 > b.1 || a.1 == b.1 && a.0 > b.0
 }
@@ -0,0 +1,273 @@
+use crate::{
+    int::{math::div_rem_double_fitting, UInt},
+    utils::err_msg,
+};
+
+type Digit = u64;
+type DoubleDigit = u128;
+
+const DIGIT_BITS: u32 = Digit::BITS;
+
+/// Modular arithmetic in the Montgomery form for a fixed odd modulus.
+///
+/// A residue `a` is represented as `a * R mod m` with `R = 2^BITS`, which
+/// replaces the division of modular multiplication by shifts: the product of
+/// two `N`-digit residues is reduced in `O(N^2)` digit operations with no
+/// division at all. Converting into and out of the Montgomery form costs a
+/// multiplication each, so the form pays off for chains of multiplications
+/// such as exponentiation.
+///
+/// # Examples
+///
+/// ```
+/// use fastnum::{int::Montgomery, u256};
+///
+/// const M: Montgomery<4> = Montgomery::new(u256!(1000000007));
+///
+/// let a = M.to_montgomery(u256!(123456789));
+/// let b = M.to_montgomery(u256!(987654321));
+///
+/// assert_eq!(M.from_montgomery(M.mul(a, b)), u256!(259106859));
+/// assert_eq!(M.pow(u256!(2), u256!(1000000006)), u256!(1));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Montgomery<const N: usize> {
+    /// Odd modulus `m`.
+    modulus: UInt<N>,
+
+    /// `-m^-1 mod 2^64`.
+    inv: Digit,
+
+    /// `R mod m`, the Montgomery form of one.
+    one: UInt<N>,
+
+    /// `R^2 mod m`, which converts residues into the Montgomery form.
+    r2: UInt<N>,
+}
+
+impl<const N: usize> Montgomery<N> {
+    /// Creates the Montgomery context for the given `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the modulus is even or less than 3.
+    #[track_caller]
+    #[inline]
+    pub const fn new(modulus: UInt<N>) -> Self {
+        match Self::checked_new(modulus) {
+            Some(ctx) => ctx,
+            None => panic!(err_msg!(
+                "Montgomery modulus must be odd and greater than 1"
+            )),
+        }
+    }
+
+    /// Creates the Montgomery context for the given `modulus`, or returns
+    /// `None` if the modulus is even or less than 3.
+    #[inline]
+    pub const fn checked_new(modulus: UInt<N>) -> Option<Self> {
+        if modulus.digits()[0] & 1 == 0 || modulus.is_one() {
+            return None;
+        }
+
+        // Newton's iteration doubles the number of correct low bits of the
+        // inverse on every step, starting from 1 correct bit: `m * m = 1 mod
+        // 2` for odd `m`.
+        let m0 = modulus.digits()[0];
+        let mut inv: Digit = 1;
+        let mut i = 0;
+
+        while i < 6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(m0.wrapping_mul(inv)));
+            i += 1;
+        }
+
+        // `R mod m = (R - m) mod m`, and `R - m` is the wrapping negation.
+        let one = UInt::ZERO.wrapping_sub(modulus).strict_rem(modulus);
+
+        // `one < m`, so its square divided by `m` always fits.
+        let (low, high) = one.widening_mul(one);
+        let (_, r2) = div_rem_double_fitting(low, high, modulus);
+
+        Some(Self {
+            modulus,
+            inv: inv.wrapping_neg(),
+            one,
+            r2,
+        })
+    }
+
+    /// Returns the modulus.
+    #[inline(always)]
+    pub const fn modulus(&self) -> UInt<N> {
+        self.modulus
+    }
+
+    /// Returns the Montgomery form of one.
+    #[inline(always)]
+    pub const fn one(&self) -> UInt<N> {
+        self.one
+    }
+
+    /// Converts `value` into the Montgomery form. The value is reduced
+    /// modulo the modulus first.
+    #[inline]
+    pub const fn to_montgomery(&self, value: UInt<N>) -> UInt<N> {
+        let value = if value.lt(&self.modulus) {
+            value
+        } else {
+            value.strict_rem(self.modulus)
+        };
+
+        self.mul(value, self.r2)
+    }
+
+    /// Converts the Montgomery form `value` back into a residue.
+    #[inline]
+    pub const fn from_montgomery(&self, value: UInt<N>) -> UInt<N> {
+        self.mul(value, UInt::ONE)
+    }
+
+    /// Multiplies two values in the Montgomery form.
+    ///
+    /// Both values must be less than the modulus.
+    #[inline]
+    pub const fn mul(&self, lhs: UInt<N>, rhs: UInt<N>) -> UInt<N> {
+        // Coarsely integrated operand scanning: every digit of `rhs` is
+        // multiplied in and then one digit is reduced, so the accumulator
+        // stays `N + 2` digits wide. The two top digits are kept apart since
+        // the width can't be generic.
+        let a = lhs.digits();
+        let b = rhs.digits();
+        let m = self.modulus.digits();
+
+        let mut t = [0; N];
+        let mut top: Digit = 0;
+        let mut i = 0;
+
+        while i < N {
+            let mut carry: Digit = 0;
+            let mut j = 0;
+
+            while j < N {
+                let s = t[j] as DoubleDigit
+                    + a[j] as DoubleDigit * b[i] as DoubleDigit
+                    + carry as DoubleDigit;
+                t[j] = s as Digit;
+                carry = (s >> DIGIT_BITS) as Digit;
+                j += 1;
+            }
+
+            let s = top as DoubleDigit + carry as DoubleDigit;
+            let (top0, top1) = (s as Digit, (s >> DIGIT_BITS) as Digit);
+
+            // Adding `q * m` clears the lowest digit, which is then shifted
+            // out.
+            let q = t[0].wrapping_mul(self.inv);
+            let s = t[0] as DoubleDigit + q as DoubleDigit * m[0] as DoubleDigit;
+            let mut carry = (s >> DIGIT_BITS) as Digit;
+
+            j = 1;
+            while j < N {
+                let s = t[j] as DoubleDigit
+                    + q as DoubleDigit * m[j] as DoubleDigit
+                    + carry as DoubleDigit;
+                t[j - 1] = s as Digit;
+                carry = (s >> DIGIT_BITS) as Digit;
+                j += 1;
+            }
+
+            let s = top0 as DoubleDigit + carry as DoubleDigit;
+            t[N - 1] = s as Digit;
+            top = top1 + (s >> DIGIT_BITS) as Digit;
+
+            i += 1;
+        }
+
+        // The result is less than `2m`.
+        let t = UInt::from_digits(t);
+
+        if top != 0 || t.ge(&self.modulus) {
+            t.wrapping_sub(self.modulus)
+        } else {
+            t
+        }
+    }
+
+    /// Squares a value in the Montgomery form.
+    #[inline]
+    pub const fn square(&self, value: UInt<N>) -> UInt<N> {
+        self.mul(value, value)
+    }
+
+    /// Adds two values in the Montgomery form (or two residues).
+    #[inline]
+    pub const fn add(&self, lhs: UInt<N>, rhs: UInt<N>) -> UInt<N> {
+        let (sum, overflow) = lhs.overflowing_add(rhs);
+
+        if overflow || sum.ge(&self.modulus) {
+            sum.wrapping_sub(self.modulus)
+        } else {
+            sum
+        }
+    }
+
+    /// Subtracts two values in the Montgomery form (or two residues).
+    #[inline]
+    pub const fn sub(&self, lhs: UInt<N>, rhs: UInt<N>) -> UInt<N> {
+        let (diff, borrow) = lhs.overflowing_sub(rhs);
+
+        if borrow {
+            diff.wrapping_add(self.modulus)
+        } else {
+            diff
+        }
+    }
+
+    /// Raises `base` (a plain residue, not in the Montgomery form) to the
+    /// power of `exp` modulo the modulus and returns a plain residue.
+    #[inline]
+    pub const fn pow(&self, base: UInt<N>, exp: UInt<N>) -> UInt<N> {
+        self.from_montgomery(self.pow_montgomery(self.to_montgomery(base), exp))
+    }
+
+    /// Raises the Montgomery form `base` to the power of `exp` and returns
+    /// the result in the Montgomery form.
+    ///
+    /// The exponent is scanned by 4-bit windows, which saves about three
+    /// quarters of the multiplications of the binary method.
+    #[inline]
+    pub const fn pow_montgomery(&self, base: UInt<N>, exp: UInt<N>) -> UInt<N> {
+        const WINDOW: u32 = 4;
+
+        let mut table = [self.one; 1 << WINDOW];
+        let mut i = 1;
+
+        while i < table.len() {
+            table[i] = self.mul(table[i - 1], base);
+            i += 1;
+        }
+
+        let bits = exp.bits();
+        let mut acc = self.one;
+        let mut pos = bits.div_ceil(WINDOW) * WINDOW;
+
+        while pos > 0 {
+            pos -= WINDOW;
+
+            let mut k = 0;
+            while k < WINDOW {
+                acc = self.square(acc);
+                k += 1;
+            }
+
+            let window = (exp.digits()[(pos / DIGIT_BITS) as usize] >> (pos % DIGIT_BITS))
+                & ((1 << WINDOW) - 1);
+            if window != 0 {
+                acc = self.mul(acc, table[window as usize]);
+            }
+        }
+
+        acc
+    }
+}
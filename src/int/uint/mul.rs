@@ -0,0 +1,368 @@
+use crate::int::UInt;
+
+type Digit = u64;
+type DoubleDigit = u128;
+
+const BITS: u32 = Digit::BITS;
+
+/// Operands with at least this many significant digits on both sides are
+/// multiplied with the Karatsuba algorithm. Below it the schoolbook
+/// multiplication is faster (see the `a*b wide` benchmark).
+const KARATSUBA_THRESHOLD: usize = 24;
+
+/// Calculates the complete product `lhs * rhs` without the possibility to
+/// overflow.
+///
+/// Returns the low and the high halves of the product.
+///
+/// Only significant digits of the operands are multiplied. Wide operands are
+/// multiplied with the Karatsuba algorithm, which takes `O(n^1.585)` digit
+/// multiplications instead of `O(n^2)`.
+#[inline]
+pub const fn widening_mul<const N: usize>(lhs: UInt<N>, rhs: UInt<N>) -> (UInt<N>, UInt<N>) {
+    let lhs = lhs.digits();
+    let rhs = rhs.digits();
+
+    let lhs_len = significant(lhs).len();
+    let rhs_len = significant(rhs).len();
+
+    if lhs_len < KARATSUBA_THRESHOLD || rhs_len < KARATSUBA_THRESHOLD {
+        return if lhs_len < rhs_len {
+            schoolbook_widening_mul(rhs, lhs, lhs_len)
+        } else {
+            schoolbook_widening_mul(lhs, rhs, rhs_len)
+        };
+    }
+
+    let mut out = [[0; N]; 2];
+    let mut scratch = [[0; N]; 8];
+
+    karatsuba_mul(
+        flatten_mut(&mut out).split_at_mut(lhs_len + rhs_len).0,
+        lhs.split_at(lhs_len).0,
+        rhs.split_at(rhs_len).0,
+        flatten_mut(&mut scratch),
+    );
+
+    (UInt::from_digits(out[0]), UInt::from_digits(out[1]))
+}
+
+/// Multiplies `lhs` by the first `rhs_len` digits of `rhs`.
+///
+/// Inner loops run over all `N` digits of `lhs`, which lets the compiler
+/// unroll them.
+#[inline]
+pub const fn schoolbook_widening_mul<const N: usize>(
+    lhs: &[Digit; N],
+    rhs: &[Digit; N],
+    rhs_len: usize,
+) -> (UInt<N>, UInt<N>) {
+    let mut low = [0; N];
+    let mut high = [0; N];
+
+    let mut i = 0;
+    while i < rhs_len {
+        let digit = rhs[i] as DoubleDigit;
+        let mut carry: Digit = 0;
+        let mut j = 0;
+
+        while j < N - i {
+            let prod =
+                digit * lhs[j] as DoubleDigit + low[i + j] as DoubleDigit + carry as DoubleDigit;
+
+            low[i + j] = prod as Digit;
+            carry = (prod >> BITS) as Digit;
+            j += 1;
+        }
+
+        while j < N {
+            let prod = digit * lhs[j] as DoubleDigit
+                + high[i + j - N] as DoubleDigit
+                + carry as DoubleDigit;
+
+            high[i + j - N] = prod as Digit;
+            carry = (prod >> BITS) as Digit;
+            j += 1;
+        }
+
+        high[i] = carry;
+        i += 1;
+    }
+
+    (UInt::from_digits(low), UInt::from_digits(high))
+}
+
+/// Writes `lhs * rhs` into `out`, which must be `lhs.len() + rhs.len()` digits
+/// long. Operands must not have leading zero digits.
+///
+/// `scratch` must be at least `8 * max(lhs.len(), rhs.len())` digits long.
+const fn karatsuba_mul(out: &mut [Digit], lhs: &[Digit], rhs: &[Digit], scratch: &mut [Digit]) {
+    let (lhs, rhs) = if lhs.len() < rhs.len() {
+        (rhs, lhs)
+    } else {
+        (lhs, rhs)
+    };
+
+    if rhs.len() < KARATSUBA_THRESHOLD {
+        schoolbook_mul(out, lhs, rhs);
+        return;
+    }
+
+    // Split both operands at `m` digits: `lhs = lhs_1 * B^m + lhs_0`.
+    let m = lhs.len().div_ceil(2);
+    let (lhs_0, lhs_1) = lhs.split_at(m);
+    let lhs_0 = significant(lhs_0);
+
+    if rhs.len() <= m {
+        // `rhs` is too short to be split:
+        // `lhs * rhs = (lhs_1 * rhs) * B^m + lhs_0 * rhs`.
+        let (high, scratch) = scratch.split_at_mut(lhs_1.len() + rhs.len());
+
+        mul(high, lhs_1, rhs, scratch);
+        fill_zero(out);
+        mul(
+            out.split_at_mut(lhs_0.len() + rhs.len()).0,
+            lhs_0,
+            rhs,
+            scratch,
+        );
+        add_assign(out.split_at_mut(m).1, high);
+        return;
+    }
+
+    let (rhs_0, rhs_1) = rhs.split_at(m);
+    let rhs_0 = significant(rhs_0);
+
+    // `lhs * rhs = z_2 * B^2m + (z_0 + z_2 - d) * B^m + z_0`, where
+    // `z_0 = lhs_0 * rhs_0`, `z_2 = lhs_1 * rhs_1` and
+    // `d = (lhs_0 - lhs_1) * (rhs_0 - rhs_1)`.
+    let (lhs_diff, scratch) = scratch.split_at_mut(m);
+    let (rhs_diff, scratch) = scratch.split_at_mut(m);
+    let (middle, scratch) = scratch.split_at_mut(2 * m + 1);
+    let (d, scratch) = scratch.split_at_mut(2 * m);
+
+    let negative = sub_abs(lhs_diff, lhs_0, lhs_1) != sub_abs(rhs_diff, rhs_0, rhs_1);
+
+    {
+        let (z_0, z_2) = out.split_at_mut(2 * m);
+
+        fill_zero(z_0);
+        mul(
+            z_0.split_at_mut(lhs_0.len() + rhs_0.len()).0,
+            lhs_0,
+            rhs_0,
+            scratch,
+        );
+        mul(z_2, lhs_1, rhs_1, scratch);
+
+        fill_zero(middle);
+        add_assign(middle, z_0);
+        add_assign(middle, z_2);
+    }
+
+    let lhs_diff = significant(lhs_diff);
+    let rhs_diff = significant(rhs_diff);
+
+    fill_zero(d);
+    mul(
+        d.split_at_mut(lhs_diff.len() + rhs_diff.len()).0,
+        lhs_diff,
+        rhs_diff,
+        scratch,
+    );
+
+    if negative {
+        add_assign(middle, d);
+    } else {
+        sub_assign(middle, d);
+    }
+
+    add_assign(out.split_at_mut(m).1, middle);
+}
+
+/// Writes `lhs * rhs` into `out` of `lhs.len() + rhs.len()` digits.
+///
+/// `scratch` must be at least `8 * max(lhs.len(), rhs.len())` digits long.
+#[inline]
+pub(crate) const fn mul_into(
+    out: &mut [Digit],
+    lhs: &[Digit],
+    rhs: &[Digit],
+    scratch: &mut [Digit],
+) {
+    let lhs = significant(lhs);
+    let rhs = significant(rhs);
+
+    let (product, rest) = out.split_at_mut(lhs.len() + rhs.len());
+
+    fill_zero(rest);
+    mul(product, lhs, rhs, scratch);
+}
+
+/// Writes `lhs * rhs` into `out` of `lhs.len() + rhs.len()` digits choosing the
+/// algorithm by the operands sizes.
+#[inline]
+const fn mul(out: &mut [Digit], lhs: &[Digit], rhs: &[Digit], scratch: &mut [Digit]) {
+    if lhs.is_empty() || rhs.is_empty() {
+        fill_zero(out);
+    } else if lhs.len() < KARATSUBA_THRESHOLD || rhs.len() < KARATSUBA_THRESHOLD {
+        schoolbook_mul(out, lhs, rhs);
+    } else {
+        karatsuba_mul(out, lhs, rhs, scratch);
+    }
+}
+
+/// Writes `lhs * rhs` into `out` of `lhs.len() + rhs.len()` digits.
+const fn schoolbook_mul(out: &mut [Digit], lhs: &[Digit], rhs: &[Digit]) {
+    fill_zero(out);
+
+    let len = rhs.len();
+    let mut i = 0;
+
+    while i < lhs.len() {
+        // Slicing up front lets the compiler elide bounds checks in the inner loop.
+        let row = out.split_at_mut(i).1.split_at_mut(len + 1).0;
+        let digit = lhs[i] as DoubleDigit;
+        let mut carry: Digit = 0;
+        let mut j = 0;
+
+        while j < len {
+            let prod = digit * rhs[j] as DoubleDigit + row[j] as DoubleDigit + carry as DoubleDigit;
+
+            row[j] = prod as Digit;
+            carry = (prod >> BITS) as Digit;
+            j += 1;
+        }
+
+        row[len] = carry;
+        i += 1;
+    }
+}
+
+/// Writes `|lhs - rhs|` into `out` and returns whether `lhs < rhs`.
+///
+/// Operands must not be longer than `out`.
+const fn sub_abs(out: &mut [Digit], lhs: &[Digit], rhs: &[Digit]) -> bool {
+    let negative = lt(lhs, rhs);
+    let (lhs, rhs) = if negative { (rhs, lhs) } else { (lhs, rhs) };
+
+    let mut borrow = false;
+    let mut i = 0;
+
+    while i < out.len() {
+        let (diff, b1) = digit(lhs, i).overflowing_sub(digit(rhs, i));
+        let (diff, b2) = diff.overflowing_sub(borrow as Digit);
+
+        out[i] = diff;
+        borrow = b1 || b2;
+        i += 1;
+    }
+
+    debug_assert!(!borrow);
+    negative
+}
+
+/// Adds `rhs` to `lhs` in place. The sum must fit into `lhs`.
+///
+/// `rhs` may be longer than `lhs` only by zero digits.
+const fn add_assign(lhs: &mut [Digit], rhs: &[Digit]) {
+    let mut carry = false;
+    let mut i = 0;
+
+    while i < lhs.len() && (i < rhs.len() || carry) {
+        let (sum, c1) = lhs[i].overflowing_add(digit(rhs, i));
+        let (sum, c2) = sum.overflowing_add(carry as Digit);
+
+        lhs[i] = sum;
+        carry = c1 || c2;
+        i += 1;
+    }
+
+    debug_assert!(!carry);
+    debug_assert!(significant(rhs).len() <= lhs.len());
+}
+
+/// Subtracts `rhs` from `lhs` in place. The difference must not be negative.
+const fn sub_assign(lhs: &mut [Digit], rhs: &[Digit]) {
+    let mut borrow = false;
+    let mut i = 0;
+
+    while i < lhs.len() && (i < rhs.len() || borrow) {
+        let (diff, b1) = lhs[i].overflowing_sub(digit(rhs, i));
+        let (diff, b2) = diff.overflowing_sub(borrow as Digit);
+
+        lhs[i] = diff;
+        borrow = b1 || b2;
+        i += 1;
+    }
+
+    debug_assert!(!borrow);
+}
+
+/// Compares the integers represented by `lhs` and `rhs`.
+const fn lt(lhs: &[Digit], rhs: &[Digit]) -> bool {
+    let lhs = significant(lhs);
+    let rhs = significant(rhs);
+
+    if lhs.len() != rhs.len() {
+        return lhs.len() < rhs.len();
+    }
+
+    let mut i = lhs.len();
+    while i > 0 {
+        i -= 1;
+
+        if lhs[i] != rhs[i] {
+            return lhs[i] < rhs[i];
+        }
+    }
+
+    false
+}
+
+/// Returns the digit at `index` or zero past the end of `digits`.
+#[inline(always)]
+const fn digit(digits: &[Digit], index: usize) -> Digit {
+    if index < digits.len() {
+        digits[index]
+    } else {
+        0
+    }
+}
+
+/// Strips leading zero digits.
+#[inline]
+pub(crate) const fn significant(digits: &[Digit]) -> &[Digit] {
+    let mut len = digits.len();
+
+    while len > 0 && digits[len - 1] == 0 {
+        len -= 1;
+    }
+
+    digits.split_at(len).0
+}
+
+/// Views the rows of `digits` as a single slice.
+///
+/// Same as `<[[T; N]]>::as_flattened_mut()`, which is not const until Rust
+/// `1.87`.
+#[inline]
+pub(crate) const fn flatten_mut<const N: usize, const K: usize>(
+    digits: &mut [[Digit; N]; K],
+) -> &mut [Digit] {
+    // SAFETY: `[[Digit; N]; K]` has the same layout as `[Digit; N * K]`.
+    #[allow(unsafe_code)]
+    unsafe {
+        core::slice::from_raw_parts_mut(digits.as_mut_ptr().cast::<Digit>(), N * K)
+    }
+}
+
+#[inline]
+pub(crate) const fn fill_zero(digits: &mut [Digit]) {
+    let mut i = 0;
+
+    while i < digits.len() {
+        digits[i] = 0;
+        i += 1;
+    }
+}
@@ -0,0 +1,109 @@
+use crate::int::{math::mul_add_digit, UInt};
+
+/// Number of entries per table row.
+///
+/// Array lengths can't be computed from `N` on stable Rust, so the tables are
+/// laid out as `N` rows: every 64-bit digit adds a bit over 19 decimal digits,
+/// so `20 * N` entries cover all powers of ten representable by `UInt<N>`.
+const ROW: usize = 20;
+
+/// `log10(2)` as a 32-bit fixed point fraction.
+const LOG10_2: u64 = 1292913986;
+
+/// Compile-time tables of powers of ten and their bit lengths for `UInt<N>`.
+pub(crate) struct Pow10<const N: usize>;
+
+impl<const N: usize> Pow10<N> {
+    /// The largest `k` such that `10^k` fits into `UInt<N>`.
+    pub const MAX_POWER: u32 = max_power::<N>();
+
+    // Kept behind a reference so that the table is placed into static memory
+    // instead of being copied on every lookup.
+    const TABLE: &'static [[UInt<N>; ROW]; N] = &powers();
+
+    const BITS: &'static [[u32; ROW]; N] = &bits(Self::TABLE);
+
+    /// Returns `10^k`.
+    ///
+    /// `k` must not exceed [`Self::MAX_POWER`].
+    #[inline(always)]
+    pub const fn power(k: u32) -> UInt<N> {
+        debug_assert!(k <= Self::MAX_POWER);
+        Self::TABLE[k as usize / ROW][k as usize % ROW]
+    }
+
+    /// Returns the bit length of `10^k`.
+    ///
+    /// `k` must not exceed [`Self::MAX_POWER`].
+    #[inline(always)]
+    pub const fn bits(k: u32) -> u32 {
+        debug_assert!(k <= Self::MAX_POWER);
+        Self::BITS[k as usize / ROW][k as usize % ROW]
+    }
+
+    /// Returns the number of decimal digits in `value`, `0` for zero.
+    ///
+    /// The bit length of `value` determines the count up to a single
+    /// comparison, which is only needed when `value` has exactly as many bits
+    /// as the next power of ten.
+    #[inline]
+    pub const fn digits_count(value: UInt<N>) -> u32 {
+        let bits = value.bits();
+
+        if bits == 0 {
+            return 0;
+        }
+
+        // `10^k <= 2^(bits - 1) <= value`
+        let k = (((bits - 1) as u64 * LOG10_2) >> 32) as u32;
+
+        if k == Self::MAX_POWER || Self::bits(k + 1) > bits || value.lt(&Self::power(k + 1)) {
+            k + 1
+        } else {
+            k + 2
+        }
+    }
+}
+
+const fn max_power<const N: usize>() -> u32 {
+    let mut power = UInt::<N>::ONE;
+    let mut k = 0;
+
+    loop {
+        let carry;
+        (power, carry) = mul_add_digit(power, 10, 0);
+
+        if carry != 0 {
+            return k;
+        }
+
+        k += 1;
+    }
+}
+
+const fn powers<const N: usize>() -> [[UInt<N>; ROW]; N] {
+    let mut table = [[UInt::ZERO; ROW]; N];
+    let mut power = UInt::ONE;
+    let mut i = 0;
+
+    // Entries beyond `MAX_POWER` overflow and are never read.
+    while i < N * ROW {
+        table[i / ROW][i % ROW] = power;
+        (power, _) = mul_add_digit(power, 10, 0);
+        i += 1;
+    }
+
+    table
+}
+
+const fn bits<const N: usize>(powers: &[[UInt<N>; ROW]; N]) -> [[u32; ROW]; N] {
+    let mut table = [[0; ROW]; N];
+    let mut i = 0;
+
+    while i < N * ROW {
+        table[i / ROW][i % ROW] = powers[i / ROW][i % ROW].bits();
+        i += 1;
+    }
+
+    table
+}
@@ -0,0 +1,261 @@
+use crate::int::{
+    math::{div_rem_digit, from_u128, isqrt},
+    Montgomery, UInt,
+};
+
+/// Primes below 256, used for trial division and as the Miller–Rabin bases.
+const SMALL_PRIMES: [u64; 54] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+/// The first 13 primes as bases make the Miller–Rabin test exact below this
+/// bound (Sorenson and Webster, 2015).
+const DETERMINISTIC_BOUND: u128 = 3_317_044_064_679_887_385_961_981;
+
+/// Number of bases that make the test exact below [`DETERMINISTIC_BOUND`].
+const DETERMINISTIC_BASES: usize = 13;
+
+/// Tests `n` for primality: trial division by the small primes followed by
+/// the Miller–Rabin test, exact with the first 13 primes as bases below
+/// [`DETERMINISTIC_BOUND`]. Larger values are tested with the Baillie–PSW
+/// test: the Miller–Rabin test to base 2 and the strong Lucas test.
+#[inline]
+pub const fn is_probable_prime<const N: usize>(n: UInt<N>) -> bool {
+    let mut i = 0;
+
+    while i < SMALL_PRIMES.len() {
+        let p = SMALL_PRIMES[i];
+
+        if n.digits()[0] == p && n.bits() <= u64::BITS {
+            return true;
+        }
+
+        if div_rem_digit(*n.digits(), p).1 == 0 {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    // No factors below 256.
+    if n.bits() <= 16 {
+        return !n.le(&UInt::ONE);
+    }
+
+    let large = match from_u128::<N>(DETERMINISTIC_BOUND) {
+        (bound, false) => n.ge(&bound),
+        _ => true,
+    };
+
+    let bases = if large { 1 } else { DETERMINISTIC_BASES };
+
+    let ctx = Montgomery::new(n);
+
+    // n - 1 = d * 2^s with odd d.
+    let n_minus_one = n.wrapping_sub(UInt::ONE);
+    let s = n_minus_one.trailing_zeros();
+    let d = n_minus_one.wrapping_shr(s);
+
+    let one = ctx.one();
+    let minus_one = ctx.sub(UInt::ZERO, one);
+
+    let mut i = 0;
+
+    'bases: while i < bases {
+        let base = ctx.to_montgomery(UInt::from_digit(SMALL_PRIMES[i]));
+        i += 1;
+
+        let mut x = ctx.pow_montgomery(base, d);
+
+        if x.eq(&one) || x.eq(&minus_one) {
+            continue;
+        }
+
+        let mut r = 1;
+        while r < s {
+            x = ctx.square(x);
+
+            if x.eq(&minus_one) {
+                continue 'bases;
+            }
+
+            if x.eq(&one) {
+                return false;
+            }
+
+            r += 1;
+        }
+
+        return false;
+    }
+
+    !large || is_strong_lucas_probable_prime(n, &ctx)
+}
+
+/// Strong Lucas probable prime test of an odd `n` with no small factors,
+/// with the parameters `P = 1` and `Q = (1 - D) / 4` chosen by Selfridge's
+/// method: `D` is the first of `5, -7, 9, -11, ...` with the Jacobi symbol
+/// `(D/n) = -1`.
+#[inline]
+const fn is_strong_lucas_probable_prime<const N: usize>(n: UInt<N>, ctx: &Montgomery<N>) -> bool {
+    let mut d: i64 = 5;
+    let mut k = 0;
+
+    loop {
+        match jacobi(d, n) {
+            -1 => break,
+            // `n` has no factors below 256, so it isn't `|D|` itself.
+            0 => return false,
+            _ => {}
+        }
+
+        // `(D/n) = -1` never holds for squares.
+        k += 1;
+        if k == 8 && is_square(n) {
+            return false;
+        }
+
+        d = if d > 0 { -d - 2 } else { -d + 2 };
+    }
+
+    let q = (1 - d) / 4;
+    let dm = signed_to_montgomery(ctx, d);
+    let qm = signed_to_montgomery(ctx, q);
+
+    // n + 1 = m * 2^s with odd m. `n` is odd and not `MAX`, which is a
+    // multiple of 3, so `n + 1` doesn't overflow.
+    let n_plus_one = n.wrapping_add(UInt::ONE);
+    let s = n_plus_one.trailing_zeros();
+    let m = n_plus_one.wrapping_shr(s);
+
+    // U_1 = 1, V_1 = P = 1.
+    let mut u = ctx.one();
+    let mut v = ctx.one();
+    let mut qk = qm;
+
+    let mut i = m.bits() - 1;
+    while i > 0 {
+        i -= 1;
+
+        // U_2k = U_k * V_k, V_2k = V_k^2 - 2 * Q^k.
+        u = ctx.mul(u, v);
+        v = ctx.sub(ctx.square(v), ctx.add(qk, qk));
+        qk = ctx.square(qk);
+
+        if m.bit(i) {
+            // U_(2k+1) = (P * U_2k + V_2k) / 2, V_(2k+1) = (D * U_2k + P * V_2k) / 2.
+            let u_next = half(ctx, ctx.add(u, v));
+            v = half(ctx, ctx.add(ctx.mul(dm, u), v));
+            u = u_next;
+            qk = ctx.mul(qk, qm);
+        }
+    }
+
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+
+    let mut r = 1;
+    while r < s {
+        // V_2k = V_k^2 - 2 * Q^k.
+        v = ctx.sub(ctx.square(v), ctx.add(qk, qk));
+        qk = ctx.square(qk);
+
+        if v.is_zero() {
+            return true;
+        }
+
+        r += 1;
+    }
+
+    false
+}
+
+/// Returns the Jacobi symbol `(a/n)` of an odd `n > |a|`.
+#[inline]
+const fn jacobi<const N: usize>(a: i64, n: UInt<N>) -> i32 {
+    let n_low = n.digits()[0];
+
+    // (-1/n) = 1 for n = 1 (mod 4), otherwise -1.
+    let mut result = if a < 0 && n_low & 3 == 3 { -1 } else { 1 };
+    let mut a = a.unsigned_abs();
+
+    // (2/n) = 1 for n = ±1 (mod 8), otherwise -1.
+    while a & 1 == 0 {
+        a >>= 1;
+        if matches!(n_low & 7, 3 | 5) {
+            result = -result;
+        }
+    }
+
+    // Quadratic reciprocity: (a/n) = (n/a) unless a = n = 3 (mod 4).
+    if a & 3 == 3 && n_low & 3 == 3 {
+        result = -result;
+    }
+
+    result * jacobi_digit(div_rem_digit(*n.digits(), a).1, a)
+}
+
+/// Returns the Jacobi symbol `(a/n)` of an odd `n`.
+#[inline]
+const fn jacobi_digit(mut a: u64, mut n: u64) -> i32 {
+    let mut result = 1;
+
+    while a != 0 {
+        while a & 1 == 0 {
+            a >>= 1;
+            if matches!(n & 7, 3 | 5) {
+                result = -result;
+            }
+        }
+
+        if a & 3 == 3 && n & 3 == 3 {
+            result = -result;
+        }
+
+        (a, n) = (n % a, a);
+    }
+
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// Converts a small signed `value` into the Montgomery form.
+#[inline]
+const fn signed_to_montgomery<const N: usize>(ctx: &Montgomery<N>, value: i64) -> UInt<N> {
+    let abs = ctx.to_montgomery(UInt::from_digit(value.unsigned_abs()));
+
+    if value < 0 {
+        ctx.sub(UInt::ZERO, abs)
+    } else {
+        abs
+    }
+}
+
+/// Halves `value` modulo the odd modulus.
+#[inline]
+const fn half<const N: usize>(ctx: &Montgomery<N>, value: UInt<N>) -> UInt<N> {
+    let half = value.wrapping_shr(1);
+
+    if value.digits()[0] & 1 == 0 {
+        half
+    } else {
+        // (value + m) / 2 without overflowing, both being odd.
+        half.wrapping_add(ctx.modulus().wrapping_shr(1))
+            .wrapping_add(UInt::ONE)
+    }
+}
+
+/// Returns `true` if `n` is a perfect square.
+#[inline]
+const fn is_square<const N: usize>(n: UInt<N>) -> bool {
+    let root = isqrt(n);
+    let (low, high) = root.widening_mul(root);
+
+    high.is_zero() && low.eq(&n)
+}
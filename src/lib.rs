@@ -12,10 +12,18 @@ pub mod int;
 
 mod utils;
 
+#[cfg(feature = "test-util")]
+#[doc(hidden)]
+pub mod test_util;
+
 pub use int::{I1024, I128, I2048, I256, I4096, I512, I8192};
 
 pub use int::{U1024, U128, U2048, U256, U4096, U512, U8192};
 
-pub use decimal::{UD1024, UD128, UD2048, UD256, UD4096, UD512, UD8192};
+pub use decimal::{UD1024, UD128, UD2048, UD256, UD4096, UD32, UD512, UD64, UD8192};
+
+pub use decimal::{D1024, D128, D2048, D256, D4096, D32, D512, D64, D8192};
+
+pub use decimal::{UFD1024, UFD128, UFD2048, UFD256, UFD4096, UFD512, UFD8192};
 
-pub use decimal::{D1024, D128, D2048, D256, D4096, D512, D8192};
+pub use decimal::{FD1024, FD128, FD2048, FD256, FD4096, FD512, FD8192};
@@ -0,0 +1,31 @@
+//! Helpers shared by the test suite and the benchmarks.
+
+/// Seeded xorshift64 generator, so that the randomized tests and benchmarks
+/// are reproducible.
+#[derive(Clone, Debug)]
+pub struct Xorshift(u64);
+
+impl Xorshift {
+    /// Creates a generator from a non-zero `seed`.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        assert!(seed != 0, "xorshift seed must be non-zero");
+        Self(seed)
+    }
+
+    /// Returns the next 64 pseudo-random bits.
+    #[inline]
+    pub const fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns the next 128 pseudo-random bits, the high half first.
+    #[inline]
+    pub const fn next_u128(&mut self) -> u128 {
+        let high = self.next_u64() as u128;
+        (high << 64) | self.next_u64() as u128
+    }
+}
@@ -0,0 +1,164 @@
+use rstest::*;
+
+use fastnum::{
+    dec128, dec256,
+    decimal::{batch, Context, RoundingMode, SignalsTraps},
+    D128, D256, D512,
+};
+
+const MAX_128: D128 = dec128!(340282366920938463463374607431768211455);
+const MAX_256: D256 =
+    dec256!(115792089237316195423570985008687907853269984665640564039457584007913129639935);
+
+#[rstest(::trace)]
+#[case(&[], dec128!(0))]
+#[case(&[dec128!(1.25)], dec128!(1.25))]
+#[case(&[dec128!(1.25), dec128!(-0.50), dec128!(3.00)], dec128!(3.75))]
+#[case(&[dec128!(-1.25), dec128!(-0.50)], dec128!(-1.75))]
+#[case(&[dec128!(1.25), dec128!(-1.25)], dec128!(0.00))]
+#[case(&[dec128!(1.5), dec128!(2.25), dec128!(-3)], dec128!(0.75))]
+#[case(&[dec128!(1e10), dec128!(1e-10)], dec128!(10000000000.0000000001))]
+fn test_sum_slice(#[case] values: &[D128], #[case] expected: D128) {
+    let res = batch::sum_slice(values, Context::default());
+
+    assert_eq!(res, expected);
+    assert_eq!(res.fractional_digits_count(), expected.fractional_digits_count());
+    assert!(res.is_op_ok());
+    assert_eq!(res, values.iter().copied().sum::<D128>());
+}
+
+#[rstest(::trace)]
+#[case(&[dec128!(-0.00)], true)]
+#[case(&[dec128!(-0.00), dec128!(-0)], true)]
+#[case(&[dec128!(-0.00), dec128!(0)], false)]
+#[case(&[dec128!(-1.25), dec128!(1.25)], false)]
+#[case(&[dec128!(0.00)], false)]
+fn test_sum_slice_zero_sign(#[case] values: &[D128], #[case] negative: bool) {
+    let res = batch::sum_slice(values, Context::default());
+
+    assert!(res.is_zero());
+    assert_eq!(res.is_sign_negative(), negative);
+}
+
+#[rstest(::trace)]
+#[case(&[D128::NAN, dec128!(1)])]
+#[case(&[dec128!(1), D128::NAN])]
+fn test_sum_slice_nan(#[case] values: &[D128]) {
+    let ctx = Context::default().with_signal_traps(SignalsTraps::empty());
+    assert!(batch::sum_slice(values, ctx).is_nan());
+}
+
+#[test]
+fn test_sum_slice_no_intermediate_overflow() {
+    let res = batch::sum_slice(&[D128::MAX, D128::MAX, -D128::MAX], Context::default());
+
+    assert_eq!(res, D128::MAX);
+    assert!(res.is_op_ok());
+}
+
+#[rstest(::trace)]
+#[case(RoundingMode::HalfUp)]
+#[case(RoundingMode::Down)]
+#[case(RoundingMode::Up)]
+#[case(RoundingMode::Floor)]
+#[case(RoundingMode::Ceiling)]
+fn test_sum_slice_rounds_once(#[case] mode: RoundingMode) {
+    let ctx = Context::default().with_rounding_mode(mode);
+    let values = [MAX_128, MAX_128, dec128!(-7), MAX_128];
+
+    let expected = values
+        .iter()
+        .map(|value| D256::from(*value))
+        .sum::<D256>()
+        .resize::<2>(ctx);
+
+    let res = batch::sum_slice(&values, ctx);
+
+    assert_eq!(res, expected);
+    assert!(res.is_op_inexact());
+    assert!(res.is_op_rounded());
+}
+
+#[rstest(::trace)]
+#[case(&[], &[], dec128!(0))]
+#[case(&[dec128!(1.50), dec128!(0.25), dec128!(10.00)], &[dec128!(2), dec128!(4), dec128!(-1)], dec128!(-6.00))]
+#[case(&[dec128!(-1.5), dec128!(-2.5)], &[dec128!(-0.1), dec128!(0.2)], dec128!(-0.35))]
+#[case(&[dec128!(1.5), dec128!(2)], &[dec128!(0.1), dec128!(0.25)], dec128!(0.65))]
+fn test_dot(#[case] lhs: &[D128], #[case] rhs: &[D128], #[case] expected: D128) {
+    let res = batch::dot(lhs, rhs, Context::default());
+
+    assert_eq!(res, expected);
+    assert_eq!(res.fractional_digits_count(), expected.fractional_digits_count());
+    assert!(res.is_op_ok());
+}
+
+#[rstest(::trace)]
+#[case(&[dec128!(-0.0)], &[dec128!(1)], true)]
+#[case(&[dec128!(0.0)], &[dec128!(-1)], true)]
+#[case(&[dec128!(-0.0), dec128!(0.0)], &[dec128!(1), dec128!(1)], false)]
+#[case(&[dec128!(-0.0), dec128!(-0)], &[dec128!(1), dec128!(1)], true)]
+fn test_dot_zero_sign(#[case] lhs: &[D128], #[case] rhs: &[D128], #[case] negative: bool) {
+    let res = batch::dot(lhs, rhs, Context::default());
+
+    assert!(res.is_zero());
+    assert_eq!(res.is_sign_negative(), negative);
+}
+
+#[rstest(::trace)]
+#[case(&[MAX_128, MAX_128], &[MAX_128, MAX_128])]
+#[case(&[MAX_128, MAX_128, MAX_128], &[MAX_128, -MAX_128, MAX_128])]
+#[case(&[MAX_128, -MAX_128, dec128!(-3)], &[MAX_128, dec128!(5), MAX_128])]
+#[case(&[dec128!(0.3), dec128!(-0.7)], &[MAX_128, MAX_128])]
+fn test_dot_rounds_once(#[case] lhs: &[D128], #[case] rhs: &[D128]) {
+    let ctx = Context::default();
+
+    let expected = lhs
+        .iter()
+        .zip(rhs)
+        .map(|(lhs, rhs)| D512::from(*lhs) * D512::from(*rhs))
+        .sum::<D512>()
+        .resize::<2>(ctx);
+
+    assert_eq!(batch::dot(lhs, rhs, ctx), expected);
+}
+
+#[test]
+#[should_panic(expected = "slices must have the same length")]
+fn test_dot_length_mismatch() {
+    let _ = batch::dot(&[dec128!(1)], &[], Context::default());
+}
+
+#[rstest(::trace)]
+#[case(&[dec128!(1.5), dec128!(-2), dec128!(0.1)], dec128!(2), &[dec128!(3.0), dec128!(-4), dec128!(0.2)])]
+#[case(&[dec128!(1.5), dec128!(-2)], dec128!(-0.5), &[dec128!(-0.75), dec128!(1.0)])]
+fn test_scale_slice(#[case] values: &[D128], #[case] factor: D128, #[case] expected: &[D128]) {
+    let mut values = values.to_vec();
+    batch::scale_slice(&mut values, factor, Context::default());
+
+    assert_eq!(values, expected);
+}
+
+#[rstest(::trace)]
+#[case(&[dec256!(1.5), dec256!(2), dec256!(0.1)], &[dec256!(2.5), dec256!(-3), dec256!(0.02)], &[dec256!(4.0), dec256!(-1), dec256!(0.12)])]
+#[case(&[dec256!(-1.5), dec256!(-0.25)], &[dec256!(-2.5), dec256!(0.25)], &[dec256!(-4.0), dec256!(0.00)])]
+#[case(&[MAX_256, MAX_256], &[MAX_256, dec256!(-1)], &[MAX_256 + MAX_256, MAX_256 - D256::ONE])]
+fn test_add_slices(#[case] lhs: &[D256], #[case] rhs: &[D256], #[case] expected: &[D256]) {
+    let mut out = vec![D256::ZERO; lhs.len()];
+    batch::add_slices(lhs, rhs, &mut out, Context::default());
+
+    assert_eq!(out, expected);
+
+    for ((out, lhs), rhs) in out.iter().zip(lhs).zip(rhs) {
+        let sum = *lhs + *rhs;
+
+        assert_eq!(out.fractional_digits_count(), sum.fractional_digits_count());
+        assert_eq!(out.is_op_inexact(), sum.is_op_inexact());
+    }
+}
+
+#[test]
+#[should_panic(expected = "slices must have the same length")]
+fn test_add_slices_length_mismatch() {
+    let mut out = [D128::ZERO; 1];
+    batch::add_slices(&[dec128!(1)], &[dec128!(1)], &mut out[..0], Context::default());
+}
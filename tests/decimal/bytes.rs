@@ -0,0 +1,176 @@
+use rstest::*;
+
+use fastnum::{
+    dec128, dec256,
+    decimal::{Context, DecimalBytes, Sign},
+    udec256, D128, D256, U256, UD256,
+};
+
+#[rstest(::trace)]
+#[case(dec256!(0))]
+#[case(dec256!(-0))]
+#[case(dec256!(0.000))]
+#[case(dec256!(1))]
+#[case(dec256!(-123.45))]
+#[case(dec256!(5e300))]
+#[case(dec256!(-1e-300))]
+#[case(D256::MAX)]
+#[case(D256::MIN)]
+#[case(D256::from_parts(U256::MAX, i16::MAX, Sign::Minus))]
+#[case(D256::INFINITY)]
+#[case(D256::NEG_INFINITY)]
+fn test_roundtrip(#[case] d: D256) {
+    let le = d.to_le_bytes();
+    let be = d.to_be_bytes();
+
+    assert_eq!(le.as_ref().len(), DecimalBytes::<4>::LEN);
+    assert_eq!(be.as_ref().len(), DecimalBytes::<4>::LEN);
+
+    for decoded in [D256::from_le_bytes(&le), D256::from_be_bytes(&be)] {
+        assert_eq!(decoded, d);
+        assert_eq!(decoded.into_parts(), d.into_parts());
+        assert!(decoded.is_op_ok());
+    }
+}
+
+#[rstest(::trace)]
+fn test_roundtrip_nan() {
+    let d = D256::NAN.neg();
+
+    assert!(D256::from_le_bytes(&d.to_le_bytes()).is_nan());
+    assert!(D256::from_be_bytes(&d.to_be_bytes()).is_nan());
+    assert_eq!(
+        D256::from_le_bytes(&d.to_le_bytes()).is_sign_negative(),
+        d.is_sign_negative()
+    );
+}
+
+#[rstest(::trace)]
+fn test_roundtrip_signals() {
+    let d = dec128!(1).div(dec128!(3), Context::default());
+    assert!(d.is_op_inexact());
+
+    let decoded = D128::from_le_bytes(&d.to_le_bytes());
+    assert!(decoded.is_op_inexact());
+    assert!(decoded.is_op_rounded());
+    assert_eq!(decoded, d);
+
+    let decoded = D128::from_be_bytes(&d.to_be_bytes());
+    assert!(decoded.is_op_inexact());
+}
+
+#[rstest(::trace)]
+fn test_layout() {
+    let d = D128::from_parts(
+        fastnum::u128!(0x0102030405060708_090a0b0c0d0e0f10),
+        -2,
+        Sign::Minus,
+    );
+
+    assert_eq!(
+        d.to_le_bytes().as_ref(),
+        [
+            0x10, 0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03,
+            0x02, 0x01, 0xfe, 0xff, 0x01, 0x00
+        ]
+    );
+    assert_eq!(
+        d.to_be_bytes().as_ref(),
+        [
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+            0x0f, 0x10, 0xff, 0xfe, 0x01, 0x00
+        ]
+    );
+}
+
+#[rstest(::trace)]
+fn test_special_normalized() {
+    let mut bytes = dec256!(123.45).to_le_bytes();
+    bytes.as_mut()[DecimalBytes::<4>::LEN - 2] = 0b0000_0110;
+
+    let d = D256::from_le_bytes(&bytes);
+    assert!(d.is_nan());
+    assert!(!d.is_infinite());
+    assert_eq!(d.digits(), U256::ZERO);
+
+    bytes.as_mut()[DecimalBytes::<4>::LEN - 2] = 0b1111_0101;
+
+    let d = D256::from_le_bytes(&bytes);
+    assert_eq!(d, D256::NEG_INFINITY);
+    assert_eq!(d.to_le_bytes(), D256::NEG_INFINITY.to_le_bytes());
+}
+
+#[rstest(::trace)]
+#[case(udec256!(0))]
+#[case(udec256!(123.45))]
+#[case(UD256::MAX)]
+#[case(UD256::INFINITY)]
+fn test_unsigned_roundtrip(#[case] d: UD256) {
+    assert_eq!(UD256::from_le_bytes(&d.to_le_bytes()), d);
+    assert_eq!(UD256::from_be_bytes(&d.to_be_bytes()), d);
+}
+
+#[rstest(::trace)]
+fn test_unsigned_layout() {
+    assert_eq!(
+        udec256!(123.45).to_le_bytes(),
+        dec256!(123.45).to_le_bytes()
+    );
+    assert_eq!(UD256::NAN.to_be_bytes(), D256::NAN.to_be_bytes());
+}
+
+#[rstest(::trace)]
+#[case(dec256!(-7.5))]
+#[case(dec256!(-0))]
+#[case(D256::NEG_INFINITY)]
+#[case(D256::MIN)]
+fn test_unsigned_negative(#[case] d: D256) {
+    for decoded in [
+        UD256::from_le_bytes(&d.to_le_bytes()),
+        UD256::from_be_bytes(&d.to_be_bytes()),
+    ] {
+        assert!(decoded.is_nan());
+        assert!(decoded.is_op_invalid());
+    }
+}
+
+#[rstest(::trace)]
+fn test_from_slice() {
+    let mut buf = [0xaa; 3 * DecimalBytes::<4>::LEN];
+    let values = [dec256!(1.5), dec256!(-2), D256::INFINITY];
+
+    for (chunk, d) in buf.chunks_exact_mut(DecimalBytes::<4>::LEN).zip(values) {
+        chunk.copy_from_slice(d.to_le_bytes().as_ref());
+    }
+
+    for (chunk, d) in buf.chunks_exact(DecimalBytes::<4>::LEN).zip(values) {
+        let bytes = DecimalBytes::<4>::from_slice(chunk).unwrap();
+        assert_eq!(D256::from_le_bytes(&bytes), d);
+    }
+
+    assert!(DecimalBytes::<4>::from_slice(&buf[..DecimalBytes::<4>::LEN - 1]).is_none());
+}
+
+#[rstest(::trace)]
+fn test_const() {
+    const BYTES: DecimalBytes<4> = dec256!(-0.25).to_be_bytes();
+    const D: D256 = D256::from_be_bytes(&BYTES);
+
+    assert_eq!(D, dec256!(-0.25));
+}
+
+#[cfg(feature = "numtraits")]
+#[rstest(::trace)]
+fn test_numtraits() {
+    use num_traits::{FromBytes, ToBytes};
+
+    fn roundtrip<T: ToBytes + FromBytes<Bytes = <T as ToBytes>::Bytes>>(x: &T) -> (T, T) {
+        (
+            T::from_le_bytes(&x.to_le_bytes()),
+            T::from_be_bytes(&x.to_be_bytes()),
+        )
+    }
+
+    assert_eq!(roundtrip(&dec256!(-3.14)), (dec256!(-3.14), dec256!(-3.14)));
+    assert_eq!(roundtrip(&udec256!(2.71)), (udec256!(2.71), udec256!(2.71)));
+}
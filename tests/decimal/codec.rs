@@ -0,0 +1,401 @@
+use rstest::*;
+
+use fastnum::{
+    dec128, dec256, dec64,
+    decimal::{
+        codec::{
+            mysql, packed,
+            zoned::{self, Charset},
+        },
+        Context, ParseError, RoundingMode,
+    },
+    udec128, D128, D256, UD128,
+};
+
+#[rstest(::trace)]
+#[case(dec128!(12345), 5, 0, &[0x12, 0x34, 0x5c])]
+#[case(dec128!(-12345), 5, 0, &[0x12, 0x34, 0x5d])]
+#[case(dec128!(1234), 4, 0, &[0x01, 0x23, 0x4c])]
+#[case(dec128!(-1.5), 4, 2, &[0x00, 0x15, 0x0d])]
+#[case(dec128!(12.3), 5, 2, &[0x01, 0x23, 0x0c])]
+#[case(dec128!(0), 1, 0, &[0x0c])]
+#[case(dec128!(0.00), 3, 2, &[0x00, 0x0c])]
+#[case(dec128!(12300), 3, -2, &[0x12, 0x3c])]
+#[case(dec128!(0.0042), 2, 4, &[0x04, 0x2c])]
+#[case(dec128!(-999999999999999999999999999999999999), 36, 0, &[0x09, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9d])]
+fn test_packed(#[case] d: D128, #[case] digits: u32, #[case] scale: i16, #[case] expected: &[u8]) {
+    let mut buf = vec![0; packed::len(digits)];
+
+    packed::encode(&d, digits, scale, &mut buf).unwrap();
+    assert_eq!(buf, expected);
+
+    let decoded = packed::decode::<2>(&buf, digits, scale).unwrap();
+    assert_eq!(decoded, d);
+    assert_eq!(decoded.fractional_digits_count(), scale);
+}
+
+#[rstest(::trace)]
+fn test_packed_unsigned() {
+    let mut buf = [0; 3];
+
+    packed::encode_unsigned(&udec128!(123.45), 5, 2, &mut buf).unwrap();
+    assert_eq!(buf, [0x12, 0x34, 0x5f]);
+    assert_eq!(
+        packed::decode_unsigned::<2>(&buf, 5, 2),
+        Ok(udec128!(123.45))
+    );
+
+    assert_eq!(
+        packed::decode_unsigned::<2>(&[0x12, 0x34, 0x5c], 5, 2),
+        Ok(udec128!(123.45))
+    );
+    assert_eq!(
+        packed::decode_unsigned::<2>(&[0x12, 0x34, 0x5d], 5, 2),
+        Err(ParseError::Signed)
+    );
+    assert_eq!(
+        packed::decode_unsigned::<2>(&[0x00, 0x00, 0x0d], 5, 2),
+        Err(ParseError::Signed)
+    );
+}
+
+#[rstest(::trace)]
+#[case(0xa, false)]
+#[case(0xb, true)]
+#[case(0xc, false)]
+#[case(0xd, true)]
+#[case(0xe, false)]
+#[case(0xf, false)]
+fn test_packed_signs(#[case] sign: u8, #[case] negative: bool) {
+    let d = packed::decode::<2>(&[0x12, 0x30 | sign], 3, 1).unwrap();
+
+    assert_eq!(d.abs(), dec128!(12.3));
+    assert_eq!(d.is_sign_negative(), negative);
+}
+
+#[rstest(::trace)]
+#[case(&[0x12, 0x34, 0x5c], 4, ParseError::InvalidLiteral)]
+#[case(&[0x12, 0x34], 5, ParseError::InvalidLiteral)]
+#[case(&[0x12, 0x34, 0x5c], 0, ParseError::Empty)]
+#[case(&[0x12, 0x34, 0x55], 5, ParseError::InvalidLiteral)]
+#[case(&[0x12, 0x3a, 0x5c], 5, ParseError::InvalidLiteral)]
+#[case(&[0xf2, 0x34, 0x5c], 5, ParseError::InvalidLiteral)]
+fn test_packed_invalid(#[case] bytes: &[u8], #[case] digits: u32, #[case] expected: ParseError) {
+    assert_eq!(packed::decode::<2>(bytes, digits, 0), Err(expected));
+}
+
+#[rstest(::trace)]
+fn test_packed_invalid_pad() {
+    // The pad nibble of an even number of digits must be zero.
+    assert_eq!(
+        packed::decode::<2>(&[0x11, 0x23, 0x4c], 4, 0),
+        Err(ParseError::InvalidLiteral)
+    );
+    assert_eq!(
+        packed::decode::<2>(&[0x11, 0x23, 0x4c], 5, 0),
+        Ok(dec128!(11234))
+    );
+}
+
+#[rstest(::trace)]
+#[case(dec128!(123456), 5, 0, ParseError::PosOverflow)]
+#[case(dec128!(-1000), 5, 2, ParseError::NegOverflow)]
+#[case(dec128!(1.234), 5, 2, ParseError::Inexact)]
+#[case(dec128!(1e-40), 5, 0, ParseError::Inexact)]
+#[case(dec128!(1e40), 50, 0, ParseError::PosOverflow)]
+#[case(D128::NAN, 5, 0, ParseError::InvalidLiteral)]
+#[case(D128::INFINITY, 5, 0, ParseError::PosOverflow)]
+#[case(D128::NEG_INFINITY, 5, 0, ParseError::NegOverflow)]
+#[case(dec128!(1), 0, 0, ParseError::Empty)]
+fn test_packed_encode_error(
+    #[case] d: D128,
+    #[case] digits: u32,
+    #[case] scale: i16,
+    #[case] expected: ParseError,
+) {
+    let mut buf = vec![0xff; packed::len(digits)];
+
+    assert_eq!(packed::encode(&d, digits, scale, &mut buf), Err(expected));
+    assert!(buf.iter().all(|&b| b == 0xff));
+}
+
+#[rstest(::trace)]
+fn test_packed_encode_wrong_len() {
+    let mut buf = [0; 4];
+
+    assert_eq!(
+        packed::encode(&dec128!(1), 5, 0, &mut buf),
+        Err(ParseError::InvalidLiteral)
+    );
+    assert_eq!(
+        packed::encode_unsigned(&UD128::ONE, 5, 0, &mut buf[..2]),
+        Err(ParseError::InvalidLiteral)
+    );
+}
+
+#[rstest(::trace)]
+fn test_packed_decode_overflow() {
+    // 20 digits don't fit into `D64`.
+    let bytes = [
+        0x09, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9d,
+    ];
+
+    assert_eq!(
+        packed::decode::<1>(&bytes, 20, 0),
+        Err(ParseError::NegOverflow)
+    );
+    assert_eq!(
+        packed::decode::<1>(
+            &[0x01, 0x84, 0x46, 0x74, 0x40, 0x73, 0x70, 0x95, 0x51, 0x61, 0x5c],
+            20,
+            0
+        ),
+        Ok(dec64!(18446744073709551615))
+    );
+}
+
+#[rstest(::trace)]
+#[case(dec128!(123.45), 2, &[0xf1, 0xf2, 0xf3, 0xf4, 0xc5], b"1234E")]
+#[case(dec128!(-123.45), 2, &[0xf1, 0xf2, 0xf3, 0xf4, 0xd5], b"1234N")]
+#[case(dec128!(-1.20), 2, &[0xf0, 0xf0, 0xf1, 0xf2, 0xd0], b"0012}")]
+#[case(dec128!(0), 0, &[0xf0, 0xf0, 0xf0, 0xf0, 0xc0], b"0000{")]
+#[case(dec128!(99999e3), -3, &[0xf9, 0xf9, 0xf9, 0xf9, 0xc9], b"9999I")]
+#[case(dec128!(-1), 4, &[0xf1, 0xf0, 0xf0, 0xf0, 0xd0], b"1000}")]
+fn test_zoned(#[case] d: D128, #[case] scale: i16, #[case] ebcdic: &[u8], #[case] ascii: &[u8]) {
+    for (charset, expected) in [(Charset::Ebcdic, ebcdic), (Charset::Ascii, ascii)] {
+        let mut buf = [0; 5];
+
+        zoned::encode(&d, scale, charset, &mut buf).unwrap();
+        assert_eq!(buf, expected, "{charset:?}");
+
+        let decoded = zoned::decode::<2>(&buf, scale, charset).unwrap();
+        assert_eq!(decoded, d, "{charset:?}");
+        assert_eq!(decoded.fractional_digits_count(), scale, "{charset:?}");
+    }
+}
+
+#[rstest(::trace)]
+fn test_zoned_ascii_overpunch() {
+    let positive = b"{ABCDEFGHI";
+    let negative = b"}JKLMNOPQR";
+
+    for digit in 0..10u8 {
+        let d = zoned::decode::<2>(&[b'1', positive[digit as usize]], 0, Charset::Ascii).unwrap();
+        assert_eq!(d, D128::from(10 + digit));
+
+        let d = zoned::decode::<2>(&[b'1', negative[digit as usize]], 0, Charset::Ascii).unwrap();
+        assert_eq!(d, -D128::from(10 + digit));
+    }
+}
+
+#[rstest(::trace)]
+fn test_zoned_unsigned() {
+    let mut buf = [0; 4];
+
+    zoned::encode_unsigned(&udec128!(12.5), 1, Charset::Ebcdic, &mut buf).unwrap();
+    assert_eq!(buf, [0xf0, 0xf1, 0xf2, 0xf5]);
+    assert_eq!(
+        zoned::decode_unsigned::<2>(&buf, 1, Charset::Ebcdic),
+        Ok(udec128!(12.5))
+    );
+
+    zoned::encode_unsigned(&udec128!(12.5), 1, Charset::Ascii, &mut buf).unwrap();
+    assert_eq!(&buf, b"0125");
+
+    assert_eq!(
+        zoned::decode_unsigned::<2>(&[0xf0, 0xf1, 0xf2, 0xc5], 1, Charset::Ebcdic),
+        Ok(udec128!(12.5))
+    );
+    assert_eq!(
+        zoned::decode_unsigned::<2>(&[0xf0, 0xf1, 0xf2, 0xd5], 1, Charset::Ebcdic),
+        Err(ParseError::Signed)
+    );
+}
+
+#[rstest(::trace)]
+#[case(&[], Charset::Ebcdic, ParseError::Empty)]
+#[case(&[0xf1, 0xc2, 0xf3], Charset::Ebcdic, ParseError::InvalidLiteral)]
+#[case(&[0xf1, 0xf2, 0xfa], Charset::Ebcdic, ParseError::InvalidLiteral)]
+#[case(&[0xf1, 0xf2, 0x33], Charset::Ebcdic, ParseError::InvalidLiteral)]
+#[case(b"12 ", Charset::Ascii, ParseError::InvalidLiteral)]
+#[case(b"1A3", Charset::Ascii, ParseError::InvalidLiteral)]
+#[case(b"-12", Charset::Ascii, ParseError::InvalidLiteral)]
+#[case(b"12S", Charset::Ascii, ParseError::InvalidLiteral)]
+#[case(b"99999999999999999999}", Charset::Ascii, ParseError::NegOverflow)]
+fn test_zoned_invalid(
+    #[case] bytes: &[u8],
+    #[case] charset: Charset,
+    #[case] expected: ParseError,
+) {
+    assert_eq!(zoned::decode::<1>(bytes, 0, charset), Err(expected));
+}
+
+#[rstest(::trace)]
+fn test_zoned_encode_error() {
+    let mut buf = [b'x'; 3];
+
+    assert_eq!(
+        zoned::encode(&dec128!(-1000), 0, Charset::Ascii, &mut buf),
+        Err(ParseError::NegOverflow)
+    );
+    assert_eq!(
+        zoned::encode(&dec128!(0.5), 0, Charset::Ascii, &mut buf),
+        Err(ParseError::Inexact)
+    );
+    assert_eq!(
+        zoned::encode(&dec128!(1), 0, Charset::Ascii, &mut []),
+        Err(ParseError::Empty)
+    );
+    assert_eq!(&buf, b"xxx");
+}
+
+#[rstest(::trace)]
+fn test_roundtrip() {
+    let mut value = dec256!(1);
+    let factor = dec256!(-3.1);
+
+    for digits in 1..=70u32 {
+        let scale = (digits / 4) as i16;
+        let d = value.with_scale(scale, Context::default());
+
+        let mut buf = vec![0; packed::len(digits)];
+        packed::encode(&d, digits, scale, &mut buf).unwrap();
+        assert_eq!(packed::decode::<4>(&buf, digits, scale), Ok(d), "{d}");
+
+        let mut buf = vec![0; digits as usize];
+        for charset in [Charset::Ebcdic, Charset::Ascii] {
+            zoned::encode(&d, scale, charset, &mut buf).unwrap();
+            assert_eq!(zoned::decode::<4>(&buf, scale, charset), Ok(d), "{d}");
+        }
+
+        value = (value * factor).round(0, RoundingMode::HalfUp);
+    }
+}
+
+#[rstest(::trace)]
+#[case(dec256!(1234567890.1234), 14, 4, &[0x81, 0x0d, 0xfb, 0x38, 0xd2, 0x04, 0xd2])]
+#[case(dec256!(-1234567890.1234), 14, 4, &[0x7e, 0xf2, 0x04, 0xc7, 0x2d, 0xfb, 0x2d])]
+#[case(dec256!(0.00), 10, 2, &[0x80, 0x00, 0x00, 0x00, 0x00])]
+#[case(dec256!(-0.01), 10, 2, &[0x7f, 0xff, 0xff, 0xff, 0xfe])]
+#[case(dec256!(123456789.987654321), 18, 9, &[0x87, 0x5b, 0xcd, 0x15, 0x3a, 0xde, 0x68, 0xb1])]
+#[case(dec256!(0.12345), 5, 5, &[0x80, 0x30, 0x39])]
+#[case(dec256!(42), 2, 0, &[0xaa])]
+#[case(dec256!(-12345678901.500), 20, 3, &[0x7f, 0xff, 0xff, 0xf3, 0xeb, 0x65, 0x5b, 0xca, 0xfe, 0x0b])]
+#[case(
+    dec256!(99999999999999999999999999999999999.999999999999999999999999999999),
+    65,
+    30,
+    &[
+        0x85, 0xf5, 0xe0, 0xff, 0x3b, 0x9a, 0xc9, 0xff, 0x3b, 0x9a, 0xc9, 0xff, 0x3b, 0x9a, 0xc9,
+        0xff, 0x3b, 0x9a, 0xc9, 0xff, 0x3b, 0x9a, 0xc9, 0xff, 0x3b, 0x9a, 0xc9, 0xff, 0x03, 0xe7,
+    ]
+)]
+fn test_mysql(
+    #[case] d: D256,
+    #[case] precision: u32,
+    #[case] scale: u32,
+    #[case] expected: &[u8],
+) {
+    let mut buf = vec![0; mysql::len(precision, scale)];
+
+    mysql::encode(&d, precision, scale, &mut buf).unwrap();
+    assert_eq!(buf, expected);
+
+    let decoded = mysql::decode::<4>(&buf, precision, scale).unwrap();
+    assert_eq!(decoded, d);
+    assert_eq!(decoded.fractional_digits_count(), scale as i16);
+}
+
+#[rstest(::trace)]
+#[case(1, 0, 1)]
+#[case(9, 0, 4)]
+#[case(10, 0, 5)]
+#[case(10, 2, 5)]
+#[case(14, 4, 7)]
+#[case(18, 9, 8)]
+#[case(65, 30, 30)]
+fn test_mysql_len(#[case] precision: u32, #[case] scale: u32, #[case] expected: usize) {
+    assert_eq!(mysql::len(precision, scale), expected);
+}
+
+#[rstest(::trace)]
+fn test_mysql_unsigned() {
+    let mut buf = [0; 3];
+
+    mysql::encode_unsigned(&udec128!(12.34), 5, 2, &mut buf).unwrap();
+    assert_eq!(buf, [0x80, 0x0c, 0x22]);
+    assert_eq!(mysql::decode_unsigned::<2>(&buf, 5, 2), Ok(udec128!(12.34)));
+    assert_eq!(
+        mysql::decode_unsigned::<2>(&[0x7f, 0xf3, 0xdd], 5, 2),
+        Err(ParseError::Signed)
+    );
+}
+
+#[rstest(::trace)]
+#[case(&[0x80, 0x0c, 0x22], 0, 0, ParseError::Empty)]
+#[case(&[0x80, 0x0c, 0x22], 5, 6, ParseError::InvalidLiteral)]
+#[case(&[0x80, 0x0c], 5, 2, ParseError::InvalidLiteral)]
+#[case(&[0x83, 0xe8, 0x22], 5, 2, ParseError::InvalidLiteral)]
+#[case(&[0x80, 0x0c, 0x64], 5, 2, ParseError::InvalidLiteral)]
+#[case(&[0xbb, 0x9a, 0xc9, 0xff, 0x80], 10, 0, ParseError::InvalidLiteral)]
+#[case(&[0x80, 0x3b, 0x9a, 0xca, 0x00], 10, 0, ParseError::InvalidLiteral)]
+#[case(&[0x99, 0x3b, 0x9a, 0xc9, 0xff, 0x3b, 0x9a, 0xc9, 0xff], 20, 0, ParseError::PosOverflow)]
+fn test_mysql_invalid(
+    #[case] bytes: &[u8],
+    #[case] precision: u32,
+    #[case] scale: u32,
+    #[case] expected: ParseError,
+) {
+    assert_eq!(mysql::decode::<1>(bytes, precision, scale), Err(expected));
+}
+
+#[rstest(::trace)]
+fn test_mysql_encode_error() {
+    let mut buf = [0x55; 3];
+
+    assert_eq!(
+        mysql::encode(&dec128!(-1000), 5, 2, &mut buf),
+        Err(ParseError::NegOverflow)
+    );
+    assert_eq!(
+        mysql::encode(&dec128!(1.001), 5, 2, &mut buf),
+        Err(ParseError::Inexact)
+    );
+    assert_eq!(
+        mysql::encode(&D128::NAN, 5, 2, &mut buf),
+        Err(ParseError::InvalidLiteral)
+    );
+    assert_eq!(
+        mysql::encode(&dec128!(1), 5, 2, &mut buf[..2]),
+        Err(ParseError::InvalidLiteral)
+    );
+    assert_eq!(buf, [0x55; 3]);
+}
+
+#[rstest(::trace)]
+fn test_mysql_order() {
+    let mut values = Vec::new();
+    let mut value = dec256!(1.5);
+
+    for _ in 0..40 {
+        values.push(value);
+        values.push(-value);
+        value = (value * dec256!(-2.71)).round(6, RoundingMode::HalfUp);
+    }
+
+    let encoded: Vec<_> = values
+        .iter()
+        .map(|d| {
+            let mut buf = vec![0; mysql::len(30, 6)];
+            mysql::encode(d, 30, 6, &mut buf).unwrap();
+            assert_eq!(mysql::decode::<4>(&buf, 30, 6), Ok(*d));
+            buf
+        })
+        .collect();
+
+    for (a, ea) in values.iter().zip(&encoded) {
+        for (b, eb) in values.iter().zip(&encoded) {
+            assert_eq!(ea.cmp(eb), a.cmp(b), "{a} <=> {b}");
+        }
+    }
+}
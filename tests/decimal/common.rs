@@ -1,5 +1,6 @@
 pub(crate) mod cmp;
 pub(crate) mod extras;
+pub(crate) mod fixed;
 pub(crate) mod fmt;
 pub(crate) mod from;
 pub(crate) mod hash;
@@ -6,3 +6,6 @@ pub(crate) mod serde;
 
 #[cfg(feature = "utoipa")]
 pub(crate) mod utoipa;
+
+#[cfg(feature = "sqlx")]
+pub(crate) mod sqlx;
@@ -3,3 +3,6 @@ pub(crate) mod mysql;
 
 #[cfg(feature = "diesel_postgres")]
 pub(crate) mod pg;
+
+#[cfg(feature = "diesel_sqlite")]
+pub(crate) mod sqlite;
@@ -0,0 +1,171 @@
+macro_rules! test_impl {
+    (D, $bits: literal) => {
+        paste::paste! { test_impl!(SIGNED: $bits, [< dec $bits >], [<D $bits>]); }
+    };
+    (UD, $bits: literal) => {
+        paste::paste! { test_impl!(UNSIGNED: $bits, [< udec $bits >], [<UD $bits>]); }
+    };
+    (UNSIGNED: $bits: tt, $dec: ident, $D: ident) => {
+        mod $dec {
+            use rstest::*;
+            use fastnum::{$dec, $D};
+
+            super::test_impl!(COMMON: $bits, $dec, $D);
+            super::test_impl!(UNSIGNED: $bits, $dec, $D, THIS);
+        }
+    };
+    (SIGNED: $bits: tt, $dec: ident, $D: ident) => {
+        mod $dec {
+            use rstest::*;
+            use fastnum::{$dec, $D};
+
+            super::test_impl!(COMMON: $bits, $dec, $D);
+            super::test_impl!(SIGNED: $bits, $dec, $D, THIS);
+        }
+    };
+    (COMMON: $bits: tt, $dec: ident, $D: ident) => {
+        use diesel::{dsl::sql, prelude::*, sql_types::{Double, Numeric, Text}, SqliteConnection};
+
+        diesel::table! {
+            t (id) {
+                id -> Integer,
+                n -> Numeric,
+                s -> Text,
+                r -> Double,
+            }
+        }
+
+        fn connect() -> SqliteConnection {
+            let mut conn = SqliteConnection::establish(":memory:").unwrap();
+
+            diesel::sql_query("CREATE TABLE t (id INTEGER PRIMARY KEY, n NUMERIC, s TEXT, r REAL)")
+                .execute(&mut conn)
+                .unwrap();
+
+            conn
+        }
+
+        fn roundtrip(d: $D) -> ($D, $D, $D) {
+            let mut conn = connect();
+
+            diesel::insert_into(t::table)
+                .values((t::id.eq(1), t::n.eq(d), t::s.eq(d), t::r.eq(&d)))
+                .execute(&mut conn)
+                .unwrap();
+
+            t::table.select((t::n, t::s, t::r)).first(&mut conn).unwrap()
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0), $dec!(0))]
+        #[case($dec!(1.20), $dec!(1.2))]
+        #[case($dec!(12345.67890), $dec!(12345.6789))]
+        #[case($dec!(0.1), $dec!(0.1))]
+        #[case($dec!(1e300), $dec!(1e300))]
+        #[case($dec!(1.00000000000000000001), $dec!(1))]
+        fn test_roundtrip(#[case] d: $D, #[case] real: $D) {
+            let (n, s, r) = roundtrip(d);
+
+            assert_eq!(n, real);
+            assert_eq!(s, d);
+            assert_eq!(s.to_string(), d.to_string());
+            assert_eq!(r, real);
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0.000000000000000000000000000001))]
+        #[case($dec!(123456789012345678901234567890.12))]
+        #[case($D::MAX)]
+        fn test_roundtrip_text(#[case] d: $D) {
+            let (_, s, _) = roundtrip(d);
+
+            assert_eq!(s, d);
+            assert_eq!(s.to_string(), d.to_string());
+        }
+
+        #[rstest(::trace)]
+        #[case("'1.20'", $dec!(1.20))]
+        #[case("42", $dec!(42))]
+        #[case("9223372036854775807", $dec!(9223372036854775807))]
+        #[case("0.1", $dec!(0.1))]
+        #[case("2.5e-10", $dec!(2.5e-10))]
+        #[case("CAST('12.50' AS REAL)", $dec!(12.5))]
+        fn test_decode(#[case] expr: &str, #[case] expected: $D) {
+            let mut conn = connect();
+
+            let n: $D = diesel::select(sql::<Numeric>(expr)).get_result(&mut conn).unwrap();
+            let s: $D = diesel::select(sql::<Text>(expr)).get_result(&mut conn).unwrap();
+            let r: $D = diesel::select(sql::<Double>(expr)).get_result(&mut conn).unwrap();
+
+            for d in [n, s, r] {
+                assert_eq!(d, expected);
+                assert_eq!(d.to_string(), expected.to_string());
+            }
+        }
+
+        #[rstest(::trace)]
+        #[case("'abc'")]
+        #[case("''")]
+        fn test_decode_invalid(#[case] expr: &str) {
+            let mut conn = connect();
+            assert!(diesel::select(sql::<Text>(expr)).get_result::<$D>(&mut conn).is_err());
+        }
+
+        #[rstest(::trace)]
+        fn test_encode_nan() {
+            let mut conn = connect();
+
+            assert!(diesel::insert_into(t::table)
+                .values((t::id.eq(1), t::r.eq($D::NAN)))
+                .execute(&mut conn)
+                .is_err());
+        }
+    };
+    (UNSIGNED: $bits: tt, $dec: ident, $D: ident, THIS) => {
+        #[rstest(::trace)]
+        #[case("'-1.20'")]
+        #[case("-42")]
+        #[case("-0.1")]
+        fn test_decode_unsigned(#[case] expr: &str) {
+            let mut conn = connect();
+
+            let e = diesel::select(sql::<Numeric>(expr))
+                .get_result::<$D>(&mut conn)
+                .unwrap_err();
+
+            assert!(format!("{e:?}").contains(concat!(
+                "(fastnum) ",
+                stringify!($D),
+                " does not support negative values"
+            )));
+        }
+    };
+    (SIGNED: $bits: tt, $dec: ident, $D: ident, THIS) => {
+        #[rstest(::trace)]
+        #[case($dec!(-1.20), $dec!(-1.2))]
+        #[case($dec!(-42), $dec!(-42))]
+        #[case($D::NEG_INFINITY, $D::NEG_INFINITY)]
+        fn test_roundtrip_signed(#[case] d: $D, #[case] real: $D) {
+            let (n, s, r) = roundtrip(d);
+
+            assert_eq!(n, real);
+            assert_eq!(s, d);
+            assert_eq!(r, real);
+        }
+
+        #[rstest(::trace)]
+        #[case("'-1.20'", $dec!(-1.20))]
+        #[case("-42", $dec!(-42))]
+        #[case("-9223372036854775808", $dec!(-9223372036854775808))]
+        #[case("-0.1", $dec!(-0.1))]
+        fn test_decode_signed(#[case] expr: &str, #[case] expected: $D) {
+            let mut conn = connect();
+
+            let d: $D = diesel::select(sql::<Numeric>(expr)).get_result(&mut conn).unwrap();
+            assert_eq!(d, expected);
+            assert_eq!(d.to_string(), expected.to_string());
+        }
+    };
+}
+
+pub(crate) use test_impl;
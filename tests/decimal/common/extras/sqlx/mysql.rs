@@ -0,0 +1,101 @@
+macro_rules! test_impl {
+    (D, $bits: literal) => {
+        paste::paste! { test_impl!(SIGNED: $bits, [< dec $bits >], [<D $bits>]); }
+    };
+    (UD, $bits: literal) => {
+        paste::paste! { test_impl!(UNSIGNED: $bits, [< udec $bits >], [<UD $bits>]); }
+    };
+    (UNSIGNED: $bits: tt, $dec: ident, $D: ident) => {
+        mod $dec {
+            use rstest::*;
+            use fastnum::{$dec, $D};
+            use fastnum::decimal::{extras::sqlx::mysql, ParseError};
+            use sqlx::{encode::{Encode, IsNull}, mysql::MySql};
+
+            super::test_impl!(COMMON: $bits, $dec, $D);
+        }
+    };
+    (SIGNED: $bits: tt, $dec: ident, $D: ident) => {
+        mod $dec {
+            use rstest::*;
+            use fastnum::{$dec, $D};
+            use fastnum::decimal::{extras::sqlx::mysql, ParseError};
+            use sqlx::{encode::{Encode, IsNull}, mysql::MySql};
+
+            super::test_impl!(COMMON: $bits, $dec, $D);
+            super::test_impl!(SIGNED: $bits, $dec, $D, THIS);
+        }
+    };
+    (COMMON: $bits: tt, $dec: ident, $D: ident) => {
+        fn encode(d: $D) -> Result<Vec<u8>, String> {
+            let mut buf = Vec::new();
+            let is_null = Encode::<MySql>::encode_by_ref(&d, &mut buf).map_err(|e| e.to_string())?;
+            assert!(matches!(is_null, IsNull::No));
+            Ok(buf)
+        }
+
+        fn decode(bytes: &[u8]) -> Result<String, ParseError> {
+            mysql::decode::<{ $bits / 64 }>(bytes).map(|d| d.to_string())
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0), b"\x010")]
+        #[case($dec!(0.00), b"\x040.00")]
+        #[case($dec!(1.10), b"\x041.10")]
+        #[case($dec!(12345.67890), b"\x0b12345.67890")]
+        #[case($dec!(100000000), b"\x09100000000")]
+        fn test_encode(#[case] d: $D, #[case] expected: &[u8]) {
+            assert_eq!(encode(d).as_deref(), Ok(expected));
+        }
+
+        #[rstest(::trace)]
+        #[case($D::NAN)]
+        #[case($D::INFINITY)]
+        fn test_encode_invalid(#[case] d: $D) {
+            assert!(encode(d).is_err());
+        }
+
+        #[rstest(::trace)]
+        #[case(b"0", $dec!(0))]
+        #[case(b"0.000", $dec!(0.000))]
+        #[case(b"123.4500", $dec!(123.4500))]
+        #[case(b"99999999999999999999999999999.999999", $dec!(99999999999999999999999999999.999999))]
+        fn test_decode(#[case] bytes: &[u8], #[case] expected: $D) {
+            assert_eq!(decode(bytes), Ok(expected.to_string()));
+        }
+
+        #[rstest(::trace)]
+        #[case(b"", ParseError::Empty)]
+        #[case(b"abc", ParseError::InvalidLiteral)]
+        #[case(b"1.5\xff", ParseError::InvalidLiteral)]
+        fn test_decode_invalid(#[case] bytes: &[u8], #[case] expected: ParseError) {
+            assert_eq!(decode(bytes), Err(expected));
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0.5))]
+        #[case($dec!(1234567890.0987654321))]
+        #[case($dec!(0.000000000000000000000000000001))]
+        fn test_roundtrip(#[case] d: $D) {
+            let bytes = encode(d).unwrap();
+            assert_eq!(bytes[0] as usize, bytes.len() - 1);
+            assert_eq!(decode(&bytes[1..]), Ok(d.to_string()));
+        }
+    };
+    (SIGNED: $bits: tt, $dec: ident, $D: ident, THIS) => {
+        #[rstest(::trace)]
+        #[case($dec!(-1234), b"\x05-1234")]
+        #[case($dec!(-123.456), b"\x08-123.456")]
+        fn test_encode_signed(#[case] d: $D, #[case] expected: &[u8]) {
+            assert_eq!(encode(d).as_deref(), Ok(expected));
+            assert_eq!(decode(&expected[1..]), Ok(d.to_string()));
+        }
+
+        #[rstest(::trace)]
+        fn test_encode_neg_infinity() {
+            assert!(encode($D::NEG_INFINITY).is_err());
+        }
+    };
+}
+
+pub(crate) use test_impl;
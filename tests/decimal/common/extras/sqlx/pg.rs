@@ -0,0 +1,110 @@
+macro_rules! test_impl {
+    (D, $bits: literal) => {
+        paste::paste! { test_impl!(SIGNED: $bits, [< dec $bits >], [<D $bits>]); }
+    };
+    (UD, $bits: literal) => {
+        paste::paste! { test_impl!(UNSIGNED: $bits, [< udec $bits >], [<UD $bits>]); }
+    };
+    (UNSIGNED: $bits: tt, $dec: ident, $D: ident) => {
+        mod $dec {
+            use rstest::*;
+            use fastnum::{$dec, $D};
+            use fastnum::decimal::{extras::sqlx::pg, ParseError};
+            use sqlx::{encode::{Encode, IsNull}, postgres::{PgArgumentBuffer, Postgres}};
+
+            super::test_impl!(COMMON: $bits, $dec, $D);
+        }
+    };
+    (SIGNED: $bits: tt, $dec: ident, $D: ident) => {
+        mod $dec {
+            use rstest::*;
+            use fastnum::{$dec, $D};
+            use fastnum::decimal::{extras::sqlx::pg, ParseError};
+            use sqlx::{encode::{Encode, IsNull}, postgres::{PgArgumentBuffer, Postgres}};
+
+            super::test_impl!(COMMON: $bits, $dec, $D);
+            super::test_impl!(SIGNED: $bits, $dec, $D, THIS);
+        }
+    };
+    (COMMON: $bits: tt, $dec: ident, $D: ident) => {
+        fn encode(d: $D) -> Vec<u8> {
+            let mut buf = PgArgumentBuffer::default();
+            let is_null = Encode::<Postgres>::encode_by_ref(&d, &mut buf).unwrap();
+            assert!(matches!(is_null, IsNull::No));
+            buf.to_vec()
+        }
+
+        fn decode(bytes: &[u8]) -> Result<String, ParseError> {
+            pg::decode::<{ $bits / 64 }>(bytes).map(|d| d.to_string())
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0), &[0, 0, 0, 0, 0x00, 0x00, 0, 0])]
+        #[case($dec!(1), &[0, 1, 0, 0, 0x00, 0x00, 0, 0, 0, 1])]
+        #[case($dec!(12345), &[0, 2, 0, 1, 0x00, 0x00, 0, 0, 0, 1, 0x09, 0x29])]
+        #[case($dec!(1.10), &[0, 2, 0, 0, 0x00, 0x00, 0, 2, 0, 1, 0x03, 0xe8])]
+        #[case($dec!(0.01), &[0, 1, 0xff, 0xff, 0x00, 0x00, 0, 2, 0, 100])]
+        #[case($dec!(12345.67890), &[0, 3, 0, 1, 0x00, 0x00, 0, 5, 0, 1, 0x09, 0x29, 0x1a, 0x85])]
+        #[case($dec!(1e8), &[0, 1, 0, 2, 0x00, 0x00, 0, 0, 0, 1])]
+        #[case($D::NAN, &[0, 0, 0, 0, 0xc0, 0x00, 0, 0])]
+        #[case($D::INFINITY, &[0, 0, 0, 0, 0xd0, 0x00, 0, 0])]
+        fn test_encode(#[case] d: $D, #[case] expected: &[u8]) {
+            assert_eq!(encode(d), expected);
+            assert_eq!(decode(expected), Ok(d.to_string()));
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(10000))]
+        #[case($dec!(0.00010000))]
+        #[case($dec!(1234567890.0987654321))]
+        #[case($dec!(1e-30))]
+        #[case($dec!(5000000000000000000000000000000))]
+        #[case($dec!(12345678901234567890123456789012345))]
+        fn test_roundtrip(#[case] d: $D) {
+            assert_eq!(decode(&encode(d)), Ok(d.to_string()));
+        }
+
+        #[rstest(::trace)]
+        #[case(&[0, 2, 0, 0, 0x00, 0x00, 0, 0, 0, 1, 0, 0], "1")]
+        #[case(&[0, 2, 0, 1, 0x00, 0x00, 0, 0, 0, 0, 0, 7], "7")]
+        #[case(&[0, 2, 0, 0, 0x00, 0x00, 0, 4, 0, 1, 0x13, 0x88], "1.5000")]
+        #[case(&[0, 1, 0xff, 0xfe, 0x00, 0x00, 0, 6, 0x04, 0xb0], "0.000012")]
+        #[case(&[0, 0, 0, 5, 0x00, 0x00, 0, 2], "0.00")]
+        fn test_decode(#[case] bytes: &[u8], #[case] expected: &str) {
+            assert_eq!(decode(bytes).as_deref(), Ok(expected));
+        }
+
+        #[rstest(::trace)]
+        #[case(&[])]
+        #[case(&[0, 0, 0, 0, 0x00, 0x00, 0])]
+        #[case(&[0, 1, 0, 0, 0x00, 0x00, 0, 0])]
+        #[case(&[0, 0, 0, 0, 0x00, 0x00, 0, 0, 0, 1])]
+        #[case(&[0xff, 0xff, 0, 0, 0x00, 0x00, 0, 0])]
+        #[case(&[0, 1, 0, 0, 0x00, 0x00, 0, 0, 0x27, 0x10])]
+        #[case(&[0, 1, 0, 0, 0x00, 0x00, 0, 0, 0xff, 0xff])]
+        #[case(&[0, 0, 0, 0, 0x12, 0x34, 0, 0])]
+        fn test_decode_invalid(#[case] bytes: &[u8]) {
+            assert_eq!(decode(bytes), Err(ParseError::InvalidLiteral));
+        }
+    };
+    (SIGNED: $bits: tt, $dec: ident, $D: ident, THIS) => {
+        #[rstest(::trace)]
+        #[case($dec!(-1234), &[0, 1, 0, 0, 0x40, 0x00, 0, 0, 0x04, 0xd2])]
+        #[case($dec!(-123.456), &[0, 2, 0, 0, 0x40, 0x00, 0, 3, 0, 123, 0x11, 0xd0])]
+        #[case($D::NEG_INFINITY, &[0, 0, 0, 0, 0xf0, 0x00, 0, 0])]
+        fn test_encode_signed(#[case] d: $D, #[case] expected: &[u8]) {
+            assert_eq!(encode(d), expected);
+            assert_eq!(decode(expected), Ok(d.to_string()));
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(-0.5))]
+        #[case($dec!(-1e-30))]
+        #[case($dec!(-12345678901234567890123456789012345))]
+        fn test_roundtrip_signed(#[case] d: $D) {
+            assert_eq!(decode(&encode(d)), Ok(d.to_string()));
+        }
+    };
+}
+
+pub(crate) use test_impl;
@@ -0,0 +1,114 @@
+macro_rules! test_impl {
+    (D, $bits: literal) => {
+        paste::paste! { test_impl!(SIGNED: $bits, [< dec $bits >], [<D $bits>]); }
+    };
+    (UD, $bits: literal) => {
+        paste::paste! { test_impl!(UNSIGNED: $bits, [< udec $bits >], [<UD $bits>]); }
+    };
+    (UNSIGNED: $bits: tt, $dec: ident, $D: ident) => {
+        mod $dec {
+            use rstest::*;
+            use fastnum::{$dec, $D};
+
+            super::test_impl!(COMMON: $bits, $dec, $D);
+            super::test_impl!(UNSIGNED: $bits, $dec, $D, THIS);
+        }
+    };
+    (SIGNED: $bits: tt, $dec: ident, $D: ident) => {
+        mod $dec {
+            use rstest::*;
+            use fastnum::{$dec, $D};
+
+            super::test_impl!(COMMON: $bits, $dec, $D);
+            super::test_impl!(SIGNED: $bits, $dec, $D, THIS);
+        }
+    };
+    (COMMON: $bits: tt, $dec: ident, $D: ident) => {
+        use futures_executor::block_on;
+        use sqlx::{Connection, SqliteConnection};
+
+        fn select(sql: &'static str) -> Result<$D, sqlx::Error> {
+            block_on(async {
+                let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+                sqlx::query_scalar(sql).fetch_one(&mut conn).await
+            })
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(0))]
+        #[case($dec!(1.20))]
+        #[case($dec!(12345.67890))]
+        #[case($dec!(0.000000000000000000000000000001))]
+        #[case($dec!(1e300))]
+        #[case($D::MAX)]
+        fn test_roundtrip(#[case] d: $D) {
+            let result: $D = block_on(async {
+                let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+
+                sqlx::query("CREATE TABLE t (d TEXT NOT NULL)")
+                    .execute(&mut conn)
+                    .await?;
+
+                sqlx::query("INSERT INTO t (d) VALUES (?)")
+                    .bind(d)
+                    .execute(&mut conn)
+                    .await?;
+
+                sqlx::query_scalar("SELECT d FROM t").fetch_one(&mut conn).await
+            })
+            .unwrap();
+
+            assert_eq!(result, d);
+            assert_eq!(result.to_string(), d.to_string());
+        }
+
+        #[rstest(::trace)]
+        #[case("SELECT '1.20'", $dec!(1.20))]
+        #[case("SELECT 42", $dec!(42))]
+        #[case("SELECT 9223372036854775807", $dec!(9223372036854775807))]
+        #[case("SELECT 0.1", $dec!(0.1))]
+        #[case("SELECT 2.5e-10", $dec!(2.5e-10))]
+        #[case("SELECT 1e100", $dec!(1e100))]
+        #[case("SELECT CAST('12.50' AS REAL)", $dec!(12.5))]
+        fn test_decode(#[case] sql: &'static str, #[case] expected: $D) {
+            let d = select(sql).unwrap();
+            assert_eq!(d, expected);
+            assert_eq!(d.to_string(), expected.to_string());
+        }
+
+        #[rstest(::trace)]
+        #[case("SELECT 'abc'")]
+        #[case("SELECT ''")]
+        fn test_decode_invalid(#[case] sql: &'static str) {
+            assert!(select(sql).is_err());
+        }
+    };
+    (UNSIGNED: $bits: tt, $dec: ident, $D: ident, THIS) => {
+        #[rstest(::trace)]
+        #[case("SELECT '-1.20'")]
+        #[case("SELECT -42")]
+        #[case("SELECT -0.1")]
+        fn test_decode_unsigned(#[case] sql: &'static str) {
+            let e = select(sql).unwrap_err();
+            assert!(e.to_string().contains(concat!(
+                "(fastnum) ",
+                stringify!($D),
+                " does not support negative values"
+            )));
+        }
+    };
+    (SIGNED: $bits: tt, $dec: ident, $D: ident, THIS) => {
+        #[rstest(::trace)]
+        #[case("SELECT '-1.20'", $dec!(-1.20))]
+        #[case("SELECT -42", $dec!(-42))]
+        #[case("SELECT -9223372036854775808", $dec!(-9223372036854775808))]
+        #[case("SELECT -0.1", $dec!(-0.1))]
+        fn test_decode_signed(#[case] sql: &'static str, #[case] expected: $D) {
+            let d = select(sql).unwrap();
+            assert_eq!(d, expected);
+            assert_eq!(d.to_string(), expected.to_string());
+        }
+    };
+}
+
+pub(crate) use test_impl;
@@ -0,0 +1,239 @@
+macro_rules! test_impl {
+    (FD, $bits: literal) => {
+        paste::paste! { test_impl!(SIGNED: $bits, [< fdec $bits >], [<FD $bits>], [< dec $bits >], [<D $bits>]); }
+    };
+    (UFD, $bits: literal) => {
+        paste::paste! { test_impl!(UNSIGNED: $bits, [< ufdec $bits >], [<UFD $bits>], [< udec $bits >], [<UD $bits>]); }
+    };
+    (UNSIGNED: $bits: tt, $fdec: ident, $FD: ident, $dec: ident, $D: ident) => {
+        mod $fdec {
+            use rstest::*;
+            use fastnum::{$fdec, $FD, $dec, $D, decimal::{Context, ParseError, RoundingMode::{self, *}}};
+
+            super::test_impl!(COMMON:: $bits, $fdec, $FD, $dec, $D);
+        }
+    };
+    (SIGNED: $bits: tt, $fdec: ident, $FD: ident, $dec: ident, $D: ident) => {
+        mod $fdec {
+            use rstest::*;
+            use fastnum::{$fdec, $FD, $dec, $D, decimal::{Context, ParseError, RoundingMode::{self, *}}};
+
+            super::test_impl!(COMMON:: $bits, $fdec, $FD, $dec, $D);
+            super::test_impl!(SIGNED:: $bits, $fdec, $FD, $dec, $D);
+        }
+    };
+    (COMMON:: $bits: tt, $fdec: ident, $FD: ident, $dec: ident, $D: ident) => {
+        #[rstest(::trace)]
+        #[case("0", "0")]
+        #[case("1.5", "150")]
+        #[case("1.50000", "150")]
+        #[case("0.01", "1")]
+        #[case("12345.67", "1234567")]
+        #[case("1e2", "10000")]
+        fn test_parse(#[case] s: &str, #[case] digits: &str) {
+            let d = $FD::<2>::from_str(s).unwrap();
+            assert_eq!(d.digits().to_string(), digits);
+            assert_eq!(d.fractional_digits_count(), 2);
+            assert!(d.is_op_ok());
+        }
+
+        #[rstest(::trace)]
+        #[case("1.234")]
+        #[case("0.001")]
+        #[case("1e-5")]
+        fn test_parse_inexact(#[case] s: &str) {
+            assert_eq!($FD::<2>::from_str(s), Err(ParseError::Inexact));
+        }
+
+        #[rstest(::trace)]
+        #[case($fdec!(0.1, 2), $fdec!(0.2, 2), $fdec!(0.3, 2))]
+        #[case($fdec!(19.99, 2), $fdec!(0.01, 2), $fdec!(20, 2))]
+        #[case($fdec!(0, 8), $fdec!(0.00000001, 8), $fdec!(0.00000001, 8))]
+        fn test_add<const S: i16>(#[case] a: $FD<S>, #[case] b: $FD<S>, #[case] expected: $FD<S>) {
+            let res = a + b;
+
+            assert_eq!(res, expected);
+            assert_eq!(res.digits(), expected.digits());
+            assert!(res.is_op_ok());
+
+            let mut a = a;
+            a += b;
+            assert_eq!(a, expected);
+        }
+
+        #[rstest(::trace)]
+        #[case($fdec!(1.5, 2), $fdec!(2, 2), $fdec!(3, 2))]
+        #[case($fdec!(0.5, 2), $fdec!(0.5, 2), $fdec!(0.25, 2))]
+        #[case($fdec!(1.23, 2), $fdec!(100, 2), $fdec!(123, 2))]
+        fn test_mul_exact<const S: i16>(#[case] a: $FD<S>, #[case] b: $FD<S>, #[case] expected: $FD<S>) {
+            let res = a * b;
+
+            assert_eq!(res, expected);
+            assert!(res.is_op_ok());
+
+            let mut a = a;
+            a *= b;
+            assert_eq!(a, expected);
+        }
+
+        #[rstest(::trace)]
+        #[case(HalfUp, $fdec!(0.25, 2), $fdec!(0.5, 2), $fdec!(0.13, 2))]
+        #[case(HalfDown, $fdec!(0.25, 2), $fdec!(0.5, 2), $fdec!(0.12, 2))]
+        #[case(HalfEven, $fdec!(0.25, 2), $fdec!(0.5, 2), $fdec!(0.12, 2))]
+        #[case(HalfEven, $fdec!(0.35, 2), $fdec!(0.5, 2), $fdec!(0.18, 2))]
+        #[case(Up, $fdec!(0.11, 2), $fdec!(0.11, 2), $fdec!(0.02, 2))]
+        #[case(Down, $fdec!(0.19, 2), $fdec!(0.19, 2), $fdec!(0.03, 2))]
+        #[case(Ceiling, $fdec!(0.11, 2), $fdec!(0.11, 2), $fdec!(0.02, 2))]
+        #[case(Floor, $fdec!(0.19, 2), $fdec!(0.19, 2), $fdec!(0.03, 2))]
+        #[case(HalfUp, $fdec!(0.00000001, 8), $fdec!(0.5, 8), $fdec!(0.00000001, 8))]
+        fn test_mul_rounded<const S: i16>(#[case] mode: RoundingMode, #[case] a: $FD<S>, #[case] b: $FD<S>, #[case] expected: $FD<S>) {
+            let res = a.mul(b, Context::default().with_rounding_mode(mode));
+
+            assert_eq!(res, expected);
+            assert!(res.is_op_rounded());
+            assert!(res.is_op_inexact());
+        }
+
+        #[rstest(::trace)]
+        #[case(HalfUp, $fdec!(6, 2), $fdec!(3, 2), $fdec!(2, 2))]
+        #[case(HalfUp, $fdec!(1, 2), $fdec!(8, 2), $fdec!(0.13, 2))]
+        #[case(HalfEven, $fdec!(1, 2), $fdec!(8, 2), $fdec!(0.12, 2))]
+        #[case(HalfUp, $fdec!(2, 4), $fdec!(3, 4), $fdec!(0.6667, 4))]
+        #[case(Down, $fdec!(2, 4), $fdec!(3, 4), $fdec!(0.6666, 4))]
+        #[case(HalfUp, $fdec!(1, 0), $fdec!(3, 0), $fdec!(0, 0))]
+        #[case(HalfUp, $fdec!(2, 0), $fdec!(3, 0), $fdec!(1, 0))]
+        #[case(HalfUp, $fdec!(1, 18), $fdec!(3, 18), $fdec!(0.333333333333333333, 18))]
+        fn test_div<const S: i16>(#[case] mode: RoundingMode, #[case] a: $FD<S>, #[case] b: $FD<S>, #[case] expected: $FD<S>) {
+            let res = a.div(b, Context::default().with_rounding_mode(mode));
+
+            assert_eq!(res, expected);
+        }
+
+        #[rstest(::trace)]
+        #[should_panic(expected = "(fastnum) division by zero")]
+        #[case($fdec!(1, 2), $fdec!(0, 2))]
+        fn test_div_by_zero<const S: i16>(#[case] a: $FD<S>, #[case] b: $FD<S>) {
+            let _ = a / b;
+        }
+
+        #[rstest(::trace)]
+        #[should_panic(expected = "(fastnum) overflow was occurred while performing arithmetic operation")]
+        #[case($FD::<2>::MAX, $FD::<2>::EPSILON)]
+        fn test_add_overflow<const S: i16>(#[case] a: $FD<S>, #[case] b: $FD<S>) {
+            let _ = a + b;
+        }
+
+        #[rstest(::trace)]
+        #[should_panic(expected = "(fastnum) overflow was occurred while performing arithmetic operation")]
+        #[case($FD::<2>::MAX, $FD::<2>::TWO)]
+        fn test_mul_overflow<const S: i16>(#[case] a: $FD<S>, #[case] b: $FD<S>) {
+            let _ = a * b;
+        }
+
+        #[rstest(::trace)]
+        #[case($fdec!(1.5, 3), $dec!(1.500))]
+        #[case($fdec!(0, 2), $dec!(0.00))]
+        #[case($FD::<2>::MAX, $D::from_str(&$FD::<2>::MAX.to_string()).unwrap())]
+        fn test_to_decimal<const S: i16>(#[case] a: $FD<S>, #[case] expected: $D) {
+            let d = a.to_decimal();
+
+            assert_eq!(d, expected);
+            assert_eq!(d.digits(), expected.digits());
+            assert_eq!(d.fractional_digits_count(), S);
+            assert_eq!($FD::<S>::try_from(d), Ok(a));
+        }
+
+        #[rstest(::trace)]
+        #[case($dec!(1.5), $fdec!(1.50, 2))]
+        #[case($dec!(1.5000), $fdec!(1.50, 2))]
+        #[case($dec!(15e1), $fdec!(150, 2))]
+        fn test_from_decimal<const S: i16>(#[case] d: $D, #[case] expected: $FD<S>) {
+            assert_eq!($FD::<S>::try_from(d), Ok(expected));
+        }
+
+        #[rstest(::trace)]
+        #[case(HalfUp, $dec!(1.005), $fdec!(1.01, 2))]
+        #[case(HalfEven, $dec!(1.005), $fdec!(1.00, 2))]
+        #[case(Down, $dec!(1.999), $fdec!(1.99, 2))]
+        #[case(HalfUp, $dec!(0.005), $fdec!(0.01, 2))]
+        #[case(HalfUp, $dec!(1e-100), $fdec!(0, 2))]
+        #[case(Up, $dec!(1e-100), $fdec!(0.01, 2))]
+        fn test_from_decimal_rounded<const S: i16>(#[case] mode: RoundingMode, #[case] d: $D, #[case] expected: $FD<S>) {
+            assert_eq!($FD::<S>::try_from(d), Err(ParseError::Inexact));
+
+            let res = $FD::<S>::from_decimal(d, Context::default().with_rounding_mode(mode));
+            assert_eq!(res, expected);
+            assert!(res.is_op_rounded());
+        }
+
+        #[rstest(::trace)]
+        #[case($fdec!(1.5, 2), "1.50")]
+        #[case($fdec!(0, 3), "0.000")]
+        #[case($fdec!(123, 0), "123")]
+        fn test_display<const S: i16>(#[case] a: $FD<S>, #[case] expected: &str) {
+            assert_eq!(a.to_string(), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case($fdec!(1.5, 2), $fdec!(1.51, 2))]
+        #[case($fdec!(0, 2), $fdec!(0.01, 2))]
+        #[case($fdec!(99.99, 2), $FD::<2>::INFINITY)]
+        fn test_cmp<const S: i16>(#[case] a: $FD<S>, #[case] b: $FD<S>) {
+            assert!(a < b);
+            assert!(b > a);
+            assert_eq!(a.max(b), b);
+            assert_eq!(a.min(b), a);
+        }
+    };
+    (SIGNED:: $bits: tt, $fdec: ident, $FD: ident, $dec: ident, $D: ident) => {
+        #[rstest(::trace)]
+        #[case($fdec!(0.1, 2), $fdec!(-0.2, 2), $fdec!(-0.1, 2))]
+        #[case($fdec!(-0.1, 2), $fdec!(-0.2, 2), $fdec!(-0.3, 2))]
+        #[case($fdec!(-0.1, 2), $fdec!(0.1, 2), $fdec!(0, 2))]
+        fn test_add_signed<const S: i16>(#[case] a: $FD<S>, #[case] b: $FD<S>, #[case] expected: $FD<S>) {
+            assert_eq!(a + b, expected);
+            assert_eq!(a - b.neg(), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case(HalfUp, $fdec!(-0.25, 2), $fdec!(0.5, 2), $fdec!(-0.13, 2))]
+        #[case(Ceiling, $fdec!(-0.11, 2), $fdec!(0.11, 2), $fdec!(-0.01, 2))]
+        #[case(Floor, $fdec!(-0.11, 2), $fdec!(0.11, 2), $fdec!(-0.02, 2))]
+        #[case(Floor, $fdec!(-0.11, 2), $fdec!(-0.11, 2), $fdec!(0.01, 2))]
+        fn test_mul_signed<const S: i16>(#[case] mode: RoundingMode, #[case] a: $FD<S>, #[case] b: $FD<S>, #[case] expected: $FD<S>) {
+            assert_eq!(a.mul(b, Context::default().with_rounding_mode(mode)), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case(HalfUp, $fdec!(-2, 4), $fdec!(3, 4), $fdec!(-0.6667, 4))]
+        #[case(Ceiling, $fdec!(-2, 4), $fdec!(3, 4), $fdec!(-0.6666, 4))]
+        #[case(Floor, $fdec!(2, 4), $fdec!(-3, 4), $fdec!(-0.6667, 4))]
+        fn test_div_signed<const S: i16>(#[case] mode: RoundingMode, #[case] a: $FD<S>, #[case] b: $FD<S>, #[case] expected: $FD<S>) {
+            assert_eq!(a.div(b, Context::default().with_rounding_mode(mode)), expected);
+        }
+
+        #[rstest(::trace)]
+        #[case($fdec!(-1.5, 2), $fdec!(1.5, 2))]
+        #[case($fdec!(-0.01, 2), $fdec!(0, 2))]
+        #[case($FD::<2>::NEG_INFINITY, $FD::<2>::MIN)]
+        fn test_cmp_signed<const S: i16>(#[case] a: $FD<S>, #[case] b: $FD<S>) {
+            assert!(a < b);
+        }
+
+        #[rstest(::trace)]
+        #[case($fdec!(-0, 2), $fdec!(0, 2))]
+        fn test_negative_zero<const S: i16>(#[case] a: $FD<S>, #[case] b: $FD<S>) {
+            use std::hash::{DefaultHasher, Hash, Hasher};
+
+            assert_eq!(a, b);
+
+            let mut ha = DefaultHasher::new();
+            let mut hb = DefaultHasher::new();
+            a.hash(&mut ha);
+            b.hash(&mut hb);
+            assert_eq!(ha.finish(), hb.finish());
+        }
+    };
+}
+
+pub(crate) use test_impl;
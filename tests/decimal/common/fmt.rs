@@ -10,6 +10,8 @@ macro_rules! test_impl {
             use rstest::*;
             use fastnum::{$dec, $D};
 
+            type U = fastnum::int::UInt<{ $bits / 64 }>;
+
             super::test_impl!(COMMON:: $bits, $dec, $D, THIS);
             super::test_impl!(UNSIGNED:: $bits, $dec, $D, THIS);
         }
@@ -19,10 +21,30 @@ macro_rules! test_impl {
             use rstest::*;
             use fastnum::{$dec, $D};
 
+            type U = fastnum::int::UInt<{ $bits / 64 }>;
+
             super::test_impl!(COMMON:: $bits, $dec, $D, THIS);
             super::test_impl!(SIGNED:: $bits, $dec, $D, THIS);
         }
     };
+    (COMMON:: 8192, $dec: ident, $D: ident, THIS) => {
+        super::test_impl!(COMMON:: 256, $dec, $D);
+    };
+    (UNSIGNED:: 8192, $dec: ident, $D: ident, THIS) => {
+        super::test_impl!(UNSIGNED:: 256, $dec, $D);
+    };
+    (SIGNED:: 8192, $dec: ident, $D: ident, THIS) => {
+        super::test_impl!(SIGNED:: 256, $dec, $D);
+    };
+    (COMMON:: 4096, $dec: ident, $D: ident, THIS) => {
+        super::test_impl!(COMMON:: 256, $dec, $D);
+    };
+    (UNSIGNED:: 4096, $dec: ident, $D: ident, THIS) => {
+        super::test_impl!(UNSIGNED:: 256, $dec, $D);
+    };
+    (SIGNED:: 4096, $dec: ident, $D: ident, THIS) => {
+        super::test_impl!(SIGNED:: 256, $dec, $D);
+    };
     (COMMON:: 512, $dec: ident, $D: ident, THIS) => {
         super::test_impl!(COMMON:: 256, $dec, $D);
     };
@@ -57,6 +79,22 @@ macro_rules! test_impl {
         super::test_impl!(COMMON:: 128, $dec, $D);
     };
     (COMMON:: 128, $dec: ident, $D: ident) => {
+        #[rstest(::trace)]
+        fn test_fmt_coefficient() {
+            let ten = U::from_digit(10);
+            let max_power = U::MAX.to_string().len() as u32 - 1;
+
+            for power in [0, 1, 19, 20, 38, max_power / 2, max_power - 1, max_power] {
+                let pow = ten.pow(power.min(max_power));
+
+                for digits in [pow, pow - U::ONE, pow + U::ONE, pow / U::from_digit(7)] {
+                    assert_eq!(coefficient(digits).to_string(), digits.to_string());
+                }
+            }
+
+            assert_eq!(coefficient(U::MAX).to_string(), U::MAX.to_string());
+        }
+
         #[rstest(::trace)]
         #[case($D::NAN, "NaN")]
         #[case($D::INFINITY, "Inf")]
@@ -75,6 +113,44 @@ macro_rules! test_impl {
             let formated = format!("{d}");
             assert_eq!(formated.as_str(), expected);
         }
+
+        #[rstest(::trace)]
+        #[case($D::NAN, "NaN")]
+        #[case($D::INFINITY, "Inf")]
+        #[case($dec!(0), "0")]
+        #[case($dec!(0.00), "0.00")]
+        #[case($dec!(10), "10")]
+        #[case($dec!(0.00123), "0.00123")]
+        #[case($dec!(1.23E-4), "0.000123")]
+        #[case($dec!(1.23E-10), "1.23E-10")]
+        #[case($dec!(1E-10), "1E-10")]
+        #[case($dec!(123.456), "123.456")]
+        #[case($dec!(123.e1), "1230")]
+        #[case($dec!(1e15), "1000000000000000")]
+        #[case($dec!(1e16), "1e+16")]
+        #[case($dec!(12345678901234567890.123456789), "12345678901234567890.123456789")]
+        #[case($dec!(1e32767), "1e+32767")]
+        #[case($dec!(1e-32767), "1E-32767")]
+        fn test_to_str_buf(#[case] d: $D, #[case] expected: &str) {
+            let mut buf = [0; $D::MAX_STR_LEN];
+            assert_eq!(d.to_str_buf(&mut buf), expected);
+            assert_eq!(format!("{d}"), expected);
+        }
+
+        #[rstest(::trace)]
+        fn test_to_str_buf_max_len() {
+            let mut buf = [0; $D::MAX_STR_LEN];
+            assert_eq!($D::MAX.to_str_buf(&mut buf), $D::MAX.to_string());
+        }
+
+        #[rstest(::trace)]
+        fn test_to_str_buf_const() {
+            const LEN: usize = {
+                let mut buf = [0; $D::MAX_STR_LEN];
+                $dec!(12.50).to_str_buf(&mut buf).len()
+            };
+            assert_eq!(LEN, 5);
+        }
         
         #[rstest(::trace)]
         #[case($D::NAN, concat!(stringify!($D), r#"(digits=[0], exp=[0], flags=[NAN], signals=[])"#))]
@@ -526,11 +602,25 @@ macro_rules! test_impl {
         super::test_impl!(UNSIGNED:: 128, $dec, $D);
     };
     (UNSIGNED:: 128, $dec: ident, $D: ident) => {
+        fn coefficient(digits: U) -> $D {
+            $D::from_parts(digits, 0)
+        }
     };
     (SIGNED:: 128, $dec: ident, $D: ident, THIS) => {
         super::test_impl!(SIGNED:: 128, $dec, $D);
     };
     (SIGNED:: 128, $dec: ident, $D: ident) => {
+        fn coefficient(digits: U) -> $D {
+            $D::from_parts(digits, 0, fastnum::decimal::Sign::Plus)
+        }
+
+        #[rstest(::trace)]
+        fn test_fmt_coefficient_signed() {
+            let digits = U::MAX / U::from_digit(3);
+            let d = $D::from_parts(digits, 0, fastnum::decimal::Sign::Minus);
+            assert_eq!(d.to_string(), format!("-{digits}"));
+        }
+
         #[rstest(::trace)]
         #[case($D::NEG_INFINITY, "-Inf")]
         #[case($dec!(-0), "-0")]
@@ -542,6 +632,20 @@ macro_rules! test_impl {
             assert_eq!(formated.as_str(), expected);
         }
 
+        #[rstest(::trace)]
+        #[case($D::NEG_INFINITY, "-Inf")]
+        #[case($dec!(-0), "-0")]
+        #[case($dec!(-0.00), "-0.00")]
+        #[case($dec!(-0.000123), "-0.000123")]
+        #[case($dec!(-1.23E-10), "-1.23E-10")]
+        #[case($dec!(-1e16), "-1e+16")]
+        #[case($dec!(-90037659.6905), "-90037659.6905")]
+        fn test_to_str_buf_signed(#[case] d: $D, #[case] expected: &str) {
+            let mut buf = [0; $D::MAX_STR_LEN];
+            assert_eq!(d.to_str_buf(&mut buf), expected);
+            assert_eq!(format!("{d}"), expected);
+        }
+
         #[rstest(::trace)]
         #[case($D::NEG_INFINITY, concat!(stringify!($D), r#"(digits=[0], exp=[0], flags=[S, INF], signals=[])"#))]
         #[case($dec!(-0), concat!(stringify!($D), r#"(digits=[0], exp=[0], flags=[S], signals=[])"#))]
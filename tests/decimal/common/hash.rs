@@ -59,6 +59,22 @@ macro_rules! test_impl {
             assert_eq!(hash(&a), hash(&b));
         }
 
+        #[rstest(::trace)]
+        #[case($dec!(1e-300), $dec!(1000000000000000000000000000000e-330))]
+        #[case($dec!(123e5), $dec!(123000000000000000000000000000000e-25))]
+        #[case($dec!(1), $dec!(100000000000000000000000000000000000000e-38))]
+        #[case($dec!(1.5), $dec!(15000000000000000000000000000000000000e-37))]
+        fn test_hash_eq_long_zeros(#[case] a: $D, #[case] b: $D) {
+            assert_eq!(a, b);
+            assert_eq!(hash(&a), hash(&b));
+
+            let a = a.normalized(fastnum::decimal::Context::default());
+            let b = b.normalized(fastnum::decimal::Context::default());
+
+            assert_eq!(a.digits(), b.digits());
+            assert_eq!(a.fractional_digits_count(), b.fractional_digits_count());
+        }
+
         #[rstest(::trace)]
         #[case($dec!(1.1234), $dec!(1.1234001))]
         #[case($dec!(10000), $dec!(10))]
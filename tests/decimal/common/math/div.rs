@@ -261,6 +261,34 @@ macro_rules! test_impl {
     };
     (SIGNED:: 128, $dec: ident, $D: ident, THIS) => {
         super::test_impl!(SIGNED:: 128, $dec, $D);
+
+        #[rstest(::trace)]
+        #[case($dec!(1), $dec!(3), $dec!(0.333333333333333333333333333333333333334), Ceiling)]
+        #[case($dec!(1), $dec!(3), $dec!(0.333333333333333333333333333333333333333), Floor)]
+        #[case($dec!(-1), $dec!(3), $dec!(-0.333333333333333333333333333333333333333), Ceiling)]
+        #[case($dec!(-1), $dec!(3), $dec!(-0.333333333333333333333333333333333333334), Floor)]
+        #[case($dec!(1), $dec!(-3), $dec!(-0.333333333333333333333333333333333333334), Floor)]
+        #[case($dec!(-2), $dec!(-3), $dec!(0.66666666666666666666666666666666666667), Ceiling)]
+        #[case($dec!(-2), $dec!(3), $dec!(-0.66666666666666666666666666666666666667), Up)]
+        #[case($dec!(-2), $dec!(3), $dec!(-0.66666666666666666666666666666666666666), Ceiling)]
+        fn test_div_inexact_signed_128(
+            #[case] a: $D,
+            #[case] b: $D,
+            #[case] expected: $D,
+            #[case] mode: RoundingMode,
+        ) {
+            let ctx = Context::default().with_rounding_mode(mode);
+            let res = a.div(b, ctx);
+
+            assert_eq!(res, expected);
+            assert_eq!(
+                res.fractional_digits_count(),
+                expected.fractional_digits_count()
+            );
+
+            assert!(res.is_op_inexact());
+            assert!(res.is_op_rounded());
+        }
     };
     (SIGNED:: 128, $dec: ident, $D: ident) => {
         #[rstest(::trace)]
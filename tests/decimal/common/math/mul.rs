@@ -108,6 +108,9 @@ macro_rules! test_impl {
         #[rstest(::trace)]
         #[case($dec!(995052931372975485719.533153137), $dec!(4.523087321), $dec!(4500711297616988541501.8369669931160760))]
         #[case($dec!(8.37664968), $dec!(1.9086963714056968482094712882596748), $dec!(15.9884808487526916537308762397695926703))]
+        #[case($dec!(100000000000000000001), $dec!(340000000000000000049), $dec!(340000000000000000052400000000000000000e2))]
+        #[case($dec!(340282366920938463463374607431768211455), $dec!(340282366920938463463374607431768211455), $dec!(115792089237316195423570985008687907853e39))]
+        #[case($dec!(340282366920938463463374607431768211455), $dec!(3402823669209384634633746074317682114.55), $dec!(115792089237316195423570985008687907853e37))]
         fn test_mul_inexact_128(#[case] a: $D, #[case] b: $D, #[case] expected: $D) {
             let res = a * b;
         
@@ -100,10 +100,10 @@ macro_rules! test_impl {
         #[rstest(::trace)]
         #[case($dec!(1.45), 1, $dec!(1.5), $dec!(1.4))]
         #[case($dec!(1.44444), 1, $dec!(1.4), $dec!(1.4))]
-        #[case($dec!(1.444445), 1, $dec!(1.5), $dec!(1.4))]
+        #[case($dec!(1.444445), 1, $dec!(1.4), $dec!(1.4))]
         #[case($dec!(1.44), 1, $dec!(1.4), $dec!(1.4))]
         #[case($dec!(0.444), 2, $dec!(0.44), $dec!(0.44))]
-        #[case($dec!(0.0045), 2, $dec!(0.01), $dec!(0.00))]
+        #[case($dec!(0.0045), 2, $dec!(0.00), $dec!(0.00))]
         #[case($dec!(1.555), 2, $dec!(1.56), $dec!(1.55))]
         #[case($dec!(4.5), 0, $dec!(5), $dec!(4))]
         #[case($dec!(5.5), 0, $dec!(6), $dec!(5))]
@@ -124,14 +124,14 @@ macro_rules! test_impl {
         #[case($dec!(1.00009), 4, $dec!(1.0001), $dec!(1.0))]
         #[case($dec!(1.00009), 3, $dec!(1.0), $dec!(1.0))]
         #[case($dec!(44), - 1, $dec!(40), $dec!(40))]
-        #[case($dec!(1.4499999999), 0, $dec!(2), $dec!(1))]
-        #[case($dec!(1.4499999999), 1, $dec!(1.5), $dec!(1.4))]
+        #[case($dec!(1.4499999999), 0, $dec!(1), $dec!(1))]
+        #[case($dec!(1.4499999999), 1, $dec!(1.4), $dec!(1.4))]
         #[case($dec!(1.4499999999), 2, $dec!(1.45), $dec!(1.44))]
         #[case($dec!(1.4499999999), 3, $dec!(1.450), $dec!(1.449))]
         #[case($dec!(1.4499999999), 4, $dec!(1.4500), $dec!(1.4499))]
         #[case($dec!(1.4499999999), 10, $dec!(1.4499999999), $dec!(1.4499999999))]
         #[case($dec!(1.4499999999), 15, $dec!(1.449999999900000), $dec!(1.449999999900000))]
-        #[case($dec!(1.449999999), 1, $dec!(1.5), $dec!(1.4))]
+        #[case($dec!(1.449999999), 1, $dec!(1.4), $dec!(1.4))]
         #[case($dec!(9999.444455556666), 10, $dec!(9999.4444555567), $dec!(9999.4444555566))]
         #[case($dec!(12345678987654321.123456789), 8, $dec!(12345678987654321.12345679), $dec!(12345678987654321.12345678))]
         #[case($dec!(18.2697343863199184516), 18, $dec!(18.269734386319918452), $dec!(18.269734386319918451))]
@@ -141,7 +141,9 @@ macro_rules! test_impl {
         #[case($dec!(1.2), 0, $dec!(1), $dec!(1))]
         #[case($dec!(0.68), 0, $dec!(1), $dec!(0))]
         #[case($dec!(0.5), 0, $dec!(1), $dec!(0))]
-        #[case($dec!(0.49), 0, $dec!(1), $dec!(0))]
+        #[case($dec!(0.49), 0, $dec!(0), $dec!(0))]
+        #[case($dec!(1.2345), 2, $dec!(1.23), $dec!(1.23))]
+        #[case($dec!(1.2351), 2, $dec!(1.24), $dec!(1.23))]
         fn test_round(#[case] x: $D, #[case] digits: i16, #[case] y: $D, #[case] z: $D) {
             assert_eq!(x.round(digits, RoundingMode::HalfUp), y);
             assert_eq!(x.round(digits, RoundingMode::Down), z);
@@ -159,17 +161,17 @@ macro_rules! test_impl {
     (SIGNED:: 128, $dec: ident, $D: ident) => {
         #[rstest(::trace)]
         #[case($dec!(-1.45), 1, $dec!(-1.5), $dec!(-1.4))]
-        #[case($dec!(-1.444445), 1, $dec!(-1.5), $dec!(-1.4))]
+        #[case($dec!(-1.444445), 1, $dec!(-1.4), $dec!(-1.4))]
         #[case($dec!(-1.44), 1, $dec!(-1.4), $dec!(-1.4))]
         #[case($dec!(-0.444), 2, $dec!(-0.44), $dec!(-0.44))]
-        #[case($dec!(-0.0045), 2, $dec!(-0.01), $dec!(-0.00))]
+        #[case($dec!(-0.0045), 2, $dec!(-0.00), $dec!(-0.00))]
         #[case($dec!(-1.555), 2, $dec!(-1.56), $dec!(-1.55))]
         #[case($dec!(-5.5), 0, $dec!(-6), $dec!(-5))]
         #[case($dec!(-1), - 1, $dec!(-00), $dec!(-00))]
         #[case($dec!(-5), - 1, $dec!(-10), $dec!(-00))]
         #[case($dec!(-44), - 1, $dec!(-40), $dec!(-40))]
-        #[case($dec!(-1.4499999999), 1, $dec!(-1.5), $dec!(-1.4))]
-        #[case($dec!(-1.449999999), 1, $dec!(-1.5), $dec!(-1.4))]
+        #[case($dec!(-1.4499999999), 1, $dec!(-1.4), $dec!(-1.4))]
+        #[case($dec!(-1.449999999), 1, $dec!(-1.4), $dec!(-1.4))]
         #[case($dec!(-9999.444455556666), 10, $dec!(-9999.4444555567), $dec!(-9999.4444555566))]
         #[case($dec!(-12345678987654321.123456789), 8, $dec!(-12345678987654321.12345679), $dec!(-12345678987654321.12345678))]
         #[case($dec!(-18.2697343863199184516), 18, $dec!(-18.269734386319918452), $dec!(-18.269734386319918451))]
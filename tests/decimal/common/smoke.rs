@@ -140,6 +140,17 @@ macro_rules! test_impl {
             assert_eq!(d.fractional_digits_count(), fractional_digits_count);
         }
         
+        #[rstest(::trace)]
+        fn test_digits_count_powers_of_ten() {
+            let mut d = $dec!(1);
+
+            for count in 1..=38 {
+                assert_eq!(d.digits_count(), count);
+                assert_eq!((d - $dec!(1)).digits_count(), if count == 1 { 1 } else { count - 1 });
+                d *= $dec!(10);
+            }
+        }
+
         #[rstest(::trace)]
         fn test_bug_shift() {
             let fee = $dec!(0e-22);
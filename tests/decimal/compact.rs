@@ -0,0 +1,383 @@
+use rstest::*;
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use fastnum::{
+    dec128, dec256, dec32, dec64, udec128, udec32, udec64,
+    decimal::{Context, ParseError, RoundingMode, SignalsTraps},
+    D128, D256, D32, D64, UD128, UD32, UD64,
+};
+
+#[test]
+fn test_size() {
+    assert_eq!(size_of::<D64>(), 16);
+    assert_eq!(size_of::<UD64>(), 16);
+    assert!(size_of::<D64>() < size_of::<D128>());
+
+    assert_eq!(size_of::<D32>(), 8);
+    assert_eq!(size_of::<UD32>(), 8);
+}
+
+#[rstest(::trace)]
+#[case("0", dec64!(0))]
+#[case("-1.5", dec64!(-1.5))]
+#[case("18446744073709551615", D64::from(u64::MAX))]
+#[case("1844674407370955161.5", dec64!(1844674407370955161.5))]
+#[case("1e-100", dec64!(1e-100))]
+fn test_parse(#[case] s: &str, #[case] expected: D64) {
+    assert_eq!(D64::from_str(s), Ok(expected));
+}
+
+#[rstest(::trace)]
+#[case("18446744073709551616", ParseError::PosOverflow)]
+#[case("-18446744073709551616", ParseError::NegOverflow)]
+fn test_parse_overflow(#[case] s: &str, #[case] expected: ParseError) {
+    assert_eq!(D64::from_str(s), Err(expected));
+}
+
+#[rstest(::trace)]
+#[case(dec64!(1.5), dec64!(2.25), dec64!(3.75))]
+#[case(dec64!(-1.5), dec64!(0.25), dec64!(-1.25))]
+#[case(dec64!(0.1), dec64!(1e-10), dec64!(0.1000000001))]
+fn test_add(#[case] a: D64, #[case] b: D64, #[case] expected: D64) {
+    let res = a + b;
+    assert_eq!(res, expected);
+    assert!(res.is_op_ok());
+    assert_eq!(res - b, a);
+}
+
+#[rstest(::trace)]
+#[case(dec64!(1.5), dec64!(2), dec64!(3.0))]
+#[case(dec64!(-1.5), dec64!(-1.5), dec64!(2.25))]
+#[case(dec64!(4294967296), dec64!(4294967296), dec64!(1844674407370955162e1))]
+fn test_mul(#[case] a: D64, #[case] b: D64, #[case] expected: D64) {
+    assert_eq!(a * b, expected);
+}
+
+#[rstest(::trace)]
+#[case(dec64!(1), dec64!(4), dec64!(0.25))]
+#[case(dec64!(-10), dec64!(4), dec64!(-2.5))]
+#[case(dec64!(1), dec64!(3), dec64!(0.3333333333333333333))]
+fn test_div(#[case] a: D64, #[case] b: D64, #[case] expected: D64) {
+    assert_eq!(a / b, expected);
+}
+
+#[rstest(::trace)]
+#[case(dec64!(1.2341), 2, RoundingMode::HalfUp, dec64!(1.23))]
+#[case(dec64!(1.2345), 2, RoundingMode::HalfUp, dec64!(1.23))]
+#[case(dec64!(-1.235), 2, RoundingMode::HalfEven, dec64!(-1.24))]
+fn test_round(#[case] a: D64, #[case] digits: i16, #[case] mode: RoundingMode, #[case] expected: D64) {
+    let res = a.round(digits, mode);
+    assert_eq!(res, expected);
+    assert_eq!(res.fractional_digits_count(), expected.fractional_digits_count());
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) division by zero")]
+#[case(dec64!(1), dec64!(0))]
+fn test_div_by_zero(#[case] a: D64, #[case] b: D64) {
+    let _ = a / b;
+}
+
+#[rstest(::trace)]
+#[case(dec64!(18446744073709551615), dec64!(2e19))]
+#[case(dec64!(1844674407370955161.5), dec64!(2e18))]
+#[case(dec64!(9999999999999999999), dec64!(1e19))]
+fn test_cmp_same_exponent(#[case] a: D64, #[case] b: D64) {
+    assert!(a < b);
+    assert!(b > a);
+    assert_ne!(a, b);
+}
+
+#[rstest(::trace)]
+#[case(dec64!(0), 1)]
+#[case(dec64!(9999999999999999999), 19)]
+#[case(dec64!(10000000000000000000), 20)]
+#[case(D64::MAX, 20)]
+fn test_digits_count(#[case] a: D64, #[case] expected: usize) {
+    assert_eq!(a.digits_count(), expected);
+}
+
+#[rstest(::trace)]
+#[case(D64::MAX, D64::MAX)]
+fn test_mul_overflow_signals(#[case] a: D64, #[case] b: D64) {
+    let ctx = Context::default().with_signal_traps(SignalsTraps::empty());
+    let res = a.mul(b, ctx);
+    assert!(res.is_infinite());
+    assert!(!res.is_op_ok());
+}
+
+#[rstest(::trace)]
+#[case(dec64!(0), dec128!(0))]
+#[case(dec64!(-1.5), dec128!(-1.5))]
+#[case(dec64!(18446744073709551615e-19), dec128!(18446744073709551615e-19))]
+#[case(D64::INFINITY, D128::INFINITY)]
+fn test_widening(#[case] a: D64, #[case] expected: D128) {
+    let res = D128::from(a);
+    assert_eq!(res, expected);
+    assert_eq!(res.fractional_digits_count(), a.fractional_digits_count());
+    assert_eq!(D256::from(a), D256::from_str(&expected.to_string()).unwrap());
+    assert_eq!(D256::from(dec64!(0.5)), dec256!(0.5));
+}
+
+#[rstest(::trace)]
+#[case(udec64!(0), udec128!(0))]
+#[case(udec64!(12.75), udec128!(12.75))]
+fn test_unsigned(#[case] a: UD64, #[case] expected: UD128) {
+    assert_eq!(UD128::from(a), expected);
+    assert_eq!((a + udec64!(1)).to_string(), (expected + udec128!(1)).to_string());
+}
+
+#[rstest(::trace)]
+#[case(0, Ok(dec64!(0)))]
+#[case(u64::MAX as u128, Ok(D64::from(u64::MAX)))]
+#[case(u64::MAX as u128 + 1, Err(ParseError::PosOverflow))]
+#[case(u128::MAX, Err(ParseError::PosOverflow))]
+fn test_try_from_u128(#[case] n: u128, #[case] expected: Result<D64, ParseError>) {
+    assert_eq!(D64::try_from(n), expected);
+}
+
+#[rstest(::trace)]
+#[case(u64::MAX as u128, Ok(udec64!(18446744073709551615)))]
+#[case(u64::MAX as u128 + 1, Err(ParseError::PosOverflow))]
+fn test_try_from_u128_unsigned(#[case] n: u128, #[case] expected: Result<UD64, ParseError>) {
+    assert_eq!(UD64::try_from(n), expected);
+}
+
+#[rstest(::trace)]
+#[case(0, Ok(dec64!(0)))]
+#[case(-(u64::MAX as i128), Ok(-D64::from(u64::MAX)))]
+#[case(u64::MAX as i128 + 1, Err(ParseError::PosOverflow))]
+#[case(-(u64::MAX as i128) - 1, Err(ParseError::NegOverflow))]
+#[case(i128::MIN, Err(ParseError::NegOverflow))]
+fn test_try_from_i128(#[case] n: i128, #[case] expected: Result<D64, ParseError>) {
+    assert_eq!(D64::try_from(n), expected);
+}
+
+#[rstest(::trace)]
+#[case(u64::MAX as i128, Ok(udec64!(18446744073709551615)))]
+#[case(u64::MAX as i128 + 1, Err(ParseError::PosOverflow))]
+#[case(-1, Err(ParseError::Signed))]
+fn test_try_from_i128_unsigned(#[case] n: i128, #[case] expected: Result<UD64, ParseError>) {
+    assert_eq!(UD64::try_from(n), expected);
+}
+
+#[test]
+fn test_from_u128() {
+    assert_eq!(D128::from(u128::MAX).to_string(), u128::MAX.to_string());
+    assert_eq!(D128::from(i128::MIN).to_string(), i128::MIN.to_string());
+    assert_eq!(UD128::from(u128::MAX).to_string(), u128::MAX.to_string());
+}
+
+#[test]
+#[should_panic(expected = "(fastnum) overflow was occurred while performing arithmetic operation")]
+fn test_mul_u128_overflow() {
+    let _ = dec64!(1) * u128::MAX;
+}
+
+#[rstest(::trace)]
+#[case("0", dec32!(0))]
+#[case("-1.5", dec32!(-1.5))]
+#[case("4294967295", D32::from(u32::MAX))]
+#[case("429496729.5", dec32!(429496729.5))]
+#[case("42949672950", dec32!(429496729.5e2))]
+#[case("1e-100", dec32!(1e-100))]
+fn test_parse_32(#[case] s: &str, #[case] expected: D32) {
+    let res = D32::from_str(s).unwrap();
+    assert_eq!(res, expected);
+    assert_eq!(res.to_decimal::<1>(), D64::from_str(s).unwrap());
+}
+
+#[rstest(::trace)]
+#[case("4294967296", ParseError::Inexact)]
+#[case("-4294967296", ParseError::Inexact)]
+#[case("1.23456789012", ParseError::Inexact)]
+#[case("18446744073709551616", ParseError::PosOverflow)]
+#[case("-18446744073709551616", ParseError::NegOverflow)]
+#[case("-1", ParseError::Signed)]
+fn test_parse_32_error(#[case] s: &str, #[case] expected: ParseError) {
+    if expected != ParseError::Signed {
+        assert_eq!(D32::from_str(s), Err(expected));
+    }
+    assert_eq!(UD32::from_str(s), Err(expected));
+}
+
+#[rstest(::trace)]
+#[case(dec32!(1.5), dec32!(2.25), dec32!(3.75))]
+#[case(dec32!(-1.5), dec32!(0.25), dec32!(-1.25))]
+#[case(dec32!(0.1), dec32!(1e-8), dec32!(0.10000001))]
+fn test_add_32(#[case] a: D32, #[case] b: D32, #[case] expected: D32) {
+    let res = a + b;
+    assert_eq!(res, expected);
+    assert!(res.is_op_ok());
+    assert_eq!(res - b, a);
+}
+
+#[rstest(::trace)]
+#[case(dec32!(4294967295), dec32!(1), dec32!(429496730e1))]
+#[case(dec32!(0.1), dec32!(1e-11), dec32!(0.1000000000))]
+fn test_add_32_rounded(#[case] a: D32, #[case] b: D32, #[case] expected: D32) {
+    let res = a + b;
+    assert_eq!(res, expected);
+    assert!(res.is_op_rounded());
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(dec32!(1.5), dec32!(2), dec32!(3.0))]
+#[case(dec32!(-1.5), dec32!(-1.5), dec32!(2.25))]
+#[case(dec32!(65536), dec32!(65537), dec32!(429503283e1))]
+fn test_mul_32(#[case] a: D32, #[case] b: D32, #[case] expected: D32) {
+    let res = a * b;
+    assert_eq!(res, expected);
+    assert_eq!(res.fractional_digits_count(), expected.fractional_digits_count());
+}
+
+#[rstest(::trace)]
+#[case(dec32!(1), dec32!(4), dec32!(0.25))]
+#[case(dec32!(-10), dec32!(4), dec32!(-2.5))]
+#[case(dec32!(1), dec32!(3), dec32!(0.3333333333))]
+#[case(dec32!(2), dec32!(3), dec32!(0.666666667))]
+fn test_div_32(#[case] a: D32, #[case] b: D32, #[case] expected: D32) {
+    assert_eq!(a / b, expected);
+}
+
+#[rstest(::trace)]
+#[case(dec32!(10), dec32!(3), dec32!(1))]
+#[case(dec32!(-7.5), dec32!(2), dec32!(-1.5))]
+fn test_rem_32(#[case] a: D32, #[case] b: D32, #[case] expected: D32) {
+    assert_eq!(a % b, expected);
+}
+
+#[rstest(::trace)]
+#[case(dec32!(1.2341), 2, RoundingMode::HalfUp, dec32!(1.23))]
+#[case(dec32!(1.2345), 2, RoundingMode::HalfUp, dec32!(1.23))]
+#[case(dec32!(-1.235), 2, RoundingMode::HalfEven, dec32!(-1.24))]
+fn test_round_32(#[case] a: D32, #[case] digits: i16, #[case] mode: RoundingMode, #[case] expected: D32) {
+    let res = a.round(digits, mode);
+    assert_eq!(res, expected);
+    assert_eq!(res.fractional_digits_count(), expected.fractional_digits_count());
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) division by zero")]
+#[case(dec32!(1), dec32!(0))]
+fn test_div_by_zero_32(#[case] a: D32, #[case] b: D32) {
+    let _ = a / b;
+}
+
+#[rstest(::trace)]
+#[case(D32::MAX, D32::MAX)]
+fn test_mul_overflow_signals_32(#[case] a: D32, #[case] b: D32) {
+    let ctx = Context::default().with_signal_traps(SignalsTraps::empty());
+    let res = a.mul(b, ctx);
+    assert!(res.is_infinite());
+    assert!(res.is_op_overflow());
+}
+
+#[rstest(::trace)]
+#[case(dec32!(0), dec128!(0))]
+#[case(dec32!(-1.5), dec128!(-1.5))]
+#[case(dec32!(4294967295e-9), dec128!(4294967295e-9))]
+#[case(D32::MAX, dec128!(4294967295e32768))]
+#[case(D32::INFINITY, D128::INFINITY)]
+fn test_widening_32(#[case] a: D32, #[case] expected: D128) {
+    let res = D128::from(a);
+    assert_eq!(res, expected);
+    assert_eq!(res.fractional_digits_count(), a.fractional_digits_count());
+    assert_eq!(D64::from(a), D64::try_from(expected).unwrap());
+    assert_eq!(D256::from(a), D256::from(expected));
+}
+
+#[rstest(::trace)]
+#[case(dec64!(1.5), Ok(dec32!(1.5)))]
+#[case(dec64!(429496729500000), Ok(dec32!(4294967295e5)))]
+#[case(dec64!(4294967296), Err(ParseError::Inexact))]
+#[case(dec64!(-4294967296), Err(ParseError::Inexact))]
+fn test_narrowing_32(#[case] a: D64, #[case] expected: Result<D32, ParseError>) {
+    assert_eq!(D32::try_from(a), expected);
+}
+
+#[test]
+fn test_from_decimal_32() {
+    let res = D32::from_decimal(dec128!(3.14159265358979), Context::default());
+    assert_eq!(res, dec32!(3.141592654));
+    assert!(res.is_op_rounded());
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(udec32!(0), udec128!(0))]
+#[case(udec32!(12.75), udec128!(12.75))]
+fn test_unsigned_32(#[case] a: UD32, #[case] expected: UD128) {
+    assert_eq!(UD128::from(a), expected);
+    assert_eq!(UD128::from(a + udec32!(1)), expected + udec128!(1));
+    assert_eq!(UD32::try_from(D32::from(a)), Ok(a));
+    assert_eq!(UD32::try_from(-D32::ONE), Err(ParseError::Signed));
+}
+
+#[test]
+#[should_panic(expected = "(fastnum) operation has negative result for unsigned type")]
+fn test_unsigned_32_negative() {
+    let _ = udec32!(1) - udec32!(2);
+}
+
+#[rstest(::trace)]
+#[case(u32::MAX as i64, Ok(D32::from(u32::MAX)))]
+#[case(-(u32::MAX as i64), Ok(-D32::from(u32::MAX)))]
+#[case(i64::MIN, Err(ParseError::Inexact))]
+#[case(10_000_000_000, Ok(dec32!(1e10)))]
+fn test_try_from_i64_32(#[case] n: i64, #[case] expected: Result<D32, ParseError>) {
+    assert_eq!(D32::try_from(n), expected);
+}
+
+#[rstest(::trace)]
+#[case(u32::MAX as u128, Ok(udec32!(4294967295)))]
+#[case(u32::MAX as u128 + 1, Err(ParseError::Inexact))]
+#[case(u128::MAX, Err(ParseError::Inexact))]
+fn test_try_from_u128_32(#[case] n: u128, #[case] expected: Result<UD32, ParseError>) {
+    assert_eq!(UD32::try_from(n), expected);
+}
+
+#[test]
+fn test_from_int_32() {
+    assert_eq!(D32::from(i32::MIN), dec32!(-2147483648));
+    assert_eq!(D32::from(u8::MAX), dec32!(255));
+    assert_eq!(UD32::from(u16::MAX), udec32!(65535));
+}
+
+#[rstest(::trace)]
+#[case(dec32!(1.5), dec32!(1.50))]
+#[case(dec32!(0), dec32!(0.00))]
+#[case(dec32!(1e3), dec32!(1000))]
+fn test_eq_hash_32(#[case] a: D32, #[case] b: D32) {
+    fn hash(d: D32) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        d.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    assert_eq!(a, b);
+    assert_eq!(hash(a), hash(b));
+}
+
+#[rstest(::trace)]
+#[case(dec32!(-1), dec32!(0))]
+#[case(dec32!(1.5), dec32!(2))]
+#[case(dec32!(4294967295), dec32!(1e10))]
+#[case(-D32::MAX, D32::MAX)]
+fn test_cmp_32(#[case] a: D32, #[case] b: D32) {
+    assert!(a < b);
+    assert!(b > a);
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_format_32() {
+    assert_eq!(dec32!(-12.5).to_string(), "-12.5");
+    assert_eq!(format!("{:?}", dec32!(1.5)), "D32(digits=[15], exp=[-1], flags=[], signals=[])");
+    assert_eq!(format!("{:#?}", dec32!(1.5)), "D32(15e-1)");
+}
@@ -5,4 +5,7 @@ pub(crate) mod utoipa;
 pub(crate) mod serde;
 
 #[cfg(feature = "diesel")]
-pub(crate) mod diesel;
\ No newline at end of file
+pub(crate) mod diesel;
+
+#[cfg(feature = "sqlx")]
+pub(crate) mod sqlx;
@@ -2,4 +2,7 @@
 pub(crate) mod mysql;
 
 #[cfg(feature = "diesel_postgres")]
-pub(crate) mod pg;
\ No newline at end of file
+pub(crate) mod pg;
+
+#[cfg(feature = "diesel_sqlite")]
+pub(crate) mod sqlite;
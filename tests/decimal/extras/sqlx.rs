@@ -0,0 +1,8 @@
+#[cfg(feature = "sqlx_mysql")]
+pub(crate) mod mysql;
+
+#[cfg(feature = "sqlx_postgres")]
+pub(crate) mod pg;
+
+#[cfg(feature = "sqlx_sqlite")]
+pub(crate) mod sqlite;
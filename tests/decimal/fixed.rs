@@ -0,0 +1,9 @@
+use crate::decimal::common::fixed::test_impl;
+
+test_impl!(FD, 128);
+test_impl!(FD, 256);
+test_impl!(FD, 512);
+
+test_impl!(UFD, 128);
+test_impl!(UFD, 256);
+test_impl!(UFD, 512);
@@ -3,7 +3,11 @@ use crate::decimal::common::fmt::test_impl;
 test_impl!(D, 128);
 test_impl!(D, 256);
 test_impl!(D, 512);
+test_impl!(D, 4096);
+test_impl!(D, 8192);
 
 test_impl!(UD, 128);
 test_impl!(UD, 256);
-test_impl!(UD, 512);
\ No newline at end of file
+test_impl!(UD, 512);
+test_impl!(UD, 4096);
+test_impl!(UD, 8192);
@@ -0,0 +1,257 @@
+use rstest::*;
+
+use fastnum::{
+    dec128, dec64,
+    decimal::{Context, Interchange, Sign, SignalsTraps},
+    D128, D64, U128,
+};
+
+#[rstest(::trace)]
+#[case(dec128!(1), 0x3280_0001, 0x2250_0001)]
+#[case(dec128!(-7.50), 0xb180_02ee, 0xa230_03d0)]
+#[case(dec128!(0.00), 0x3180_0000, 0x2230_0000)]
+#[case(dec128!(-0), 0xb280_0000, 0xa250_0000)]
+#[case(dec128!(9999999e90), 0x77f8_967f, 0x77f3_fcff)]
+#[case(dec128!(1e-101), 0x0000_0001, 0x0000_0001)]
+#[case(dec128!(1e-95), 0x0300_0001, 0x0060_0001)]
+#[case(D128::INFINITY, 0x7800_0000, 0x7800_0000)]
+#[case(D128::NEG_INFINITY, 0xf800_0000, 0xf800_0000)]
+fn test_decimal32(#[case] d: D128, #[case] bid: u32, #[case] dpd: u32) {
+    let ctx = Context::default();
+
+    assert_eq!(d.to_bid32(ctx), bid);
+    assert_eq!(d.to_dpd32(ctx), dpd);
+
+    for decoded in [D128::from_bid32(bid), D128::from_dpd32(dpd)] {
+        assert_eq!(decoded, d);
+        assert_eq!(decoded.is_sign_negative(), d.is_sign_negative());
+        assert_eq!(
+            decoded.fractional_digits_count(),
+            d.fractional_digits_count()
+        );
+    }
+}
+
+#[rstest(::trace)]
+#[case(dec128!(1), 0x31c0_0000_0000_0001, 0x2238_0000_0000_0001)]
+#[case(dec128!(-7.50), 0xb180_0000_0000_02ee, 0xa230_0000_0000_03d0)]
+#[case(dec128!(9999999999999999e369), 0x77fb_86f2_6fc0_ffff, 0x77fc_ff3f_cff3_fcff)]
+#[case(dec128!(1e-398), 0x0000_0000_0000_0001, 0x0000_0000_0000_0001)]
+#[case(D128::NEG_INFINITY, 0xf800_0000_0000_0000, 0xf800_0000_0000_0000)]
+fn test_decimal64(#[case] d: D128, #[case] bid: u64, #[case] dpd: u64) {
+    let ctx = Context::default();
+
+    assert_eq!(d.to_bid64(ctx), bid);
+    assert_eq!(d.to_dpd64(ctx), dpd);
+
+    for decoded in [D128::from_bid64(bid), D128::from_dpd64(dpd)] {
+        assert_eq!(decoded, d);
+        assert_eq!(decoded.is_sign_negative(), d.is_sign_negative());
+        assert_eq!(
+            decoded.fractional_digits_count(),
+            d.fractional_digits_count()
+        );
+    }
+}
+
+#[rstest(::trace)]
+#[case(dec128!(1), 0x3040_0000_0000_0000_0000_0000_0000_0001, 0x2208_0000_0000_0000_0000_0000_0000_0001)]
+#[case(dec128!(-0.1), 0xb03e_0000_0000_0000_0000_0000_0000_0001, 0xa207_c000_0000_0000_0000_0000_0000_0001)]
+#[case(
+    dec128!(9999999999999999999999999999999999e6111),
+    0x5fff_ed09_bead_87c0_378d_8e63_ffff_ffff,
+    0x77ff_cff3_fcff_3fcf_f3fc_ff3f_cff3_fcff
+)]
+#[case(dec128!(1e-6176), 0x0000_0000_0000_0000_0000_0000_0000_0001, 0x0000_0000_0000_0000_0000_0000_0000_0001)]
+#[case(
+    D128::INFINITY,
+    0x7800_0000_0000_0000_0000_0000_0000_0000,
+    0x7800_0000_0000_0000_0000_0000_0000_0000
+)]
+fn test_decimal128(#[case] d: D128, #[case] bid: u128, #[case] dpd: u128) {
+    let ctx = Context::default();
+
+    assert_eq!(d.to_bid128(ctx), bid);
+    assert_eq!(d.to_dpd128(ctx), dpd);
+
+    for decoded in [D128::from_bid128(bid, ctx), D128::from_dpd128(dpd, ctx)] {
+        assert_eq!(decoded, d);
+        assert_eq!(decoded.is_sign_negative(), d.is_sign_negative());
+        assert_eq!(
+            decoded.fractional_digits_count(),
+            d.fractional_digits_count()
+        );
+    }
+}
+
+#[rstest(::trace)]
+fn test_nan() {
+    let ctx = Context::default();
+
+    assert_eq!(D128::NAN.to_bid32(ctx), 0x7c00_0000);
+    assert_eq!(D128::NAN.to_dpd64(ctx), 0x7c00_0000_0000_0000);
+    assert_eq!(D128::NAN.to_bid128(ctx), 0x7c00 << 112);
+
+    // Signaling NaN and payloads.
+    assert!(D128::from_bid32(0x7e00_0000).is_nan());
+    assert!(D128::from_dpd32(0xfc00_0123).is_nan());
+    assert!(D128::from_bid64(0x7e00_0000_0000_0001).is_nan());
+    assert!(D128::from_dpd128(0x7e00 << 112, ctx).is_nan());
+}
+
+#[rstest(::trace)]
+fn test_non_canonical() {
+    // BID coefficients above the maximum are zero.
+    let d = D128::from_bid32(0x77ff_ffff);
+    assert!(d.is_zero());
+    assert_eq!(d.to_bid32(Context::default()), 0x5f80_0000);
+
+    assert!(D128::from_bid64(0x6fff_ffff_ffff_ffff).is_zero());
+
+    // Non-canonical declets decode like the canonical ones.
+    assert_eq!(D128::from_dpd32(0x2230_03ff), dec128!(9.99));
+    assert_eq!(D128::from_dpd32(0x2230_00ff), dec128!(9.99));
+}
+
+#[rstest(::trace)]
+fn test_declets() {
+    let ctx = Context::default();
+
+    for n in 0..1000u32 {
+        let d = D128::from_parts(U128::from(n), 0, Sign::Plus);
+        let bits = d.to_dpd32(ctx);
+
+        assert_eq!(bits >> 20, 0x225, "{n}");
+        assert_eq!(D128::from_dpd32(bits), d, "{n}");
+
+        let d = D128::from_parts(U128::from(n * 1000 + 999 - n), 0, Sign::Minus);
+        assert_eq!(D128::from_dpd32(d.to_dpd32(ctx)), d, "{n}");
+    }
+}
+
+#[rstest(::trace)]
+#[case(dec128!(123456789), Interchange::Decimal32, dec128!(1.234568e8), true)]
+#[case(dec128!(1234567000), Interchange::Decimal32, dec128!(1.234567e9), false)]
+#[case(dec128!(9999999.5), Interchange::Decimal32, dec128!(1.000000e7), true)]
+#[case(dec128!(-1.00000000000000005), Interchange::Decimal64, dec128!(-1.000000000000000), true)]
+#[case(
+    dec128!(0.3333333333333333333333333333333333),
+    Interchange::Decimal128,
+    dec128!(0.3333333333333333333333333333333333),
+    false
+)]
+#[case(
+    dec128!(0.33333333333333333333333333333333333),
+    Interchange::Decimal128,
+    dec128!(0.3333333333333333333333333333333333),
+    true
+)]
+fn test_rounding(
+    #[case] d: D128,
+    #[case] format: Interchange,
+    #[case] expected: D128,
+    #[case] inexact: bool,
+) {
+    let ctx = Context::default();
+    let rounded = d.round_to_interchange(format, ctx);
+
+    assert_eq!(rounded, expected);
+    assert_eq!(rounded.digits(), expected.digits());
+    assert_eq!(rounded.is_op_inexact(), inexact);
+
+    let (encoded, decoded) = match format {
+        Interchange::Decimal32 => {
+            let bits = d.to_bid32(ctx);
+            (bits as u128, D128::from_bid32(bits))
+        }
+        Interchange::Decimal64 => {
+            let bits = d.to_bid64(ctx);
+            (bits as u128, D128::from_bid64(bits))
+        }
+        Interchange::Decimal128 => {
+            let bits = d.to_bid128(ctx);
+            (bits, D128::from_bid128(bits, ctx))
+        }
+    };
+
+    assert_eq!(decoded, expected);
+    assert_eq!(decoded.digits(), expected.digits());
+    assert!(decoded.is_op_ok(), "{encoded:#x}");
+}
+
+#[rstest(::trace)]
+fn test_rounding_mode() {
+    let ctx = Context::default().with_rounding_mode(fastnum::decimal::RoundingMode::Down);
+    let d = dec128!(1.99999999).round_to_interchange(Interchange::Decimal32, ctx);
+
+    assert_eq!(d, dec128!(1.999999));
+    assert!(d.is_op_rounded());
+}
+
+#[rstest(::trace)]
+fn test_clamped() {
+    let ctx = Context::default();
+    let d = dec128!(1e96).round_to_interchange(Interchange::Decimal32, ctx);
+
+    assert_eq!(d, dec128!(1e96));
+    assert_eq!(d.digits(), fastnum::u128!(1000000));
+    assert!(d.is_op_clamped() && !d.is_op_rounded());
+    assert_eq!(dec128!(1e96).to_bid32(ctx), 0x5f8f_4240);
+
+    let d = dec128!(0e200).round_to_interchange(Interchange::Decimal32, ctx);
+    assert!(d.is_zero() && d.is_op_clamped());
+    assert_eq!(d.to_bid32(ctx), 0x5f80_0000);
+
+    // The padded coefficient doesn't fit into `D64`, but is encoded.
+    assert_eq!(
+        D128::from_bid128(dec64!(1e6144).to_bid128(ctx), ctx),
+        dec128!(1e6144)
+    );
+}
+
+#[rstest(::trace)]
+fn test_subnormal() {
+    let ctx = Context::default().with_signal_traps(SignalsTraps::empty());
+
+    let d = dec128!(1.5e-101).round_to_interchange(Interchange::Decimal32, ctx);
+    assert_eq!(d, dec128!(2e-101));
+    assert!(d.is_op_subnormal() && d.is_op_underflow() && d.is_op_inexact());
+
+    let d = dec128!(1e-100).round_to_interchange(Interchange::Decimal32, ctx);
+    assert!(d.is_op_subnormal() && !d.is_op_underflow());
+    assert_eq!(d.to_bid32(ctx), 0x0080_0001);
+
+    let d = dec128!(1e-103).round_to_interchange(Interchange::Decimal32, ctx);
+    assert!(d.is_zero() && d.is_op_underflow());
+}
+
+#[rstest(::trace)]
+fn test_overflow() {
+    let ctx = Context::default().with_signal_traps(SignalsTraps::empty());
+
+    let d = dec128!(1e97).round_to_interchange(Interchange::Decimal32, ctx);
+    assert!(d.is_infinite() && d.is_op_overflow());
+
+    let d = dec128!(-99999995e89).round_to_interchange(Interchange::Decimal32, ctx);
+    assert!(d.is_infinite() && d.is_sign_negative() && d.is_op_overflow());
+
+    assert_eq!(dec128!(1e385).to_bid64(ctx), 0x7800_0000_0000_0000);
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) overflow was occurred while performing arithmetic operation")]
+fn test_overflow_panic() {
+    let _ = dec128!(1e97).to_dpd32(Context::default());
+}
+
+#[rstest(::trace)]
+fn test_narrow() {
+    let ctx = Context::default();
+    let bits = 0x3040_0000_0000_0000_0000_0000_0000_0000 | 12345678901234567890123456789012u128;
+
+    let d = D64::from_bid128(bits, ctx);
+    assert_eq!(d, dec64!(12345678901234567890).mul(dec64!(1e12), ctx));
+    assert!(d.is_op_rounded() && d.is_op_inexact());
+
+    assert_eq!(D64::from_dpd64(dec128!(-7.50).to_dpd64(ctx)), dec64!(-7.50));
+}
@@ -13,3 +13,54 @@ test_impl!(UD, 512);
 
 
 
+mod wide {
+    use rstest::*;
+
+    use fastnum::{decimal::Sign, D4096, D8192, U8192};
+
+    #[rstest(::trace)]
+    #[case(1, 70)]
+    #[case(64, 4000)]
+    #[case(1500, 1500)]
+    #[case(3100, 3100)]
+    #[case(4000, 4000)]
+    #[case(4095, 4096)]
+    #[case(5000, 3000)]
+    #[case(7000, 1100)]
+    fn test_div_d8192_exact(#[case] quotient_bits: u32, #[case] divisor_bits: u32) {
+        for (a, b) in [
+            (U8192::MAX >> (8192 - quotient_bits), U8192::MAX >> (8192 - divisor_bits)),
+            (
+                (U8192::MAX >> (8192 - quotient_bits)) / U8192::from_digit(3),
+                (U8192::MAX >> (8192 - divisor_bits)) / U8192::from_digit(7),
+            ),
+            (
+                U8192::ONE << (quotient_bits - 1),
+                (U8192::MAX >> (8192 - divisor_bits)) ^ U8192::from_digit(0xFF),
+            ),
+        ] {
+            if a.bits() + b.bits() > 8192 {
+                continue;
+            }
+
+            let quot =
+                D8192::from_parts(a * b, -3, Sign::Minus) / D8192::from_parts(b, 2, Sign::Plus);
+
+            assert_eq!(quot, D8192::from_parts(a, -5, Sign::Minus));
+            assert!(quot.is_op_ok());
+        }
+    }
+
+    #[rstest(::trace)]
+    #[case(300)]
+    #[case(616)]
+    fn test_div_d4096_nines(#[case] k: usize) {
+        // (10^2k - 1) / (10^k - 1) = 10^k + 1
+        let lhs = D4096::from_str(&"9".repeat(2 * k)).unwrap();
+        let rhs = D4096::from_str(&"9".repeat(k)).unwrap();
+        let quot = lhs / rhs;
+
+        assert_eq!(quot.to_string(), format!("1{}1", "0".repeat(k - 1)));
+        assert!(quot.is_op_ok());
+    }
+}
@@ -7,3 +7,52 @@ test_impl!(D, 512);
 test_impl!(UD, 128);
 test_impl!(UD, 256);
 test_impl!(UD, 512);
+
+mod wide {
+    use rstest::*;
+
+    use fastnum::{decimal::Sign, D4096, D8192, U8192};
+
+    #[rstest(::trace)]
+    #[case(1, 1)]
+    #[case(23, 4000)]
+    #[case(1500, 1500)]
+    #[case(1536, 2500)]
+    #[case(3000, 5100)]
+    #[case(4095, 4096)]
+    fn test_mul_d8192_exact(#[case] lhs_bits: u32, #[case] rhs_bits: u32) {
+        for (a, b) in [
+            (U8192::MAX >> (8192 - lhs_bits), U8192::MAX >> (8192 - rhs_bits)),
+            (
+                (U8192::MAX >> (8192 - lhs_bits)) / U8192::from_digit(3),
+                (U8192::MAX >> (8192 - rhs_bits)) / U8192::from_digit(7),
+            ),
+        ] {
+            let prod = D8192::from_parts(a, -5, Sign::Plus) * D8192::from_parts(b, 2, Sign::Minus);
+
+            assert_eq!(prod, D8192::from_parts(a * b, -3, Sign::Minus));
+            assert!(prod.is_op_ok());
+        }
+    }
+
+    #[rstest(::trace)]
+    #[case(300)]
+    #[case(616)]
+    fn test_mul_d4096_nines(#[case] k: usize) {
+        // (10^k - 1)^2 = 10^2k - 2 * 10^k + 1
+        let nines = D4096::from_str(&"9".repeat(k)).unwrap();
+        let prod = nines * nines;
+
+        assert_eq!(prod.to_string(), format!("{}8{}1", "9".repeat(k - 1), "0".repeat(k - 1)));
+        assert!(prod.is_op_ok());
+    }
+
+    #[rstest(::trace)]
+    fn test_mul_d4096_rounded() {
+        let nines = D4096::from_str(&"9".repeat(1233)).unwrap();
+        let prod = nines * nines;
+
+        assert_eq!(prod, D4096::from_str(&format!("{}8e1233", "9".repeat(1232))).unwrap());
+        assert!(prod.is_op_inexact());
+    }
+}
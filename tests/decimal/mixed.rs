@@ -0,0 +1,77 @@
+use rstest::*;
+
+use fastnum::{dec128, dec256, dec512, udec128, udec256, D128, D256, D512, UD128, UD256};
+
+#[rstest(::trace)]
+#[case(dec128!(1.5), dec256!(2.25), dec256!(3.75), dec256!(-0.75), dec256!(3.375), dec256!(0.66666666666666666666666666666666666666666666666666666666666666666666666666667))]
+#[case(dec128!(-10), dec256!(4), dec256!(-6), dec256!(-14), dec256!(-40), dec256!(-2.5))]
+fn test_signed(#[case] a: D128, #[case] b: D256, #[case] sum: D256, #[case] diff: D256, #[case] prod: D256, #[case] quot: D256) {
+    assert_eq!(a + b, sum);
+    assert_eq!(b + a, sum);
+    assert_eq!(a - b, diff);
+    assert_eq!(b - a, -diff);
+    assert_eq!(a * b, prod);
+    assert_eq!(b * a, prod);
+    assert_eq!(a / b, quot);
+
+    let mut c = b;
+    c += a;
+    assert_eq!(c, sum);
+
+    let mut c = b;
+    c *= a;
+    assert_eq!(c, prod);
+}
+
+#[rstest(::trace)]
+#[case(udec128!(1.5), dec256!(-2), dec256!(-0.5), dec256!(-3.0))]
+#[case(udec128!(10), dec256!(0.5), dec256!(10.5), dec256!(5.0))]
+fn test_unsigned_signed(#[case] a: UD128, #[case] b: D256, #[case] sum: D256, #[case] prod: D256) {
+    assert_eq!(a + b, sum);
+    assert_eq!(b + a, sum);
+    assert_eq!(a * b, prod);
+    assert_eq!(b * a, prod);
+
+    let mut c = b;
+    c += a;
+    assert_eq!(c, sum);
+}
+
+#[rstest(::trace)]
+#[case(udec128!(1.5), dec128!(-2), dec128!(-0.5))]
+fn test_unsigned_signed_same_width(#[case] a: UD128, #[case] b: D128, #[case] sum: D128) {
+    assert_eq!(a + b, sum);
+    assert_eq!(b + a, sum);
+    assert_eq!(a - b, -(b - a));
+
+    let mut c = b;
+    c += a;
+    assert_eq!(c, sum);
+}
+
+#[rstest(::trace)]
+#[case(udec128!(1.5), udec256!(2.5), udec256!(4.0), udec256!(3.75))]
+fn test_unsigned(#[case] a: UD128, #[case] b: UD256, #[case] sum: UD256, #[case] prod: UD256) {
+    assert_eq!(a + b, sum);
+    assert_eq!(b + a, sum);
+    assert_eq!(a * b, prod);
+    assert_eq!(b - a, udec256!(1.0));
+
+    let mut c = b;
+    c += a;
+    assert_eq!(c, sum);
+}
+
+#[rstest(::trace)]
+#[case(dec128!(7), dec512!(2), dec512!(1))]
+fn test_rem(#[case] a: D128, #[case] b: D512, #[case] expected: D512) {
+    assert_eq!(a % b, expected);
+}
+
+#[test]
+fn test_output_type() {
+    let _: D256 = dec128!(1) + dec256!(1);
+    let _: D256 = udec128!(1) * dec256!(1);
+    let _: D512 = dec512!(1) - udec256!(1);
+    let _: UD256 = udec128!(1) / udec256!(1);
+}
@@ -5,13 +5,23 @@
 pub(crate) mod common;
 
 mod assertions;
+mod batch;
+mod bytes;
 mod cmp;
+mod codec;
+mod compact;
 mod extras;
+mod fixed;
 mod format;
 mod from;
 mod hash;
+mod ieee754;
 mod math;
+mod mixed;
+mod ordered;
 mod parse;
+mod parts;
+mod resize;
 mod round;
 mod scale;
 mod smoke;
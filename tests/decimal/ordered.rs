@@ -0,0 +1,219 @@
+use rstest::*;
+
+use fastnum::{
+    dec128, dec256, dec64,
+    decimal::{OrderedBytes, ParseError, Sign},
+    test_util::Xorshift,
+    D128, D256, D64, U256,
+};
+
+#[rstest(::trace)]
+#[case(dec256!(0))]
+#[case(dec256!(-0))]
+#[case(dec256!(1))]
+#[case(dec256!(-1))]
+#[case(dec256!(123.45))]
+#[case(dec256!(-0.00001))]
+#[case(dec256!(5e300))]
+#[case(D256::MAX)]
+#[case(D256::MIN)]
+#[case(D256::from_parts(U256::MAX, i16::MAX, Sign::Plus))]
+#[case(D256::from_parts(U256::ONE, i16::MAX, Sign::Minus))]
+#[case(D256::INFINITY)]
+#[case(D256::NEG_INFINITY)]
+fn test_roundtrip(#[case] d: D256) {
+    let key = d.to_ordered_bytes();
+    let decoded = D256::from_ordered_bytes(&key).unwrap();
+
+    assert_eq!(decoded, d);
+    assert_eq!(decoded.is_sign_negative(), d.is_sign_negative());
+    assert_eq!(decoded.to_ordered_bytes(), key);
+}
+
+#[rstest(::trace)]
+fn test_roundtrip_nan() {
+    let key = D256::NAN.to_ordered_bytes();
+
+    assert!(D256::from_ordered_bytes(&key).unwrap().is_nan());
+    assert_eq!(D256::NAN.neg().to_ordered_bytes(), key);
+}
+
+#[rstest(::trace)]
+#[case(dec256!(1.500), dec256!(1.5))]
+#[case(dec256!(-100), dec256!(-1e2))]
+#[case(dec256!(0.000), dec256!(0))]
+#[case(dec256!(-0.0), dec256!(-0))]
+fn test_normalized(#[case] d: D256, #[case] expected: D256) {
+    let decoded = D256::from_ordered_bytes(&d.to_ordered_bytes()).unwrap();
+
+    assert_eq!(d.to_ordered_bytes(), expected.to_ordered_bytes());
+    assert_eq!(decoded.digits(), expected.digits());
+    assert_eq!(
+        decoded.fractional_digits_count(),
+        expected.fractional_digits_count()
+    );
+}
+
+#[rstest(::trace)]
+fn test_trailing_zeros_at_min_scale() {
+    let d = D128::from_parts(fastnum::u128!(1000), i16::MIN, Sign::Plus);
+    let decoded = D128::from_ordered_bytes(&d.to_ordered_bytes()).unwrap();
+
+    assert_eq!(decoded.into_parts(), d.into_parts());
+}
+
+#[rstest(::trace)]
+fn test_special_order() {
+    let keys = [
+        D256::NEG_INFINITY,
+        D256::MIN,
+        dec256!(-1),
+        D256::from_parts(U256::ONE, i16::MAX, Sign::Minus),
+        dec256!(-0),
+        dec256!(0),
+        D256::from_parts(U256::ONE, i16::MAX, Sign::Plus),
+        dec256!(1),
+        D256::MAX,
+        D256::INFINITY,
+        D256::NAN,
+    ]
+    .map(|d| d.to_ordered_bytes());
+
+    assert!(keys.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[rstest(::trace)]
+fn test_layout() {
+    assert_eq!(OrderedBytes::<1>::LEN, 15);
+    assert_eq!(OrderedBytes::<4>::LEN, 45);
+
+    assert_eq!(
+        dec64!(12.5).to_ordered_bytes().as_ref(),
+        [4, 0x80, 0, 0, 1, 0x12, 0x50, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(
+        dec64!(-12.5).to_ordered_bytes().as_ref(),
+        [1, 0x7f, 0xff, 0xff, 0xfe, 0xed, 0xaf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+    assert_eq!(D64::NEG_INFINITY.to_ordered_bytes(), OrderedBytes::ZERO);
+}
+
+#[rstest(::trace)]
+fn test_invalid() {
+    let mut key = dec64!(12.5).to_ordered_bytes();
+    key.as_mut()[0] = 7;
+    assert_eq!(
+        D64::from_ordered_bytes(&key),
+        Err(ParseError::InvalidLiteral)
+    );
+
+    let mut key = dec64!(12.5).to_ordered_bytes();
+    key.as_mut()[6] = 0x0a;
+    assert_eq!(
+        D64::from_ordered_bytes(&key),
+        Err(ParseError::InvalidLiteral)
+    );
+
+    let mut key = dec64!(12.5).to_ordered_bytes();
+    key.as_mut()[5] = 0x01;
+    assert_eq!(
+        D64::from_ordered_bytes(&key),
+        Err(ParseError::InvalidLiteral)
+    );
+
+    let mut key = dec64!(0).to_ordered_bytes();
+    key.as_mut()[1] = 0x80;
+    assert_eq!(
+        D64::from_ordered_bytes(&key),
+        Err(ParseError::InvalidLiteral)
+    );
+
+    let mut key = dec64!(1).to_ordered_bytes();
+    key.as_mut()[5..].fill(0x99);
+    assert_eq!(D64::from_ordered_bytes(&key), Err(ParseError::PosOverflow));
+
+    let mut key = dec64!(1).to_ordered_bytes();
+    key.as_mut()[1..5].copy_from_slice(&[0, 0, 0, 0]);
+    assert_eq!(
+        D64::from_ordered_bytes(&key),
+        Err(ParseError::ExponentOverflow)
+    );
+}
+
+#[rstest(::trace)]
+fn test_from_slice() {
+    let key = dec128!(-3.25).to_ordered_bytes();
+    let bytes = OrderedBytes::<2>::from_slice(key.as_ref()).unwrap();
+
+    assert_eq!(D128::from_ordered_bytes(&bytes), Ok(dec128!(-3.25)));
+}
+
+/// Random finite decimals, many of them with equal or close values at
+/// different scales.
+fn random_decimals(count: usize) -> Vec<D256> {
+    let mut rng = Xorshift::new(0x9e37_79b9_7f4a_7c15);
+    let mut values = Vec::with_capacity(count);
+
+    while values.len() < count {
+        let bits = (rng.next_u64() % 257) as u32;
+        let digits = if bits == 0 {
+            U256::ZERO
+        } else {
+            U256::from_digits(core::array::from_fn(|_| rng.next_u64())) >> (256 - bits)
+        };
+
+        let scale = match rng.next_u64() % 4 {
+            0 => i16::MIN + (rng.next_u64() % 100) as i16,
+            1 => i16::MAX - (rng.next_u64() % 100) as i16,
+            _ => (rng.next_u64() % 160) as i16 - 80,
+        };
+
+        let sign = if rng.next_u64() % 2 == 0 {
+            Sign::Plus
+        } else {
+            Sign::Minus
+        };
+
+        let d = D256::from_parts(digits, scale, sign);
+        values.push(d);
+
+        // The same value with more trailing zeros.
+        let zeros = (rng.next_u64() % 5) as u32;
+        if let (Some(digits), Some(scale)) = (
+            digits.checked_mul(U256::from(10u64.pow(zeros))),
+            scale.checked_add(zeros as i16),
+        ) {
+            values.push(D256::from_parts(digits, scale, sign));
+        }
+
+        // A close value.
+        if let Some(digits) = digits.checked_add(U256::ONE) {
+            values.push(D256::from_parts(digits, scale, sign));
+        }
+    }
+
+    values
+}
+
+#[rstest(::trace)]
+fn test_order_agrees_with_cmp() {
+    let values = random_decimals(400);
+    let keys: Vec<_> = values.iter().map(|d| d.to_ordered_bytes()).collect();
+
+    for (a, ka) in values.iter().zip(&keys) {
+        for (b, kb) in values.iter().zip(&keys) {
+            assert_eq!(ka.cmp(kb), a.cmp(b), "{a:?} <=> {b:?}");
+        }
+    }
+}
+
+#[rstest(::trace)]
+fn test_random_roundtrip() {
+    for d in random_decimals(2000) {
+        let key = d.to_ordered_bytes();
+        let decoded = D256::from_ordered_bytes(&key).unwrap();
+
+        assert_eq!(decoded.cmp(&d), core::cmp::Ordering::Equal, "{d:?}");
+        assert_eq!(decoded.to_ordered_bytes(), key);
+    }
+}
@@ -0,0 +1,68 @@
+use rstest::*;
+
+use fastnum::{
+    dec128, dec256, dec64, u128, u256, udec128,
+    decimal::Sign,
+    D128, D256, D64, U128, U256, UD128,
+};
+
+#[rstest(::trace)]
+#[case(u256!(12345), 2, Sign::Minus, dec256!(-123.45))]
+#[case(u256!(12345), 2, Sign::Plus, dec256!(123.45))]
+#[case(u256!(0), 5, Sign::Plus, dec256!(0.00000))]
+#[case(u256!(0), 0, Sign::Minus, dec256!(-0))]
+#[case(u256!(5), -3, Sign::Plus, dec256!(5e3))]
+#[case(U256::MAX, i16::MAX, Sign::Minus, D256::from_str(&format!("-{}e-{}", U256::MAX, i16::MAX)).unwrap())]
+fn test_parts(#[case] digits: U256, #[case] scale: i16, #[case] sign: Sign, #[case] expected: D256) {
+    let d = D256::from_parts(digits, scale, sign);
+
+    assert_eq!(d, expected);
+    assert_eq!(d.digits(), expected.digits());
+    assert_eq!(d.fractional_digits_count(), expected.fractional_digits_count());
+    assert_eq!(d.into_parts(), (digits, scale, sign));
+    assert!(d.is_op_ok());
+}
+
+#[rstest(::trace)]
+#[case(-12345, 2, dec128!(-123.45))]
+#[case(0, 0, dec128!(0))]
+#[case(1, -2, dec128!(100))]
+#[case(i128::MAX, 38, dec128!(1.70141183460469231731687303715884105727))]
+#[case(i128::MIN, 0, dec128!(-170141183460469231731687303715884105728))]
+fn test_from_i128_with_scale(#[case] mantissa: i128, #[case] scale: i16, #[case] expected: D128) {
+    let d = D128::from_i128_with_scale(mantissa, scale);
+
+    assert_eq!(d, expected);
+    assert_eq!(d.fractional_digits_count(), scale);
+}
+
+#[rstest(::trace)]
+#[case(u64::MAX, 0, dec64!(18446744073709551615))]
+#[case(5, 1, dec64!(0.5))]
+fn test_from_u64_with_scale(#[case] mantissa: u64, #[case] scale: i16, #[case] expected: D64) {
+    assert_eq!(D64::from_u64_with_scale(mantissa, scale), expected);
+}
+
+#[rstest(::trace)]
+#[case(i64::MIN, 0, dec64!(-9223372036854775808))]
+#[case(-5, 1, dec64!(-0.5))]
+fn test_from_i64_with_scale(#[case] mantissa: i64, #[case] scale: i16, #[case] expected: D64) {
+    assert_eq!(D64::from_i64_with_scale(mantissa, scale), expected);
+}
+
+#[rstest(::trace)]
+#[case(u128!(12345), 2, udec128!(123.45))]
+#[case(U128::MAX, 0, UD128::from(u128::MAX))]
+fn test_unsigned_parts(#[case] digits: U128, #[case] scale: i16, #[case] expected: UD128) {
+    let d = UD128::from_parts(digits, scale);
+
+    assert_eq!(d, expected);
+    assert_eq!(d.into_parts(), (digits, scale));
+    assert_eq!(UD128::from_u128_with_scale(expected.digits().to_string().parse().unwrap(), scale), expected);
+}
+
+#[test]
+#[should_panic(expected = "(fastnum) overflow was occurred while performing arithmetic operation")]
+fn test_from_u128_with_scale_overflow() {
+    let _ = D64::from_u128_with_scale(u128::MAX, 2);
+}
@@ -0,0 +1,98 @@
+use rstest::*;
+
+use fastnum::{
+    dec128, dec256, dec512, dec64, udec128, udec256,
+    decimal::{Context, ParseError, RoundingMode, SignalsTraps},
+    D128, D256, D512, UD128, UD256,
+};
+
+#[rstest(::trace)]
+#[case(dec128!(0), dec256!(0))]
+#[case(dec128!(-1.50), dec256!(-1.50))]
+#[case(D128::MAX, D256::from_str("340282366920938463463374607431768211455e32768").unwrap())]
+#[case(D128::NEG_INFINITY, D256::NEG_INFINITY)]
+fn test_widening(#[case] a: D128, #[case] expected: D256) {
+    let res = D256::from(a);
+
+    assert_eq!(res, expected);
+    assert_eq!(res.digits().to_string(), a.digits().to_string());
+    assert_eq!(res.fractional_digits_count(), a.fractional_digits_count());
+    assert!(res.is_op_ok());
+
+    assert_eq!(D512::from(a), D512::from(res));
+    assert_eq!(D128::try_from(res), Ok(a));
+    assert_eq!(a.resize::<4>(Context::default()), expected);
+}
+
+#[rstest(::trace)]
+#[case(dec256!(1.5), dec128!(1.5))]
+#[case(dec256!(-340282366920938463463374607431768211455), dec128!(-340282366920938463463374607431768211455))]
+fn test_narrowing_exact(#[case] a: D256, #[case] expected: D128) {
+    assert_eq!(D128::try_from(a), Ok(expected));
+
+    let res = a.resize::<2>(Context::default());
+    assert_eq!(res, expected);
+    assert!(res.is_op_ok());
+}
+
+#[rstest(::trace)]
+#[case(dec256!(3402823669209384634633746074317682114550), dec128!(340282366920938463463374607431768211455e1))]
+#[case(dec256!(40000000000000000000000000000000000000000), dec128!(4e40))]
+#[case(dec256!(-1.0000000000000000000000000000000000000000), dec128!(-1))]
+fn test_narrowing_zero_digits(#[case] a: D256, #[case] expected: D128) {
+    assert_eq!(D128::try_from(a), Ok(expected));
+
+    let res = a.resize::<2>(Context::default());
+    assert_eq!(res, expected);
+    assert!(res.is_op_rounded());
+    assert!(!res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(RoundingMode::HalfUp, dec256!(340282366920938463463374607431768211456), dec128!(34028236692093846346337460743176821146e1))]
+#[case(RoundingMode::Down, dec256!(340282366920938463463374607431768211456), dec128!(34028236692093846346337460743176821145e1))]
+#[case(RoundingMode::HalfUp, dec256!(-0.1234567890123456789012345678901234567885), dec128!(-0.123456789012345678901234567890123456789))]
+#[case(RoundingMode::HalfEven, dec256!(-0.1234567890123456789012345678901234567885), dec128!(-0.123456789012345678901234567890123456788))]
+#[case(RoundingMode::Floor, dec256!(-0.1234567890123456789012345678901234567881), dec128!(-0.123456789012345678901234567890123456789))]
+#[case(RoundingMode::HalfUp, dec256!(999999999999999999999999999999999999999.9), dec128!(1.00000000000000000000000000000000000000e39))]
+fn test_narrowing_rounded(#[case] mode: RoundingMode, #[case] a: D256, #[case] expected: D128) {
+    assert_eq!(D128::try_from(a), Err(ParseError::Inexact));
+
+    let res = a.resize::<2>(Context::default().with_rounding_mode(mode));
+    assert_eq!(res, expected);
+    assert!(res.is_op_rounded());
+    assert!(res.is_op_inexact());
+}
+
+#[rstest(::trace)]
+#[case(D512::MAX, ParseError::PosOverflow)]
+#[case(D512::MIN, ParseError::NegOverflow)]
+fn test_narrowing_overflow(#[case] a: D512, #[case] expected: ParseError) {
+    assert_eq!(D128::try_from(a), Err(expected));
+
+    let res = a.resize::<2>(Context::default().with_signal_traps(SignalsTraps::empty()));
+    assert!(res.is_infinite());
+    assert!(res.is_op_overflow());
+    assert_eq!(res.is_negative(), a.is_negative());
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) overflow was occurred while performing arithmetic operation")]
+#[case(D512::MAX)]
+fn test_narrowing_overflow_panic(#[case] a: D512) {
+    let _ = a.resize::<2>(Context::default());
+}
+
+#[rstest(::trace)]
+#[case(dec512!(0.5), dec64!(0.5))]
+fn test_narrowing_to_64(#[case] a: D512, #[case] expected: fastnum::D64) {
+    assert_eq!(fastnum::D64::try_from(a), Ok(expected));
+}
+
+#[rstest(::trace)]
+#[case(udec128!(12.75), udec256!(12.75))]
+fn test_unsigned(#[case] a: UD128, #[case] expected: UD256) {
+    assert_eq!(UD256::from(a), expected);
+    assert_eq!(UD128::try_from(expected), Ok(a));
+    assert_eq!(expected.resize::<2>(Context::default()), a);
+}
@@ -0,0 +1,69 @@
+use rstest::*;
+
+use fastnum::{i256, I256};
+
+#[rstest(::trace)]
+#[case(i256!(0), "0")]
+#[case(i256!(-1), "-1")]
+#[case(i256!(-1200), "-1200")]
+#[case(
+    I256::MAX,
+    "57896044618658097711785492504343953926634992332820282019728792003956564819967"
+)]
+#[case(
+    I256::MIN,
+    "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+)]
+fn test_display(#[case] n: I256, #[case] expected: &str) {
+    assert_eq!(n.to_string(), expected);
+    assert_eq!(n.to_str_radix(10), expected);
+    assert_eq!(expected.parse::<I256>(), Ok(n));
+
+    let mut buf = [0; I256::MAX_STR_LEN];
+    assert_eq!(n.to_str_radix_buf(10, &mut buf), expected);
+}
+
+#[rstest(::trace)]
+fn test_to_str_radix_buf_min() {
+    let mut buf = [0; I256::MAX_STR_LEN];
+    let expected = format!("-1{}", "0".repeat(255));
+
+    assert_eq!(I256::MIN.to_str_radix_buf(2, &mut buf), expected);
+}
+
+#[rstest(::trace)]
+#[case(i256!(-0xff), -255)]
+#[case(i256!(0x7f), 127)]
+#[case(i256!(-0b101), -5)]
+#[case(i256!(-0o17), -15)]
+#[case(i256!(-1_000), -1000)]
+fn test_prefixed_literals(#[case] n: I256, #[case] expected: i64) {
+    assert_eq!(n, I256::from(expected));
+}
+
+#[rstest(::trace)]
+fn test_min_literal() {
+    const MIN: I256 = i256!(-0x8000000000000000000000000000000000000000000000000000000000000000);
+    assert_eq!(MIN, I256::MIN);
+}
+
+#[rstest(::trace)]
+fn test_format() {
+    let n = I256::from(-42);
+
+    assert_eq!(format!("{n:>6}"), "   -42");
+    assert_eq!(format!("{n:06}"), "-00042");
+    assert_eq!(format!("{n:e}"), "-4.2e1");
+    assert_eq!(format!("{n:x}"), "f".repeat(62) + "d6");
+    assert_eq!(n.to_str_radix(16), "-2a");
+    assert_eq!(I256::from_str_radix("-2a", 16), Ok(n));
+}
+
+#[rstest(::trace)]
+#[case("57896044618658097711785492504343953926634992332820282019728792003956564819968")]
+#[case("-57896044618658097711785492504343953926634992332820282019728792003956564819969")]
+#[case("-")]
+#[case("")]
+fn test_parse_error(#[case] s: &str) {
+    assert!(s.parse::<I256>().is_err());
+}
@@ -0,0 +1,105 @@
+use rstest::*;
+
+use fastnum::{i256, test_util::Xorshift, I128, I256, U256};
+
+/// Deterministic xorshift sequence of operands of both signs.
+fn operands() -> impl Iterator<Item = i128> {
+    let mut rng = Xorshift::new(0x9e37_79b9_7f4a_7c15);
+
+    (0..2000).map(move |i| {
+        let n = rng.next_u128() as i128;
+        match i % 4 {
+            0 => n,
+            1 => n >> (rng.next_u64() % 128),
+            2 => [i128::MIN, i128::MAX, -1, 0, 1][(rng.next_u64() % 5) as usize],
+            _ => rng.next_u64() as i64 as i128,
+        }
+    })
+}
+
+fn i(n: i128) -> I128 {
+    I128::try_from(n).unwrap()
+}
+
+#[rstest(::trace)]
+fn test_i128_oracle() {
+    let lhs: Vec<i128> = operands().collect();
+    let rhs: Vec<i128> = operands().skip(1).chain([0]).collect();
+
+    for (&a, &b) in lhs.iter().zip(rhs.iter()) {
+        let (x, y) = (i(a), i(b));
+
+        assert_eq!(
+            x.overflowing_add(y),
+            (i(a.overflowing_add(b).0), a.overflowing_add(b).1)
+        );
+        assert_eq!(
+            x.overflowing_sub(y),
+            (i(a.overflowing_sub(b).0), a.overflowing_sub(b).1)
+        );
+        assert_eq!(
+            x.overflowing_mul(y),
+            (i(a.overflowing_mul(b).0), a.overflowing_mul(b).1)
+        );
+        assert_eq!(x.checked_div(y), a.checked_div(b).map(i));
+        assert_eq!(x.checked_rem(y), a.checked_rem(b).map(i));
+        assert_eq!(x.checked_div_euclid(y), a.checked_div_euclid(b).map(i));
+        assert_eq!(x.checked_rem_euclid(y), a.checked_rem_euclid(b).map(i));
+        assert_eq!(x.saturating_add(y), i(a.saturating_add(b)));
+        assert_eq!(x.saturating_sub(y), i(a.saturating_sub(b)));
+        assert_eq!(x.saturating_mul(y), i(a.saturating_mul(b)));
+        assert_eq!(x.abs_diff(y), a.abs_diff(b).into());
+        assert_eq!(x.cmp(&y), a.cmp(&b));
+        assert_eq!(
+            x.overflowing_neg(),
+            (i(a.overflowing_neg().0), a.overflowing_neg().1)
+        );
+        assert_eq!(
+            x.overflowing_abs(),
+            (i(a.overflowing_abs().0), a.overflowing_abs().1)
+        );
+        assert_eq!(x.signum(), i(a.signum()));
+
+        let shift = (b as u128 % 128) as u32;
+        assert_eq!(x << shift, i(a << shift));
+        assert_eq!(x >> shift, i(a >> shift));
+
+        let exp = (b as u128 % 8) as u32;
+        assert_eq!(
+            x.overflowing_pow(exp),
+            (i(a.overflowing_pow(exp).0), a.overflowing_pow(exp).1)
+        );
+
+        assert_eq!(i128::try_from(x), Ok(a));
+        assert_eq!(i64::try_from(x).ok(), i64::try_from(a).ok());
+        assert_eq!(u64::try_from(x).ok(), u64::try_from(a).ok());
+        assert_eq!(x.to_string(), a.to_string());
+        assert_eq!(format!("{x:x}"), format!("{a:x}"));
+        assert_eq!(a.to_string().parse::<I128>(), Ok(x));
+    }
+}
+
+#[rstest(::trace)]
+fn test_bounds() {
+    assert_eq!(I256::MAX.to_bits(), U256::MAX >> 1);
+    assert_eq!(I256::MIN.to_bits(), U256::ONE << 255);
+    assert_eq!(I256::MIN.unsigned_abs(), U256::ONE << 255);
+    assert_eq!(I256::MAX.checked_add(I256::ONE), None);
+    assert_eq!(I256::MIN.checked_sub(I256::ONE), None);
+    assert_eq!(I256::MIN.checked_neg(), None);
+    assert_eq!(I256::MIN.checked_div(I256::NEG_ONE), None);
+    assert_eq!(I256::MIN.wrapping_div(I256::NEG_ONE), I256::MIN);
+    assert_eq!(I256::MIN >> 255, I256::NEG_ONE);
+}
+
+#[rstest(::trace)]
+fn test_resize() {
+    let x = I256::from(-5);
+
+    assert_eq!(I128::try_from(x), Ok(I128::from(-5)));
+    assert_eq!(
+        I256::from(I128::MIN),
+        i256!(-170141183460469231731687303715884105728)
+    );
+    assert!(I128::try_from(I256::from(I128::MIN) - I256::ONE).is_err());
+}
@@ -0,0 +1,4 @@
+mod display;
+mod math;
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,39 @@
+use rstest::*;
+use serde_test::{Configure, Token};
+
+use fastnum::{i256, I256};
+
+#[rstest(::trace)]
+#[case(i256!(0), [0, 0, 0, 0])]
+#[case(i256!(1), [1, 0, 0, 0])]
+#[case(i256!(-1), [u64::MAX; 4])]
+#[case(I256::MIN, [0, 0, 0, 1 << 63])]
+fn test_serde_bnum_layout(#[case] n: I256, #[case] digits: [u64; 4]) {
+    let mut tokens = vec![
+        Token::Struct {
+            name: "BInt",
+            len: 1,
+        },
+        Token::Str("bits"),
+        Token::Struct {
+            name: "BUint",
+            len: 1,
+        },
+        Token::Str("digits"),
+        Token::Tuple { len: 4 },
+    ];
+    tokens.extend(digits.map(Token::U64));
+    tokens.extend([Token::TupleEnd, Token::StructEnd, Token::StructEnd]);
+
+    serde_test::assert_tokens(&n.readable(), &tokens);
+    serde_test::assert_tokens(&n.compact(), &tokens);
+}
+
+#[rstest(::trace)]
+#[case(r#"{"bits":{"digits":[18446744073709551615,18446744073709551615,18446744073709551615,18446744073709551615]}}"#, i256!(-1))]
+#[case(r#"{"bits":"5"}"#, i256!(5))]
+#[case(r#"-12345"#, i256!(-12345))]
+#[case(r#""-340282366920938463463374607431768211456""#, i256!(-340282366920938463463374607431768211456))]
+fn test_serde_deserialize_json(#[case] json: &str, #[case] expected: I256) {
+    assert_eq!(serde_json::from_str::<I256>(json).unwrap(), expected);
+}
@@ -1 +1,2 @@
+mod i256;
 mod u256;
@@ -0,0 +1,153 @@
+use rstest::*;
+
+use fastnum::{
+    int::{
+        ct::{self, Mask},
+        UInt,
+    },
+    test_util::Xorshift,
+    U256, U512,
+};
+
+/// Random values mixed with the edge cases, where the variable-time code
+/// takes its shortcuts.
+fn operands<const N: usize>() -> Vec<UInt<N>> {
+    let mut rng = Xorshift::new(0x9e37_79b9_7f4a_7c15);
+    let mut values = vec![
+        UInt::ZERO,
+        UInt::ONE,
+        UInt::MAX,
+        UInt::MAX >> 1_u32,
+        UInt::ONE << (UInt::<N>::BITS - 1),
+    ];
+
+    for i in 0..200 {
+        let n = UInt::<N>::from_digits(core::array::from_fn(|_| rng.next_u64()));
+        values.push(n >> (i % UInt::<N>::BITS));
+    }
+
+    values
+}
+
+fn check<const N: usize>() {
+    let values = operands::<N>();
+
+    for (a, b) in values.iter().zip(values.iter().rev()) {
+        assert_eq!(ct::eq(a, b).to_bool(), a == b);
+        assert_eq!(ct::eq(a, a), Mask::TRUE);
+        assert_eq!(ct::lt(a, b).to_bool(), a < b);
+        assert_eq!(ct::gt(a, b).to_bool(), a > b);
+        assert_eq!(ct::le(a, b).to_bool(), a <= b);
+        assert_eq!(ct::ge(a, b).to_bool(), a >= b);
+        assert_eq!(ct::is_zero(a).to_bool(), a.is_zero());
+
+        let (sum, carry) = a.overflowing_add(*b);
+        assert_eq!(ct::overflowing_add(a, b), (sum, Mask::from_bool(carry)));
+
+        let (diff, borrow) = a.overflowing_sub(*b);
+        assert_eq!(ct::overflowing_sub(a, b), (diff, Mask::from_bool(borrow)));
+
+        assert_eq!(ct::widening_mul(a, b), a.widening_mul(*b));
+        assert_eq!(ct::wrapping_mul(a, b), a.wrapping_mul(*b));
+
+        assert_eq!(ct::select(a, b, Mask::FALSE), *a);
+        assert_eq!(ct::select(a, b, Mask::TRUE), *b);
+
+        let (mut x, mut y) = (*a, *b);
+        ct::swap(&mut x, &mut y, Mask::TRUE);
+        assert_eq!((x, y), (*b, *a));
+        ct::swap(&mut x, &mut y, Mask::FALSE);
+        assert_eq!((x, y), (*b, *a));
+
+        if b.is_zero() {
+            continue;
+        }
+
+        assert_eq!(ct::rem(a, b), *a % *b);
+
+        let (x, y) = (*a % *b, a.wrapping_mul(*a) % *b);
+        assert_eq!(ct::mod_mul(&x, &y, b), x.mod_mul(y, *b));
+        assert_eq!(ct::mod_sub(&ct::mod_add(&x, &y, b), &y, b), x);
+        assert_eq!(
+            ct::mod_add(&x, &y, b),
+            match x.checked_add(y) {
+                Some(sum) => sum % *b,
+                None => x.wrapping_add(y).wrapping_sub(*b),
+            }
+        );
+    }
+}
+
+#[rstest(::trace)]
+fn test_ct_u64() {
+    check::<1>();
+}
+
+#[rstest(::trace)]
+fn test_ct_u256() {
+    check::<4>();
+}
+
+#[rstest(::trace)]
+fn test_ct_u512() {
+    check::<8>();
+}
+
+#[rstest(::trace)]
+fn test_rem_wide() {
+    let (low, high) = U256::MAX.widening_mul(U256::MAX);
+    let modulus = U256::MAX - U256::from(188_u8);
+
+    let wide = U512::from(high) << 256_u32 | U512::from(low);
+    let expected = U256::try_from(wide % U512::from(modulus)).unwrap();
+
+    assert_eq!(ct::rem_wide(&low, &high, &modulus), expected);
+}
+
+#[rstest(::trace)]
+fn test_mask() {
+    assert!(Mask::TRUE.to_bool());
+    assert!(!Mask::FALSE.to_bool());
+    assert_eq!(Mask::TRUE.not(), Mask::FALSE);
+    assert_eq!(Mask::TRUE.and(Mask::FALSE), Mask::FALSE);
+    assert_eq!(Mask::TRUE.or(Mask::FALSE), Mask::TRUE);
+    assert_eq!(Mask::from_bit(1).to_word(), u64::MAX);
+}
+
+#[cfg(feature = "subtle")]
+mod subtle_traits {
+    use rstest::*;
+    use subtle::{
+        Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+    };
+
+    use fastnum::{i256, u256};
+
+    #[rstest(::trace)]
+    fn test_subtle_uint() {
+        let (a, b) = (u256!(5), u256!(7));
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert!(bool::from(b.ct_gt(&a)));
+        assert!(bool::from(a.ct_lt(&b)));
+        assert_eq!(
+            ConditionallySelectable::conditional_select(&a, &b, Choice::from(1)),
+            b
+        );
+    }
+
+    #[rstest(::trace)]
+    fn test_subtle_int() {
+        let (a, b) = (i256!(-5), i256!(7));
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(bool::from(b.ct_gt(&a)));
+        assert!(bool::from(a.ct_lt(&b)));
+        assert!(bool::from(i256!(-7).ct_lt(&a)));
+        assert_eq!(
+            ConditionallySelectable::conditional_select(&a, &b, Choice::from(0)),
+            a
+        );
+    }
+}
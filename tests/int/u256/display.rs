@@ -0,0 +1,102 @@
+use rstest::*;
+
+use fastnum::{u256, U256};
+
+#[rstest(::trace)]
+#[case(u256!(0), "0")]
+#[case(u256!(1), "1")]
+#[case(u256!(10000000000000000000), "10000000000000000000")]
+#[case(u256!(340282366920938463463374607431768211456), "340282366920938463463374607431768211456")]
+#[case(
+    U256::MAX,
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+)]
+fn test_display(#[case] n: U256, #[case] expected: &str) {
+    assert_eq!(n.to_string(), expected);
+    assert_eq!(n.to_str_radix(10), expected);
+    assert_eq!(format!("{n:?}"), expected);
+}
+
+#[rstest(::trace)]
+#[case(u256!(0), "0", "0", "0", "0")]
+#[case(u256!(255), "ff", "FF", "377", "11111111")]
+#[case(u256!(0x123456789abcdef0123456789abcdef), "123456789abcdef0123456789abcdef", "123456789ABCDEF0123456789ABCDEF", "11064254742325715736004432126361152746757", "1001000110100010101100111100010011010101111001101111011110000000100100011010001010110011110001001101010111100110111101111")]
+fn test_radix(
+    #[case] n: U256,
+    #[case] hex: &str,
+    #[case] upper_hex: &str,
+    #[case] octal: &str,
+    #[case] binary: &str,
+) {
+    assert_eq!(format!("{n:x}"), hex);
+    assert_eq!(format!("{n:X}"), upper_hex);
+    assert_eq!(format!("{n:o}"), octal);
+    assert_eq!(format!("{n:b}"), binary);
+    assert_eq!(format!("{n:#x}"), format!("0x{hex}"));
+    assert_eq!(n.to_str_radix(16), hex);
+    assert_eq!(U256::from_str_radix(hex, 16), Ok(n));
+    assert_eq!(U256::from_str_radix(octal, 8), Ok(n));
+    assert_eq!(U256::from_str_radix(binary, 2), Ok(n));
+}
+
+#[rstest(::trace)]
+#[case(u256!(0), 36, "0")]
+#[case(u256!(35), 36, "z")]
+#[case(u256!(1295), 36, "zz")]
+#[case(u256!(80), 3, "2222")]
+#[case(U256::MAX, 36, "6dp5qcb22im238nr3wvp0ic7q99w035jmy2iw7i6n43d37jtof")]
+fn test_to_str_radix(#[case] n: U256, #[case] radix: u32, #[case] expected: &str) {
+    assert_eq!(n.to_str_radix(radix), expected);
+    assert_eq!(U256::from_str_radix(expected, radix), Ok(n));
+
+    let mut buf = [0; U256::MAX_STR_LEN];
+    assert_eq!(n.to_str_radix_buf(radix, &mut buf), expected);
+}
+
+#[rstest(::trace)]
+fn test_to_str_radix_buf_max_len() {
+    let mut buf = [0; U256::MAX_STR_LEN];
+
+    for radix in 2..=36 {
+        assert_eq!(
+            U256::MAX.to_str_radix_buf(radix, &mut buf),
+            U256::MAX.to_str_radix(radix)
+        );
+    }
+}
+
+#[rstest(::trace)]
+fn test_to_str_radix_buf_const() {
+    const fn hex(value: U256, buf: &mut [u8]) -> &str {
+        value.to_str_radix_buf(16, buf)
+    }
+
+    const LEN: usize = hex(u256!(0xcafe), &mut [0; U256::MAX_STR_LEN]).len();
+    assert_eq!(LEN, 4);
+}
+
+#[rstest(::trace)]
+#[should_panic]
+fn test_to_str_radix_buf_short() {
+    let mut buf = [0; 2];
+    let _ = u256!(255).to_str_radix_buf(10, &mut buf);
+}
+
+#[rstest(::trace)]
+#[case(u256!(0), "0e0")]
+#[case(u256!(7), "7e0")]
+#[case(u256!(1200), "1.2e3")]
+#[case(u256!(123456789), "1.23456789e8")]
+fn test_exp(#[case] n: U256, #[case] expected: &str) {
+    assert_eq!(format!("{n:e}"), expected);
+    assert_eq!(format!("{n:E}"), expected.to_uppercase());
+}
+
+#[rstest(::trace)]
+fn test_padding() {
+    let n = u256!(42);
+    assert_eq!(format!("{n:>6}"), "    42");
+    assert_eq!(format!("{n:06}"), "000042");
+    assert_eq!(format!("{n:+}"), "+42");
+    assert_eq!(format!("{n:#010x}"), "0x0000002a");
+}
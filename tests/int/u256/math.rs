@@ -0,0 +1,153 @@
+use rstest::*;
+
+use fastnum::{test_util::Xorshift, u256, U128, U256};
+
+/// Deterministic xorshift sequence of operands covering both digits.
+fn operands() -> impl Iterator<Item = u128> {
+    let mut rng = Xorshift::new(0x2545_f491_4f6c_dd1d);
+
+    (0..2000).map(move |i| {
+        let n = rng.next_u128();
+        // Mix in short values and runs of ones, which exercise carries.
+        match i % 4 {
+            0 => n,
+            1 => n >> (rng.next_u64() % 128),
+            2 => u128::MAX >> (rng.next_u64() % 128),
+            _ => rng.next_u64() as u128,
+        }
+    })
+}
+
+fn u(n: u128) -> U128 {
+    U128::from(n)
+}
+
+#[rstest(::trace)]
+fn test_u128_oracle() {
+    let lhs: Vec<u128> = operands().collect();
+    let rhs: Vec<u128> = operands().skip(1).chain([0]).collect();
+
+    for (&a, &b) in lhs.iter().zip(rhs.iter()) {
+        let (x, y) = (u(a), u(b));
+
+        assert_eq!(
+            x.overflowing_add(y),
+            (u(a.overflowing_add(b).0), a.overflowing_add(b).1)
+        );
+        assert_eq!(
+            x.overflowing_sub(y),
+            (u(a.overflowing_sub(b).0), a.overflowing_sub(b).1)
+        );
+        assert_eq!(
+            x.overflowing_mul(y),
+            (u(a.overflowing_mul(b).0), a.overflowing_mul(b).1)
+        );
+        assert_eq!(x.checked_div(y), a.checked_div(b).map(u));
+        assert_eq!(x.checked_rem(y), a.checked_rem(b).map(u));
+        assert_eq!(x.saturating_add(y), u(a.saturating_add(b)));
+        assert_eq!(x.saturating_sub(y), u(a.saturating_sub(b)));
+        assert_eq!(x.abs_diff(y), u(a.abs_diff(b)));
+        assert_eq!(x.cmp(&y), a.cmp(&b));
+        assert_eq!(x & y, u(a & b));
+        assert_eq!(x | y, u(a | b));
+        assert_eq!(x ^ y, u(a ^ b));
+        assert_eq!(!x, u(!a));
+
+        let shift = (b % 128) as u32;
+        assert_eq!(x << shift, u(a << shift));
+        assert_eq!(x >> shift, u(a >> shift));
+        assert_eq!(x.rotate_left(shift), u(a.rotate_left(shift)));
+        assert_eq!(x.rotate_right(shift), u(a.rotate_right(shift)));
+
+        assert_eq!(x.count_ones(), a.count_ones());
+        assert_eq!(x.leading_zeros(), a.leading_zeros());
+        assert_eq!(x.trailing_zeros(), a.trailing_zeros());
+        assert_eq!(x.reverse_bits(), u(a.reverse_bits()));
+        assert_eq!(x.checked_ilog2(), a.checked_ilog2());
+        assert_eq!(x.checked_ilog10(), a.checked_ilog10());
+        assert_eq!(
+            x.checked_next_power_of_two(),
+            a.checked_next_power_of_two().map(u)
+        );
+
+        let exp = (b % 8) as u32;
+        assert_eq!(
+            x.overflowing_pow(exp),
+            (u(a.overflowing_pow(exp).0), a.overflowing_pow(exp).1)
+        );
+
+        assert_eq!(u128::try_from(x), Ok(a));
+        assert_eq!(x.to_string(), a.to_string());
+        assert_eq!(format!("{x:x}"), format!("{a:x}"));
+        assert_eq!(a.to_string().parse::<U128>(), Ok(x));
+    }
+}
+
+#[rstest(::trace)]
+fn test_div_rem_identity() {
+    for (a, b) in operands().zip(operands().skip(3)) {
+        let x = U256::from(a) << 100_u32 | U256::from(b);
+        let y = U256::from(b | 1);
+
+        let (q, r) = x.div_rem(y);
+        assert!(r < y);
+        assert_eq!(q * y + r, x);
+    }
+}
+
+#[rstest(::trace)]
+fn test_widening_mul() {
+    let (low, high) = U256::MAX.widening_mul(U256::MAX);
+    assert_eq!(low, U256::ONE);
+    assert_eq!(high, U256::MAX - U256::ONE);
+}
+
+#[rstest(::trace)]
+#[case(U256::MAX, U256::ONE, U256::ZERO, true)]
+#[case(u256!(1), u256!(2), u256!(3), false)]
+#[case(u256!(18446744073709551615), u256!(1), u256!(18446744073709551616), false)]
+fn test_overflowing_add(
+    #[case] a: U256,
+    #[case] b: U256,
+    #[case] sum: U256,
+    #[case] overflow: bool,
+) {
+    assert_eq!(a.overflowing_add(b), (sum, overflow));
+    assert_eq!(sum.overflowing_sub(b), (a, overflow));
+}
+
+#[rstest(::trace)]
+fn test_checked() {
+    assert_eq!(U256::MAX.checked_add(U256::ONE), None);
+    assert_eq!(U256::ZERO.checked_sub(U256::ONE), None);
+    assert_eq!(U256::MAX.checked_mul(U256::TWO), None);
+    assert_eq!(U256::ONE.checked_div(U256::ZERO), None);
+    assert_eq!(U256::ONE.checked_shl(256), None);
+    assert_eq!(U256::TWO.checked_pow(256), None);
+    assert_eq!(U256::TWO.checked_pow(255), Some(U256::ONE << 255));
+    assert_eq!(U256::MAX.saturating_mul(U256::TWO), U256::MAX);
+}
+
+#[rstest(::trace)]
+fn test_ilog() {
+    assert_eq!(U256::MAX.ilog10(), 77);
+    assert_eq!(U256::MAX.ilog2(), 255);
+    assert_eq!(u256!(1000).ilog(u256!(10)), 3);
+    assert_eq!(u256!(999).ilog(u256!(10)), 2);
+    assert_eq!(U256::ZERO.checked_ilog10(), None);
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_div_by_zero() {
+    let _ = U256::ONE / U256::ZERO;
+}
+
+#[rstest(::trace)]
+fn test_resize() {
+    let x = U256::from(u128::MAX);
+
+    assert_eq!(U128::try_from(x), Ok(U128::MAX));
+    assert!(U128::try_from(x + U256::ONE).is_err());
+    assert_eq!(U256::from(U128::MAX), x);
+}
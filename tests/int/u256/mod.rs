@@ -1,4 +1,8 @@
-// mod display;
-// mod math;
+mod ct;
+mod display;
+mod math;
+mod number_theory;
 mod parse;
+#[cfg(feature = "serde")]
+mod serde;
 mod smoke;
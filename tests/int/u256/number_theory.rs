@@ -0,0 +1,221 @@
+use rstest::*;
+
+use fastnum::{
+    i256,
+    int::{Montgomery, UInt},
+    test_util::Xorshift,
+    u256, I256, U1024, U128, U2048, U256, U512,
+};
+
+const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn mod_pow_u128(mut base: u128, mut exp: u128, modulus: u128) -> u128 {
+    let mut acc = 1 % modulus;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = acc * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    acc
+}
+
+fn is_prime_u64(n: u64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+#[rstest(::trace)]
+fn test_u128_oracle() {
+    let mut rng = Xorshift::new(SEED);
+
+    for i in 0..2000 {
+        let a = rng.next_u128() >> (i % 128);
+        let b = rng.next_u128() >> (rng.next_u64() % 128);
+        let (x, y) = (U128::from(a), U128::from(b));
+
+        let g = gcd_u128(a, b);
+        assert_eq!(x.gcd(y), U128::from(g));
+        assert_eq!(x.isqrt(), U128::from(a.isqrt()));
+
+        let (eg, s, t) = U256::from(a).extended_gcd(U256::from(b));
+        assert_eq!(eg, U256::from(g));
+        let signed = |n: u128| I256::try_from(n).unwrap();
+        assert_eq!(s * signed(a) + t * signed(b), signed(g));
+
+        // Moduli below 2^64 keep the oracle products within u128.
+        let modulus = (rng.next_u64() >> (rng.next_u64() % 64)).max(1) as u128;
+        let exp = a >> (rng.next_u64() % 128);
+        assert_eq!(
+            x.mod_pow(U128::from(exp), U128::from(modulus)),
+            U128::from(mod_pow_u128(a, exp, modulus)),
+            "{a}^{exp} mod {modulus}"
+        );
+
+        match x.mod_inv(U128::from(modulus)) {
+            Some(inv) => {
+                assert_eq!(gcd_u128(a, modulus), 1);
+                assert_eq!(x.mod_mul(inv, U128::from(modulus)), U128::from(1 % modulus));
+            }
+            None => assert_ne!(gcd_u128(a, modulus), 1),
+        }
+    }
+}
+
+#[rstest(::trace)]
+fn test_lcm() {
+    assert_eq!(u256!(4).lcm(u256!(6)), u256!(12));
+    assert_eq!(u256!(0).lcm(u256!(6)), u256!(0));
+    assert_eq!(U256::MAX.checked_lcm(U256::MAX), Some(U256::MAX));
+    assert_eq!(U256::MAX.checked_lcm(U256::MAX - U256::ONE), None);
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) attempt to calculate the least common multiple with overflow")]
+fn test_lcm_overflow() {
+    let _ = (U256::ONE << 255_u32).lcm(U256::THREE);
+}
+
+#[rstest(::trace)]
+#[should_panic(expected = "(fastnum) attempt to calculate the remainder with a divisor of zero")]
+fn test_mod_pow_zero_modulus() {
+    let _ = U256::TWO.mod_pow(U256::TWO, U256::ZERO);
+}
+
+#[rstest(::trace)]
+fn test_isqrt_bounds() {
+    assert_eq!(U256::MAX.isqrt(), U256::from(u128::MAX));
+    assert_eq!(
+        (U256::from(u128::MAX) * U256::from(u128::MAX)).isqrt(),
+        U256::from(u128::MAX)
+    );
+    assert_eq!(U2048::MAX.isqrt(), U2048::MAX >> 1024_u32);
+    assert_eq!(i256!(-4).checked_isqrt(), None);
+    assert_eq!(i256!(17).isqrt(), i256!(4));
+}
+
+#[rstest(::trace)]
+fn test_signed_gcd() {
+    assert_eq!(i256!(-12).gcd(i256!(18)), u256!(6));
+    assert_eq!(
+        i256!(-240).extended_gcd(i256!(-46)),
+        (u256!(2), i256!(9), i256!(-47))
+    );
+    assert_eq!(I256::MIN.gcd(i256!(0)), U256::ONE << 255_u32);
+}
+
+#[rstest(::trace)]
+fn test_small_primes() {
+    for n in 0..5000_u64 {
+        assert_eq!(U256::from(n).is_probable_prime(), is_prime_u64(n), "{n}");
+    }
+}
+
+#[rstest(::trace)]
+// Carmichael numbers.
+#[case(u256!(561))]
+#[case(u256!(41041))]
+#[case(u256!(825265))]
+#[case(u256!(321197185))]
+// Strong pseudoprimes to the bases from 2 to 23, and from 2 to 37.
+#[case(u256!(3825123056546413051))]
+#[case(u256!(318665857834031151167461))]
+#[case(u256!(3317044064679887385961981))]
+// Strong pseudoprimes to base 2 of the form (6k + 1)(12k + 1)(18k + 1).
+#[case(u256!(1296390242802544826734940689))]
+#[case(u256!(1296606082094757796247098489))]
+#[case(u256!(1296628795225868517080714521))]
+// Product of the Mersenne primes 2^61 - 1 and 2^89 - 1.
+#[case(u256!(1427247692705959880439315947500961989719490561))]
+fn test_composites(#[case] n: U256) {
+    assert!(!n.is_probable_prime());
+}
+
+#[rstest(::trace)]
+#[case(u256!(18446744073709551557))]
+#[case(u256!(170141183460469231731687303715884105727))]
+// 2^255 - 19
+#[case(u256!(57896044618658097711785492504343953926634992332820282019728792003956564819949))]
+// secp256k1 field prime
+#[case(u256!(115792089237316195423570985008687907853269984665640564039457584007908834671663))]
+fn test_primes(#[case] p: U256) {
+    assert!(p.is_probable_prime());
+
+    // Fermat's little theorem and inverses modulo a prime.
+    let mut rng = Xorshift::new(SEED);
+    for _ in 0..20 {
+        let a = U256::from_digits(core::array::from_fn(|_| rng.next_u64())) % p;
+        if a.is_zero() {
+            continue;
+        }
+
+        assert_eq!(a.mod_pow(p - U256::ONE, p), U256::ONE);
+        assert_eq!(a.mod_mul(a.mod_inv(p).unwrap(), p), U256::ONE);
+    }
+}
+
+#[rstest(::trace)]
+fn test_mersenne_primes() {
+    assert!((U1024::ONE << 521_u32)
+        .wrapping_sub(U1024::ONE)
+        .is_probable_prime());
+    assert!((U1024::ONE << 607_u32)
+        .wrapping_sub(U1024::ONE)
+        .is_probable_prime());
+    assert!(!(U1024::ONE << 523_u32)
+        .wrapping_sub(U1024::ONE)
+        .is_probable_prime());
+    assert!((U2048::ONE << 1279_u32)
+        .wrapping_sub(U2048::ONE)
+        .is_probable_prime());
+}
+
+fn check_montgomery<const N: usize>(modulus: UInt<N>) {
+    let ctx = Montgomery::new(modulus);
+    let mut rng = Xorshift::new(SEED);
+
+    for _ in 0..50 {
+        let a = UInt::<N>::from_digits(core::array::from_fn(|_| rng.next_u64())) % modulus;
+        let b = UInt::<N>::from_digits(core::array::from_fn(|_| rng.next_u64())) % modulus;
+
+        let product = ctx.from_montgomery(ctx.mul(ctx.to_montgomery(a), ctx.to_montgomery(b)));
+        assert_eq!(product, a.mod_mul(b, modulus));
+        assert_eq!(ctx.from_montgomery(ctx.to_montgomery(a)), a);
+        assert_eq!(ctx.sub(ctx.add(a, b), b), a);
+    }
+
+    assert_eq!(ctx.from_montgomery(ctx.one()), UInt::ONE);
+}
+
+#[rstest(::trace)]
+fn test_montgomery() {
+    check_montgomery(U256::MAX);
+    check_montgomery(U256::MAX >> 1_u32);
+    check_montgomery(U256::from(u64::MAX) + U256::TWO);
+    check_montgomery(U512::MAX - U512::TWO);
+    check_montgomery(U1024::from_digits([0xffff_ffff_ffff_ffc5; 16]));
+    check_montgomery(UInt::<1>::from_digit(3));
+}
+
+#[rstest(::trace)]
+fn test_montgomery_even_modulus() {
+    assert!(Montgomery::checked_new(u256!(10)).is_none());
+    assert!(Montgomery::checked_new(u256!(1)).is_none());
+
+    // Even moduli fall back to the plain method.
+    assert_eq!(u256!(3).mod_pow(u256!(200), u256!(1) << 200_u32), {
+        let mut acc = U256::ONE;
+        for _ in 0..200 {
+            acc = acc.wrapping_mul(u256!(3));
+        }
+        acc & ((U256::ONE << 200_u32) - U256::ONE)
+    });
+}
@@ -16,6 +16,24 @@ fn test_max_u256_hex() {
     assert_eq!(x, U256::MAX);
 }
 
+#[rstest]
+#[case(u256!(0xff), 255)]
+#[case(u256!(0o777), 511)]
+#[case(u256!(0b1010), 10)]
+#[case(u256!(0xdead_beef), 0xdead_beef)]
+#[case(u256!(0b_1111_0000), 0b_1111_0000)]
+#[case(u256!(1_000_000), 1_000_000)]
+#[case(u256!(0x0), 0)]
+fn test_prefixed_literals(#[case] n: U256, #[case] expected: u64) {
+    assert_eq!(n, U256::from(expected));
+}
+
+#[rstest]
+fn test_max_u256_binary() {
+    const X: U256 = u256!(0b1111111111111111111111111111111111111111111111111111111111111111_1111111111111111111111111111111111111111111111111111111111111111_1111111111111111111111111111111111111111111111111111111111111111_1111111111111111111111111111111111111111111111111111111111111111);
+    assert_eq!(X, U256::MAX);
+}
+
 #[rstest]
 #[should_panic(expected = "attempt to parse integer from string containing invalid digit")]
 fn test_u256_negative() {
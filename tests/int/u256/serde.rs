@@ -0,0 +1,71 @@
+use rstest::*;
+use serde::{Deserialize, Serialize};
+use serde_test::{Configure, Token};
+
+use fastnum::{u256, U256};
+
+#[rstest(::trace)]
+#[case(u256!(0), [0, 0, 0, 0])]
+#[case(u256!(1), [1, 0, 0, 0])]
+#[case(u256!(340282366920938463463374607431768211456), [0, 0, 1, 0])]
+#[case(U256::MAX, [u64::MAX; 4])]
+fn test_serde_bnum_layout(#[case] n: U256, #[case] digits: [u64; 4]) {
+    let mut tokens = vec![
+        Token::Struct {
+            name: "BUint",
+            len: 1,
+        },
+        Token::Str("digits"),
+        Token::Tuple { len: 4 },
+    ];
+    tokens.extend(digits.map(Token::U64));
+    tokens.extend([Token::TupleEnd, Token::StructEnd]);
+
+    serde_test::assert_tokens(&n.readable(), &tokens);
+    serde_test::assert_tokens(&n.compact(), &tokens);
+}
+
+#[rstest(::trace)]
+#[case(r#"{"digits":[1,0,1,0]}"#, u256!(340282366920938463463374607431768211457))]
+#[case(r#"[[1,0,0,0]]"#, u256!(1))]
+#[case(r#"12345"#, u256!(12345))]
+#[case(r#""340282366920938463463374607431768211456""#, u256!(340282366920938463463374607431768211456))]
+fn test_serde_deserialize_json(#[case] json: &str, #[case] expected: U256) {
+    assert_eq!(serde_json::from_str::<U256>(json).unwrap(), expected);
+}
+
+#[rstest(::trace)]
+#[case(r#"{"digits":[1,0,0]}"#)]
+#[case(r#"{"bits":[1,0,0,0]}"#)]
+#[case(r#"-1"#)]
+#[case(r#""1.5""#)]
+fn test_serde_deserialize_json_invalid(#[case] json: &str) {
+    assert!(serde_json::from_str::<U256>(json).is_err());
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Amount {
+    #[serde(with = "fastnum::int::serde::string")]
+    value: U256,
+}
+
+#[rstest(::trace)]
+#[case(u256!(0), "0")]
+#[case(
+    U256::MAX,
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+)]
+fn test_serde_string(#[case] value: U256, #[case] expected: &'static str) {
+    serde_test::assert_tokens(
+        &Amount { value },
+        &[
+            Token::Struct {
+                name: "Amount",
+                len: 1,
+            },
+            Token::Str("value"),
+            Token::Str(expected),
+            Token::StructEnd,
+        ],
+    );
+}
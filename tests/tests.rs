@@ -1,2 +1,2 @@
 mod decimal;
-// mod int;
+mod int;